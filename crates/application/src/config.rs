@@ -1,24 +1,257 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use domain::value_object::{Amount, Margin};
+use domain::value_object::{Amount, MAX_BET_AMOUNT, Margin};
 
 #[derive(Deserialize)]
 pub struct AppConfig {
     pub coefficient: CoefficientConfig,
     pub setup: SetupConfig,
+    #[serde(default)]
+    pub storage: StorageConfig,
+    #[serde(default)]
+    pub auth: AuthConfig,
+    #[serde(default)]
+    pub leaderboard: LeaderboardConfig,
+    #[serde(default)]
+    pub deposit: DepositConfig,
+    #[serde(default)]
+    pub limit: LimitConfig,
+    #[serde(default)]
+    pub autoplay: AutoplayConfig,
+    #[serde(default)]
+    pub exposure: ExposureConfig,
+    #[serde(default)]
+    pub admin: AdminConfig,
+    #[serde(default)]
+    pub engine: EngineConfig,
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct CoefficientConfig {
     pub tracked_games: u8,
     pub margin: Margin,
+    #[serde(default)]
+    pub margin_method: MarginMethod,
     pub alpha: i32,
     pub totals: Vec<u8>,
     pub deviation_min: f64,
     pub deviation_max: f64,
+    #[serde(default)]
+    pub shading: ShadingConfig,
+}
+
+/// How the margin is spread over the outcomes of a market, the book adding up to
+/// `1 / (1 - margin)` whichever is picked.
+#[derive(Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum MarginMethod {
+    /// Every probability scaled up by the same factor.
+    #[default]
+    Proportional,
+    /// Margin shared out in proportion to the fair odds, longshots carrying most of it.
+    MarginWeighted,
+    /// Shin's model of a bookmaker guarding against insiders.
+    Shin,
+    /// Every probability raised to the same power.
+    Power,
+    /// Odds in favour of every outcome scaled by the same ratio.
+    OddsRatio,
 }
 
+/// Moves offers away from the outcomes the book would lose most on, off while
+/// `sensitivity` is zero.
+#[derive(Serialize, Deserialize, Clone, Copy, Default)]
+pub struct ShadingConfig {
+    /// Implied probability an outcome gains for every unit of money the book stands to lose
+    /// on it, and gives up for every unit it stands to win.
+    pub sensitivity: f64,
+    /// Largest shift of an outcome's probability either way.
+    pub max_shift: f64,
+}
+
+/// How the games are played out, independent from the model the book prices them with.
 #[derive(Deserialize, Clone, Copy)]
+pub struct EngineConfig {
+    pub model: EngineModel,
+    /// Goal variance a negative binomial team has on top of the Poisson one, in units of
+    /// its mean goals squared.
+    pub dispersion: f64,
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        Self {
+            model: EngineModel::Classic,
+            dispersion: 0.1,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum EngineModel {
+    /// Winner drawn from the pricing formula with noise on top, then goals around the
+    /// averages of the teams to fit it.
+    #[default]
+    Classic,
+    /// Goals of each team drawn on their own around its average.
+    Poisson,
+    /// Poisson goals with an overdispersed, gamma distributed, mean.
+    NegativeBinomial,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
 pub struct SetupConfig {
     pub balance: Amount,
 }
+
+#[derive(Deserialize, Clone, Copy, Default)]
+pub struct StorageConfig {
+    pub backend: StorageBackend,
+}
+
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    #[default]
+    Database,
+    Memory,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+pub struct AuthConfig {
+    pub session_ttl_secs: i64,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            session_ttl_secs: 24 * 60 * 60,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy)]
+pub struct LeaderboardConfig {
+    /// Settled bets a simulation needs inside the window to be ranked.
+    pub min_bets: usize,
+    /// Rounds a simulation needs to have played to be ranked.
+    pub min_rounds: u32,
+    pub page_size: usize,
+    pub max_page_size: usize,
+}
+
+impl Default for LeaderboardConfig {
+    fn default() -> Self {
+        Self {
+            min_bets: 5,
+            min_rounds: 1,
+            page_size: 20,
+            max_page_size: 100,
+        }
+    }
+}
+
+/// Bounds of a single deposit, in cents.
+#[derive(Deserialize, Clone, Copy)]
+pub struct DepositConfig {
+    pub min_amount: i64,
+    pub max_amount: i64,
+}
+
+impl Default for DepositConfig {
+    fn default() -> Self {
+        Self {
+            min_amount: 10_00,
+            max_amount: 1_000_000,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy)]
+pub struct LimitConfig {
+    /// Rounds a relaxed limit waits before taking effect, tightened ones apply at once.
+    pub relax_delay_rounds: u32,
+}
+
+impl Default for LimitConfig {
+    fn default() -> Self {
+        Self {
+            relax_delay_rounds: 3,
+        }
+    }
+}
+
+/// Stakes are in cents.
+#[derive(Deserialize, Clone, Copy)]
+pub struct AutoplayConfig {
+    /// Rounds a single autoplay may play.
+    pub max_rounds: u32,
+    /// Stake on the favourite of every game.
+    pub flat_stake: i64,
+    /// Stake after a win, doubled after every loss.
+    pub martingale_stake: i64,
+    /// Share of the full Kelly stake to bet.
+    pub kelly_fraction: f64,
+    pub value_stake: i64,
+    /// Expected return over the stake an offer must promise to be bet on.
+    pub value_min_edge: f64,
+}
+
+impl Default for AutoplayConfig {
+    fn default() -> Self {
+        Self {
+            max_rounds: 100,
+            flat_stake: 20_00,
+            martingale_stake: 10_00,
+            kelly_fraction: 0.5,
+            value_stake: 20_00,
+            value_min_edge: 0.0,
+        }
+    }
+}
+
+/// What the book agrees to stand to lose, in cents. Liabilities count the unsettled bets of
+/// a simulation.
+#[derive(Deserialize, Clone, Copy)]
+pub struct ExposureConfig {
+    /// Stake of a single bet.
+    pub max_stake: i64,
+    /// Stake times coefficient of a single bet.
+    pub max_payout: i64,
+    /// Loss of the book if a single outcome comes in.
+    pub max_event_liability: i64,
+    /// Loss of the book on a game, every market settled the worst way for it.
+    pub max_game_liability: i64,
+    /// Loss of the book on the games of a round, each settled the worst way for it.
+    pub max_round_liability: i64,
+}
+
+impl Default for ExposureConfig {
+    fn default() -> Self {
+        Self {
+            max_stake: MAX_BET_AMOUNT,
+            max_payout: 5_000_000,
+            max_event_liability: 2_500_000,
+            max_game_liability: 5_000_000,
+            max_round_liability: 10_000_000,
+        }
+    }
+}
+
+/// Who may see the house-side reports.
+#[derive(Deserialize, Clone)]
+pub struct AdminConfig {
+    pub logins: Vec<String>,
+    /// Simulations listed among the biggest winners of the book report.
+    pub top_winners: usize,
+}
+
+impl Default for AdminConfig {
+    fn default() -> Self {
+        Self {
+            logins: vec![],
+            top_winners: 10,
+        }
+    }
+}
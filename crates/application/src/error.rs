@@ -0,0 +1,342 @@
+use std::fmt;
+
+use crate::service::{BetValidationError, LiabilityScope};
+use domain::error::DomainError;
+use domain::value_object::LimitKind;
+
+/// How a failure should be reported to the caller.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    Validation,
+    NotFound,
+    Conflict,
+    InsufficientFunds,
+    /// Well-formed, but contradicting the rules of the game.
+    Unprocessable,
+    Unauthorized,
+    /// Refused by a limit or for lack of rights.
+    Forbidden,
+    Internal,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Entity {
+    Bet,
+    Game,
+    GameStat,
+    Simulation,
+    Team,
+    User,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AppError {
+    NotFound(Entity),
+    RoundAlreadyRandomized,
+    RoundNotRandomized,
+    /// Unplayed round already holds bets autoplay didn't place.
+    RoundHasOpenBets,
+    InvalidLogin,
+    WeakPassword {
+        min_len: usize,
+    },
+    LoginTaken,
+    InvalidCredentials,
+    Unauthorized,
+    InvalidSimulationName {
+        max_len: usize,
+    },
+    SimulationNameTaken,
+    SimulationArchived,
+    InvalidDocument,
+    UnsupportedDocumentVersion {
+        version: u32,
+    },
+    /// Bounds are in cents.
+    ImportedStakeOutOfRange {
+        min: i64,
+        max: i64,
+    },
+    ImportedBetMismatch,
+    UnsupportedFormat,
+    InvalidForkRound {
+        max: u32,
+    },
+    UnsupportedMetric,
+    InvalidPage {
+        max_per_page: usize,
+    },
+    InvalidLimits,
+    /// Bounds are in cents.
+    InvalidDeposit {
+        min: i64,
+        max: i64,
+    },
+    UnsupportedStrategy,
+    InvalidAutoplayRounds {
+        max: u32,
+    },
+    UnsupportedOddsFormat,
+    AdminOnly,
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(entity) => write!(f, "{:?} not found", entity),
+            Self::RoundAlreadyRandomized => write!(f, "Last round already randomized"),
+            Self::RoundNotRandomized => write!(f, "Last round didn't random"),
+            Self::RoundHasOpenBets => write!(
+                f,
+                "Last round already has bets, play it out before autoplaying"
+            ),
+            Self::InvalidLogin => write!(f, "Login must be non-empty and without spaces"),
+            Self::WeakPassword { min_len } => {
+                write!(f, "Password must be at least {} characters", min_len)
+            }
+            Self::LoginTaken => write!(f, "Login already taken"),
+            Self::InvalidCredentials => write!(f, "Wrong login or password"),
+            Self::Unauthorized => write!(f, "Authentication required"),
+            Self::InvalidSimulationName { max_len } => write!(
+                f,
+                "Simulation name must be non-empty and at most {} characters",
+                max_len
+            ),
+            Self::SimulationNameTaken => write!(f, "Simulation with this name already exists"),
+            Self::SimulationArchived => write!(f, "Simulation is archived and read-only"),
+            Self::InvalidDocument => write!(f, "Simulation document is malformed"),
+            Self::UnsupportedDocumentVersion { version } => {
+                write!(f, "Simulation document version {} isn't supported", version)
+            }
+            Self::ImportedStakeOutOfRange { min, max } => write!(
+                f,
+                "Imported stakes must be from {} to {}",
+                *min as f64 / 100.0,
+                *max as f64 / 100.0
+            ),
+            Self::ImportedBetMismatch => write!(
+                f,
+                "Imported bet is settled against the score of its game the wrong way"
+            ),
+            Self::UnsupportedFormat => write!(f, "Format must be json or msgpack"),
+            Self::InvalidForkRound { max } => {
+                write!(f, "Round to fork at must be at most {}", max)
+            }
+            Self::UnsupportedMetric => write!(f, "Metric must be balance, roi or profit"),
+            Self::InvalidPage { max_per_page } => write!(
+                f,
+                "Pages are numbered from 1 and hold from 1 to {} entries",
+                max_per_page
+            ),
+            Self::InvalidLimits => write!(
+                f,
+                "Limits must be positive and a loss limit must span at least one round"
+            ),
+            Self::InvalidDeposit { min, max } => write!(
+                f,
+                "Deposit must be from {} to {}",
+                *min as f64 / 100.0,
+                *max as f64 / 100.0
+            ),
+            Self::UnsupportedStrategy => {
+                write!(f, "Strategy must be flat, martingale, kelly or value")
+            }
+            Self::UnsupportedOddsFormat => write!(
+                f,
+                "Odds format must be decimal, fractional, american, hong_kong, malay or indonesian"
+            ),
+            Self::InvalidAutoplayRounds { max } => {
+                write!(f, "Autoplay must run from 1 to {} rounds", max)
+            }
+            Self::AdminOnly => write!(f, "Only administrators may do this"),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+/// Typed error with a kind and a stable machine-readable code.
+pub trait Classify {
+    fn kind(&self) -> ErrorKind;
+
+    fn code(&self) -> &'static str;
+}
+
+impl Classify for AppError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::NotFound(_) => ErrorKind::NotFound,
+            Self::RoundAlreadyRandomized
+            | Self::RoundNotRandomized
+            | Self::RoundHasOpenBets
+            | Self::LoginTaken
+            | Self::SimulationNameTaken
+            | Self::SimulationArchived => ErrorKind::Conflict,
+            Self::InvalidLogin
+            | Self::WeakPassword { .. }
+            | Self::InvalidSimulationName { .. }
+            | Self::InvalidDocument
+            | Self::UnsupportedDocumentVersion { .. }
+            | Self::UnsupportedFormat
+            | Self::InvalidForkRound { .. }
+            | Self::UnsupportedMetric
+            | Self::InvalidPage { .. }
+            | Self::InvalidLimits
+            | Self::InvalidDeposit { .. }
+            | Self::UnsupportedStrategy
+            | Self::InvalidAutoplayRounds { .. }
+            | Self::UnsupportedOddsFormat => ErrorKind::Validation,
+            Self::ImportedStakeOutOfRange { .. } | Self::ImportedBetMismatch => {
+                ErrorKind::Unprocessable
+            }
+            Self::InvalidCredentials | Self::Unauthorized => ErrorKind::Unauthorized,
+            Self::AdminOnly => ErrorKind::Forbidden,
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            Self::NotFound(Entity::Bet) => "bet_not_found",
+            Self::NotFound(Entity::Game) => "game_not_found",
+            Self::NotFound(Entity::GameStat) => "game_stat_not_found",
+            Self::NotFound(Entity::Simulation) => "simulation_not_found",
+            Self::NotFound(Entity::Team) => "team_not_found",
+            Self::NotFound(Entity::User) => "user_not_found",
+            Self::RoundAlreadyRandomized => "round_already_randomized",
+            Self::RoundNotRandomized => "round_not_randomized",
+            Self::RoundHasOpenBets => "round_has_open_bets",
+            Self::InvalidLogin => "invalid_login",
+            Self::WeakPassword { .. } => "weak_password",
+            Self::LoginTaken => "login_taken",
+            Self::InvalidCredentials => "invalid_credentials",
+            Self::Unauthorized => "unauthorized",
+            Self::InvalidSimulationName { .. } => "invalid_simulation_name",
+            Self::SimulationNameTaken => "simulation_name_taken",
+            Self::SimulationArchived => "simulation_archived",
+            Self::InvalidDocument => "invalid_document",
+            Self::UnsupportedDocumentVersion { .. } => "unsupported_document_version",
+            Self::ImportedStakeOutOfRange { .. } => "imported_stake_out_of_range",
+            Self::ImportedBetMismatch => "imported_bet_mismatch",
+            Self::UnsupportedFormat => "unsupported_format",
+            Self::InvalidForkRound { .. } => "invalid_fork_round",
+            Self::UnsupportedMetric => "unsupported_metric",
+            Self::InvalidPage { .. } => "invalid_page",
+            Self::InvalidLimits => "invalid_limits",
+            Self::InvalidDeposit { .. } => "invalid_deposit",
+            Self::UnsupportedStrategy => "unsupported_strategy",
+            Self::InvalidAutoplayRounds { .. } => "invalid_autoplay_rounds",
+            Self::UnsupportedOddsFormat => "unsupported_odds_format",
+            Self::AdminOnly => "admin_only",
+        }
+    }
+}
+
+impl Classify for BetValidationError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::StakeTooLow { .. }
+            | Self::StakeTooHigh { .. }
+            | Self::PayoutTooHigh { .. }
+            | Self::MarketClosed => ErrorKind::Validation,
+            Self::InsufficientFunds { .. } => ErrorKind::InsufficientFunds,
+            Self::LimitExceeded { .. }
+            | Self::CoolingOff { .. }
+            | Self::LiabilityExceeded { .. } => ErrorKind::Forbidden,
+            Self::GameNotFound => ErrorKind::NotFound,
+            Self::GameNotInCurrentRound
+            | Self::GameAlreadyPlayed
+            | Self::DuplicateSelection
+            | Self::CoefficientChanged { .. } => ErrorKind::Conflict,
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            Self::StakeTooLow { .. } => "stake_too_low",
+            Self::StakeTooHigh { .. } => "stake_too_high",
+            Self::InsufficientFunds { .. } => "insufficient_funds",
+            Self::GameNotFound => "game_not_found",
+            Self::GameNotInCurrentRound => "game_not_in_current_round",
+            Self::GameAlreadyPlayed => "game_already_played",
+            Self::DuplicateSelection => "duplicate_selection",
+            Self::MarketClosed => "market_closed",
+            Self::CoefficientChanged { .. } => "coefficient_changed",
+            Self::LimitExceeded {
+                limit: LimitKind::Stake,
+                ..
+            } => "stake_limit_exceeded",
+            Self::LimitExceeded {
+                limit: LimitKind::RoundStake,
+                ..
+            } => "round_stake_limit_exceeded",
+            Self::LimitExceeded {
+                limit: LimitKind::Loss,
+                ..
+            } => "loss_limit_exceeded",
+            Self::LimitExceeded {
+                limit: LimitKind::RoundDeposit,
+                ..
+            } => "round_deposit_limit_exceeded",
+            Self::CoolingOff { .. } => "cooling_off",
+            Self::PayoutTooHigh { .. } => "payout_too_high",
+            Self::LiabilityExceeded {
+                scope: LiabilityScope::Event,
+                ..
+            } => "event_liability_exceeded",
+            Self::LiabilityExceeded {
+                scope: LiabilityScope::Game,
+                ..
+            } => "game_liability_exceeded",
+            Self::LiabilityExceeded {
+                scope: LiabilityScope::Round,
+                ..
+            } => "round_liability_exceeded",
+        }
+    }
+}
+
+impl Classify for DomainError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidAmount | Self::InvalidCoefficient | Self::InvalidMargin => {
+                ErrorKind::Validation
+            }
+            Self::InsufficientFunds { .. } => ErrorKind::InsufficientFunds,
+            Self::TotalsMismatch => ErrorKind::Internal,
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidAmount => "invalid_amount",
+            Self::InvalidCoefficient => "invalid_coefficient",
+            Self::InvalidMargin => "invalid_margin",
+            Self::TotalsMismatch => "totals_mismatch",
+            Self::InsufficientFunds { .. } => "insufficient_funds",
+        }
+    }
+}
+
+/// Kind and code of the first typed error in the chain, `internal_error` if none.
+pub fn classify(error: &anyhow::Error) -> (ErrorKind, &'static str) {
+    for cause in error.chain() {
+        let typed = cause
+            .downcast_ref::<AppError>()
+            .map(|e| (e.kind(), e.code()))
+            .or_else(|| {
+                cause
+                    .downcast_ref::<BetValidationError>()
+                    .map(|e| (e.kind(), e.code()))
+            })
+            .or_else(|| {
+                cause
+                    .downcast_ref::<DomainError>()
+                    .map(|e| (e.kind(), e.code()))
+            });
+        if let Some(typed) = typed {
+            return typed;
+        }
+    }
+
+    (ErrorKind::Internal, "internal_error")
+}
@@ -1,4 +1,6 @@
 pub mod config;
+pub mod error;
 pub mod repository;
 pub mod service;
+pub mod strategy;
 pub mod usecase;
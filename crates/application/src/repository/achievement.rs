@@ -0,0 +1,38 @@
+use anyhow::Result;
+
+use domain::{
+    entity::{Achievement, Simulation},
+    value_object::Id,
+};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait IAchievementRepo {
+    fn add(&self, achievement: Achievement) -> Result<()>;
+
+    /// Ordered by the round they were unlocked in.
+    fn achievements_by_simulation_id(
+        &self,
+        simulation_id: Id<Simulation>,
+    ) -> Result<Vec<Achievement>>;
+
+    fn next_id(&self) -> Id<Achievement>;
+}
+
+impl<T: IAchievementRepo + ?Sized> IAchievementRepo for Box<T> {
+    fn add(&self, achievement: Achievement) -> Result<()> {
+        (**self).add(achievement)
+    }
+
+    fn achievements_by_simulation_id(
+        &self,
+        simulation_id: Id<Simulation>,
+    ) -> Result<Vec<Achievement>> {
+        (**self).achievements_by_simulation_id(simulation_id)
+    }
+
+    fn next_id(&self) -> Id<Achievement> {
+        (**self).next_id()
+    }
+}
@@ -0,0 +1,32 @@
+use anyhow::Result;
+
+use domain::{
+    entity::{AutoplayRun, Simulation},
+    value_object::Id,
+};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait IAutoplayRunRepo {
+    fn add(&self, run: AutoplayRun) -> Result<()>;
+
+    /// Ordered from the earliest run to the latest.
+    fn runs_by_simulation_id(&self, simulation_id: Id<Simulation>) -> Result<Vec<AutoplayRun>>;
+
+    fn next_id(&self) -> Id<AutoplayRun>;
+}
+
+impl<T: IAutoplayRunRepo + ?Sized> IAutoplayRunRepo for Box<T> {
+    fn add(&self, run: AutoplayRun) -> Result<()> {
+        (**self).add(run)
+    }
+
+    fn runs_by_simulation_id(&self, simulation_id: Id<Simulation>) -> Result<Vec<AutoplayRun>> {
+        (**self).runs_by_simulation_id(simulation_id)
+    }
+
+    fn next_id(&self) -> Id<AutoplayRun> {
+        (**self).next_id()
+    }
+}
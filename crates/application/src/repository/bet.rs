@@ -1,21 +1,80 @@
 use anyhow::Result;
 
 use domain::{
-    entity::Bet,
+    entity::{Bet, Game, Simulation},
     value_object::{Coefficient, Id},
 };
 #[cfg(test)]
 use mockall::automock;
 
+/// Settled bets of one simulation summed up, money in cents.
+#[derive(Clone, Copy)]
+pub struct SettledTotals {
+    pub simulation_id: Id<Simulation>,
+    pub bets: usize,
+    pub staked: i64,
+    /// Payouts of the won bets, stakes included.
+    pub returned: i64,
+}
+
 #[cfg_attr(test, automock)]
 pub trait IBetRepo {
     fn add(&self, bet: Bet) -> Result<()>;
 
+    /// Inserts every bet or none of them.
+    fn add_all(&self, bets: Vec<Bet>) -> Result<()>;
+
     fn update_status(&self, bet: Bet) -> Result<()>;
 
-    fn min_coefficient_lose(&self) -> Option<Coefficient>;
+    fn min_coefficient_lose(&self, simulation_id: Id<Simulation>) -> Option<Coefficient>;
+
+    fn not_calculated_bets(&self, simulation_id: Id<Simulation>) -> Vec<Bet>;
 
-    fn not_calculated_bets(&self) -> Vec<Bet>;
+    fn bets_by_game_id(&self, game_id: Id<Game>) -> Result<Vec<Bet>>;
+
+    fn bets_by_simulation_id(&self, simulation_id: Id<Simulation>) -> Result<Vec<Bet>>;
+
+    /// Settled bets of every active simulation that has any, only those on games of its last
+    /// `rounds` rounds if given.
+    fn settled_totals(&self, rounds: Option<u32>) -> Result<Vec<SettledTotals>>;
 
     fn next_id(&self) -> Id<Bet>;
 }
+
+impl<T: IBetRepo + ?Sized> IBetRepo for Box<T> {
+    fn add(&self, bet: Bet) -> Result<()> {
+        (**self).add(bet)
+    }
+
+    fn add_all(&self, bets: Vec<Bet>) -> Result<()> {
+        (**self).add_all(bets)
+    }
+
+    fn update_status(&self, bet: Bet) -> Result<()> {
+        (**self).update_status(bet)
+    }
+
+    fn min_coefficient_lose(&self, simulation_id: Id<Simulation>) -> Option<Coefficient> {
+        (**self).min_coefficient_lose(simulation_id)
+    }
+
+    fn not_calculated_bets(&self, simulation_id: Id<Simulation>) -> Vec<Bet> {
+        (**self).not_calculated_bets(simulation_id)
+    }
+
+    fn bets_by_game_id(&self, game_id: Id<Game>) -> Result<Vec<Bet>> {
+        (**self).bets_by_game_id(game_id)
+    }
+
+    fn bets_by_simulation_id(&self, simulation_id: Id<Simulation>) -> Result<Vec<Bet>> {
+        (**self).bets_by_simulation_id(simulation_id)
+    }
+
+    fn settled_totals(&self, rounds: Option<u32>) -> Result<Vec<SettledTotals>> {
+        (**self).settled_totals(rounds)
+    }
+
+    fn next_id(&self) -> Id<Bet> {
+        (**self).next_id()
+    }
+}
@@ -0,0 +1,27 @@
+use anyhow::Result;
+
+use domain::{
+    entity::Simulation,
+    value_object::{BetLimits, Id},
+};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait IBetLimitRepo {
+    /// No limits if none were ever set.
+    fn limits_by_simulation_id(&self, simulation_id: Id<Simulation>) -> Result<BetLimits>;
+
+    /// Replaces the limits of `simulation_id`.
+    fn set_limits(&self, simulation_id: Id<Simulation>, limits: BetLimits) -> Result<()>;
+}
+
+impl<T: IBetLimitRepo + ?Sized> IBetLimitRepo for Box<T> {
+    fn limits_by_simulation_id(&self, simulation_id: Id<Simulation>) -> Result<BetLimits> {
+        (**self).limits_by_simulation_id(simulation_id)
+    }
+
+    fn set_limits(&self, simulation_id: Id<Simulation>, limits: BetLimits) -> Result<()> {
+        (**self).set_limits(simulation_id, limits)
+    }
+}
@@ -0,0 +1,39 @@
+use anyhow::Result;
+
+use domain::{
+    entity::{Deposit, Simulation},
+    value_object::{Amount, Id},
+};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait IDepositRepo {
+    fn add(&self, deposit: Deposit) -> Result<()>;
+
+    /// Ordered from the earliest deposit to the latest.
+    fn deposits_by_simulation_id(&self, simulation_id: Id<Simulation>) -> Result<Vec<Deposit>>;
+
+    /// Sum of the deposits of every active simulation that made any.
+    fn deposited_totals(&self) -> Result<Vec<(Id<Simulation>, Amount)>>;
+
+    fn next_id(&self) -> Id<Deposit>;
+}
+
+impl<T: IDepositRepo + ?Sized> IDepositRepo for Box<T> {
+    fn add(&self, deposit: Deposit) -> Result<()> {
+        (**self).add(deposit)
+    }
+
+    fn deposits_by_simulation_id(&self, simulation_id: Id<Simulation>) -> Result<Vec<Deposit>> {
+        (**self).deposits_by_simulation_id(simulation_id)
+    }
+
+    fn deposited_totals(&self) -> Result<Vec<(Id<Simulation>, Amount)>> {
+        (**self).deposited_totals()
+    }
+
+    fn next_id(&self) -> Id<Deposit> {
+        (**self).next_id()
+    }
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+
+use domain::{entity::Forecast, value_object::Id};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait IForecastRepo {
+    fn add_all(&self, forecasts: Vec<Forecast>) -> Result<()>;
+
+    /// Forecasts of every simulation, archived ones included.
+    fn all_forecasts(&self) -> Result<Vec<Forecast>>;
+
+    fn next_id(&self) -> Id<Forecast>;
+}
+
+impl<T: IForecastRepo + ?Sized> IForecastRepo for Box<T> {
+    fn add_all(&self, forecasts: Vec<Forecast>) -> Result<()> {
+        (**self).add_all(forecasts)
+    }
+
+    fn all_forecasts(&self) -> Result<Vec<Forecast>> {
+        (**self).all_forecasts()
+    }
+
+    fn next_id(&self) -> Id<Forecast> {
+        (**self).next_id()
+    }
+}
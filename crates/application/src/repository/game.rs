@@ -11,6 +11,9 @@ use mockall::automock;
 pub trait IGameRepo {
     fn add(&self, game: Game) -> Result<()>;
 
+    /// Inserts every game or none of them.
+    fn add_all(&self, games: Vec<Game>) -> Result<()>;
+
     fn game_by_id(&self, game_id: Id<Game>) -> Result<Game>;
 
     fn games_id_by_team_id(
@@ -23,6 +26,13 @@ pub trait IGameRepo {
     fn games_id_by_round(&self, round: u32, simulation_id: Id<Simulation>)
     -> Result<Vec<Id<Game>>>;
 
+    /// Games of rounds up to `max_round` inclusive, ordered by round.
+    fn games_by_simulation_id(
+        &self,
+        simulation_id: Id<Simulation>,
+        max_round: u32,
+    ) -> Result<Vec<Game>>;
+
     fn h2hs_id_by_team_id(
         &self,
         home_team_id: Id<Team>,
@@ -33,3 +43,56 @@ pub trait IGameRepo {
 
     fn next_id(&self) -> Id<Game>;
 }
+
+impl<T: IGameRepo + ?Sized> IGameRepo for Box<T> {
+    fn add(&self, game: Game) -> Result<()> {
+        (**self).add(game)
+    }
+
+    fn add_all(&self, games: Vec<Game>) -> Result<()> {
+        (**self).add_all(games)
+    }
+
+    fn game_by_id(&self, game_id: Id<Game>) -> Result<Game> {
+        (**self).game_by_id(game_id)
+    }
+
+    fn games_id_by_team_id(
+        &self,
+        team_id: Id<Team>,
+        simulation_id: Id<Simulation>,
+        cnt: u8,
+    ) -> Result<Vec<(Id<Game>, bool)>> {
+        (**self).games_id_by_team_id(team_id, simulation_id, cnt)
+    }
+
+    fn games_id_by_round(
+        &self,
+        round: u32,
+        simulation_id: Id<Simulation>,
+    ) -> Result<Vec<Id<Game>>> {
+        (**self).games_id_by_round(round, simulation_id)
+    }
+
+    fn games_by_simulation_id(
+        &self,
+        simulation_id: Id<Simulation>,
+        max_round: u32,
+    ) -> Result<Vec<Game>> {
+        (**self).games_by_simulation_id(simulation_id, max_round)
+    }
+
+    fn h2hs_id_by_team_id(
+        &self,
+        home_team_id: Id<Team>,
+        guest_team_id: Id<Team>,
+        simulation_id: Id<Simulation>,
+        cnt: u8,
+    ) -> Result<Vec<(Id<Game>, bool)>> {
+        (**self).h2hs_id_by_team_id(home_team_id, guest_team_id, simulation_id, cnt)
+    }
+
+    fn next_id(&self) -> Id<Game> {
+        (**self).next_id()
+    }
+}
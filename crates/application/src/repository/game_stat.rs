@@ -1,7 +1,7 @@
 use anyhow::Result;
 
 use domain::{
-    entity::{Game, GameStat},
+    entity::{Game, GameStat, Simulation},
     value_object::{Id, Winner},
 };
 #[cfg(test)]
@@ -11,8 +11,18 @@ use mockall::automock;
 pub trait IGameStatRepo {
     fn add(&self, game_stat: GameStat) -> Result<()>;
 
+    /// Inserts every game stat or none of them.
+    fn add_all(&self, game_stats: Vec<GameStat>) -> Result<()>;
+
     fn game_stat_by_game_id(&self, game_id: Id<Game>) -> Result<GameStat>;
 
+    /// Stats of the games played in rounds up to `max_round` inclusive.
+    fn game_stats_by_simulation_id(
+        &self,
+        simulation_id: Id<Simulation>,
+        max_round: u32,
+    ) -> Result<Vec<GameStat>>;
+
     fn winner_by_game_id(&self, game_id: Id<Game>, is_home: bool) -> Option<Winner>;
 
     fn score_by_game_id(&self, game_id: Id<Game>, is_home: bool) -> Option<(u8, u8)>;
@@ -21,3 +31,41 @@ pub trait IGameStatRepo {
 
     fn next_id(&self) -> Id<GameStat>;
 }
+
+impl<T: IGameStatRepo + ?Sized> IGameStatRepo for Box<T> {
+    fn add(&self, game_stat: GameStat) -> Result<()> {
+        (**self).add(game_stat)
+    }
+
+    fn add_all(&self, game_stats: Vec<GameStat>) -> Result<()> {
+        (**self).add_all(game_stats)
+    }
+
+    fn game_stat_by_game_id(&self, game_id: Id<Game>) -> Result<GameStat> {
+        (**self).game_stat_by_game_id(game_id)
+    }
+
+    fn game_stats_by_simulation_id(
+        &self,
+        simulation_id: Id<Simulation>,
+        max_round: u32,
+    ) -> Result<Vec<GameStat>> {
+        (**self).game_stats_by_simulation_id(simulation_id, max_round)
+    }
+
+    fn winner_by_game_id(&self, game_id: Id<Game>, is_home: bool) -> Option<Winner> {
+        (**self).winner_by_game_id(game_id, is_home)
+    }
+
+    fn score_by_game_id(&self, game_id: Id<Game>, is_home: bool) -> Option<(u8, u8)> {
+        (**self).score_by_game_id(game_id, is_home)
+    }
+
+    fn goals_by_game_id(&self, game_id: Id<Game>, is_home: bool) -> Option<u8> {
+        (**self).goals_by_game_id(game_id, is_home)
+    }
+
+    fn next_id(&self) -> Id<GameStat> {
+        (**self).next_id()
+    }
+}
@@ -1,22 +1,58 @@
+mod achievement;
+mod autoplay_run;
 mod bet;
+mod bet_limit;
+mod deposit;
+mod forecast;
 mod game;
 mod game_stat;
+mod odds_snapshot;
+mod session;
 mod simulation;
 mod team;
+mod unit_of_work;
+mod user;
 
-pub use bet::IBetRepo;
+pub use achievement::IAchievementRepo;
+pub use autoplay_run::IAutoplayRunRepo;
+pub use bet::{IBetRepo, SettledTotals};
+pub use bet_limit::IBetLimitRepo;
+pub use deposit::IDepositRepo;
+pub use forecast::IForecastRepo;
 pub use game::IGameRepo;
 pub use game_stat::IGameStatRepo;
+pub use odds_snapshot::IOddsSnapshotRepo;
+pub use session::ISessionRepo;
 pub use simulation::ISimulationRepo;
 pub use team::ITeamRepo;
+pub use unit_of_work::IUnitOfWork;
+pub use user::IUserRepo;
 
+#[cfg(test)]
+pub use achievement::MockIAchievementRepo;
+#[cfg(test)]
+pub use autoplay_run::MockIAutoplayRunRepo;
 #[cfg(test)]
 pub use bet::MockIBetRepo;
 #[cfg(test)]
+pub use bet_limit::MockIBetLimitRepo;
+#[cfg(test)]
+pub use deposit::MockIDepositRepo;
+#[cfg(test)]
+pub use forecast::MockIForecastRepo;
+#[cfg(test)]
 pub use game::MockIGameRepo;
 #[cfg(test)]
 pub use game_stat::MockIGameStatRepo;
 #[cfg(test)]
+pub use odds_snapshot::MockIOddsSnapshotRepo;
+#[cfg(test)]
+pub use session::MockISessionRepo;
+#[cfg(test)]
 pub use simulation::MockISimulationRepo;
 #[cfg(test)]
 pub use team::MockITeamRepo;
+#[cfg(test)]
+pub use unit_of_work::FakeUnitOfWork;
+#[cfg(test)]
+pub use user::MockIUserRepo;
@@ -0,0 +1,32 @@
+use anyhow::Result;
+
+use domain::{
+    entity::{Game, OddsSnapshot},
+    value_object::Id,
+};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait IOddsSnapshotRepo {
+    fn add_all(&self, snapshots: Vec<OddsSnapshot>) -> Result<()>;
+
+    /// Oldest first.
+    fn snapshots_by_game_id(&self, game_id: Id<Game>) -> Result<Vec<OddsSnapshot>>;
+
+    fn next_id(&self) -> Id<OddsSnapshot>;
+}
+
+impl<T: IOddsSnapshotRepo + ?Sized> IOddsSnapshotRepo for Box<T> {
+    fn add_all(&self, snapshots: Vec<OddsSnapshot>) -> Result<()> {
+        (**self).add_all(snapshots)
+    }
+
+    fn snapshots_by_game_id(&self, game_id: Id<Game>) -> Result<Vec<OddsSnapshot>> {
+        (**self).snapshots_by_game_id(game_id)
+    }
+
+    fn next_id(&self) -> Id<OddsSnapshot> {
+        (**self).next_id()
+    }
+}
@@ -0,0 +1,28 @@
+use anyhow::Result;
+
+use domain::entity::Session;
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait ISessionRepo {
+    fn add(&self, session: Session) -> Result<()>;
+
+    fn session_by_token(&self, token: &str) -> Option<Session>;
+
+    fn remove_by_token(&self, token: &str) -> Result<()>;
+}
+
+impl<T: ISessionRepo + ?Sized> ISessionRepo for Box<T> {
+    fn add(&self, session: Session) -> Result<()> {
+        (**self).add(session)
+    }
+
+    fn session_by_token(&self, token: &str) -> Option<Session> {
+        (**self).session_by_token(token)
+    }
+
+    fn remove_by_token(&self, token: &str) -> Result<()> {
+        (**self).remove_by_token(token)
+    }
+}
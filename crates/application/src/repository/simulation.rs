@@ -1,7 +1,9 @@
 use anyhow::Result;
-use std::net::IpAddr;
 
-use domain::{entity::Simulation, value_object::Id};
+use domain::{
+    entity::{Simulation, User},
+    value_object::Id,
+};
 #[cfg(test)]
 use mockall::automock;
 
@@ -9,7 +11,13 @@ use mockall::automock;
 pub trait ISimulationRepo {
     fn add(&self, simulation: Simulation) -> Result<()>;
 
-    fn simulation_by_ip(&self, ip: IpAddr) -> Option<Simulation>;
+    fn simulations_by_user_id(&self, user_id: Id<User>) -> Result<Vec<Simulation>>;
+
+    /// Not archived simulations of every user.
+    fn active_simulations(&self) -> Result<Vec<Simulation>>;
+
+    /// Simulations of every user, archived ones included.
+    fn all_simulations(&self) -> Result<Vec<Simulation>>;
 
     fn simulation_by_id(&self, id: Id<Simulation>) -> Result<Simulation>;
 
@@ -19,3 +27,37 @@ pub trait ISimulationRepo {
 
     fn next_id(&self) -> Id<Simulation>;
 }
+
+impl<T: ISimulationRepo + ?Sized> ISimulationRepo for Box<T> {
+    fn add(&self, simulation: Simulation) -> Result<()> {
+        (**self).add(simulation)
+    }
+
+    fn simulations_by_user_id(&self, user_id: Id<User>) -> Result<Vec<Simulation>> {
+        (**self).simulations_by_user_id(user_id)
+    }
+
+    fn active_simulations(&self) -> Result<Vec<Simulation>> {
+        (**self).active_simulations()
+    }
+
+    fn all_simulations(&self) -> Result<Vec<Simulation>> {
+        (**self).all_simulations()
+    }
+
+    fn simulation_by_id(&self, id: Id<Simulation>) -> Result<Simulation> {
+        (**self).simulation_by_id(id)
+    }
+
+    fn remove_by_id(&self, simulation_id: Id<Simulation>) {
+        (**self).remove_by_id(simulation_id)
+    }
+
+    fn update_by_id(&self, simulation: Simulation) -> Result<()> {
+        (**self).update_by_id(simulation)
+    }
+
+    fn next_id(&self) -> Id<Simulation> {
+        (**self).next_id()
+    }
+}
@@ -10,3 +10,13 @@ pub trait ITeamRepo {
 
     fn team_by_id(&self, id: Id<Team>) -> Result<Team>;
 }
+
+impl<T: ITeamRepo + ?Sized> ITeamRepo for Box<T> {
+    fn all_teams_id(&self) -> Vec<Id<Team>> {
+        (**self).all_teams_id()
+    }
+
+    fn team_by_id(&self, id: Id<Team>) -> Result<Team> {
+        (**self).team_by_id(id)
+    }
+}
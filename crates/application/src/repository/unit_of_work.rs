@@ -0,0 +1,24 @@
+use anyhow::Result;
+
+/// Runs several repository calls as one atomic change: either every write made
+/// inside `work` is kept or, if `work` fails, none of them is.
+pub trait IUnitOfWork {
+    fn atomically(&self, work: &mut dyn FnMut() -> Result<()>) -> Result<()>;
+}
+
+impl<T: IUnitOfWork + ?Sized> IUnitOfWork for Box<T> {
+    fn atomically(&self, work: &mut dyn FnMut() -> Result<()>) -> Result<()> {
+        (**self).atomically(work)
+    }
+}
+
+/// Test double that runs the work directly, without any rollback.
+#[cfg(test)]
+pub struct FakeUnitOfWork;
+
+#[cfg(test)]
+impl IUnitOfWork for FakeUnitOfWork {
+    fn atomically(&self, work: &mut dyn FnMut() -> Result<()>) -> Result<()> {
+        work()
+    }
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+
+use domain::{entity::User, value_object::Id};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait IUserRepo {
+    /// Fails with [`AppError::LoginTaken`](crate::error::AppError::LoginTaken) if the login
+    /// is already stored, however close the other insert came.
+    fn add(&self, user: User) -> Result<()>;
+
+    fn user_by_id(&self, id: Id<User>) -> Result<User>;
+
+    fn user_by_login(&self, login: &str) -> Option<User>;
+
+    fn remove_by_id(&self, user_id: Id<User>);
+
+    fn update_by_id(&self, user: User) -> Result<()>;
+
+    fn next_id(&self) -> Id<User>;
+}
+
+impl<T: IUserRepo + ?Sized> IUserRepo for Box<T> {
+    fn add(&self, user: User) -> Result<()> {
+        (**self).add(user)
+    }
+
+    fn user_by_id(&self, id: Id<User>) -> Result<User> {
+        (**self).user_by_id(id)
+    }
+
+    fn user_by_login(&self, login: &str) -> Option<User> {
+        (**self).user_by_login(login)
+    }
+
+    fn remove_by_id(&self, user_id: Id<User>) {
+        (**self).remove_by_id(user_id)
+    }
+
+    fn update_by_id(&self, user: User) -> Result<()> {
+        (**self).update_by_id(user)
+    }
+
+    fn next_id(&self) -> Id<User> {
+        (**self).next_id()
+    }
+}
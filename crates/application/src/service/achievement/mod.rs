@@ -0,0 +1,141 @@
+use anyhow::Result;
+use tracing::info;
+
+use super::now;
+use crate::{
+    config::SetupConfig,
+    repository::{IAchievementRepo, IBetRepo, ISimulationRepo},
+    usecase::TrackAchievements,
+};
+use domain::{
+    entity::{Achievement, Bet, Simulation},
+    value_object::{AchievementKind, Amount, Id, MIN_BET_AMOUNT},
+};
+
+/// Smallest coefficient of a winning bet that counts as a long shot, 10.00.
+const LONG_SHOT_COEFFICIENT: i32 = 10_00;
+
+/// What the rules see of a simulation.
+struct Progress {
+    simulation: Simulation,
+    bets: Vec<Bet>,
+    start_balance: Amount,
+}
+
+impl Progress {
+    fn won_bets(&self) -> impl Iterator<Item = &Bet> {
+        self.bets.iter().filter(|b| b.is_won() == Some(true))
+    }
+
+    /// Nothing is left to bet and no pending bet can bring money back.
+    fn is_bankrupt(&self) -> bool {
+        self.simulation.balance().clear_value() < MIN_BET_AMOUNT
+            && self.bets.iter().all(|b| b.is_won().is_some())
+    }
+
+    fn survived(&self, rounds: u32) -> bool {
+        self.simulation.round() >= rounds && !self.is_bankrupt()
+    }
+}
+
+struct Rule {
+    kind: AchievementKind,
+    is_met: fn(&Progress) -> bool,
+}
+
+const RULES: [Rule; 7] = [
+    Rule {
+        kind: AchievementKind::FirstWin,
+        is_met: |p| p.won_bets().next().is_some(),
+    },
+    Rule {
+        kind: AchievementKind::LongShot,
+        is_met: |p| {
+            p.won_bets()
+                .any(|b| b.coefficient().clear_value() >= LONG_SHOT_COEFFICIENT)
+        },
+    },
+    Rule {
+        kind: AchievementKind::DoubledBalance,
+        is_met: |p| p.simulation.balance().clear_value() >= 2 * p.start_balance.clear_value(),
+    },
+    Rule {
+        kind: AchievementKind::Survived10,
+        is_met: |p| p.survived(10),
+    },
+    Rule {
+        kind: AchievementKind::Survived50,
+        is_met: |p| p.survived(50),
+    },
+    Rule {
+        kind: AchievementKind::Survived100,
+        is_met: |p| p.survived(100),
+    },
+    Rule {
+        kind: AchievementKind::Bankrupt,
+        is_met: Progress::is_bankrupt,
+    },
+];
+
+pub struct AchievementService<A: IAchievementRepo, S: ISimulationRepo, B: IBetRepo> {
+    achievement_repo: A,
+    simulation_repo: S,
+    bet_repo: B,
+    config: SetupConfig,
+}
+
+impl<A: IAchievementRepo, S: ISimulationRepo, B: IBetRepo> TrackAchievements
+    for AchievementService<A, S, B>
+{
+    fn evaluate(&self, simulation_id: Id<Simulation>) -> Result<Vec<Achievement>> {
+        let unlocked = self
+            .achievement_repo
+            .achievements_by_simulation_id(simulation_id)?
+            .into_iter()
+            .map(|a| a.kind())
+            .collect::<Vec<_>>();
+        let progress = Progress {
+            simulation: self.simulation_repo.simulation_by_id(simulation_id)?,
+            bets: self.bet_repo.bets_by_simulation_id(simulation_id)?,
+            start_balance: self.config.balance,
+        };
+
+        let mut new = vec![];
+        for rule in RULES.iter().filter(|r| !unlocked.contains(&r.kind)) {
+            if !(rule.is_met)(&progress) {
+                continue;
+            }
+            let achievement = Achievement::new(
+                self.achievement_repo.next_id(),
+                simulation_id,
+                rule.kind,
+                progress.simulation.round(),
+                now(),
+            );
+            self.achievement_repo.add(achievement)?;
+            info!(kind = rule.kind.code(), "Achievement unlocked");
+            new.push(achievement);
+        }
+
+        Ok(new)
+    }
+
+    fn achievements(&self, simulation_id: Id<Simulation>) -> Result<Vec<Achievement>> {
+        self.achievement_repo
+            .achievements_by_simulation_id(simulation_id)
+    }
+}
+
+impl<A: IAchievementRepo, S: ISimulationRepo, B: IBetRepo> AchievementService<A, S, B> {
+    pub fn new(achievement_repo: A, simulation_repo: S, bet_repo: B, config: SetupConfig) -> Self {
+        Self {
+            achievement_repo,
+            simulation_repo,
+            bet_repo,
+            config,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,160 @@
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use super::super::*;
+use crate::repository::{MockIAchievementRepo, MockIBetRepo, MockISimulationRepo};
+use domain::value_object::{Event, MIN_BALANCE_AMOUNT, Winner};
+
+type Service = AchievementService<MockIAchievementRepo, MockISimulationRepo, MockIBetRepo>;
+
+fn simulation(balance: i64, round: u32) -> Simulation {
+    Simulation::new(
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        "main".to_string(),
+        Amount::new(balance, Some(MIN_BALANCE_AMOUNT)).unwrap(),
+        Some(round),
+    )
+}
+
+/// Bets are `(coefficient, is_won)`.
+fn bets(simulation: &Simulation, bets: &[(f64, Option<bool>)]) -> Vec<Bet> {
+    bets.iter()
+        .map(|&(coefficient, is_won)| {
+            Bet::new(
+                Uuid::now_v7().into(),
+                simulation.id(),
+                Amount::new(1000, Some(MIN_BET_AMOUNT)).unwrap(),
+                coefficient.try_into().unwrap(),
+                Uuid::now_v7().into(),
+                Event::WDL(Winner::W1),
+                is_won,
+            )
+        })
+        .collect()
+}
+
+/// Starts from 100.00; the returned list collects every persisted achievement.
+fn service(
+    simulation: Simulation,
+    bets: Vec<Bet>,
+    unlocked: Vec<AchievementKind>,
+) -> (Service, Arc<Mutex<Vec<Achievement>>>) {
+    let stored = unlocked
+        .into_iter()
+        .map(|kind| Achievement::new(Uuid::now_v7().into(), simulation.id(), kind, 0, 0))
+        .collect::<Vec<_>>();
+    let added = Arc::new(Mutex::new(stored));
+    let mut achievement_repo = MockIAchievementRepo::new();
+    let rec = added.clone();
+    achievement_repo
+        .expect_achievements_by_simulation_id()
+        .returning(move |_| Ok(rec.lock().unwrap().clone()));
+    achievement_repo
+        .expect_next_id()
+        .returning(|| <Id<Achievement>>::from(Uuid::now_v7()));
+    let rec = added.clone();
+    achievement_repo.expect_add().returning(move |a| {
+        rec.lock().unwrap().push(a);
+        Ok(())
+    });
+    let mut sim_repo = MockISimulationRepo::new();
+    sim_repo
+        .expect_simulation_by_id()
+        .returning(move |_| Ok(simulation.clone()));
+    let mut bet_repo = MockIBetRepo::new();
+    bet_repo
+        .expect_bets_by_simulation_id()
+        .returning(move |_| Ok(bets.clone()));
+    let config = SetupConfig {
+        balance: Amount::new(10000, Some(MIN_BALANCE_AMOUNT)).unwrap(),
+    };
+
+    (
+        AchievementService::new(achievement_repo, sim_repo, bet_repo, config),
+        added,
+    )
+}
+
+fn kinds(achievements: &[Achievement]) -> Vec<AchievementKind> {
+    achievements.iter().map(|a| a.kind()).collect()
+}
+
+#[test]
+fn nothing_unlocked() {
+    let sim = simulation(10000, 3);
+    let bets = bets(&sim, &[(2.0, Some(false)), (3.0, None)]);
+    let (service, added) = service(sim, bets, vec![]);
+
+    let new = service.evaluate(Uuid::now_v7().into()).unwrap();
+
+    assert!(new.is_empty());
+    assert!(added.lock().unwrap().is_empty());
+}
+
+#[test]
+fn unlock_wins() {
+    let sim = simulation(25000, 12);
+    let bets = bets(&sim, &[(1.5, Some(true)), (12.0, Some(true))]);
+    let (service, added) = service(sim, bets, vec![]);
+
+    let new = service.evaluate(Uuid::now_v7().into()).unwrap();
+
+    assert_eq!(
+        kinds(&new),
+        [
+            AchievementKind::FirstWin,
+            AchievementKind::LongShot,
+            AchievementKind::DoubledBalance,
+            AchievementKind::Survived10,
+        ]
+    );
+    assert!(new.iter().all(|a| a.round() == 12));
+    assert_eq!(added.lock().unwrap().len(), 4);
+}
+
+#[test]
+fn long_shot_needs_a_win() {
+    let sim = simulation(10000, 1);
+    let bets = bets(&sim, &[(15.0, Some(false)), (9.99, Some(true))]);
+    let (service, _) = service(sim, bets, vec![]);
+
+    let new = service.evaluate(Uuid::now_v7().into()).unwrap();
+
+    assert_eq!(kinds(&new), [AchievementKind::FirstWin]);
+}
+
+#[test]
+fn unlock_once() {
+    let sim = simulation(10000, 50);
+    let (service, added) = service(sim, vec![], vec![AchievementKind::Survived10]);
+
+    let first = service.evaluate(Uuid::now_v7().into()).unwrap();
+    let second = service.evaluate(Uuid::now_v7().into()).unwrap();
+
+    assert_eq!(kinds(&first), [AchievementKind::Survived50]);
+    assert!(second.is_empty());
+    assert_eq!(added.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn bankrupt() {
+    let sim = simulation(500, 60);
+    let bets = bets(&sim, &[(2.0, Some(false))]);
+    let (service, _) = service(sim, bets, vec![]);
+
+    let new = service.evaluate(Uuid::now_v7().into()).unwrap();
+
+    assert_eq!(kinds(&new), [AchievementKind::Bankrupt]);
+}
+
+#[test]
+fn pending_bet_keeps_hope() {
+    let sim = simulation(500, 10);
+    let bets = bets(&sim, &[(2.0, None)]);
+    let (service, _) = service(sim, bets, vec![]);
+
+    let new = service.evaluate(Uuid::now_v7().into()).unwrap();
+
+    assert_eq!(kinds(&new), [AchievementKind::Survived10]);
+}
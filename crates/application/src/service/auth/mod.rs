@@ -0,0 +1,117 @@
+use anyhow::{Result, anyhow, bail};
+use argon2::password_hash::{PasswordHash, SaltString};
+use argon2::{Argon2, PasswordHasher, PasswordVerifier};
+use rand::{Rng, rng};
+use tracing::{debug, info};
+
+use super::now;
+use crate::{
+    config::AuthConfig,
+    error::AppError,
+    repository::{ISessionRepo, IUserRepo},
+    usecase::Authenticate,
+};
+use domain::entity::{Session, User};
+
+const MIN_PASSWORD_LEN: usize = 8;
+const SALT_BYTES: usize = 16;
+const TOKEN_BYTES: usize = 32;
+
+pub struct AuthService<U: IUserRepo, S: ISessionRepo> {
+    user_repo: U,
+    session_repo: S,
+    config: AuthConfig,
+}
+
+impl<U: IUserRepo, S: ISessionRepo> Authenticate for AuthService<U, S> {
+    fn register(&self, login: &str, password: &str) -> Result<User> {
+        if login.is_empty() || login.contains(char::is_whitespace) {
+            bail!(AppError::InvalidLogin);
+        }
+        if password.chars().count() < MIN_PASSWORD_LEN {
+            bail!(AppError::WeakPassword {
+                min_len: MIN_PASSWORD_LEN
+            });
+        }
+        // Only spares the hashing, a concurrent registration is refused by the insert.
+        if self.user_repo.user_by_login(login).is_some() {
+            bail!(AppError::LoginTaken);
+        }
+        let salt_bytes: [u8; SALT_BYTES] = rng().random();
+        let salt = SaltString::encode_b64(&salt_bytes).map_err(|e| anyhow!("{e}"))?;
+        let password_hash = Argon2::default()
+            .hash_password(password.as_bytes(), &salt)
+            .map_err(|e| anyhow!("{e}"))?
+            .to_string();
+        debug!("Password hashed");
+        let id = self.user_repo.next_id();
+        let user = User::new(id, login.to_string(), password_hash);
+        self.user_repo.add(user.clone())?;
+        info!("User registered");
+
+        Ok(user)
+    }
+
+    fn login(&self, login: &str, password: &str) -> Result<Session> {
+        let user = self
+            .user_repo
+            .user_by_login(login)
+            .ok_or(AppError::InvalidCredentials)?;
+        // Legacy users carried over from simulations keyed by ip have no password at all.
+        let password_hash =
+            PasswordHash::new(user.password_hash()).map_err(|_| AppError::InvalidCredentials)?;
+        if Argon2::default()
+            .verify_password(password.as_bytes(), &password_hash)
+            .is_err()
+        {
+            bail!(AppError::InvalidCredentials);
+        }
+        debug!("Password verified");
+        let token = Self::generate_token();
+        let expires_at = now() + self.config.session_ttl_secs;
+        let session = Session::new(token, user.id(), expires_at);
+        self.session_repo.add(session.clone())?;
+        info!("User logged in");
+
+        Ok(session)
+    }
+
+    fn logout(&self, token: &str) -> Result<()> {
+        self.session_repo.remove_by_token(token)?;
+        info!("User logged out");
+
+        Ok(())
+    }
+
+    fn authenticate(&self, token: &str) -> Result<User> {
+        let session = self
+            .session_repo
+            .session_by_token(token)
+            .ok_or(AppError::Unauthorized)?;
+        if session.is_expired(now()) {
+            self.session_repo.remove_by_token(token)?;
+            debug!("Expired session removed");
+            bail!(AppError::Unauthorized);
+        }
+
+        self.user_repo.user_by_id(session.user_id())
+    }
+}
+
+impl<U: IUserRepo, S: ISessionRepo> AuthService<U, S> {
+    pub fn new(user_repo: U, session_repo: S, config: AuthConfig) -> Self {
+        Self {
+            user_repo,
+            session_repo,
+            config,
+        }
+    }
+
+    fn generate_token() -> String {
+        let bytes: [u8; TOKEN_BYTES] = rng().random();
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,237 @@
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use super::super::*;
+use crate::error::{ErrorKind, classify};
+use crate::repository::{MockISessionRepo, MockIUserRepo};
+use domain::value_object::Id;
+
+fn config() -> AuthConfig {
+    AuthConfig {
+        session_ttl_secs: 3600,
+    }
+}
+
+fn registered_user(password: &str) -> User {
+    let mut user_repo = MockIUserRepo::new();
+    user_repo.expect_user_by_login().returning(|_| None);
+    user_repo
+        .expect_next_id()
+        .returning(|| <Id<User>>::from(Uuid::now_v7()));
+    user_repo.expect_add().returning(|_| Ok(()));
+    let auth = AuthService::new(user_repo, MockISessionRepo::new(), config());
+
+    auth.register("player", password).unwrap()
+}
+
+#[test]
+fn register_success() {
+    let user = registered_user("correct horse");
+
+    assert_eq!(user.login(), "player");
+    assert_ne!(user.password_hash(), "correct horse");
+}
+
+#[test]
+fn register_login_taken() {
+    let user = registered_user("correct horse");
+    let mut user_repo = MockIUserRepo::new();
+    user_repo
+        .expect_user_by_login()
+        .returning(move |_| Some(user.clone()));
+    let auth = AuthService::new(user_repo, MockISessionRepo::new(), config());
+
+    let res = auth.register("player", "correct horse");
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Conflict, "login_taken")
+    );
+}
+
+#[test]
+fn register_login_taken_concurrently() {
+    let mut user_repo = MockIUserRepo::new();
+    user_repo.expect_user_by_login().returning(|_| None);
+    user_repo
+        .expect_next_id()
+        .returning(|| <Id<User>>::from(Uuid::now_v7()));
+    user_repo
+        .expect_add()
+        .returning(|_| Err(AppError::LoginTaken.into()));
+    let auth = AuthService::new(user_repo, MockISessionRepo::new(), config());
+
+    let res = auth.register("player", "correct horse");
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Conflict, "login_taken")
+    );
+}
+
+#[test]
+fn register_weak_password() {
+    let auth = AuthService::new(MockIUserRepo::new(), MockISessionRepo::new(), config());
+
+    let res = auth.register("player", "short");
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Validation, "weak_password")
+    );
+}
+
+#[test]
+fn register_invalid_login() {
+    let auth = AuthService::new(MockIUserRepo::new(), MockISessionRepo::new(), config());
+
+    let res = auth.register("two words", "correct horse");
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Validation, "invalid_login")
+    );
+}
+
+#[test]
+fn login_success() {
+    let user = registered_user("correct horse");
+    let user_id = user.id();
+    let mut user_repo = MockIUserRepo::new();
+    user_repo
+        .expect_user_by_login()
+        .returning(move |_| Some(user.clone()));
+    let mut session_repo = MockISessionRepo::new();
+    session_repo.expect_add().times(1).returning(|_| Ok(()));
+    let auth = AuthService::new(user_repo, session_repo, config());
+
+    let session = auth.login("player", "correct horse").unwrap();
+
+    assert!(session.user_id() == user_id);
+    assert_eq!(session.token().len(), 64);
+    assert!(!session.is_expired(now()));
+}
+
+#[test]
+fn login_wrong_password() {
+    let user = registered_user("correct horse");
+    let mut user_repo = MockIUserRepo::new();
+    user_repo
+        .expect_user_by_login()
+        .returning(move |_| Some(user.clone()));
+    let auth = AuthService::new(user_repo, MockISessionRepo::new(), config());
+
+    let res = auth.login("player", "wrong horse");
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Unauthorized, "invalid_credentials")
+    );
+}
+
+#[test]
+fn login_legacy_user_without_password() {
+    let mut user_repo = MockIUserRepo::new();
+    user_repo.expect_user_by_login().returning(|login| {
+        Some(User::new(
+            Uuid::now_v7().into(),
+            login.to_string(),
+            String::new(),
+        ))
+    });
+    let auth = AuthService::new(user_repo, MockISessionRepo::new(), config());
+
+    let res = auth.login("legacy-10.0.0.1", "");
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Unauthorized, "invalid_credentials")
+    );
+}
+
+#[test]
+fn login_unknown_user() {
+    let mut user_repo = MockIUserRepo::new();
+    user_repo.expect_user_by_login().returning(|_| None);
+    let auth = AuthService::new(user_repo, MockISessionRepo::new(), config());
+
+    let res = auth.login("nobody", "correct horse");
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Unauthorized, "invalid_credentials")
+    );
+}
+
+#[test]
+fn authenticate_success() {
+    let user = registered_user("correct horse");
+    let session = Session::new("token".to_string(), user.id(), now() + 60);
+    let mut user_repo = MockIUserRepo::new();
+    let found = user.clone();
+    user_repo
+        .expect_user_by_id()
+        .returning(move |_| Ok(found.clone()));
+    let mut session_repo = MockISessionRepo::new();
+    session_repo
+        .expect_session_by_token()
+        .returning(move |_| Some(session.clone()));
+    let auth = AuthService::new(user_repo, session_repo, config());
+
+    let res = auth.authenticate("token");
+
+    assert!(res.unwrap() == user);
+}
+
+#[test]
+fn authenticate_expired() {
+    let session = Session::new("token".to_string(), Uuid::now_v7().into(), now() - 1);
+    let removed = Arc::new(Mutex::new(false));
+    let mut session_repo = MockISessionRepo::new();
+    session_repo
+        .expect_session_by_token()
+        .returning(move |_| Some(session.clone()));
+    let flag = removed.clone();
+    session_repo.expect_remove_by_token().returning(move |_| {
+        *flag.lock().unwrap() = true;
+        Ok(())
+    });
+    let auth = AuthService::new(MockIUserRepo::new(), session_repo, config());
+
+    let res = auth.authenticate("token");
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Unauthorized, "unauthorized")
+    );
+    assert!(*removed.lock().unwrap());
+}
+
+#[test]
+fn authenticate_unknown_token() {
+    let mut session_repo = MockISessionRepo::new();
+    session_repo.expect_session_by_token().returning(|_| None);
+    let auth = AuthService::new(MockIUserRepo::new(), session_repo, config());
+
+    let res = auth.authenticate("token");
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Unauthorized, "unauthorized")
+    );
+}
+
+#[test]
+fn logout_success() {
+    let mut session_repo = MockISessionRepo::new();
+    session_repo
+        .expect_remove_by_token()
+        .withf(|token| token == "token")
+        .times(1)
+        .returning(|_| Ok(()));
+    let auth = AuthService::new(MockIUserRepo::new(), session_repo, config());
+
+    let res = auth.logout("token");
+
+    assert!(res.is_ok());
+}
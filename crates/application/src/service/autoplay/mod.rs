@@ -0,0 +1,278 @@
+use anyhow::{Result, bail};
+use tracing::{debug, info};
+
+use super::{check_active, now};
+use crate::{
+    config::AutoplayConfig,
+    error::{AppError, ErrorKind, classify},
+    repository::{IAutoplayRunRepo, IBetRepo, IGameRepo, ISimulationRepo},
+    strategy::{self, Offer, Strategy},
+    usecase::{AdviseStakes, Autoplay, CalculateBet, CreateRound, MakeBet, RandomizeRound},
+};
+use domain::{
+    entity::{AutoplayRun, Simulation},
+    value_object::{Amount, Id, MIN_BET_AMOUNT, StrategyKind},
+};
+
+pub struct StrategySummary {
+    pub strategy: StrategyKind,
+    pub runs: usize,
+    pub rounds: u32,
+    pub bets: u32,
+    pub staked: Amount,
+    pub returned: Amount,
+    /// Returned minus staked.
+    pub profit: Amount,
+}
+
+/// Bets of a single round and what they brought, in cents.
+#[derive(Default)]
+struct RoundResult {
+    bets: u32,
+    staked: i64,
+    returned: i64,
+}
+
+pub struct AutoplayService<
+    C: CreateRound,
+    M: MakeBet,
+    V: AdviseStakes,
+    K: CalculateBet,
+    R: RandomizeRound,
+    G: IGameRepo,
+    B: IBetRepo,
+    S: ISimulationRepo,
+    A: IAutoplayRunRepo,
+> {
+    round_creator: C,
+    bet_maker: M,
+    adviser: V,
+    bet_calculator: K,
+    randomizer: R,
+    game_repo: G,
+    bet_repo: B,
+    simulation_repo: S,
+    autoplay_run_repo: A,
+    config: AutoplayConfig,
+}
+
+impl<
+    C: CreateRound,
+    M: MakeBet,
+    V: AdviseStakes,
+    K: CalculateBet,
+    R: RandomizeRound,
+    G: IGameRepo,
+    B: IBetRepo,
+    S: ISimulationRepo,
+    A: IAutoplayRunRepo,
+> Autoplay for AutoplayService<C, M, V, K, R, G, B, S, A>
+{
+    fn autoplay(
+        &self,
+        simulation_id: Id<Simulation>,
+        strategy: StrategyKind,
+        rounds: u32,
+    ) -> Result<AutoplayRun> {
+        if rounds == 0 || rounds > self.config.max_rounds {
+            bail!(AppError::InvalidAutoplayRounds {
+                max: self.config.max_rounds,
+            });
+        }
+        let mut simulation = self.simulation_repo.simulation_by_id(simulation_id)?;
+        check_active(&simulation)?;
+        let start_balance = simulation.balance();
+        let mut bot = strategy::strategy(strategy, &self.config);
+        let mut first_round = None;
+        let mut played = 0;
+        let mut total = RoundResult::default();
+        while played < rounds && simulation.balance().clear_value() >= MIN_BET_AMOUNT {
+            let result = self.play_round(&mut simulation, bot.as_mut())?;
+            first_round.get_or_insert(simulation.round());
+            played += 1;
+            total.bets += result.bets;
+            total.staked += result.staked;
+            total.returned += result.returned;
+            simulation = self.simulation_repo.simulation_by_id(simulation_id)?;
+        }
+        info!(
+            strategy = strategy.code(),
+            played,
+            bets = total.bets,
+            "Autoplay finished"
+        );
+
+        let run = AutoplayRun::new(
+            self.autoplay_run_repo.next_id(),
+            simulation_id,
+            strategy,
+            first_round.unwrap_or(simulation.round()),
+            played,
+            total.bets,
+            Amount::new(total.staked, None)?,
+            Amount::new(total.returned, None)?,
+            start_balance,
+            simulation.balance(),
+            now(),
+        );
+        self.autoplay_run_repo.add(run)?;
+        debug!("Autoplay run added to repo");
+
+        Ok(run)
+    }
+
+    fn runs(&self, simulation_id: Id<Simulation>) -> Result<Vec<AutoplayRun>> {
+        self.autoplay_run_repo.runs_by_simulation_id(simulation_id)
+    }
+
+    fn summaries(&self, simulation_id: Id<Simulation>) -> Result<Vec<StrategySummary>> {
+        let runs = self
+            .autoplay_run_repo
+            .runs_by_simulation_id(simulation_id)?;
+        let mut summaries = vec![];
+        for strategy in StrategyKind::ALL {
+            let runs = runs
+                .iter()
+                .filter(|r| r.strategy() == strategy)
+                .collect::<Vec<_>>();
+            if runs.is_empty() {
+                continue;
+            }
+            let staked = runs.iter().map(|r| r.staked().clear_value()).sum::<i64>();
+            let returned = runs.iter().map(|r| r.returned().clear_value()).sum::<i64>();
+            summaries.push(StrategySummary {
+                strategy,
+                runs: runs.len(),
+                rounds: runs.iter().map(|r| r.rounds()).sum(),
+                bets: runs.iter().map(|r| r.bets()).sum(),
+                staked: Amount::new(staked, None)?,
+                returned: Amount::new(returned, None)?,
+                profit: Amount::new(returned - staked, None)?,
+            });
+        }
+
+        Ok(summaries)
+    }
+}
+
+impl<
+    C: CreateRound,
+    M: MakeBet,
+    V: AdviseStakes,
+    K: CalculateBet,
+    R: RandomizeRound,
+    G: IGameRepo,
+    B: IBetRepo,
+    S: ISimulationRepo,
+    A: IAutoplayRunRepo,
+> AutoplayService<C, M, V, K, R, G, B, S, A>
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        round_creator: C,
+        bet_maker: M,
+        adviser: V,
+        bet_calculator: K,
+        randomizer: R,
+        game_repo: G,
+        bet_repo: B,
+        simulation_repo: S,
+        autoplay_run_repo: A,
+        config: AutoplayConfig,
+    ) -> Self {
+        Self {
+            round_creator,
+            bet_maker,
+            adviser,
+            bet_calculator,
+            randomizer,
+            game_repo,
+            bet_repo,
+            simulation_repo,
+            autoplay_run_repo,
+            config,
+        }
+    }
+
+    /// Creates a round, or picks up the one left unplayed unless it already holds bets, lets
+    /// the strategy bet on it, plays it out and settles the bets.
+    fn play_round(
+        &self,
+        simulation: &mut Simulation,
+        bot: &mut (dyn Strategy + Send),
+    ) -> Result<RoundResult> {
+        if let Err(e) = self.round_creator.create_round(simulation) {
+            if !matches!(e.downcast_ref(), Some(AppError::RoundNotRandomized)) {
+                return Err(e);
+            }
+            // Their payouts would be settled along with the strategy's and counted as its own.
+            if !self
+                .bet_repo
+                .not_calculated_bets(simulation.id())
+                .is_empty()
+            {
+                bail!(AppError::RoundHasOpenBets);
+            }
+            debug!("Playing the round left unplayed");
+        }
+        let mut offers = vec![];
+        for game_id in self
+            .game_repo
+            .games_id_by_round(simulation.round(), simulation.id())?
+        {
+            let advice = match self.adviser.advise(simulation, game_id) {
+                Ok(advice) => advice,
+                Err(e) if classify(&e).0 != ErrorKind::Internal => {
+                    debug!(error = %e, "Game can't be priced, skipping it");
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            for a in advice {
+                offers.push(Offer {
+                    game_id,
+                    event: a.event,
+                    coefficient: a.coefficient,
+                    probability: a.model_probability,
+                });
+            }
+        }
+
+        let mut result = RoundResult::default();
+        for stake in bot.decide(&offers, simulation.balance()) {
+            let offer = stake.offer;
+            let placed = self.bet_maker.make_bet(
+                simulation.id(),
+                offer.game_id,
+                stake.amount,
+                offer.event,
+                offer.coefficient,
+            );
+            match placed {
+                Ok(_) => {
+                    result.bets += 1;
+                    result.staked += stake.amount.clear_value();
+                }
+                Err(e) if classify(&e).0 != ErrorKind::Internal => {
+                    debug!(error = %e, "Bet refused, skipping it");
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        self.randomizer.randomize_round(simulation)?;
+        result.returned = self
+            .bet_calculator
+            .calculate_bets(simulation.id())?
+            .clear_value();
+        bot.settle(
+            Amount::new(result.staked, None)?,
+            Amount::new(result.returned, None)?,
+        );
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,442 @@
+use anyhow::anyhow;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use super::super::*;
+use crate::error::{ErrorKind, classify};
+use crate::repository::{MockIAutoplayRunRepo, MockIBetRepo, MockIGameRepo, MockISimulationRepo};
+use crate::service::{BetValidationError, StakeAdvice};
+use crate::usecase::{
+    MockAdviseStakes, MockCalculateBet, MockCreateRound, MockMakeBet, MockRandomizeRound,
+};
+use domain::entity::{Bet, Game};
+use domain::error::DomainError;
+use domain::value_object::{Event, MIN_BALANCE_AMOUNT, Winner};
+
+type Service = AutoplayService<
+    MockCreateRound,
+    MockMakeBet,
+    MockAdviseStakes,
+    MockCalculateBet,
+    MockRandomizeRound,
+    MockIGameRepo,
+    MockIBetRepo,
+    MockISimulationRepo,
+    MockIAutoplayRunRepo,
+>;
+type Shared<T> = Arc<Mutex<T>>;
+
+const START_BALANCE: i64 = 100_000;
+/// What a won flat stake at 1.9 brings back.
+const WIN: i64 = 38_00;
+
+fn amount(value: i64) -> Amount {
+    Amount::new(value, None).unwrap()
+}
+
+/// Offer on `event` at `coefficient` the engine gives `probability`.
+fn advice(event: Event, coefficient: f64, probability: f64) -> StakeAdvice {
+    StakeAdvice {
+        event,
+        coefficient: coefficient.try_into().unwrap(),
+        implied_probability: 1. / coefficient,
+        model_probability: probability,
+        expected_value: probability * coefficient - 1.,
+        kelly_fraction: 0.,
+        kelly_stake: amount(0),
+    }
+}
+
+struct Table {
+    simulation: Shared<Simulation>,
+    runs: Shared<Vec<AutoplayRun>>,
+    round_creator: MockCreateRound,
+    bet_maker: MockMakeBet,
+    adviser: MockAdviseStakes,
+    bet_calculator: MockCalculateBet,
+    randomizer: MockRandomizeRound,
+    game_repo: MockIGameRepo,
+    bet_repo: MockIBetRepo,
+    simulation_repo: MockISimulationRepo,
+    autoplay_run_repo: MockIAutoplayRunRepo,
+}
+
+impl Table {
+    /// Simulation in round 2 holding `balance` and a single game in every round it creates,
+    /// every bet on it winning.
+    fn new(balance: i64) -> Self {
+        let simulation = Arc::new(Mutex::new(Simulation::new(
+            Uuid::now_v7().into(),
+            Uuid::now_v7().into(),
+            "main".to_string(),
+            Amount::new(balance, Some(MIN_BALANCE_AMOUNT)).unwrap(),
+            Some(2),
+        )));
+        let runs = Arc::new(Mutex::new(vec![]));
+
+        let mut round_creator = MockCreateRound::new();
+        let created = simulation.clone();
+        round_creator.expect_create_round().returning(move |s| {
+            s.increment_round();
+            *created.lock().unwrap() = s.clone();
+            Ok(vec![])
+        });
+
+        let game_id: Id<Game> = Uuid::now_v7().into();
+        let mut game_repo = MockIGameRepo::new();
+        game_repo
+            .expect_games_id_by_round()
+            .returning(move |_, _| Ok(vec![game_id]));
+        let current = simulation.clone();
+        game_repo.expect_game_by_id().returning(move |id| {
+            let simulation = current.lock().unwrap();
+            Ok(Game::new(
+                id,
+                simulation.id(),
+                Uuid::now_v7().into(),
+                Uuid::now_v7().into(),
+                simulation.round(),
+            ))
+        });
+
+        let mut adviser = MockAdviseStakes::new();
+        adviser.expect_advise().returning(|_, _| {
+            Ok(vec![
+                advice(Event::WDL(Winner::W1), 1.9, 0.5),
+                advice(Event::WDL(Winner::X), 3.2, 0.3),
+                advice(Event::WDL(Winner::W2), 4.5, 0.2),
+            ])
+        });
+
+        let mut bet_maker = MockMakeBet::new();
+        let pending = Arc::new(Mutex::new(0));
+        let staked = simulation.clone();
+        let placed = pending.clone();
+        bet_maker
+            .expect_make_bet()
+            .returning(move |_, _, amount, _, _| {
+                staked.lock().unwrap().make_bet(amount)?;
+                *placed.lock().unwrap() += 1;
+                Ok(vec![])
+            });
+
+        let mut randomizer = MockRandomizeRound::new();
+        randomizer
+            .expect_randomize_round()
+            .returning(|_| Ok(vec![]));
+
+        let mut bet_calculator = MockCalculateBet::new();
+        let settled = simulation.clone();
+        bet_calculator.expect_calculate_bets().returning(move |_| {
+            let won = amount(WIN * std::mem::take(&mut *pending.lock().unwrap()));
+            settled.lock().unwrap().process_bet(won)?;
+            Ok(won)
+        });
+
+        let mut bet_repo = MockIBetRepo::new();
+        bet_repo.expect_not_calculated_bets().returning(|_| vec![]);
+
+        let mut simulation_repo = MockISimulationRepo::new();
+        let stored = simulation.clone();
+        simulation_repo
+            .expect_simulation_by_id()
+            .returning(move |_| Ok(stored.lock().unwrap().clone()));
+
+        let mut autoplay_run_repo = MockIAutoplayRunRepo::new();
+        autoplay_run_repo
+            .expect_next_id()
+            .returning(|| Uuid::now_v7().into());
+        let added = runs.clone();
+        autoplay_run_repo.expect_add().returning(move |run| {
+            added.lock().unwrap().push(run);
+            Ok(())
+        });
+        let listed = runs.clone();
+        autoplay_run_repo
+            .expect_runs_by_simulation_id()
+            .returning(move |_| Ok(listed.lock().unwrap().clone()));
+
+        Self {
+            simulation,
+            runs,
+            round_creator,
+            bet_maker,
+            adviser,
+            bet_calculator,
+            randomizer,
+            game_repo,
+            bet_repo,
+            simulation_repo,
+            autoplay_run_repo,
+        }
+    }
+
+    fn simulation_id(&self) -> Id<Simulation> {
+        self.simulation.lock().unwrap().id()
+    }
+
+    fn service(self) -> Service {
+        AutoplayService::new(
+            self.round_creator,
+            self.bet_maker,
+            self.adviser,
+            self.bet_calculator,
+            self.randomizer,
+            self.game_repo,
+            self.bet_repo,
+            self.simulation_repo,
+            self.autoplay_run_repo,
+            AutoplayConfig::default(),
+        )
+    }
+}
+
+#[test]
+fn flat_plays_rounds_and_records_run() {
+    let table = Table::new(START_BALANCE);
+    let sim_id = table.simulation_id();
+    let simulation = table.simulation.clone();
+    let runs = table.runs.clone();
+    let service = table.service();
+
+    let run = service.autoplay(sim_id, StrategyKind::Flat, 3).unwrap();
+
+    assert!(run.strategy() == StrategyKind::Flat);
+    assert_eq!(run.first_round(), 3);
+    assert_eq!(run.rounds(), 3);
+    assert_eq!(run.bets(), 3);
+    assert_eq!(run.staked().clear_value(), 60_00);
+    assert_eq!(run.returned().clear_value(), 3 * WIN);
+    assert_eq!(run.start_balance().clear_value(), START_BALANCE);
+    let end_balance = START_BALANCE - 60_00 + 3 * WIN;
+    assert_eq!(run.end_balance().clear_value(), end_balance);
+    let simulation = simulation.lock().unwrap();
+    assert_eq!(simulation.round(), 5);
+    assert_eq!(simulation.balance().clear_value(), end_balance);
+    assert!(runs.lock().unwrap().as_slice() == [run]);
+}
+
+#[test]
+fn rounds_out_of_bounds_refused() {
+    let table = Table::new(START_BALANCE);
+    let sim_id = table.simulation_id();
+    let runs = table.runs.clone();
+    let service = table.service();
+
+    for rounds in [0, AutoplayConfig::default().max_rounds + 1] {
+        let err = service
+            .autoplay(sim_id, StrategyKind::Flat, rounds)
+            .err()
+            .unwrap();
+        assert_eq!(
+            classify(&err),
+            (ErrorKind::Validation, "invalid_autoplay_rounds")
+        );
+    }
+    assert!(runs.lock().unwrap().is_empty());
+}
+
+#[test]
+fn archived_simulation_refused() {
+    let table = Table::new(START_BALANCE);
+    table.simulation.lock().unwrap().archive(1);
+    let sim_id = table.simulation_id();
+    let service = table.service();
+
+    let err = service
+        .autoplay(sim_id, StrategyKind::Flat, 3)
+        .err()
+        .unwrap();
+
+    assert_eq!(classify(&err).1, "simulation_archived");
+}
+
+#[test]
+fn stops_once_balance_below_min_bet() {
+    let table = Table::new(MIN_BET_AMOUNT - 1);
+    let sim_id = table.simulation_id();
+    let service = table.service();
+
+    let run = service.autoplay(sim_id, StrategyKind::Flat, 3).unwrap();
+
+    assert_eq!(run.rounds(), 0);
+    assert_eq!(run.bets(), 0);
+    assert_eq!(run.first_round(), 2);
+    assert_eq!(run.end_balance().clear_value(), MIN_BET_AMOUNT - 1);
+}
+
+#[test]
+fn unplayed_round_played_first() {
+    let mut table = Table::new(START_BALANCE);
+    table.round_creator = MockCreateRound::new();
+    table
+        .round_creator
+        .expect_create_round()
+        .times(1)
+        .returning(|_| Err(AppError::RoundNotRandomized.into()));
+    let sim_id = table.simulation_id();
+    let service = table.service();
+
+    let run = service.autoplay(sim_id, StrategyKind::Flat, 1).unwrap();
+
+    assert_eq!(run.first_round(), 2);
+    assert_eq!(run.rounds(), 1);
+    assert_eq!(run.bets(), 1);
+}
+
+#[test]
+fn unplayed_round_with_manual_bet_refused() {
+    let mut table = Table::new(START_BALANCE);
+    table.round_creator = MockCreateRound::new();
+    table
+        .round_creator
+        .expect_create_round()
+        .returning(|_| Err(AppError::RoundNotRandomized.into()));
+    let sim_id = table.simulation_id();
+    let stake = amount(20_00);
+    table.simulation.lock().unwrap().make_bet(stake).unwrap();
+    let manual = Bet::new(
+        Uuid::now_v7().into(),
+        sim_id,
+        stake,
+        (1.9).try_into().unwrap(),
+        Uuid::now_v7().into(),
+        Event::WDL(Winner::W1),
+        None,
+    );
+    table.bet_repo = MockIBetRepo::new();
+    table
+        .bet_repo
+        .expect_not_calculated_bets()
+        .returning(move |_| vec![manual]);
+    table.bet_maker = MockMakeBet::new();
+    table.bet_calculator = MockCalculateBet::new();
+    let simulation = table.simulation.clone();
+    let runs = table.runs.clone();
+    let service = table.service();
+
+    let err = service
+        .autoplay(sim_id, StrategyKind::Flat, 1)
+        .err()
+        .unwrap();
+
+    assert_eq!(classify(&err), (ErrorKind::Conflict, "round_has_open_bets"));
+    assert_eq!(
+        simulation.lock().unwrap().balance().clear_value(),
+        START_BALANCE - 20_00
+    );
+    assert!(runs.lock().unwrap().is_empty());
+}
+
+#[test]
+fn refused_bets_skipped() {
+    let mut table = Table::new(START_BALANCE);
+    table.adviser = MockAdviseStakes::new();
+    table
+        .adviser
+        .expect_advise()
+        .returning(|_, _| Ok(vec![advice(Event::WDL(Winner::W1), 1.9, 0.5)]));
+    table.bet_maker = MockMakeBet::new();
+    table
+        .bet_maker
+        .expect_make_bet()
+        .returning(|_, _, _, _, _| Err(BetValidationError::MarketClosed.into()));
+    let sim_id = table.simulation_id();
+    let service = table.service();
+
+    let run = service.autoplay(sim_id, StrategyKind::Flat, 2).unwrap();
+
+    assert_eq!(run.rounds(), 2);
+    assert_eq!(run.bets(), 0);
+    assert_eq!(run.staked().clear_value(), 0);
+}
+
+#[test]
+fn internal_failure_aborts_without_record() {
+    let mut table = Table::new(START_BALANCE);
+    table.adviser = MockAdviseStakes::new();
+    table
+        .adviser
+        .expect_advise()
+        .returning(|_, _| Ok(vec![advice(Event::WDL(Winner::W1), 1.9, 0.5)]));
+    table.bet_maker = MockMakeBet::new();
+    table
+        .bet_maker
+        .expect_make_bet()
+        .returning(|_, _, _, _, _| Err(anyhow!("connection lost")));
+    let sim_id = table.simulation_id();
+    let runs = table.runs.clone();
+    let service = table.service();
+
+    let err = service
+        .autoplay(sim_id, StrategyKind::Flat, 2)
+        .err()
+        .unwrap();
+
+    assert_eq!(classify(&err).0, ErrorKind::Internal);
+    assert!(runs.lock().unwrap().is_empty());
+}
+
+#[test]
+fn summaries_add_up_per_strategy() {
+    let table = Table::new(START_BALANCE);
+    let sim_id = table.simulation_id();
+    let service = table.service();
+    service.autoplay(sim_id, StrategyKind::Flat, 2).unwrap();
+    service.autoplay(sim_id, StrategyKind::Kelly, 1).unwrap();
+    service.autoplay(sim_id, StrategyKind::Flat, 1).unwrap();
+
+    let summaries = service.summaries(sim_id).unwrap();
+
+    assert_eq!(summaries.len(), 2);
+    let flat = &summaries[0];
+    assert!(flat.strategy == StrategyKind::Flat);
+    assert_eq!((flat.runs, flat.rounds, flat.bets), (2, 3, 3));
+    assert_eq!(flat.staked.clear_value(), 60_00);
+    assert_eq!(flat.returned.clear_value(), 3 * WIN);
+    assert_eq!(flat.profit.clear_value(), 3 * WIN - 60_00);
+    let kelly = &summaries[1];
+    assert!(kelly.strategy == StrategyKind::Kelly);
+    assert_eq!((kelly.runs, kelly.rounds, kelly.bets), (1, 1, 0));
+}
+
+#[test]
+fn kelly_backs_offer_beating_model_probability() {
+    let mut table = Table::new(START_BALANCE);
+    table.adviser = MockAdviseStakes::new();
+    table.adviser.expect_advise().returning(|_, _| {
+        Ok(vec![
+            advice(Event::WDL(Winner::W1), 1.9, 0.6),
+            advice(Event::WDL(Winner::X), 3.2, 0.25),
+            advice(Event::WDL(Winner::W2), 4.5, 0.15),
+        ])
+    });
+    let sim_id = table.simulation_id();
+    let service = table.service();
+
+    let run = service.autoplay(sim_id, StrategyKind::Kelly, 1).unwrap();
+
+    let share = (0.6 * 1.9 - 1.) / (1.9 - 1.) * 0.5;
+    assert_eq!(run.bets(), 1);
+    assert_eq!(
+        run.staked().clear_value(),
+        (START_BALANCE as f64 * share) as i64
+    );
+}
+
+#[test]
+fn unpriced_game_skipped() {
+    let mut table = Table::new(START_BALANCE);
+    table.adviser = MockAdviseStakes::new();
+    table
+        .adviser
+        .expect_advise()
+        .returning(|_, _| Err(DomainError::InvalidCoefficient.into()));
+    let sim_id = table.simulation_id();
+    let service = table.service();
+
+    let run = service.autoplay(sim_id, StrategyKind::Flat, 2).unwrap();
+
+    assert_eq!(run.rounds(), 2);
+    assert_eq!(run.bets(), 0);
+}
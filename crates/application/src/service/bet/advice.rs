@@ -0,0 +1,83 @@
+use anyhow::Result;
+use tracing::debug;
+
+use super::{BetCalculator, BetService};
+use crate::{
+    repository::{
+        IBetLimitRepo, IBetRepo, IGameRepo, IGameStatRepo, IOddsSnapshotRepo, ISimulationRepo,
+        IUnitOfWork,
+    },
+    usecase::AdviseStakes,
+};
+use domain::{
+    entity::{Game, Simulation},
+    value_object::{Amount, Coefficient, Event, Id},
+};
+
+/// How an offered price compares with the engine's own probability of the event.
+pub struct StakeAdvice {
+    pub event: Event,
+    pub coefficient: Coefficient,
+    /// Probability the price stands for, `1 / coefficient`.
+    pub implied_probability: f64,
+    /// Probability the price is set from before the margin is applied, the engine's own
+    /// shaded by the money already on the game, so the margin keeps every offer unfavourable.
+    pub model_probability: f64,
+    /// Expected return per unit staked.
+    pub expected_value: f64,
+    /// Share of the balance the Kelly criterion stakes, zero unless the bet is favourable.
+    pub kelly_fraction: f64,
+    pub kelly_stake: Amount,
+}
+
+impl StakeAdvice {
+    pub(super) fn new(
+        event: Event,
+        coefficient: Coefficient,
+        model_probability: f64,
+        balance: Amount,
+    ) -> Result<Self> {
+        let price = f64::from(coefficient);
+        let expected_value = model_probability * price - 1.;
+        let kelly_fraction = (expected_value / (price - 1.)).max(0.);
+        let kelly_stake = (balance.clear_value() as f64 * kelly_fraction) as i64;
+
+        Ok(Self {
+            event,
+            coefficient,
+            implied_probability: 1. / price,
+            model_probability,
+            expected_value,
+            kelly_fraction,
+            kelly_stake: Amount::new(kelly_stake, None)?,
+        })
+    }
+}
+
+impl<
+    B: IBetRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    S: ISimulationRepo,
+    L: IBetLimitRepo,
+    U: IUnitOfWork,
+    O: IOddsSnapshotRepo,
+> AdviseStakes for BetService<B, G, GS, S, L, U, O>
+{
+    fn advise(&self, simulation: &Simulation, game_id: Id<Game>) -> Result<Vec<StakeAdvice>> {
+        let game = self.offered_game(simulation, game_id)?;
+        let balance = simulation.balance();
+        let mut probs = self.winner_probabilities(&game)?;
+        probs.append(&mut self.total_probabilities(&game)?);
+        let probs = self.shaded(&game, &probs)?;
+        let coefficients =
+            BetCalculator::price(&probs, self.config.margin, self.config.margin_method)?;
+        debug!("Shaded probabilities priced");
+
+        probs
+            .into_iter()
+            .zip(coefficients)
+            .map(|((event, p), (_, coefficient))| StakeAdvice::new(event, coefficient, p, balance))
+            .collect()
+    }
+}
@@ -0,0 +1,135 @@
+use anyhow::Result;
+
+use super::{BetService, BetValidationError, LiabilityScope, liability};
+use crate::repository::{
+    IBetLimitRepo, IBetRepo, IGameRepo, IGameStatRepo, IOddsSnapshotRepo, ISimulationRepo,
+    IUnitOfWork,
+};
+use domain::{
+    entity::{Bet, Game},
+    value_object::{Amount, Coefficient, Event, Id, MAX_BET_AMOUNT},
+};
+
+/// Limit of the book a stake is held to.
+#[derive(Clone, Copy)]
+enum Cap {
+    Payout,
+    Liability(LiabilityScope),
+}
+
+impl<
+    B: IBetRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    S: ISimulationRepo,
+    L: IBetLimitRepo,
+    U: IUnitOfWork,
+    O: IOddsSnapshotRepo,
+> BetService<B, G, GS, S, L, U, O>
+{
+    /// Refuses a stake that would take the book over its payout or liability limits.
+    pub(super) fn check_exposure(
+        &self,
+        game: &Game,
+        amount: Amount,
+        event: Event,
+        coefficient: Coefficient,
+    ) -> Result<()> {
+        let open = self.bet_repo.not_calculated_bets(game.simulation_id());
+        for (cap, max_stake) in self.caps(game, event, coefficient, &open) {
+            if amount.clear_value() <= max_stake {
+                continue;
+            }
+            let error = match cap {
+                Cap::Payout => BetValidationError::PayoutTooHigh {
+                    max: self.exposure.max_payout,
+                },
+                Cap::Liability(scope) => BetValidationError::LiabilityExceeded { scope, max_stake },
+            };
+            return Err(error.into());
+        }
+
+        Ok(())
+    }
+
+    /// Largest stake the book still takes on each of `offers`, in cents.
+    pub(super) fn remaining_stakes(
+        &self,
+        game: &Game,
+        offers: &[(Event, Coefficient)],
+    ) -> Vec<i64> {
+        let open = self.bet_repo.not_calculated_bets(game.simulation_id());
+        offers
+            .iter()
+            .map(|&(event, coefficient)| {
+                self.caps(game, event, coefficient, &open)
+                    .into_iter()
+                    .map(|(_, max_stake)| max_stake)
+                    .fold(self.exposure.max_stake.min(MAX_BET_AMOUNT), i64::min)
+            })
+            .collect()
+    }
+
+    /// Largest stake on `event` of `game` at `coefficient` every limit of the book allows,
+    /// given the unsettled bets `open` of the simulation.
+    fn caps(
+        &self,
+        game: &Game,
+        event: Event,
+        coefficient: Coefficient,
+        open: &[Bet],
+    ) -> Vec<(Cap, i64)> {
+        let coefficient = coefficient.clear_value() as i64;
+        let game_bets = open
+            .iter()
+            .filter(|b| b.game_id() == game.id())
+            .copied()
+            .collect::<Vec<_>>();
+        let market = liability::liabilities(&liability::market_of(event), &game_bets);
+        let net = market
+            .iter()
+            .find(|(e, _)| *e == event)
+            .map_or(0, |&(_, net)| net);
+        let market_worst = market.iter().map(|&(_, net)| net).max().unwrap_or_default();
+        let game_rest = liability::worst_case(&game_bets) - market_worst;
+        let mut games: Vec<Id<Game>> = vec![];
+        for bet in open {
+            if !games.contains(&bet.game_id()) {
+                games.push(bet.game_id());
+            }
+        }
+        let round_worst = games
+            .into_iter()
+            .map(|id| {
+                let bets = open
+                    .iter()
+                    .filter(|b| b.game_id() == id)
+                    .copied()
+                    .collect::<Vec<_>>();
+                liability::worst_case(&bets)
+            })
+            .sum::<i64>();
+        let round_rest = round_worst - market_worst;
+
+        // A stake `s` adds `s * (coefficient - 1)` to what the book loses on the event.
+        let liability_cap = |max: i64, rest: i64| (max - rest - net) * 100 / (coefficient - 100);
+        [
+            (Cap::Payout, self.exposure.max_payout * 100 / coefficient),
+            (
+                Cap::Liability(LiabilityScope::Event),
+                liability_cap(self.exposure.max_event_liability, 0),
+            ),
+            (
+                Cap::Liability(LiabilityScope::Game),
+                liability_cap(self.exposure.max_game_liability, game_rest),
+            ),
+            (
+                Cap::Liability(LiabilityScope::Round),
+                liability_cap(self.exposure.max_round_liability, round_rest),
+            ),
+        ]
+        .into_iter()
+        .map(|(cap, max_stake)| (cap, max_stake.max(0)))
+        .collect()
+    }
+}
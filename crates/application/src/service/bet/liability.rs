@@ -0,0 +1,101 @@
+use super::BetCalculator;
+use crate::config::ShadingConfig;
+use std::cmp::Ordering;
+
+use domain::{
+    entity::Bet,
+    value_object::{Event, EventTotal, Winner},
+};
+
+/// Smallest probability shading leaves an outcome with.
+const MIN_PROBABILITY: f64 = 0.01;
+
+/// What the book would lose on each of `events` if it came in, in cents: the payout of the
+/// unsettled bets on it less every stake on its market. The events of a market come one
+/// after another.
+pub(super) fn liabilities(events: &[Event], bets: &[Bet]) -> Vec<(Event, i64)> {
+    let open = bets
+        .iter()
+        .filter(|b| b.is_won().is_none())
+        .collect::<Vec<_>>();
+    let mut liabilities = Vec::with_capacity(events.len());
+    for market in events.chunk_by(|a, b| BetCalculator::same_market(*a, *b)) {
+        let market_staked = open
+            .iter()
+            .filter(|b| BetCalculator::same_market(b.event(), market[0]))
+            .map(|b| b.amount().clear_value())
+            .sum::<i64>();
+        for &event in market {
+            let payout = open
+                .iter()
+                .filter(|b| b.event() == event)
+                .map(|b| b.payout().clear_value())
+                .sum::<i64>();
+            liabilities.push((event, payout - market_staked));
+        }
+    }
+
+    liabilities
+}
+
+/// What the book would lose on the unsettled `bets` at worst, in cents, every market settled
+/// the way costing it most. Markets nobody bet on cost nothing.
+pub(crate) fn worst_case(bets: &[Bet]) -> i64 {
+    let mut markets: Vec<[Event; 3]> = vec![];
+    for bet in bets.iter().filter(|b| b.is_won().is_none()) {
+        let market = market_of(bet.event());
+        if !markets.contains(&market) {
+            markets.push(market);
+        }
+    }
+
+    markets
+        .iter()
+        .map(|market| {
+            liabilities(market, bets)
+                .into_iter()
+                .map(|(_, net)| net)
+                .max()
+                .unwrap_or_default()
+        })
+        .sum()
+}
+
+/// Every event of the market `event` is offered in.
+pub(super) fn market_of(event: Event) -> [Event; 3] {
+    match event {
+        Event::WDL(_) => [Winner::W1, Winner::X, Winner::W2].map(Event::WDL),
+        Event::T(EventTotal { total, .. }) => [Ordering::Greater, Ordering::Equal, Ordering::Less]
+            .map(|ordering| Event::T(EventTotal { total, ordering })),
+    }
+}
+
+/// Model probabilities moved towards the outcomes the book would lose on and away from the
+/// ones it would win on, every market still adding up to what it did.
+pub(super) fn shade(
+    probs: &[(Event, f64)],
+    liabilities: &[(Event, i64)],
+    config: ShadingConfig,
+) -> Vec<(Event, f64)> {
+    let mut shaded = Vec::with_capacity(probs.len());
+    for market in probs.chunk_by(|a, b| BetCalculator::same_market(a.0, b.0)) {
+        let moved = market
+            .iter()
+            .map(|&(event, p)| {
+                let net = liabilities
+                    .iter()
+                    .find(|(e, _)| *e == event)
+                    .map_or(0, |&(_, net)| net);
+                let shift = (config.sensitivity * net as f64 / 100.)
+                    .clamp(-config.max_shift, config.max_shift);
+                (p + shift).max(MIN_PROBABILITY)
+            })
+            .collect::<Vec<_>>();
+        let scale = market.iter().map(|&(_, p)| p).sum::<f64>() / moved.iter().sum::<f64>();
+        for (&(event, _), p) in market.iter().zip(moved) {
+            shaded.push((event, p * scale));
+        }
+    }
+
+    shaded
+}
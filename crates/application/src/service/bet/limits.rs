@@ -0,0 +1,98 @@
+use anyhow::Result;
+use std::collections::BTreeMap;
+use tracing::debug;
+
+use super::{BetService, BetValidationError};
+use crate::{
+    repository::{
+        IBetLimitRepo, IBetRepo, IGameRepo, IGameStatRepo, IOddsSnapshotRepo, ISimulationRepo,
+        IUnitOfWork,
+    },
+    service::now,
+};
+use domain::{
+    entity::{Bet, Simulation},
+    value_object::{Amount, LimitKind, LimitWarning},
+};
+
+impl<
+    B: IBetRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    S: ISimulationRepo,
+    L: IBetLimitRepo,
+    U: IUnitOfWork,
+    O: IOddsSnapshotRepo,
+> BetService<B, G, GS, S, L, U, O>
+{
+    /// Refuses a stake that breaks a responsible-gaming limit of the simulation and warns
+    /// about the limits it brings close.
+    pub(super) fn check_limits(
+        &self,
+        simulation: &Simulation,
+        amount: Amount,
+    ) -> Result<Vec<LimitWarning>> {
+        let limits = self
+            .bet_limit_repo
+            .limits_by_simulation_id(simulation.id())?
+            .in_round(simulation.round());
+        if let Some(until) = limits.cool_off_until.filter(|&until| now() < until) {
+            return Err(BetValidationError::CoolingOff { until }.into());
+        }
+
+        let stake = amount.clear_value();
+        let mut usage = vec![];
+        if let Some(max) = limits.max_stake {
+            usage.push((LimitKind::Stake, stake, max));
+        }
+        if limits.max_round_stake.is_some() || limits.loss_limit.is_some() {
+            let rounds = self
+                .game_repo
+                .games_by_simulation_id(simulation.id(), simulation.round())?
+                .into_iter()
+                .map(|g| (g.id(), g.round()))
+                .collect::<BTreeMap<_, _>>();
+            let bets = self.bet_repo.bets_by_simulation_id(simulation.id())?;
+            let round_of = |bet: &Bet| rounds.get(&bet.game_id()).copied();
+
+            if let Some(max) = limits.max_round_stake {
+                let staked = bets
+                    .iter()
+                    .filter(|b| round_of(b) == Some(simulation.round()))
+                    .map(|b| b.amount().clear_value())
+                    .sum::<i64>();
+                usage.push((LimitKind::RoundStake, staked + stake, max));
+            }
+            if let Some(limit) = limits.loss_limit {
+                // Pending stakes count as lost until they are settled.
+                let first_round = simulation.round().saturating_sub(limit.rounds) + 1;
+                let mut loss = 0;
+                for bet in bets {
+                    if round_of(&bet).is_none_or(|r| r < first_round) {
+                        continue;
+                    }
+                    loss += bet.amount().clear_value();
+                    if bet.is_won() == Some(true) {
+                        loss -= bet.payout().clear_value();
+                    }
+                }
+                usage.push((LimitKind::Loss, loss + stake, limit.amount));
+            }
+        }
+
+        let mut warnings = vec![];
+        for (limit, used, max) in usage {
+            if used > max.clear_value() {
+                return Err(BetValidationError::LimitExceeded {
+                    limit,
+                    max: max.clear_value(),
+                }
+                .into());
+            }
+            warnings.extend(LimitWarning::check(limit, Amount::new(used, None)?, max));
+        }
+        debug!(warnings = warnings.len(), "Limits checked");
+
+        Ok(warnings)
+    }
+}
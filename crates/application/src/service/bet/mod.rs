@@ -1,127 +1,174 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
 use tracing::debug;
 
+use super::check_active;
 use crate::{
-    config::CoefficientConfig,
-    repository::{IBetRepo, IGameRepo, IGameStatRepo, ISimulationRepo},
+    config::{CoefficientConfig, ExposureConfig, MarginMethod},
+    error::{AppError, Entity},
+    repository::{
+        IBetLimitRepo, IBetRepo, IGameRepo, IGameStatRepo, IOddsSnapshotRepo, ISimulationRepo,
+        IUnitOfWork,
+    },
     usecase::{CalculateBet, MakeBet, MakeReport},
 };
 use domain::{
-    entity::{Bet, Game, Simulation, Team},
+    entity::{Bet, Game, OddsSnapshot, Simulation, Team},
     value_object::{
-        Amount, BetStatistics, Coefficient, Event, EventTotal, Id, Margin, PastResults, PastTotals,
-        Winner,
+        Amount, BetStatistics, Coefficient, Event, EventTotal, Id, LimitWarning, Margin,
+        PastResults, PastTotals, Winner,
     },
 };
 
+mod advice;
+mod exposure;
+pub(super) mod liability;
+mod limits;
+mod odds;
+mod overround;
+mod validation;
+
+pub use advice::StakeAdvice;
+pub use odds::{ClosingLine, OddsHistory};
+pub use validation::{BetValidationError, LiabilityScope};
+
 const EPS: f64 = 1e-7;
 
-pub struct BetService<B: IBetRepo, G: IGameRepo, GS: IGameStatRepo, S: ISimulationRepo> {
+pub struct BetService<
+    B: IBetRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    S: ISimulationRepo,
+    L: IBetLimitRepo,
+    U: IUnitOfWork,
+    O: IOddsSnapshotRepo,
+> {
     bet_repo: B,
     game_repo: G,
     game_stat_repo: GS,
     simulation_repo: S,
+    bet_limit_repo: L,
+    unit_of_work: U,
+    odds_snapshot_repo: O,
     config: CoefficientConfig,
+    exposure: ExposureConfig,
 }
 
-impl<B: IBetRepo, G: IGameRepo, GS: IGameStatRepo, S: ISimulationRepo> MakeBet
-    for BetService<B, G, GS, S>
+impl<
+    B: IBetRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    S: ISimulationRepo,
+    L: IBetLimitRepo,
+    U: IUnitOfWork,
+    O: IOddsSnapshotRepo,
+> MakeBet for BetService<B, G, GS, S, L, U, O>
 {
     fn make_bet(
         &self,
-        game: &Game,
+        simulation_id: Id<Simulation>,
+        game_id: Id<Game>,
         amount: Amount,
         event: Event,
         coefficient: Coefficient,
-    ) -> Result<()> {
-        let id = self.bet_repo.next_id();
-        let simulation_id = game.simulation_id();
-        let bet = Bet::new(
-            id,
-            simulation_id,
-            amount,
-            coefficient,
-            game.id(),
-            event,
-            None,
-        );
-        debug!("Initialize bet");
-        self.bet_repo.add(bet)?;
-        debug!("Bet added in bet_repo");
-        let mut simulation = self.simulation_repo.simulation_by_id(simulation_id)?;
-        debug!("Got simulation");
-        simulation.make_bet(amount)?;
-        debug!("Bet made");
-        self.simulation_repo.update_by_id(simulation)?;
-        debug!("Simulation updated in simulation_repo");
-
-        Ok(())
+    ) -> Result<Vec<LimitWarning>> {
+        let mut warnings = vec![];
+        self.unit_of_work.atomically(&mut || {
+            let mut simulation = self.simulation_repo.simulation_by_id(simulation_id)?;
+            debug!("Got simulation");
+            let game = self.game_repo.game_by_id(game_id).map_err(|e| {
+                match e.downcast_ref::<AppError>() {
+                    Some(AppError::NotFound(_)) => BetValidationError::GameNotFound.into(),
+                    _ => e,
+                }
+            })?;
+            self.validate_bet(&simulation, &game, amount, event, coefficient)?;
+            debug!("Bet validated");
+            self.check_exposure(&game, amount, event, coefficient)?;
+            debug!("Bet is within the book's exposure");
+            warnings = self.check_limits(&simulation, amount)?;
+            debug!("Bet is within limits");
+            let id = self.bet_repo.next_id();
+            let bet = Bet::new(id, simulation_id, amount, coefficient, game_id, event, None);
+            debug!("Initialize bet");
+            self.bet_repo.add(bet)?;
+            debug!("Bet added in bet_repo");
+            simulation.make_bet(amount)?;
+            debug!("Bet made");
+            self.simulation_repo.update_by_id(simulation)?;
+            debug!("Simulation updated in simulation_repo");
+
+            Ok(())
+        })?;
+
+        Ok(warnings)
     }
 
-    fn calculate_coefficients(&self, game: &Game) -> Result<Vec<(Event, Coefficient)>> {
-        let mut coefficients = self.calculate_winner_coefficients(game)?;
-        debug!("Winner coefficients calculated");
-        let mut tc = self.calculate_total_coefficients(game)?;
-        debug!("Total coefficients calculated");
-        coefficients.append(&mut tc);
+    fn calculate_coefficients(
+        &self,
+        simulation: &Simulation,
+        game_id: Id<Game>,
+    ) -> Result<Vec<(Event, Coefficient)>> {
+        let game = self.offered_game(simulation, game_id)?;
+        debug!("Game is offered");
 
-        Ok(coefficients)
+        self.offers(&game)
     }
 
     fn calculate_winner_coefficients(&self, game: &Game) -> Result<Vec<(Event, Coefficient)>> {
-        let home_res = self.past_results_by_team_id(game.home_team_id(), game.simulation_id())?;
-        debug!("Got past results of home team");
-        let guest_res = self.past_results_by_team_id(game.guest_team_id(), game.simulation_id())?;
-        debug!("Got past results of guest team");
-        let h2h_res = self.h2h_results_by_game(game)?;
-        debug!("Got h2h past results");
-
-        BetCalculator::calculate_winner_coefficients(
-            home_res,
-            guest_res,
-            h2h_res,
-            self.config.alpha,
-            self.config.tracked_games,
-            self.config.margin,
-        )
+        self.offer(game, &self.winner_probabilities(game)?)
     }
 
     fn calculate_total_coefficients(&self, game: &Game) -> Result<Vec<(Event, Coefficient)>> {
-        let mut total_coefficients = vec![];
-        for &total in self.config.totals.iter() {
-            let h2h_totals = self.h2h_totals(game, total)?;
-            let home_team_past_totals =
-                self.past_totals(game.home_team_id(), game.simulation_id(), total)?;
-            let guest_team_past_totals =
-                self.past_totals(game.guest_team_id(), game.simulation_id(), total)?;
-            let totals = ((h2h_totals + home_team_past_totals)? + guest_team_past_totals)?;
-            debug!(total, "Got past totals");
-
-            let mut ttl =
-                BetCalculator::calculate_total_coefficients(total, totals, self.config.margin)?;
-            total_coefficients.append(&mut ttl);
-        }
+        self.offer(game, &self.total_probabilities(game)?)
+    }
 
-        Ok(total_coefficients)
+    fn max_stakes(
+        &self,
+        simulation: &Simulation,
+        game_id: Id<Game>,
+        offers: &[(Event, Coefficient)],
+    ) -> Result<Vec<Amount>> {
+        let game = self.offered_game(simulation, game_id)?;
+        debug!("Game is offered");
+
+        self.remaining_stakes(&game, offers)
+            .into_iter()
+            .map(|stake| Amount::new(stake, None))
+            .collect()
     }
 }
 
-impl<B: IBetRepo, G: IGameRepo, GS: IGameStatRepo, S: ISimulationRepo> CalculateBet
-    for BetService<B, G, GS, S>
+impl<
+    B: IBetRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    S: ISimulationRepo,
+    L: IBetLimitRepo,
+    U: IUnitOfWork,
+    O: IOddsSnapshotRepo,
+> CalculateBet for BetService<B, G, GS, S, L, U, O>
 {
-    fn calculate_bets(&self) -> Result<Amount> {
+    fn calculate_bets(&self, simulation_id: Id<Simulation>) -> Result<Amount> {
         let mut profit = 0;
-        let nc_bets = self.bet_repo.not_calculated_bets();
-        if let Some(bet) = nc_bets.first() {
-            debug!("There are unsettled bets");
-            let mut simulation = self.simulation_repo.simulation_by_id(bet.simulation_id())?;
-            for bet in nc_bets {
-                profit += self.calculate_bet(bet, &mut simulation)?.clear_value();
+        self.unit_of_work.atomically(&mut || {
+            profit = 0;
+            let nc_bets = self.bet_repo.not_calculated_bets(simulation_id);
+            if !nc_bets.is_empty() {
+                debug!("There are unsettled bets");
+                let mut simulation = self.simulation_repo.simulation_by_id(simulation_id)?;
+                check_active(&simulation)?;
+                for bet in nc_bets {
+                    profit += self.calculate_bet(bet, &mut simulation)?.clear_value();
+                }
+                self.simulation_repo.update_by_id(simulation)?;
+                debug!("Balance updated in repo");
             }
-            self.simulation_repo.update_by_id(simulation)?;
-            debug!("Balance updated in repo");
-        }
+
+            Ok(())
+        })?;
 
         Ok(Amount::new(profit, None).unwrap())
     }
@@ -161,32 +208,184 @@ impl<B: IBetRepo, G: IGameRepo, GS: IGameStatRepo, S: ISimulationRepo> Calculate
     }
 }
 
-impl<B: IBetRepo, G: IGameRepo, GS: IGameStatRepo, S: ISimulationRepo> MakeReport
-    for BetService<B, G, GS, S>
+impl<
+    B: IBetRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    S: ISimulationRepo,
+    L: IBetLimitRepo,
+    U: IUnitOfWork,
+    O: IOddsSnapshotRepo,
+> MakeReport for BetService<B, G, GS, S, L, U, O>
 {
-    fn make_report(&self, start_balance: Amount) -> BetStatistics {
-        let min_coefficient_lose = self.bet_repo.min_coefficient_lose();
+    fn make_report(
+        &self,
+        simulation_id: Id<Simulation>,
+        start_balance: Amount,
+    ) -> Result<BetStatistics> {
+        let min_coefficient_lose = self.bet_repo.min_coefficient_lose(simulation_id);
+        let clvs = self
+            .closing_lines(simulation_id)?
+            .iter()
+            .filter_map(ClosingLine::clv)
+            .collect::<Vec<_>>();
         debug!("All data for report received");
+        let clv = (!clvs.is_empty()).then(|| clvs.iter().sum::<f64>() / clvs.len() as f64);
+
+        Ok(BetStatistics::new(start_balance, min_coefficient_lose, clv))
+    }
+
+    fn bets(&self, simulation_id: Id<Simulation>) -> Result<Vec<Bet>> {
+        let mut bets = self.bet_repo.bets_by_simulation_id(simulation_id)?;
+        bets.sort_by_key(|b| b.id().value());
 
-        BetStatistics::new(start_balance, min_coefficient_lose)
+        Ok(bets)
+    }
+
+    fn closing_lines(&self, simulation_id: Id<Simulation>) -> Result<Vec<ClosingLine>> {
+        let mut snapshots = BTreeMap::<Id<Game>, Vec<OddsSnapshot>>::new();
+        let mut lines = vec![];
+        for bet in self.bets(simulation_id)? {
+            if bet.is_won().is_none() {
+                lines.push(ClosingLine { bet, closing: None });
+                continue;
+            }
+            let game_snapshots = match snapshots.entry(bet.game_id()) {
+                Entry::Occupied(e) => e.into_mut(),
+                Entry::Vacant(e) => e.insert(
+                    self.odds_snapshot_repo
+                        .snapshots_by_game_id(bet.game_id())?,
+                ),
+            };
+            let closing = game_snapshots
+                .iter()
+                .rev()
+                .find(|s| s.event() == bet.event())
+                .map(|s| s.coefficient());
+            lines.push(ClosingLine { bet, closing });
+        }
+
+        Ok(lines)
     }
 }
 
-impl<B: IBetRepo, G: IGameRepo, GS: IGameStatRepo, S: ISimulationRepo> BetService<B, G, GS, S> {
+impl<
+    B: IBetRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    S: ISimulationRepo,
+    L: IBetLimitRepo,
+    U: IUnitOfWork,
+    O: IOddsSnapshotRepo,
+> BetService<B, G, GS, S, L, U, O>
+{
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         bet_repo: B,
         game_repo: G,
         game_stat_repo: GS,
         simulation_repo: S,
+        bet_limit_repo: L,
+        unit_of_work: U,
+        odds_snapshot_repo: O,
         config: CoefficientConfig,
+        exposure: ExposureConfig,
     ) -> Self {
         Self {
             bet_repo,
             game_repo,
             game_stat_repo,
             simulation_repo,
+            bet_limit_repo,
+            unit_of_work,
+            odds_snapshot_repo,
             config,
+            exposure,
+        }
+    }
+
+    /// Game of the current round of `simulation`, any other one, foreign or unknown, not
+    /// being found.
+    fn offered_game(&self, simulation: &Simulation, game_id: Id<Game>) -> Result<Game> {
+        let game = self.game_repo.game_by_id(game_id)?;
+        if game.simulation_id() != simulation.id() || game.round() != simulation.round() {
+            bail!(AppError::NotFound(Entity::Game));
+        }
+
+        Ok(game)
+    }
+
+    /// Every offer on `game`, recorded as it was offered.
+    fn offers(&self, game: &Game) -> Result<Vec<(Event, Coefficient)>> {
+        let mut coefficients = self.calculate_winner_coefficients(game)?;
+        debug!("Winner coefficients calculated");
+        let mut tc = self.calculate_total_coefficients(game)?;
+        debug!("Total coefficients calculated");
+        coefficients.append(&mut tc);
+        self.record_odds(game, &coefficients)?;
+        debug!("Odds recorded");
+
+        Ok(coefficients)
+    }
+
+    /// Prices of the events of `game`, the model probabilities shaded by the money already on
+    /// them.
+    fn offer(&self, game: &Game, probs: &[(Event, f64)]) -> Result<Vec<(Event, Coefficient)>> {
+        BetCalculator::price(
+            &self.shaded(game, probs)?,
+            self.config.margin,
+            self.config.margin_method,
+        )
+    }
+
+    /// Model probabilities of the events of `game` moved by the money already on them, the
+    /// ones its prices are set from.
+    fn shaded(&self, game: &Game, probs: &[(Event, f64)]) -> Result<Vec<(Event, f64)>> {
+        let shading = self.config.shading;
+        if shading.sensitivity == 0. {
+            return Ok(probs.to_vec());
         }
+        let events = probs.iter().map(|&(e, _)| e).collect::<Vec<_>>();
+        let bets = self.bet_repo.bets_by_game_id(game.id())?;
+        let liabilities = liability::liabilities(&events, &bets);
+        debug!("Liabilities of the game counted");
+
+        Ok(liability::shade(probs, &liabilities, shading))
+    }
+
+    fn winner_probabilities(&self, game: &Game) -> Result<Vec<(Event, f64)>> {
+        let home_res = self.past_results_by_team_id(game.home_team_id(), game.simulation_id())?;
+        debug!("Got past results of home team");
+        let guest_res = self.past_results_by_team_id(game.guest_team_id(), game.simulation_id())?;
+        debug!("Got past results of guest team");
+        let h2h_res = self.h2h_results_by_game(game)?;
+        debug!("Got h2h past results");
+
+        Ok(BetCalculator::winner_probabilities(
+            home_res,
+            guest_res,
+            h2h_res,
+            self.config.alpha,
+            self.config.tracked_games,
+        ))
+    }
+
+    fn total_probabilities(&self, game: &Game) -> Result<Vec<(Event, f64)>> {
+        let mut total_probabilities = vec![];
+        for &total in self.config.totals.iter() {
+            let h2h_totals = self.h2h_totals(game, total)?;
+            let home_team_past_totals =
+                self.past_totals(game.home_team_id(), game.simulation_id(), total)?;
+            let guest_team_past_totals =
+                self.past_totals(game.guest_team_id(), game.simulation_id(), total)?;
+            let totals = ((h2h_totals + home_team_past_totals)? + guest_team_past_totals)?;
+            debug!(total, "Got past totals");
+
+            let mut ttl = BetCalculator::total_probabilities(total, totals);
+            total_probabilities.append(&mut ttl);
+        }
+
+        Ok(total_probabilities)
     }
 
     fn past_results_by_team_id(
@@ -267,7 +466,7 @@ impl<B: IBetRepo, G: IGameRepo, GS: IGameStatRepo, S: ISimulationRepo> BetServic
     }
 }
 
-struct BetCalculator;
+pub(super) struct BetCalculator;
 
 impl BetCalculator {
     fn normalize(probs: &mut [f64]) {
@@ -280,14 +479,14 @@ impl BetCalculator {
         }
     }
 
-    pub fn calculate_winner_coefficients(
+    /// Model probabilities of the home win, the draw and the away win.
+    pub fn winner_probabilities(
         home_res: PastResults,
         guest_res: PastResults,
         h2h_res: PastResults,
         alpha: i32,
         tracked_games: u8,
-        margin: Margin,
-    ) -> Result<Vec<(Event, Coefficient)>> {
+    ) -> Vec<(Event, f64)> {
         let prob_base = h2h_res.pts_diff() as f64 / alpha as f64;
         let win_prob = (((home_res.wins + 1) + (guest_res.loses + 1)) as f64
             / 2.
@@ -302,56 +501,55 @@ impl BetCalculator {
             - prob_base;
         let mut probs = [win_prob, draw_prob, lose_prob];
         Self::normalize(&mut probs);
-        for p in probs.iter_mut() {
-            *p = (1. - f64::from(margin)) / *p;
-        }
 
-        Ok(vec![
-            (Event::WDL(Winner::W1), probs[0].try_into()?),
-            (Event::WDL(Winner::X), probs[1].try_into()?),
-            (Event::WDL(Winner::W2), probs[2].try_into()?),
-        ])
+        vec![
+            (Event::WDL(Winner::W1), probs[0]),
+            (Event::WDL(Winner::X), probs[1]),
+            (Event::WDL(Winner::W2), probs[2]),
+        ]
     }
 
-    pub fn calculate_total_coefficients(
-        total: u8,
-        totals: PastTotals,
-        margin: Margin,
-    ) -> Result<Vec<(Event, Coefficient)>> {
-        let mut total_coefficients = vec![];
-
+    /// Model probabilities of the total going over, hitting and going under `total`.
+    pub fn total_probabilities(total: u8, totals: PastTotals) -> Vec<(Event, f64)> {
         let n = totals.size() as f64 + 3.;
         let tg = n / (totals.greater() as f64 + 1.);
         let te = n / (totals.equal() as f64 + 1.);
         let tl = n / (totals.less() as f64 + 1.);
         let mut probs = [tg, te, tl];
         Self::normalize(&mut probs);
-        for p in probs.iter_mut() {
-            *p = (1. - f64::from(margin)) / *p;
+
+        [Ordering::Greater, Ordering::Equal, Ordering::Less]
+            .into_iter()
+            .zip(probs)
+            .map(|(ordering, p)| (Event::T(EventTotal { total, ordering }), p))
+            .collect()
+    }
+
+    /// Prices of the events with `margin` spread by `method` over every market among them,
+    /// the events of a market coming one after another.
+    pub fn price(
+        probs: &[(Event, f64)],
+        margin: Margin,
+        method: MarginMethod,
+    ) -> Result<Vec<(Event, Coefficient)>> {
+        let mut coefficients = Vec::with_capacity(probs.len());
+        for market in probs.chunk_by(|a, b| Self::same_market(a.0, b.0)) {
+            let market_probs = market.iter().map(|&(_, p)| p).collect::<Vec<_>>();
+            let implied = overround::distribute(method, &market_probs, margin);
+            for (&(event, _), q) in market.iter().zip(implied) {
+                coefficients.push((event, (1. / q).try_into()?));
+            }
+        }
+
+        Ok(coefficients)
+    }
+
+    fn same_market(a: Event, b: Event) -> bool {
+        match (a, b) {
+            (Event::WDL(_), Event::WDL(_)) => true,
+            (Event::T(a), Event::T(b)) => a.total == b.total,
+            _ => false,
         }
-        total_coefficients.push((
-            Event::T(EventTotal {
-                total,
-                ordering: Ordering::Greater,
-            }),
-            probs[0].try_into()?,
-        ));
-        total_coefficients.push((
-            Event::T(EventTotal {
-                total,
-                ordering: Ordering::Equal,
-            }),
-            probs[1].try_into()?,
-        ));
-        total_coefficients.push((
-            Event::T(EventTotal {
-                total,
-                ordering: Ordering::Less,
-            }),
-            probs[2].try_into()?,
-        ));
-
-        Ok(total_coefficients)
     }
 }
 
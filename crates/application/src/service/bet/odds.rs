@@ -0,0 +1,134 @@
+use anyhow::{Result, bail};
+
+use super::BetService;
+use crate::{
+    error::{AppError, Entity},
+    repository::{
+        IBetLimitRepo, IBetRepo, IGameRepo, IGameStatRepo, IOddsSnapshotRepo, ISimulationRepo,
+        IUnitOfWork,
+    },
+    service::now,
+    usecase::ShowOddsHistory,
+};
+use domain::{
+    entity::{Bet, Game, OddsSnapshot, Simulation},
+    value_object::{Coefficient, Event, Id},
+};
+
+/// Every price offered on an event of a game, oldest first.
+pub struct OddsHistory {
+    pub event: Event,
+    pub snapshots: Vec<OddsSnapshot>,
+}
+
+impl OddsHistory {
+    /// First price offered on the event.
+    pub fn opening(&self) -> Coefficient {
+        self.snapshots[0].coefficient()
+    }
+
+    /// Last price offered before the game was played.
+    pub fn closing(&self) -> Coefficient {
+        self.snapshots[self.snapshots.len() - 1].coefficient()
+    }
+}
+
+/// Bet set against the last price offered on its event before the game was played.
+pub struct ClosingLine {
+    pub bet: Bet,
+    /// `None` until the bet is settled or when no price of the event was stored.
+    pub closing: Option<Coefficient>,
+}
+
+impl ClosingLine {
+    /// How much better the price taken was than the closing one, 0.05 for 5%.
+    pub fn clv(&self) -> Option<f64> {
+        self.closing
+            .map(|c| f64::from(self.bet.coefficient()) / f64::from(c) - 1.)
+    }
+}
+
+impl<
+    B: IBetRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    S: ISimulationRepo,
+    L: IBetLimitRepo,
+    U: IUnitOfWork,
+    O: IOddsSnapshotRepo,
+> ShowOddsHistory for BetService<B, G, GS, S, L, U, O>
+{
+    fn odds_history(
+        &self,
+        simulation_id: Id<Simulation>,
+        game_id: Id<Game>,
+    ) -> Result<Vec<OddsHistory>> {
+        let game = self.game_repo.game_by_id(game_id)?;
+        if game.simulation_id() != simulation_id {
+            bail!(AppError::NotFound(Entity::Game));
+        }
+
+        let mut history: Vec<OddsHistory> = vec![];
+        for snapshot in self.odds_snapshot_repo.snapshots_by_game_id(game_id)? {
+            match history.iter_mut().find(|h| h.event == snapshot.event()) {
+                Some(h) => h.snapshots.push(snapshot),
+                None => history.push(OddsHistory {
+                    event: snapshot.event(),
+                    snapshots: vec![snapshot],
+                }),
+            }
+        }
+
+        Ok(history)
+    }
+}
+
+impl<
+    B: IBetRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    S: ISimulationRepo,
+    L: IBetLimitRepo,
+    U: IUnitOfWork,
+    O: IOddsSnapshotRepo,
+> BetService<B, G, GS, S, L, U, O>
+{
+    /// Stores the offers of `game` whose price is new or differs from the last one stored,
+    /// leaving the closing prices alone once the game is played.
+    pub(super) fn record_odds(&self, game: &Game, offers: &[(Event, Coefficient)]) -> Result<()> {
+        if self
+            .game_stat_repo
+            .score_by_game_id(game.id(), true)
+            .is_some()
+        {
+            return Ok(());
+        }
+        let snapshots = self.odds_snapshot_repo.snapshots_by_game_id(game.id())?;
+        let created_at = now();
+        let changed = offers
+            .iter()
+            .filter(|&&(event, coefficient)| {
+                snapshots
+                    .iter()
+                    .rev()
+                    .find(|s| s.event() == event)
+                    .is_none_or(|s| s.coefficient() != coefficient)
+            })
+            .map(|&(event, coefficient)| {
+                OddsSnapshot::new(
+                    self.odds_snapshot_repo.next_id(),
+                    game.simulation_id(),
+                    game.id(),
+                    event,
+                    coefficient,
+                    created_at,
+                )
+            })
+            .collect::<Vec<_>>();
+        if changed.is_empty() {
+            return Ok(());
+        }
+
+        self.odds_snapshot_repo.add_all(changed)
+    }
+}
@@ -0,0 +1,85 @@
+use crate::config::MarginMethod;
+use domain::value_object::Margin;
+
+const ITERATIONS: usize = 100;
+
+/// Implied probabilities of a market with model probabilities `probs`, the margin spread by
+/// `method` so that they add up to `1 / (1 - margin)`. Shin's model can't carry a book above
+/// `(Σ√p)²` and stops there.
+pub(super) fn distribute(method: MarginMethod, probs: &[f64], margin: Margin) -> Vec<f64> {
+    let book = 1. / (1. - f64::from(margin));
+    match method {
+        MarginMethod::Proportional => probs.iter().map(|p| p * book).collect(),
+        MarginMethod::MarginWeighted => {
+            let odds = probs.iter().map(|p| 1. / p).sum::<f64>();
+            probs
+                .iter()
+                .map(|p| p + (book - 1.) * (1. / p) / odds)
+                .collect()
+        }
+        MarginMethod::Shin => {
+            let shin = |z: f64| -> Vec<f64> {
+                let roots = probs
+                    .iter()
+                    .map(|p| (z * p + (1. - z) * p * p).sqrt())
+                    .collect::<Vec<_>>();
+                let sum = roots.iter().sum::<f64>();
+                roots.into_iter().map(|r| r * sum).collect()
+            };
+            shin(solve(|z| shin(z).iter().sum(), 0., 1., book))
+        }
+        MarginMethod::Power => {
+            let power = |k: f64| probs.iter().map(|p| p.powf(1. / k)).collect::<Vec<_>>();
+            power(solve(
+                |k| power(k).iter().sum(),
+                1.,
+                upper_bound(|k| power(k).iter().sum(), book),
+                book,
+            ))
+        }
+        MarginMethod::OddsRatio => {
+            let ratio = |c: f64| {
+                probs
+                    .iter()
+                    .map(|p| c * p / (1. - p + c * p))
+                    .collect::<Vec<_>>()
+            };
+            ratio(solve(
+                |c| ratio(c).iter().sum(),
+                1.,
+                upper_bound(|c| ratio(c).iter().sum(), book),
+                book,
+            ))
+        }
+    }
+}
+
+/// Parameter from `1` up whose book reaches `book`, doubled while it falls short.
+fn upper_bound(book_at: impl Fn(f64) -> f64, book: f64) -> f64 {
+    let mut hi = 2.;
+    for _ in 0..ITERATIONS {
+        if book_at(hi) >= book {
+            break;
+        }
+        hi *= 2.;
+    }
+
+    hi
+}
+
+/// Parameter in `lo..=hi` at which the book, growing with the parameter, comes to `book`.
+fn solve(book_at: impl Fn(f64) -> f64, mut lo: f64, mut hi: f64, book: f64) -> f64 {
+    if book_at(hi) <= book {
+        return hi;
+    }
+    for _ in 0..ITERATIONS {
+        let mid = (lo + hi) / 2.;
+        if book_at(mid) < book {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    (lo + hi) / 2.
+}
@@ -0,0 +1,190 @@
+use uuid::Uuid;
+
+use super::super::*;
+use super::mock::no_limits;
+use super::odds_snapshot_repo;
+use crate::config::{ExposureConfig, ShadingConfig};
+use crate::error::{ErrorKind, classify};
+use crate::repository::{
+    FakeUnitOfWork, MockIBetLimitRepo, MockIBetRepo, MockIGameRepo, MockIGameStatRepo,
+    MockIOddsSnapshotRepo, MockISimulationRepo,
+};
+use crate::usecase::AdviseStakes;
+
+const MARGIN: f64 = 0.12;
+
+type Service = BetService<
+    MockIBetRepo,
+    MockIGameRepo,
+    MockIGameStatRepo,
+    MockISimulationRepo,
+    MockIBetLimitRepo,
+    FakeUnitOfWork,
+    MockIOddsSnapshotRepo,
+>;
+
+fn amount(value: i64) -> Amount {
+    Amount::new(value, None).unwrap()
+}
+
+/// Service over a simulation without any played games but `game`.
+fn service(game: Game) -> Service {
+    service_shading(game, vec![], MARGIN, ShadingConfig::default())
+}
+
+/// Service pricing `game` holding the unsettled `bets` at `margin` with `shading`.
+fn service_shading(game: Game, bets: Vec<Bet>, margin: f64, shading: ShadingConfig) -> Service {
+    let mut bet_repo = MockIBetRepo::new();
+    bet_repo
+        .expect_bets_by_game_id()
+        .returning(move |_| Ok(bets.clone()));
+    let mut game_repo = MockIGameRepo::new();
+    game_repo.expect_game_by_id().returning(move |_| Ok(game));
+    game_repo
+        .expect_games_id_by_team_id()
+        .returning(|_, _, _| Ok(vec![]));
+    game_repo
+        .expect_h2hs_id_by_team_id()
+        .returning(|_, _, _, _| Ok(vec![]));
+    let mut gs_repo = MockIGameStatRepo::new();
+    gs_repo.expect_score_by_game_id().returning(|_, _| None);
+    let config = CoefficientConfig {
+        tracked_games: 5,
+        margin: Margin::try_from(margin).unwrap(),
+        margin_method: MarginMethod::Proportional,
+        alpha: 15,
+        totals: vec![2, 3],
+        deviation_min: 0.8,
+        deviation_max: 1.2,
+        shading,
+    };
+
+    BetService::new(
+        bet_repo,
+        game_repo,
+        gs_repo,
+        MockISimulationRepo::new(),
+        no_limits(),
+        FakeUnitOfWork,
+        odds_snapshot_repo(),
+        config,
+        ExposureConfig::default(),
+    )
+}
+
+fn simulation() -> Simulation {
+    Simulation::new(
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        "default".to_string(),
+        amount(100_000),
+        Some(1),
+    )
+}
+
+fn game(simulation: &Simulation) -> Game {
+    Game::new(
+        Uuid::now_v7().into(),
+        simulation.id(),
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        1,
+    )
+}
+
+#[test]
+fn margin_makes_every_offer_a_losing_bet() {
+    let simulation = simulation();
+    let game = game(&simulation);
+
+    let advice = service(game).advise(&simulation, game.id()).unwrap();
+
+    assert_eq!(advice.len(), 9);
+    for a in advice {
+        assert!(a.implied_probability > a.model_probability);
+        assert!(a.expected_value < 0.);
+        assert!((a.expected_value + MARGIN).abs() < 0.01);
+        assert_eq!(a.kelly_fraction, 0.);
+        assert_eq!(a.kelly_stake.clear_value(), 0);
+    }
+}
+
+#[test]
+fn shaded_offers_stay_losing_bets() {
+    let simulation = simulation();
+    let game = game(&simulation);
+    // As shipped in config.toml.
+    let margin = 0.07;
+    let shading = ShadingConfig {
+        sensitivity: 0.0001,
+        max_shift: 0.05,
+    };
+    let stake = Bet::new(
+        Uuid::now_v7().into(),
+        simulation.id(),
+        amount(500_000),
+        (2.0).try_into().unwrap(),
+        game.id(),
+        Event::WDL(Winner::W1),
+        None,
+    );
+    let service = service_shading(game, vec![stake], margin, shading);
+    let unshaded = service_shading(game, vec![], margin, shading)
+        .advise(&simulation, game.id())
+        .unwrap();
+
+    let advice = service.advise(&simulation, game.id()).unwrap();
+
+    let draw = |advice: &[StakeAdvice]| f64::from(advice[1].coefficient);
+    assert!(
+        draw(&advice) > draw(&unshaded),
+        "the book is long on a draw"
+    );
+    for a in advice {
+        assert!(a.expected_value < 0.);
+        assert_eq!(a.kelly_stake.clear_value(), 0);
+    }
+}
+
+#[test]
+fn advice_follows_offered_prices() {
+    let simulation = simulation();
+    let game = game(&simulation);
+    let service = service(game);
+
+    let advice = service.advise(&simulation, game.id()).unwrap();
+    let offers = service
+        .calculate_coefficients(&simulation, game.id())
+        .unwrap();
+
+    for (a, (event, coefficient)) in advice.iter().zip(offers) {
+        assert_eq!(a.event, event);
+        assert_eq!(a.coefficient, coefficient);
+    }
+}
+
+#[test]
+fn advice_on_foreign_game() {
+    let game = game(&simulation());
+    let service = service(game);
+
+    let res = service.advise(&simulation(), game.id());
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::NotFound, "game_not_found")
+    );
+}
+
+#[test]
+fn favourable_price_gets_kelly_stake() {
+    let coefficient = Coefficient::try_from(2.2).unwrap();
+
+    let advice =
+        StakeAdvice::new(Event::WDL(Winner::W1), coefficient, 0.5, amount(100_000)).unwrap();
+
+    assert!((advice.expected_value - 0.1).abs() < EPS);
+    assert!((advice.kelly_fraction - 0.1 / 1.2).abs() < EPS);
+    assert_eq!(advice.kelly_stake.clear_value(), 8333);
+    assert!((advice.implied_probability - 1. / 2.2).abs() < EPS);
+}
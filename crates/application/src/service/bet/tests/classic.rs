@@ -39,7 +39,8 @@ fn calculate_total_coefficients() {
     totals.add_total(3);
     let margin = Margin::try_from(0.12).unwrap();
 
-    let res = BetCalculator::calculate_total_coefficients(total, totals, margin).unwrap();
+    let probs = BetCalculator::total_probabilities(total, totals);
+    let res = BetCalculator::price(&probs, margin, MarginMethod::Proportional).unwrap();
 
     let mut sum = 0.;
     for (_, coefficient) in res {
@@ -67,9 +68,8 @@ fn calculate_winner_coefficients() {
     };
     let margin = Margin::try_from(0.12).unwrap();
 
-    let res =
-        BetCalculator::calculate_winner_coefficients(home_res, guest_res, h2h_res, 60, 25, margin)
-            .unwrap();
+    let probs = BetCalculator::winner_probabilities(home_res, guest_res, h2h_res, 60, 25);
+    let res = BetCalculator::price(&probs, margin, MarginMethod::Proportional).unwrap();
 
     let mut sum = 0.;
     for (_, coefficient) in res {
@@ -77,3 +77,29 @@ fn calculate_winner_coefficients() {
     }
     assert!(sum > 1.);
 }
+
+#[test]
+fn model_probabilities_add_up() {
+    let home_res = PastResults {
+        wins: 3,
+        draws: 1,
+        loses: 1,
+    };
+    let guest_res = PastResults {
+        wins: 1,
+        draws: 2,
+        loses: 2,
+    };
+    let mut totals = PastTotals::new(2);
+    totals.add_total(1);
+    totals.add_total(4);
+
+    let winner =
+        BetCalculator::winner_probabilities(home_res, guest_res, PastResults::new(), 30, 5);
+    let total = BetCalculator::total_probabilities(2, totals);
+
+    for probs in [winner, total] {
+        assert_eq!(probs.len(), 3);
+        assert!((probs.iter().map(|(_, p)| p).sum::<f64>() - 1.).abs() < EPS);
+    }
+}
@@ -0,0 +1,304 @@
+use uuid::Uuid;
+
+use super::super::*;
+use super::odds_snapshot_repo;
+use crate::config::{ExposureConfig, ShadingConfig};
+use crate::error::{ErrorKind, classify};
+use crate::repository::{
+    FakeUnitOfWork, MockIBetLimitRepo, MockIBetRepo, MockIGameRepo, MockIGameStatRepo,
+    MockIOddsSnapshotRepo, MockISimulationRepo,
+};
+use domain::value_object::{BetLimits, EventTotal, MAX_BET_AMOUNT};
+use std::cmp::Ordering;
+
+type Service = BetService<
+    MockIBetRepo,
+    MockIGameRepo,
+    MockIGameStatRepo,
+    MockISimulationRepo,
+    MockIBetLimitRepo,
+    FakeUnitOfWork,
+    MockIOddsSnapshotRepo,
+>;
+
+const W1: Event = Event::WDL(Winner::W1);
+
+fn amount(value: i64) -> Amount {
+    Amount::new(value, None).unwrap()
+}
+
+/// Round with two games of a simulation with 10000.00; `open` holds
+/// `(game, event, stake, coefficient)` of the unsettled bets made before.
+fn service(exposure: ExposureConfig, open: &[(usize, Event, i64, f64)]) -> (Service, Game) {
+    let simulation = Simulation::new(
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        "default".to_string(),
+        amount(1_000_000),
+        Some(1),
+    );
+    let games = [0, 1].map(|_| {
+        Game::new(
+            Uuid::now_v7().into(),
+            simulation.id(),
+            Uuid::now_v7().into(),
+            Uuid::now_v7().into(),
+            1,
+        )
+    });
+    let bets = open
+        .iter()
+        .map(|&(game, event, stake, coefficient)| {
+            Bet::new(
+                Uuid::now_v7().into(),
+                simulation.id(),
+                amount(stake),
+                coefficient.try_into().unwrap(),
+                games[game].id(),
+                event,
+                None,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut bet_repo = MockIBetRepo::new();
+    let open_bets = bets.clone();
+    bet_repo
+        .expect_not_calculated_bets()
+        .returning(move |_| open_bets.clone());
+    let game_bets = bets.clone();
+    bet_repo.expect_bets_by_game_id().returning(move |game_id| {
+        Ok(game_bets
+            .iter()
+            .filter(|b| b.game_id() == game_id)
+            .copied()
+            .collect())
+    });
+    bet_repo
+        .expect_bets_by_simulation_id()
+        .returning(move |_| Ok(bets.clone()));
+    bet_repo
+        .expect_next_id()
+        .returning(|| <Id<Bet>>::from(Uuid::now_v7()));
+    bet_repo.expect_add().returning(|_| Ok(()));
+    let mut game_repo = MockIGameRepo::new();
+    game_repo
+        .expect_game_by_id()
+        .returning(move |id| Ok(*games.iter().find(|g| g.id() == id).unwrap()));
+    game_repo
+        .expect_games_id_by_team_id()
+        .returning(|_, _, _| Ok(vec![]));
+    game_repo
+        .expect_h2hs_id_by_team_id()
+        .returning(|_, _, _, _| Ok(vec![]));
+    let mut gs_repo = MockIGameStatRepo::new();
+    gs_repo.expect_score_by_game_id().returning(|_, _| None);
+    gs_repo.expect_winner_by_game_id().returning(|_, _| None);
+    let mut sim_repo = MockISimulationRepo::new();
+    sim_repo
+        .expect_simulation_by_id()
+        .returning(move |_| Ok(simulation.clone()));
+    sim_repo.expect_update_by_id().returning(|_| Ok(()));
+    let mut bet_limit_repo = MockIBetLimitRepo::new();
+    bet_limit_repo
+        .expect_limits_by_simulation_id()
+        .returning(|_| Ok(BetLimits::default()));
+    let config = CoefficientConfig {
+        tracked_games: 5,
+        margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
+        alpha: 15,
+        totals: vec![2, 3],
+        deviation_min: 0.8,
+        deviation_max: 1.2,
+        shading: ShadingConfig::default(),
+    };
+    let bs = BetService::new(
+        bet_repo,
+        game_repo,
+        gs_repo,
+        sim_repo,
+        bet_limit_repo,
+        FakeUnitOfWork,
+        odds_snapshot_repo(),
+        config,
+        exposure,
+    );
+
+    (bs, games[0])
+}
+
+/// Limits high enough for none but the ones a test sets to matter.
+fn loose() -> ExposureConfig {
+    ExposureConfig {
+        max_stake: MAX_BET_AMOUNT,
+        max_payout: 100_000_000,
+        max_event_liability: 100_000_000,
+        max_game_liability: 100_000_000,
+        max_round_liability: 100_000_000,
+    }
+}
+
+/// Offered coefficient and largest allowed stake on the home win.
+fn home_win(bs: &Service, game: Game) -> (Coefficient, i64) {
+    let simulation = bs
+        .simulation_repo
+        .simulation_by_id(game.simulation_id())
+        .unwrap();
+    let offers = bs.calculate_coefficients(&simulation, game.id()).unwrap();
+    let position = offers.iter().position(|(e, _)| *e == W1).unwrap();
+    let max_stakes = bs.max_stakes(&simulation, game.id(), &offers).unwrap();
+
+    (offers[position].1, max_stakes[position].clear_value())
+}
+
+fn bet_home_win(bs: &Service, game: Game, stake: i64) -> Result<Vec<LimitWarning>> {
+    let (coefficient, _) = home_win(bs, game);
+
+    bs.make_bet(
+        game.simulation_id(),
+        game.id(),
+        amount(stake),
+        W1,
+        coefficient,
+    )
+}
+
+fn total(total: u8, ordering: Ordering) -> Event {
+    Event::T(EventTotal { total, ordering })
+}
+
+#[test]
+fn payout_cap() {
+    let exposure = ExposureConfig {
+        max_payout: 20_000,
+        ..loose()
+    };
+    let (bs, game) = service(exposure, &[]);
+    let (coefficient, max_stake) = home_win(&bs, game);
+
+    let over = bet_home_win(&bs, game, max_stake + 1);
+
+    assert_eq!(max_stake, 20_000 * 100 / coefficient.clear_value() as i64);
+    assert!(bet_home_win(&bs, game, max_stake).is_ok());
+    assert_eq!(
+        classify(&over.err().unwrap()),
+        (ErrorKind::Validation, "payout_too_high")
+    );
+}
+
+#[test]
+fn event_liability() {
+    let exposure = ExposureConfig {
+        max_event_liability: 30_000,
+        ..loose()
+    };
+    let (fresh, game) = service(exposure, &[]);
+    // 100.00 on the draw is kept by the book if the home side wins.
+    let (hedged, other) = service(exposure, &[(0, Event::WDL(Winner::X), 10_000, 3.0)]);
+    let (coefficient, max_stake) = home_win(&fresh, game);
+    let (_, hedged_max_stake) = home_win(&hedged, other);
+
+    let err = bet_home_win(&fresh, game, max_stake + 1).err().unwrap();
+
+    assert_eq!(
+        max_stake,
+        30_000 * 100 / (coefficient.clear_value() as i64 - 100)
+    );
+    assert!(hedged_max_stake > max_stake);
+    assert_eq!(
+        classify(&err),
+        (ErrorKind::Forbidden, "event_liability_exceeded")
+    );
+    assert_eq!(
+        *err.downcast_ref::<BetValidationError>().unwrap(),
+        BetValidationError::LiabilityExceeded {
+            scope: LiabilityScope::Event,
+            max_stake
+        }
+    );
+}
+
+#[test]
+fn game_liability() {
+    let exposure = ExposureConfig {
+        max_game_liability: 50_000,
+        ..loose()
+    };
+    // The book loses 200.00 at worst on the total of the same game.
+    let open = [(0, total(2, Ordering::Greater), 10_000, 3.0)];
+    let (bs, game) = service(exposure, &open);
+    let (coefficient, max_stake) = home_win(&bs, game);
+
+    let over = bet_home_win(&bs, game, max_stake + 1);
+
+    assert_eq!(
+        max_stake,
+        30_000 * 100 / (coefficient.clear_value() as i64 - 100)
+    );
+    assert!(bet_home_win(&bs, game, max_stake).is_ok());
+    assert_eq!(
+        classify(&over.err().unwrap()),
+        (ErrorKind::Forbidden, "game_liability_exceeded")
+    );
+}
+
+#[test]
+fn round_liability() {
+    let exposure = ExposureConfig {
+        max_round_liability: 50_000,
+        ..loose()
+    };
+    // The other game of the round costs the book 300.00 at worst.
+    let open = [(1, W1, 10_000, 4.0)];
+    let (bs, game) = service(exposure, &open);
+    let (coefficient, max_stake) = home_win(&bs, game);
+
+    let over = bet_home_win(&bs, game, max_stake + 1);
+
+    assert_eq!(
+        max_stake,
+        20_000 * 100 / (coefficient.clear_value() as i64 - 100)
+    );
+    assert!(bet_home_win(&bs, game, max_stake).is_ok());
+    assert_eq!(
+        classify(&over.err().unwrap()),
+        (ErrorKind::Forbidden, "round_liability_exceeded")
+    );
+}
+
+#[test]
+fn exhausted_liability_allows_nothing() {
+    let exposure = ExposureConfig {
+        max_round_liability: 20_000,
+        ..loose()
+    };
+    let open = [(1, W1, 10_000, 4.0)];
+    let (bs, game) = service(exposure, &open);
+
+    let (_, max_stake) = home_win(&bs, game);
+
+    assert_eq!(max_stake, 0);
+}
+
+#[test]
+fn stake_cap_from_config() {
+    let exposure = ExposureConfig {
+        max_stake: 5_000,
+        ..loose()
+    };
+    let (bs, game) = service(exposure, &[]);
+
+    let (_, max_stake) = home_win(&bs, game);
+    let over = bet_home_win(&bs, game, 5_001);
+
+    assert_eq!(max_stake, 5_000);
+    assert_eq!(
+        *over
+            .err()
+            .unwrap()
+            .downcast_ref::<BetValidationError>()
+            .unwrap(),
+        BetValidationError::StakeTooHigh { max: 5_000 }
+    );
+}
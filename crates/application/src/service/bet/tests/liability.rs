@@ -0,0 +1,162 @@
+use uuid::Uuid;
+
+use super::super::*;
+use super::mock::no_limits;
+use super::odds_snapshot_repo;
+use crate::config::{ExposureConfig, ShadingConfig};
+use crate::repository::{
+    FakeUnitOfWork, MockIBetRepo, MockIGameRepo, MockIGameStatRepo, MockISimulationRepo,
+};
+
+const SHADING: ShadingConfig = ShadingConfig {
+    sensitivity: 0.0001,
+    max_shift: 0.05,
+};
+
+const WDL: [Event; 3] = [
+    Event::WDL(Winner::W1),
+    Event::WDL(Winner::X),
+    Event::WDL(Winner::W2),
+];
+
+fn bet(event: Event, amount: i64, coefficient: i32, is_won: Option<bool>) -> Bet {
+    Bet::new(
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        Amount::new(amount, None).unwrap(),
+        coefficient.try_into().unwrap(),
+        Uuid::now_v7().into(),
+        event,
+        is_won,
+    )
+}
+
+fn even_market() -> Vec<(Event, f64)> {
+    WDL.into_iter().map(|e| (e, 1. / 3.)).collect()
+}
+
+#[test]
+fn liabilities_net_of_market_stakes() {
+    let over = Event::T(EventTotal {
+        total: 2,
+        ordering: Ordering::Greater,
+    });
+    let bets = [
+        bet(WDL[0], 10_000, 250, None),
+        bet(WDL[2], 5_000, 300, None),
+        bet(over, 2_000, 190, None),
+        bet(WDL[1], 50_000, 300, Some(false)),
+    ];
+
+    let liabilities = liability::liabilities(&[WDL[0], WDL[1], WDL[2], over], &bets);
+
+    let nets = liabilities.iter().map(|&(_, net)| net).collect::<Vec<_>>();
+    assert_eq!(nets, [10_000, -15_000, 0, 1_800]);
+    assert!(
+        liabilities
+            .iter()
+            .map(|&(e, _)| e)
+            .eq([WDL[0], WDL[1], WDL[2], over])
+    );
+}
+
+#[test]
+fn liabilities_pay_what_settlement_pays() {
+    let mut won = bet(WDL[0], 10_050, 250, None);
+
+    let liabilities = liability::liabilities(&WDL, &[won]);
+
+    assert_eq!(liabilities[0].1, won.set_win().clear_value() - 10_050);
+    assert_eq!(liabilities[0].1, 14_950);
+}
+
+#[test]
+fn shading_follows_the_money() {
+    let liabilities = [(WDL[0], 20_000), (WDL[1], -10_000), (WDL[2], -10_000)];
+
+    let shaded = liability::shade(&even_market(), &liabilities, SHADING);
+
+    let probs = shaded.iter().map(|&(_, p)| p).collect::<Vec<_>>();
+    assert!((probs.iter().sum::<f64>() - 1.).abs() < 1e-9);
+    assert!(probs[0] > 1. / 3.);
+    assert!(probs[1] < 1. / 3.);
+    assert!((probs[1] - probs[2]).abs() < 1e-9);
+}
+
+#[test]
+fn shading_capped() {
+    let huge = [(WDL[0], 100_000_000), (WDL[1], 0), (WDL[2], 0)];
+    let capped = [(WDL[0], 50_000), (WDL[1], 0), (WDL[2], 0)];
+
+    let shaded = liability::shade(&even_market(), &huge, SHADING);
+
+    let expected = liability::shade(&even_market(), &capped, SHADING);
+    for ((_, p), (_, q)) in shaded.iter().zip(expected) {
+        assert!((p - q).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn no_money_no_shading() {
+    let shaded = liability::shade(&even_market(), &[], SHADING);
+
+    for ((_, p), (_, q)) in shaded.iter().zip(even_market()) {
+        assert!((p - q).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn offers_shortened_on_the_side_holding_stakes() {
+    let service = |bets: Vec<Bet>| {
+        let mut bet_repo = MockIBetRepo::new();
+        bet_repo
+            .expect_bets_by_game_id()
+            .returning(move |_| Ok(bets.clone()));
+        let mut game_repo = MockIGameRepo::new();
+        game_repo
+            .expect_games_id_by_team_id()
+            .returning(|_, _, _| Ok(vec![]));
+        game_repo
+            .expect_h2hs_id_by_team_id()
+            .returning(|_, _, _, _| Ok(vec![]));
+        BetService::new(
+            bet_repo,
+            game_repo,
+            MockIGameStatRepo::new(),
+            MockISimulationRepo::new(),
+            no_limits(),
+            FakeUnitOfWork,
+            odds_snapshot_repo(),
+            CoefficientConfig {
+                tracked_games: 5,
+                margin: Margin::try_from(0.07).unwrap(),
+                margin_method: MarginMethod::Proportional,
+                alpha: 15,
+                totals: vec![2],
+                deviation_min: 0.8,
+                deviation_max: 1.2,
+                shading: SHADING,
+            },
+            ExposureConfig::default(),
+        )
+    };
+    let game = Game::new(
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        1,
+    );
+
+    let before = service(vec![])
+        .calculate_winner_coefficients(&game)
+        .unwrap();
+    let after = service(vec![bet(WDL[0], 30_000, 279, None)])
+        .calculate_winner_coefficients(&game)
+        .unwrap();
+
+    let value = |offers: &[(Event, Coefficient)], i: usize| offers[i].1.clear_value();
+    assert!(value(&after, 0) < value(&before, 0));
+    assert!(value(&after, 1) > value(&before, 1));
+    assert!(value(&after, 2) > value(&before, 2));
+}
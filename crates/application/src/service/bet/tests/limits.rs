@@ -0,0 +1,265 @@
+use uuid::Uuid;
+
+use super::super::*;
+use super::odds_snapshot_repo;
+use crate::config::{ExposureConfig, ShadingConfig};
+use crate::error::{ErrorKind, classify};
+use crate::repository::{
+    FakeUnitOfWork, MockIBetLimitRepo, MockIBetRepo, MockIGameRepo, MockIGameStatRepo,
+    MockIOddsSnapshotRepo, MockISimulationRepo,
+};
+use crate::service::now;
+use domain::value_object::{BetLimits, LimitKind, LossLimit};
+
+type Service = BetService<
+    MockIBetRepo,
+    MockIGameRepo,
+    MockIGameStatRepo,
+    MockISimulationRepo,
+    MockIBetLimitRepo,
+    FakeUnitOfWork,
+    MockIOddsSnapshotRepo,
+>;
+
+fn amount(value: i64) -> Amount {
+    Amount::new(value, None).unwrap()
+}
+
+/// Third round of a simulation with 1000.00 and one game per round; `history` holds
+/// `(round, stake, coefficient, is_won)` of the bets made before.
+fn service(limits: BetLimits, history: &[(u32, i64, f64, Option<bool>)]) -> (Service, Game) {
+    let simulation = Simulation::new(
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        "default".to_string(),
+        amount(100000),
+        Some(3),
+    );
+    let games = [1, 2, 3].map(|round| {
+        Game::new(
+            Uuid::now_v7().into(),
+            simulation.id(),
+            Uuid::now_v7().into(),
+            Uuid::now_v7().into(),
+            round,
+        )
+    });
+    let bets = history
+        .iter()
+        .map(|&(round, stake, coefficient, is_won)| {
+            Bet::new(
+                Uuid::now_v7().into(),
+                simulation.id(),
+                amount(stake),
+                coefficient.try_into().unwrap(),
+                games[round as usize - 1].id(),
+                Event::WDL(Winner::W1),
+                is_won,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut bet_repo = MockIBetRepo::new();
+    bet_repo.expect_not_calculated_bets().returning(|_| vec![]);
+    bet_repo.expect_bets_by_game_id().returning(|_| Ok(vec![]));
+    bet_repo
+        .expect_bets_by_simulation_id()
+        .returning(move |_| Ok(bets.clone()));
+    bet_repo
+        .expect_next_id()
+        .returning(|| <Id<Bet>>::from(Uuid::now_v7()));
+    bet_repo.expect_add().returning(|_| Ok(()));
+    let mut game_repo = MockIGameRepo::new();
+    game_repo
+        .expect_game_by_id()
+        .returning(move |id| Ok(*games.iter().find(|g| g.id() == id).unwrap()));
+    game_repo
+        .expect_games_by_simulation_id()
+        .returning(move |_, max_round| {
+            Ok(games
+                .iter()
+                .filter(|g| g.round() <= max_round)
+                .copied()
+                .collect())
+        });
+    game_repo
+        .expect_games_id_by_team_id()
+        .returning(|_, _, _| Ok(vec![]));
+    game_repo
+        .expect_h2hs_id_by_team_id()
+        .returning(|_, _, _, _| Ok(vec![]));
+    let mut gs_repo = MockIGameStatRepo::new();
+    gs_repo.expect_score_by_game_id().returning(|_, _| None);
+    gs_repo.expect_winner_by_game_id().returning(|_, _| None);
+    let mut sim_repo = MockISimulationRepo::new();
+    sim_repo
+        .expect_simulation_by_id()
+        .returning(move |_| Ok(simulation.clone()));
+    sim_repo.expect_update_by_id().returning(|_| Ok(()));
+    let mut bet_limit_repo = MockIBetLimitRepo::new();
+    bet_limit_repo
+        .expect_limits_by_simulation_id()
+        .returning(move |_| Ok(limits));
+    let config = CoefficientConfig {
+        tracked_games: 5,
+        margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
+        alpha: 15,
+        totals: vec![2, 3],
+        deviation_min: 0.8,
+        deviation_max: 1.2,
+        shading: ShadingConfig::default(),
+    };
+    let bs = BetService::new(
+        bet_repo,
+        game_repo,
+        gs_repo,
+        sim_repo,
+        bet_limit_repo,
+        FakeUnitOfWork,
+        odds_snapshot_repo(),
+        config,
+        ExposureConfig::default(),
+    );
+
+    (bs, games[2])
+}
+
+fn make_bet(bs: &Service, game: Game, stake: i64) -> Result<Vec<LimitWarning>> {
+    let simulation = bs
+        .simulation_repo
+        .simulation_by_id(game.simulation_id())
+        .unwrap();
+    let (event, coefficient) = bs.calculate_coefficients(&simulation, game.id()).unwrap()[0];
+
+    bs.make_bet(
+        game.simulation_id(),
+        game.id(),
+        amount(stake),
+        event,
+        coefficient,
+    )
+}
+
+fn refusal(res: Result<Vec<LimitWarning>>) -> BetValidationError {
+    *res.err()
+        .unwrap()
+        .downcast_ref::<BetValidationError>()
+        .unwrap()
+}
+
+fn warned(warnings: &[LimitWarning]) -> Vec<(LimitKind, i64)> {
+    warnings
+        .iter()
+        .map(|w| (w.kind, w.used.clear_value()))
+        .collect()
+}
+
+#[test]
+fn no_limits() {
+    let (bs, game) = service(BetLimits::default(), &[(3, 50000, 2.0, None)]);
+
+    let res = make_bet(&bs, game, 50000);
+
+    assert!(res.unwrap().is_empty());
+}
+
+#[test]
+fn stake_limit() {
+    let limits = BetLimits {
+        max_stake: Some(amount(5000)),
+        ..Default::default()
+    };
+    let (bs, game) = service(limits, &[]);
+
+    let quiet = make_bet(&bs, game, 3000).unwrap();
+    let close = make_bet(&bs, game, 4000).unwrap();
+    let over = make_bet(&bs, game, 5001);
+
+    assert!(quiet.is_empty());
+    assert_eq!(warned(&close), [(LimitKind::Stake, 4000)]);
+    assert_eq!(close[0].max.clear_value(), 5000);
+    assert_eq!(
+        refusal(over),
+        BetValidationError::LimitExceeded {
+            limit: LimitKind::Stake,
+            max: 5000
+        }
+    );
+}
+
+#[test]
+fn round_stake_limit() {
+    let limits = BetLimits {
+        max_round_stake: Some(amount(10000)),
+        ..Default::default()
+    };
+    // Stakes of earlier rounds don't count.
+    let history = [(2, 9000, 2.0, Some(false)), (3, 7000, 2.0, None)];
+    let (bs, game) = service(limits, &history);
+
+    let close = make_bet(&bs, game, 2000).unwrap();
+    let over = make_bet(&bs, game, 3001);
+
+    assert_eq!(warned(&close), [(LimitKind::RoundStake, 9000)]);
+    assert_eq!(
+        refusal(over),
+        BetValidationError::LimitExceeded {
+            limit: LimitKind::RoundStake,
+            max: 10000
+        }
+    );
+}
+
+#[test]
+fn loss_limit() {
+    let limits = BetLimits {
+        loss_limit: Some(LossLimit {
+            amount: amount(10000),
+            rounds: 2,
+        }),
+        ..Default::default()
+    };
+    // Round 1 is out of the window, round 2 lost 50.00 and won back 40.00 on top of the stake,
+    // the pending stake of round 3 counts as lost.
+    let history = [
+        (1, 20000, 2.0, Some(false)),
+        (2, 5000, 2.0, Some(false)),
+        (2, 2000, 3.0, Some(true)),
+        (3, 1000, 2.0, None),
+    ];
+    let (bs, game) = service(limits, &history);
+
+    let quiet = make_bet(&bs, game, 5000).unwrap();
+    let close = make_bet(&bs, game, 8000).unwrap();
+    let over = make_bet(&bs, game, 8001);
+
+    assert!(quiet.is_empty());
+    assert_eq!(warned(&close), [(LimitKind::Loss, 10000)]);
+    assert_eq!(
+        classify(&over.err().unwrap()),
+        (ErrorKind::Forbidden, "loss_limit_exceeded")
+    );
+}
+
+#[test]
+fn cooling_off() {
+    let running = BetLimits {
+        cool_off_until: Some(now() + 3600),
+        ..Default::default()
+    };
+    let expired = BetLimits {
+        cool_off_until: Some(now() - 1),
+        ..Default::default()
+    };
+    let (blocked, game) = service(running, &[]);
+    let (allowed, other) = service(expired, &[]);
+
+    let res = make_bet(&blocked, game, 1000);
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Forbidden, "cooling_off")
+    );
+    assert!(make_bet(&allowed, other, 1000).is_ok());
+}
@@ -1,103 +1,192 @@
 use anyhow::anyhow;
-use std::net::Ipv4Addr;
 use uuid::Uuid;
 
 use super::super::*;
-use crate::repository::{MockIBetRepo, MockIGameRepo, MockIGameStatRepo, MockISimulationRepo};
+use super::odds_snapshot_repo;
+use crate::config::{ExposureConfig, ShadingConfig};
+use crate::repository::{
+    FakeUnitOfWork, MockIBetLimitRepo, MockIBetRepo, MockIGameRepo, MockIGameStatRepo,
+    MockISimulationRepo,
+};
+use domain::value_object::BetLimits;
+
+/// Limits aren't under test here.
+pub(super) fn no_limits() -> MockIBetLimitRepo {
+    let mut bet_limit_repo = MockIBetLimitRepo::new();
+    bet_limit_repo
+        .expect_limits_by_simulation_id()
+        .returning(|_| Ok(BetLimits::default()));
+
+    bet_limit_repo
+}
 
 #[test]
 fn make_bet_success() {
+    let sim_id = <Id<Simulation>>::from(Uuid::now_v7());
+    let game_id = <Id<Game>>::from(Uuid::now_v7());
     let mut bet_repo = MockIBetRepo::new();
+    bet_repo.expect_not_calculated_bets().returning(|_| vec![]);
     bet_repo
         .expect_next_id()
         .returning(|| <Id<Bet>>::from(Uuid::now_v7()));
+    bet_repo.expect_bets_by_game_id().returning(|_| Ok(vec![]));
     bet_repo.expect_add().returning(|_| Ok(()));
-    let game_repo = MockIGameRepo::new();
-    let gs_repo = MockIGameStatRepo::new();
+    let mut game_repo = MockIGameRepo::new();
+    game_repo.expect_game_by_id().returning(move |_| {
+        Ok(Game::new(
+            game_id,
+            sim_id,
+            Uuid::now_v7().into(),
+            Uuid::now_v7().into(),
+            1,
+        ))
+    });
+    game_repo
+        .expect_games_id_by_team_id()
+        .returning(|_, _, _| Ok(vec![]));
+    game_repo
+        .expect_h2hs_id_by_team_id()
+        .returning(|_, _, _, _| Ok(vec![]));
+    let mut gs_repo = MockIGameStatRepo::new();
+    gs_repo.expect_score_by_game_id().returning(|_, _| None);
     let mut sim_repo = MockISimulationRepo::new();
-    sim_repo.expect_simulation_by_id().returning(|_| {
+    sim_repo.expect_simulation_by_id().returning(move |_| {
         Ok(Simulation::new(
+            sim_id,
             Uuid::now_v7().into(),
-            std::net::IpAddr::V4(Ipv4Addr::LOCALHOST),
-            Amount::new(1000, None).unwrap(),
-            None,
+            "default".to_string(),
+            Amount::new(100000, None).unwrap(),
+            Some(1),
         ))
     });
     sim_repo.expect_update_by_id().returning(|_| Ok(()));
     let config = CoefficientConfig {
         tracked_games: 5,
         margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
         alpha: 15,
         totals: vec![2, 3],
         deviation_min: 0.8,
         deviation_max: 1.2,
+        shading: ShadingConfig::default(),
     };
-    let bs = BetService::new(bet_repo, game_repo, gs_repo, sim_repo, config);
-    let game = Game::new(
-        Uuid::now_v7().into(),
-        Uuid::now_v7().into(),
-        Uuid::now_v7().into(),
-        Uuid::now_v7().into(),
-        1,
+    let bs = BetService::new(
+        bet_repo,
+        game_repo,
+        gs_repo,
+        sim_repo,
+        no_limits(),
+        FakeUnitOfWork,
+        odds_snapshot_repo(),
+        config,
+        ExposureConfig::default(),
     );
+    let simulation = bs.simulation_repo.simulation_by_id(sim_id).unwrap();
     let amount = Amount::new(1000, None).unwrap();
-    let event = Event::WDL(Winner::W1);
-    let coefficient = 189.try_into().unwrap();
+    let (event, coefficient) = bs.calculate_coefficients(&simulation, game_id).unwrap()[0];
 
-    let res = bs.make_bet(&game, amount, event, coefficient);
+    let res = bs.make_bet(sim_id, game_id, amount, event, coefficient);
 
     assert!(res.is_ok());
 }
 
 #[test]
 fn make_bet_failure() {
+    let sim_id = <Id<Simulation>>::from(Uuid::now_v7());
+    let game_id = <Id<Game>>::from(Uuid::now_v7());
     let mut bet_repo = MockIBetRepo::new();
+    bet_repo.expect_not_calculated_bets().returning(|_| vec![]);
     bet_repo
         .expect_next_id()
         .returning(|| <Id<Bet>>::from(Uuid::now_v7()));
+    bet_repo.expect_bets_by_game_id().returning(|_| Ok(vec![]));
     bet_repo.expect_add().returning(|_| Err(anyhow!("err")));
-    let game_repo = MockIGameRepo::new();
-    let gs_repo = MockIGameStatRepo::new();
+    let mut game_repo = MockIGameRepo::new();
+    game_repo.expect_game_by_id().returning(move |_| {
+        Ok(Game::new(
+            game_id,
+            sim_id,
+            Uuid::now_v7().into(),
+            Uuid::now_v7().into(),
+            1,
+        ))
+    });
+    game_repo
+        .expect_games_id_by_team_id()
+        .returning(|_, _, _| Ok(vec![]));
+    game_repo
+        .expect_h2hs_id_by_team_id()
+        .returning(|_, _, _, _| Ok(vec![]));
+    let mut gs_repo = MockIGameStatRepo::new();
+    gs_repo.expect_score_by_game_id().returning(|_, _| None);
     let mut sim_repo = MockISimulationRepo::new();
-    sim_repo.expect_simulation_by_id().returning(|_| {
+    sim_repo.expect_simulation_by_id().returning(move |_| {
         Ok(Simulation::new(
+            sim_id,
             Uuid::now_v7().into(),
-            std::net::IpAddr::V4(Ipv4Addr::LOCALHOST),
-            Amount::new(1000, None).unwrap(),
-            None,
+            "default".to_string(),
+            Amount::new(100000, None).unwrap(),
+            Some(1),
         ))
     });
     sim_repo.expect_update_by_id().returning(|_| Ok(()));
     let config = CoefficientConfig {
         tracked_games: 5,
         margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
         alpha: 15,
         totals: vec![2, 3],
         deviation_min: 0.8,
         deviation_max: 1.2,
+        shading: ShadingConfig::default(),
     };
-    let bs = BetService::new(bet_repo, game_repo, gs_repo, sim_repo, config);
-    let game = Game::new(
+    let bs = BetService::new(
+        bet_repo,
+        game_repo,
+        gs_repo,
+        sim_repo,
+        no_limits(),
+        FakeUnitOfWork,
+        odds_snapshot_repo(),
+        config,
+        ExposureConfig::default(),
+    );
+    let simulation = bs.simulation_repo.simulation_by_id(sim_id).unwrap();
+    let amount = Amount::new(1000, None).unwrap();
+    let (event, coefficient) = bs.calculate_coefficients(&simulation, game_id).unwrap()[0];
+
+    let res = bs.make_bet(sim_id, game_id, amount, event, coefficient);
+
+    assert!(res.is_err());
+    assert_eq!(res.err().unwrap().to_string(), "err");
+}
+
+/// Simulation in its first round and a game of it.
+fn offered_game() -> (Simulation, Game) {
+    let simulation = Simulation::new(
         Uuid::now_v7().into(),
         Uuid::now_v7().into(),
+        "default".to_string(),
+        Amount::new(100000, None).unwrap(),
+        Some(1),
+    );
+    let game = Game::new(
+        Uuid::now_v7().into(),
+        simulation.id(),
         Uuid::now_v7().into(),
         Uuid::now_v7().into(),
         1,
     );
-    let amount = Amount::new(1000, None).unwrap();
-    let event = Event::WDL(Winner::W1);
-    let coefficient = 189.try_into().unwrap();
-
-    let res = bs.make_bet(&game, amount, event, coefficient);
 
-    assert!(res.is_err());
-    assert_eq!(res.err().unwrap().to_string(), "err");
+    (simulation, game)
 }
 
 #[test]
 fn calculate_coefficients_success() {
+    let (simulation, game) = offered_game();
     let bet_repo = MockIBetRepo::new();
     let mut game_repo = MockIGameRepo::new();
+    game_repo.expect_game_by_id().returning(move |_| Ok(game));
     game_repo
         .expect_games_id_by_team_id()
         .returning(|_, _, _| Ok(vec![(<Id<Game>>::from(Uuid::now_v7()), true)]));
@@ -115,29 +204,36 @@ fn calculate_coefficients_success() {
     let config = CoefficientConfig {
         tracked_games: 5,
         margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
         alpha: 15,
         totals: vec![2, 3],
         deviation_min: 0.8,
         deviation_max: 1.2,
+        shading: ShadingConfig::default(),
     };
-    let bs = BetService::new(bet_repo, game_repo, gs_repo, sim_repo, config);
-    let game = Game::new(
-        Uuid::now_v7().into(),
-        Uuid::now_v7().into(),
-        Uuid::now_v7().into(),
-        Uuid::now_v7().into(),
-        1,
+    let bs = BetService::new(
+        bet_repo,
+        game_repo,
+        gs_repo,
+        sim_repo,
+        no_limits(),
+        FakeUnitOfWork,
+        odds_snapshot_repo(),
+        config,
+        ExposureConfig::default(),
     );
 
-    let res = bs.calculate_coefficients(&game);
+    let res = bs.calculate_coefficients(&simulation, game.id());
 
     assert!(res.is_ok());
 }
 
 #[test]
 fn calculate_coefficients_failure() {
+    let (simulation, game) = offered_game();
     let bet_repo = MockIBetRepo::new();
     let mut game_repo = MockIGameRepo::new();
+    game_repo.expect_game_by_id().returning(move |_| Ok(game));
     game_repo
         .expect_games_id_by_team_id()
         .returning(|_, _, _| Ok(vec![(<Id<Game>>::from(Uuid::now_v7()), true)]));
@@ -155,21 +251,26 @@ fn calculate_coefficients_failure() {
     let config = CoefficientConfig {
         tracked_games: 5,
         margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
         alpha: 15,
         totals: vec![2, 3],
         deviation_min: 0.8,
         deviation_max: 1.2,
+        shading: ShadingConfig::default(),
     };
-    let bs = BetService::new(bet_repo, game_repo, gs_repo, sim_repo, config);
-    let game = Game::new(
-        Uuid::now_v7().into(),
-        Uuid::now_v7().into(),
-        Uuid::now_v7().into(),
-        Uuid::now_v7().into(),
-        1,
+    let bs = BetService::new(
+        bet_repo,
+        game_repo,
+        gs_repo,
+        sim_repo,
+        no_limits(),
+        FakeUnitOfWork,
+        odds_snapshot_repo(),
+        config,
+        ExposureConfig::default(),
     );
 
-    let res = bs.calculate_coefficients(&game);
+    let res = bs.calculate_coefficients(&simulation, game.id());
 
     assert!(res.is_err());
     assert_eq!(res.err().unwrap().to_string(), "err");
@@ -178,7 +279,7 @@ fn calculate_coefficients_failure() {
 #[test]
 fn calculate_bets_success() {
     let mut bet_repo = MockIBetRepo::new();
-    bet_repo.expect_not_calculated_bets().returning(|| {
+    bet_repo.expect_not_calculated_bets().returning(|_| {
         vec![Bet::new(
             Uuid::now_v7().into(),
             Uuid::now_v7().into(),
@@ -199,7 +300,8 @@ fn calculate_bets_success() {
     sim_repo.expect_simulation_by_id().returning(|_| {
         Ok(Simulation::new(
             Uuid::now_v7().into(),
-            std::net::IpAddr::V4(Ipv4Addr::LOCALHOST),
+            Uuid::now_v7().into(),
+            "default".to_string(),
             Amount::new(1000, None).unwrap(),
             None,
         ))
@@ -208,14 +310,26 @@ fn calculate_bets_success() {
     let config = CoefficientConfig {
         tracked_games: 5,
         margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
         alpha: 15,
         totals: vec![2, 3],
         deviation_min: 0.8,
         deviation_max: 1.2,
+        shading: ShadingConfig::default(),
     };
-    let bs = BetService::new(bet_repo, game_repo, gs_repo, sim_repo, config);
+    let bs = BetService::new(
+        bet_repo,
+        game_repo,
+        gs_repo,
+        sim_repo,
+        no_limits(),
+        FakeUnitOfWork,
+        odds_snapshot_repo(),
+        config,
+        ExposureConfig::default(),
+    );
 
-    let res = bs.calculate_bets();
+    let res = bs.calculate_bets(Uuid::now_v7().into());
 
     assert!(res.is_ok());
 }
@@ -223,7 +337,7 @@ fn calculate_bets_success() {
 #[test]
 fn calculate_bets_failure() {
     let mut bet_repo = MockIBetRepo::new();
-    bet_repo.expect_not_calculated_bets().returning(|| {
+    bet_repo.expect_not_calculated_bets().returning(|_| {
         vec![Bet::new(
             Uuid::now_v7().into(),
             Uuid::now_v7().into(),
@@ -244,7 +358,8 @@ fn calculate_bets_failure() {
     sim_repo.expect_simulation_by_id().returning(|_| {
         Ok(Simulation::new(
             Uuid::now_v7().into(),
-            std::net::IpAddr::V4(Ipv4Addr::LOCALHOST),
+            Uuid::now_v7().into(),
+            "default".to_string(),
             Amount::new(1000, None).unwrap(),
             None,
         ))
@@ -255,14 +370,26 @@ fn calculate_bets_failure() {
     let config = CoefficientConfig {
         tracked_games: 5,
         margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
         alpha: 15,
         totals: vec![2, 3],
         deviation_min: 0.8,
         deviation_max: 1.2,
+        shading: ShadingConfig::default(),
     };
-    let bs = BetService::new(bet_repo, game_repo, gs_repo, sim_repo, config);
+    let bs = BetService::new(
+        bet_repo,
+        game_repo,
+        gs_repo,
+        sim_repo,
+        no_limits(),
+        FakeUnitOfWork,
+        odds_snapshot_repo(),
+        config,
+        ExposureConfig::default(),
+    );
 
-    let res = bs.calculate_bets();
+    let res = bs.calculate_bets(Uuid::now_v7().into());
 
     assert!(res.is_err());
     assert_eq!(res.err().unwrap().to_string(), "err");
@@ -274,22 +401,37 @@ fn make_report_full() {
     let coefficient = Coefficient::try_from(189).unwrap();
     bet_repo
         .expect_min_coefficient_lose()
-        .returning(move || Some(coefficient.clone()));
+        .returning(move |_| Some(coefficient));
+    bet_repo
+        .expect_bets_by_simulation_id()
+        .returning(|_| Ok(vec![]));
     let game_repo = MockIGameRepo::new();
     let gs_repo = MockIGameStatRepo::new();
     let sim_repo = MockISimulationRepo::new();
     let config = CoefficientConfig {
         tracked_games: 5,
         margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
         alpha: 15,
         totals: vec![2, 3],
         deviation_min: 0.8,
         deviation_max: 1.2,
+        shading: ShadingConfig::default(),
     };
-    let bs = BetService::new(bet_repo, game_repo, gs_repo, sim_repo, config);
+    let bs = BetService::new(
+        bet_repo,
+        game_repo,
+        gs_repo,
+        sim_repo,
+        no_limits(),
+        FakeUnitOfWork,
+        odds_snapshot_repo(),
+        config,
+        ExposureConfig::default(),
+    );
     let amount = Amount::new(1000, None).unwrap();
 
-    let bs = bs.make_report(amount);
+    let bs = bs.make_report(Uuid::now_v7().into(), amount).unwrap();
 
     assert!(bs.start_balance() == amount);
     assert_eq!(bs.min_coefficient_lose(), Some(coefficient));
@@ -298,23 +440,39 @@ fn make_report_full() {
 #[test]
 fn make_report_empty() {
     let mut bet_repo = MockIBetRepo::new();
-    bet_repo.expect_min_coefficient_lose().returning(|| None);
+    bet_repo.expect_min_coefficient_lose().returning(|_| None);
+    bet_repo
+        .expect_bets_by_simulation_id()
+        .returning(|_| Ok(vec![]));
     let game_repo = MockIGameRepo::new();
     let gs_repo = MockIGameStatRepo::new();
     let sim_repo = MockISimulationRepo::new();
     let config = CoefficientConfig {
         tracked_games: 5,
         margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
         alpha: 15,
         totals: vec![2, 3],
         deviation_min: 0.8,
         deviation_max: 1.2,
+        shading: ShadingConfig::default(),
     };
-    let bs = BetService::new(bet_repo, game_repo, gs_repo, sim_repo, config);
+    let bs = BetService::new(
+        bet_repo,
+        game_repo,
+        gs_repo,
+        sim_repo,
+        no_limits(),
+        FakeUnitOfWork,
+        odds_snapshot_repo(),
+        config,
+        ExposureConfig::default(),
+    );
     let amount = Amount::new(1000, None).unwrap();
 
-    let bs = bs.make_report(amount);
+    let bs = bs.make_report(Uuid::now_v7().into(), amount).unwrap();
 
     assert!(bs.start_balance() == amount);
     assert_eq!(bs.min_coefficient_lose(), None);
+    assert_eq!(bs.clv(), None);
 }
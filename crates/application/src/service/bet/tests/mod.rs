@@ -1,2 +1,28 @@
 mod mock;
 mod classic;
+mod validation;
+mod limits;
+mod advice;
+mod overround;
+mod liability;
+mod exposure;
+mod odds;
+
+use uuid::Uuid;
+
+use crate::repository::MockIOddsSnapshotRepo;
+use domain::{entity::OddsSnapshot, value_object::Id};
+
+/// Odds snapshot repo keeping nothing, so every offer is new to it.
+fn odds_snapshot_repo() -> MockIOddsSnapshotRepo {
+    let mut odds_snapshot_repo = MockIOddsSnapshotRepo::new();
+    odds_snapshot_repo
+        .expect_snapshots_by_game_id()
+        .returning(|_| Ok(vec![]));
+    odds_snapshot_repo
+        .expect_next_id()
+        .returning(|| <Id<OddsSnapshot>>::from(Uuid::now_v7()));
+    odds_snapshot_repo.expect_add_all().returning(|_| Ok(()));
+
+    odds_snapshot_repo
+}
@@ -0,0 +1,297 @@
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use super::super::*;
+use super::mock::no_limits;
+use crate::config::{ExposureConfig, ShadingConfig};
+use crate::error::{AppError, Entity, ErrorKind, classify};
+use crate::repository::{
+    FakeUnitOfWork, MockIBetLimitRepo, MockIBetRepo, MockIGameRepo, MockIGameStatRepo,
+    MockIOddsSnapshotRepo, MockISimulationRepo,
+};
+use crate::usecase::{MakeReport, ShowOddsHistory};
+use domain::entity::OddsSnapshot;
+
+type Service = BetService<
+    MockIBetRepo,
+    MockIGameRepo,
+    MockIGameStatRepo,
+    MockISimulationRepo,
+    MockIBetLimitRepo,
+    FakeUnitOfWork,
+    MockIOddsSnapshotRepo,
+>;
+
+type Snapshots = Arc<Mutex<Vec<OddsSnapshot>>>;
+
+const W1: Event = Event::WDL(Winner::W1);
+
+fn game() -> Game {
+    Game::new(
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        1,
+    )
+}
+
+/// Simulation `game` is of, in `round`.
+fn simulation(game: Game, round: u32) -> Simulation {
+    Simulation::new(
+        game.simulation_id(),
+        Uuid::now_v7().into(),
+        "default".to_string(),
+        Amount::new(100_000, None).unwrap(),
+        Some(round),
+    )
+}
+
+fn snapshot(game: Game, event: Event, coefficient: f64, created_at: i64) -> OddsSnapshot {
+    OddsSnapshot::new(
+        Uuid::now_v7().into(),
+        game.simulation_id(),
+        game.id(),
+        event,
+        coefficient.try_into().unwrap(),
+        created_at,
+    )
+}
+
+fn bet(game: Game, event: Event, coefficient: f64, is_won: Option<bool>) -> Bet {
+    Bet::new(
+        Uuid::now_v7().into(),
+        game.simulation_id(),
+        Amount::new(10_000, None).unwrap(),
+        coefficient.try_into().unwrap(),
+        game.id(),
+        event,
+        is_won,
+    )
+}
+
+/// Service over a simulation without any played games but `game` if it has a `score`,
+/// keeping snapshots in `snapshots`.
+fn service(game: Game, snapshots: Snapshots, score: Option<(u8, u8)>, bets: Vec<Bet>) -> Service {
+    let mut bet_repo = MockIBetRepo::new();
+    bet_repo.expect_min_coefficient_lose().returning(|_| None);
+    bet_repo
+        .expect_bets_by_simulation_id()
+        .returning(move |_| Ok(bets.clone()));
+    let mut game_repo = MockIGameRepo::new();
+    game_repo
+        .expect_games_id_by_team_id()
+        .returning(|_, _, _| Ok(vec![]));
+    game_repo
+        .expect_h2hs_id_by_team_id()
+        .returning(|_, _, _, _| Ok(vec![]));
+    game_repo.expect_game_by_id().returning(move |id| {
+        if id == game.id() {
+            Ok(game)
+        } else {
+            Err(AppError::NotFound(Entity::Game).into())
+        }
+    });
+    let mut gs_repo = MockIGameStatRepo::new();
+    gs_repo
+        .expect_score_by_game_id()
+        .returning(move |id, _| score.filter(|_| id == game.id()));
+    let mut odds_snapshot_repo = MockIOddsSnapshotRepo::new();
+    let stored = snapshots.clone();
+    odds_snapshot_repo
+        .expect_snapshots_by_game_id()
+        .returning(move |_| Ok(stored.lock().unwrap().clone()));
+    odds_snapshot_repo
+        .expect_next_id()
+        .returning(|| <Id<OddsSnapshot>>::from(Uuid::now_v7()));
+    odds_snapshot_repo.expect_add_all().returning(move |added| {
+        snapshots.lock().unwrap().extend(added);
+        Ok(())
+    });
+    let config = CoefficientConfig {
+        tracked_games: 5,
+        margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
+        alpha: 15,
+        totals: vec![2, 3],
+        deviation_min: 0.8,
+        deviation_max: 1.2,
+        shading: ShadingConfig::default(),
+    };
+
+    BetService::new(
+        bet_repo,
+        game_repo,
+        gs_repo,
+        MockISimulationRepo::new(),
+        no_limits(),
+        FakeUnitOfWork,
+        odds_snapshot_repo,
+        config,
+        ExposureConfig::default(),
+    )
+}
+
+#[test]
+fn offers_recorded_once() {
+    let game = game();
+    let snapshots = Snapshots::default();
+    let bs = service(game, snapshots.clone(), None, vec![]);
+
+    let offers = bs
+        .calculate_coefficients(&simulation(game, 1), game.id())
+        .unwrap();
+    bs.calculate_coefficients(&simulation(game, 1), game.id())
+        .unwrap();
+
+    let snapshots = snapshots.lock().unwrap();
+    assert_eq!(snapshots.len(), offers.len());
+    for (snapshot, (event, coefficient)) in snapshots.iter().zip(offers) {
+        assert!(snapshot.game_id() == game.id());
+        assert_eq!(snapshot.event(), event);
+        assert_eq!(snapshot.coefficient(), coefficient);
+    }
+}
+
+#[test]
+fn changed_price_recorded() {
+    let game = game();
+    let snapshots = Snapshots::default();
+    let bs = service(game, snapshots.clone(), None, vec![]);
+    let offers = bs
+        .calculate_coefficients(&simulation(game, 1), game.id())
+        .unwrap();
+    snapshots.lock().unwrap().push(snapshot(game, W1, 9.5, 0));
+
+    bs.calculate_coefficients(&simulation(game, 1), game.id())
+        .unwrap();
+
+    let snapshots = snapshots.lock().unwrap();
+    assert_eq!(snapshots.len(), offers.len() + 2);
+    assert_eq!(snapshots.last().unwrap().event(), W1);
+    assert_eq!(snapshots.last().unwrap().coefficient(), offers[0].1);
+}
+
+#[test]
+fn history_per_event() {
+    let game = game();
+    let total = Event::T(EventTotal {
+        total: 2,
+        ordering: Ordering::Less,
+    });
+    let snapshots = Snapshots::new(Mutex::new(vec![
+        snapshot(game, W1, 2.1, 10),
+        snapshot(game, total, 1.8, 10),
+        snapshot(game, W1, 1.95, 20),
+        snapshot(game, W1, 1.9, 30),
+    ]));
+    let bs = service(game, snapshots, None, vec![]);
+
+    let history = bs.odds_history(game.simulation_id(), game.id()).unwrap();
+
+    let history = history
+        .iter()
+        .map(|h| {
+            (
+                h.event,
+                h.snapshots.len(),
+                f64::from(h.opening()),
+                f64::from(h.closing()),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(history, [(W1, 3, 2.1, 1.9), (total, 1, 1.8, 1.8)]);
+}
+
+#[test]
+fn history_of_foreign_game() {
+    let game = game();
+    let bs = service(game, Snapshots::default(), None, vec![]);
+
+    let res = bs.odds_history(Uuid::now_v7().into(), game.id());
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::NotFound, "game_not_found")
+    );
+}
+
+#[test]
+fn offers_of_other_games_refused() {
+    let game = game();
+    let snapshots = Snapshots::default();
+    let bs = service(game, snapshots.clone(), None, vec![]);
+    let foreign = Simulation::new(
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        "default".to_string(),
+        Amount::new(100_000, None).unwrap(),
+        Some(1),
+    );
+
+    let refused = [
+        bs.calculate_coefficients(&foreign, game.id()),
+        bs.calculate_coefficients(&simulation(game, 1), Uuid::now_v7().into()),
+        bs.calculate_coefficients(&simulation(game, 2), game.id()),
+    ];
+
+    for res in refused {
+        assert_eq!(
+            classify(&res.err().unwrap()),
+            (ErrorKind::NotFound, "game_not_found")
+        );
+    }
+    assert!(snapshots.lock().unwrap().is_empty());
+    assert!(
+        bs.max_stakes(
+            &foreign,
+            game.id(),
+            &[(W1, Coefficient::try_from(2.).unwrap())]
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn played_game_keeps_closing_prices() {
+    let game = game();
+    let snapshots = Snapshots::new(Mutex::new(vec![snapshot(game, W1, 9.5, 0)]));
+    let bs = service(game, snapshots.clone(), Some((1, 0)), vec![]);
+
+    bs.calculate_coefficients(&simulation(game, 1), game.id())
+        .unwrap();
+
+    assert_eq!(snapshots.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn clv_against_closing_price() {
+    let game = game();
+    let draw = Event::WDL(Winner::X);
+    let snapshots = Snapshots::new(Mutex::new(vec![
+        snapshot(game, W1, 2.2, 10),
+        snapshot(game, draw, 3.0, 10),
+        snapshot(game, W1, 2.0, 20),
+    ]));
+    let bets = vec![
+        bet(game, W1, 2.2, Some(true)),
+        bet(game, draw, 2.4, Some(false)),
+        bet(game, Event::WDL(Winner::W2), 4.0, None),
+    ];
+    let bs = service(game, snapshots, Some((1, 0)), bets);
+
+    let lines = bs.closing_lines(game.simulation_id()).unwrap();
+    let stat = bs
+        .make_report(game.simulation_id(), Amount::new(100_000, None).unwrap())
+        .unwrap();
+
+    let closing = lines
+        .iter()
+        .map(|l| l.closing.map(f64::from))
+        .collect::<Vec<_>>();
+    assert_eq!(closing, [Some(2.0), Some(3.0), None]);
+    assert!((lines[0].clv().unwrap() - 0.1).abs() < 1e-9);
+    assert!((lines[1].clv().unwrap() + 0.2).abs() < 1e-9);
+    assert_eq!(lines[2].clv(), None);
+    assert!((stat.clv().unwrap() + 0.05).abs() < 1e-9);
+}
@@ -0,0 +1,103 @@
+use super::super::*;
+
+const METHODS: [MarginMethod; 5] = [
+    MarginMethod::Proportional,
+    MarginMethod::MarginWeighted,
+    MarginMethod::Shin,
+    MarginMethod::Power,
+    MarginMethod::OddsRatio,
+];
+
+const MARKETS: [[f64; 3]; 4] = [
+    [0.5, 0.3, 0.2],
+    [1. / 3., 1. / 3., 1. / 3.],
+    [0.7, 0.2, 0.1],
+    [0.45, 0.1, 0.45],
+];
+
+fn margin(value: f64) -> Margin {
+    Margin::try_from(value).unwrap()
+}
+
+#[test]
+fn book_adds_up_to_margin_for_every_method() {
+    for method in METHODS {
+        for m in [0.05, 0.12] {
+            for probs in MARKETS {
+                let implied = overround::distribute(method, &probs, margin(m));
+
+                let book = implied.iter().sum::<f64>();
+                assert!((book - 1. / (1. - m)).abs() < 1e-9, "{method:?} {probs:?}");
+                for (q, p) in implied.iter().zip(probs) {
+                    assert!(*q > p && *q < 1., "{method:?} {probs:?}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn zero_margin_keeps_model_probabilities() {
+    for method in METHODS {
+        let implied = overround::distribute(method, &MARKETS[0], margin(0.));
+
+        for (q, p) in implied.iter().zip(MARKETS[0]) {
+            assert!((q - p).abs() < 1e-9, "{method:?}");
+        }
+    }
+}
+
+#[test]
+fn proportional_spreads_margin_evenly() {
+    let implied = overround::distribute(MarginMethod::Proportional, &MARKETS[2], margin(0.12));
+
+    for (q, p) in implied.iter().zip(MARKETS[2]) {
+        assert!((q / p - 1. / 0.88).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn longshots_carry_more_margin() {
+    for method in &METHODS[1..] {
+        let implied = overround::distribute(*method, &MARKETS[2], margin(0.12));
+
+        let favourite = implied[0] / MARKETS[2][0];
+        let longshot = implied[2] / MARKETS[2][2];
+        assert!(longshot > favourite, "{method:?}");
+    }
+}
+
+#[test]
+fn price_spreads_margin_per_market() {
+    let mut totals = PastTotals::new(2);
+    totals.add_total(1);
+    totals.add_total(3);
+    totals.add_total(4);
+    let home_res = PastResults {
+        wins: 3,
+        draws: 1,
+        loses: 1,
+    };
+    let mut probs = BetCalculator::winner_probabilities(
+        home_res,
+        PastResults::new(),
+        PastResults::new(),
+        30,
+        5,
+    );
+    probs.append(&mut BetCalculator::total_probabilities(2, totals));
+    probs.append(&mut BetCalculator::total_probabilities(
+        3,
+        PastTotals::new(3),
+    ));
+
+    for method in METHODS {
+        let coefficients = BetCalculator::price(&probs, margin(0.07), method).unwrap();
+
+        assert_eq!(coefficients.len(), 9);
+        for market in coefficients.chunks(3) {
+            let book = market.iter().map(|(_, c)| 1. / f64::from(*c)).sum::<f64>();
+            assert!((book - 1. / 0.93).abs() < 0.01, "{method:?} {book}");
+        }
+    }
+}
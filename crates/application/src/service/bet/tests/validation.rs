@@ -0,0 +1,324 @@
+use std::cmp::Ordering;
+use uuid::Uuid;
+
+use super::super::*;
+use super::mock::no_limits;
+use super::odds_snapshot_repo;
+use crate::config::{ExposureConfig, ShadingConfig};
+use crate::error::{ErrorKind, classify};
+use crate::repository::{
+    FakeUnitOfWork, MockIBetLimitRepo, MockIBetRepo, MockIGameRepo, MockIGameStatRepo,
+    MockIOddsSnapshotRepo, MockISimulationRepo,
+};
+use domain::value_object::{MAX_BET_AMOUNT, MIN_BET_AMOUNT};
+
+type Service = BetService<
+    MockIBetRepo,
+    MockIGameRepo,
+    MockIGameStatRepo,
+    MockISimulationRepo,
+    MockIBetLimitRepo,
+    FakeUnitOfWork,
+    MockIOddsSnapshotRepo,
+>;
+
+fn simulation(balance: i64) -> Simulation {
+    Simulation::new(
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        "default".to_string(),
+        Amount::new(balance, None).unwrap(),
+        Some(1),
+    )
+}
+
+fn game(simulation: &Simulation, round: u32) -> Game {
+    Game::new(
+        Uuid::now_v7().into(),
+        simulation.id(),
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        round,
+    )
+}
+
+fn service(simulation: Simulation, game: Game, played: bool, placed: Vec<Event>) -> Service {
+    let simulation_id = simulation.id();
+    let mut bet_repo = MockIBetRepo::new();
+    bet_repo.expect_not_calculated_bets().returning(|_| vec![]);
+    bet_repo.expect_bets_by_game_id().returning(move |game_id| {
+        Ok(placed
+            .iter()
+            .map(|&event| {
+                Bet::new(
+                    Uuid::now_v7().into(),
+                    simulation_id,
+                    Amount::new(1000, None).unwrap(),
+                    189.try_into().unwrap(),
+                    game_id,
+                    event,
+                    None,
+                )
+            })
+            .collect())
+    });
+    bet_repo
+        .expect_next_id()
+        .returning(|| <Id<Bet>>::from(Uuid::now_v7()));
+    bet_repo.expect_add().returning(|_| Ok(()));
+    let mut game_repo = MockIGameRepo::new();
+    game_repo.expect_game_by_id().returning(move |_| Ok(game));
+    game_repo
+        .expect_games_id_by_team_id()
+        .returning(|_, _, _| Ok(vec![]));
+    game_repo
+        .expect_h2hs_id_by_team_id()
+        .returning(|_, _, _, _| Ok(vec![]));
+    let mut gs_repo = MockIGameStatRepo::new();
+    gs_repo
+        .expect_score_by_game_id()
+        .returning(move |_, _| played.then_some((1, 0)));
+    let mut sim_repo = MockISimulationRepo::new();
+    sim_repo
+        .expect_simulation_by_id()
+        .returning(move |_| Ok(simulation.clone()));
+    sim_repo.expect_update_by_id().returning(|_| Ok(()));
+    let config = CoefficientConfig {
+        tracked_games: 5,
+        margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
+        alpha: 15,
+        totals: vec![2, 3],
+        deviation_min: 0.8,
+        deviation_max: 1.2,
+        shading: ShadingConfig::default(),
+    };
+
+    BetService::new(
+        bet_repo,
+        game_repo,
+        gs_repo,
+        sim_repo,
+        no_limits(),
+        FakeUnitOfWork,
+        odds_snapshot_repo(),
+        config,
+        ExposureConfig::default(),
+    )
+}
+
+fn make_bet(
+    simulation: Simulation,
+    game: Game,
+    stake: i64,
+    event: Event,
+    played: bool,
+    placed: Vec<Event>,
+) -> Option<BetValidationError> {
+    let bs = service(simulation.clone(), game, played, vec![]);
+    // Offered whether or not the game may be bet on.
+    let coefficient = bs
+        .offers(&game)
+        .unwrap()
+        .into_iter()
+        .find(|(e, _)| *e == event)
+        .map_or(189.try_into().unwrap(), |(_, c)| c);
+    let simulation_id = simulation.id();
+    let bs = service(simulation, game, played, placed);
+    let amount = Amount::new(stake, None).unwrap();
+
+    bs.make_bet(simulation_id, game.id(), amount, event, coefficient)
+        .err()
+        .map(|e| *e.downcast_ref::<BetValidationError>().unwrap())
+}
+
+#[test]
+fn valid_bet() {
+    let simulation = simulation(5000);
+    let game = game(&simulation, 1);
+
+    let res = make_bet(simulation, game, 5000, Event::WDL(Winner::X), false, vec![]);
+
+    assert_eq!(res, None);
+}
+
+#[test]
+fn stake_too_low() {
+    let simulation = simulation(5000);
+    let game = game(&simulation, 1);
+
+    let res = make_bet(simulation, game, 999, Event::WDL(Winner::X), false, vec![]);
+
+    assert_eq!(
+        res,
+        Some(BetValidationError::StakeTooLow {
+            min: MIN_BET_AMOUNT
+        })
+    );
+}
+
+#[test]
+fn stake_too_high() {
+    let simulation = simulation(MAX_BET_AMOUNT * 2);
+    let game = game(&simulation, 1);
+
+    let res = make_bet(
+        simulation,
+        game,
+        MAX_BET_AMOUNT + 1,
+        Event::WDL(Winner::X),
+        false,
+        vec![],
+    );
+
+    assert_eq!(
+        res,
+        Some(BetValidationError::StakeTooHigh {
+            max: MAX_BET_AMOUNT
+        })
+    );
+}
+
+#[test]
+fn insufficient_funds() {
+    let simulation = simulation(5000);
+    let game = game(&simulation, 1);
+
+    let res = make_bet(simulation, game, 5001, Event::WDL(Winner::X), false, vec![]);
+
+    assert_eq!(
+        res,
+        Some(BetValidationError::InsufficientFunds { balance: 5000 })
+    );
+}
+
+#[test]
+fn foreign_game() {
+    let simulation = simulation(5000);
+    let game = game(&self::simulation(5000), 1);
+
+    let res = make_bet(simulation, game, 5000, Event::WDL(Winner::X), false, vec![]);
+
+    assert_eq!(res, Some(BetValidationError::GameNotFound));
+}
+
+#[test]
+fn game_not_in_current_round() {
+    let simulation = simulation(5000);
+    let game = game(&simulation, 0);
+
+    let res = make_bet(simulation, game, 5000, Event::WDL(Winner::X), false, vec![]);
+
+    assert_eq!(res, Some(BetValidationError::GameNotInCurrentRound));
+}
+
+#[test]
+fn game_already_played() {
+    let simulation = simulation(5000);
+    let game = game(&simulation, 1);
+
+    let res = make_bet(simulation, game, 5000, Event::WDL(Winner::X), true, vec![]);
+
+    assert_eq!(res, Some(BetValidationError::GameAlreadyPlayed));
+}
+
+#[test]
+fn duplicate_selection() {
+    let simulation = simulation(5000);
+    let game = game(&simulation, 1);
+    let event = Event::WDL(Winner::X);
+
+    let res = make_bet(simulation, game, 5000, event, false, vec![event]);
+
+    assert_eq!(res, Some(BetValidationError::DuplicateSelection));
+}
+
+#[test]
+fn other_selection_on_same_game() {
+    let simulation = simulation(5000);
+    let game = game(&simulation, 1);
+
+    let res = make_bet(
+        simulation,
+        game,
+        5000,
+        Event::WDL(Winner::X),
+        false,
+        vec![Event::WDL(Winner::W1)],
+    );
+
+    assert_eq!(res, None);
+}
+
+#[test]
+fn market_closed() {
+    let simulation = simulation(5000);
+    let game = game(&simulation, 1);
+    let event = Event::T(EventTotal {
+        total: 7,
+        ordering: Ordering::Greater,
+    });
+
+    let res = make_bet(simulation, game, 5000, event, false, vec![]);
+
+    assert_eq!(res, Some(BetValidationError::MarketClosed));
+}
+
+#[test]
+fn coefficient_changed() {
+    let simulation = simulation(5000);
+    let game = game(&simulation, 1);
+    let bs = service(simulation.clone(), game, false, vec![]);
+    let (event, current) = bs.calculate_coefficients(&simulation, game.id()).unwrap()[0];
+    let stale = Coefficient::try_from(current.clear_value() + 1).unwrap();
+    let amount = Amount::new(5000, None).unwrap();
+
+    let res = bs.make_bet(simulation.id(), game.id(), amount, event, stale);
+
+    assert_eq!(
+        res.err().unwrap().downcast_ref::<BetValidationError>(),
+        Some(&BetValidationError::CoefficientChanged { current })
+    );
+}
+
+#[test]
+fn rejections_are_classified() {
+    let simulation = simulation(5000);
+    let game = game(&simulation, 1);
+    let bs = service(simulation.clone(), game, false, vec![]);
+    let (event, coefficient) = bs.calculate_coefficients(&simulation, game.id()).unwrap()[0];
+    let amount = Amount::new(5001, None).unwrap();
+
+    let err = bs
+        .make_bet(simulation.id(), game.id(), amount, event, coefficient)
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        classify(&err),
+        (ErrorKind::InsufficientFunds, "insufficient_funds")
+    );
+}
+
+#[test]
+fn archived_simulation() {
+    let mut simulation = simulation(5000);
+    simulation.archive(1_700_000_000);
+    let simulation_id = simulation.id();
+    let game = game(&simulation, 1);
+    let bs = service(simulation, game, false, vec![]);
+    let amount = Amount::new(5000, None).unwrap();
+
+    let res = bs.make_bet(
+        simulation_id,
+        game.id(),
+        amount,
+        Event::WDL(Winner::X),
+        189.try_into().unwrap(),
+    );
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Conflict, "simulation_archived")
+    );
+}
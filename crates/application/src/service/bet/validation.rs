@@ -0,0 +1,182 @@
+use anyhow::Result;
+use std::fmt;
+use tracing::debug;
+
+use super::BetService;
+use crate::{
+    repository::{
+        IBetLimitRepo, IBetRepo, IGameRepo, IGameStatRepo, IOddsSnapshotRepo, ISimulationRepo,
+        IUnitOfWork,
+    },
+    service::check_active,
+};
+use domain::{
+    entity::{Game, Simulation},
+    value_object::{Amount, Coefficient, Event, LimitKind, MAX_BET_AMOUNT, MIN_BET_AMOUNT},
+};
+
+/// Reasons a bet is refused before anything is written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BetValidationError {
+    StakeTooLow {
+        min: i64,
+    },
+    StakeTooHigh {
+        max: i64,
+    },
+    InsufficientFunds {
+        balance: i64,
+    },
+    GameNotFound,
+    GameNotInCurrentRound,
+    GameAlreadyPlayed,
+    DuplicateSelection,
+    MarketClosed,
+    CoefficientChanged {
+        current: Coefficient,
+    },
+    LimitExceeded {
+        limit: LimitKind,
+        max: i64,
+    },
+    CoolingOff {
+        until: i64,
+    },
+    PayoutTooHigh {
+        max: i64,
+    },
+    /// `max_stake` is the most the book still takes on the event.
+    LiabilityExceeded {
+        scope: LiabilityScope,
+        max_stake: i64,
+    },
+}
+
+/// What a liability limit of the book is counted over.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LiabilityScope {
+    Event,
+    Game,
+    Round,
+}
+
+impl fmt::Display for BetValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StakeTooLow { min } => write!(f, "Minimal bet is {}", as_float(*min)),
+            Self::StakeTooHigh { max } => write!(f, "Maximal bet is {}", as_float(*max)),
+            Self::InsufficientFunds { balance } => {
+                write!(f, "Haven't enough money, balance is {}", as_float(*balance))
+            }
+            Self::GameNotFound => write!(f, "Game not found in this simulation"),
+            Self::GameNotInCurrentRound => write!(f, "Game isn't in the current round"),
+            Self::GameAlreadyPlayed => write!(f, "Game already played"),
+            Self::DuplicateSelection => write!(f, "Bet on this event already made"),
+            Self::MarketClosed => write!(f, "Event isn't offered for this game"),
+            Self::CoefficientChanged { current } => {
+                write!(f, "Coefficient changed, current is {}", f64::from(*current))
+            }
+            Self::LimitExceeded { limit, max } => {
+                let limit = match limit {
+                    LimitKind::Stake => "Stake",
+                    LimitKind::RoundStake => "Round stake",
+                    LimitKind::Loss => "Loss",
+                    LimitKind::RoundDeposit => "Round deposit",
+                };
+                write!(f, "{} limit of {} would be exceeded", limit, as_float(*max))
+            }
+            Self::CoolingOff { until } => write!(f, "Betting is paused until {}", until),
+            Self::PayoutTooHigh { max } => write!(f, "Maximal payout is {}", as_float(*max)),
+            Self::LiabilityExceeded { scope, max_stake } => {
+                let scope = match scope {
+                    LiabilityScope::Event => "Event",
+                    LiabilityScope::Game => "Game",
+                    LiabilityScope::Round => "Round",
+                };
+                write!(
+                    f,
+                    "{} liability limit allows a stake of at most {}",
+                    scope,
+                    as_float(*max_stake)
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for BetValidationError {}
+
+fn as_float(value: i64) -> f64 {
+    f64::from(Amount::new(value, None).unwrap())
+}
+
+impl<
+    B: IBetRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    S: ISimulationRepo,
+    L: IBetLimitRepo,
+    U: IUnitOfWork,
+    O: IOddsSnapshotRepo,
+> BetService<B, G, GS, S, L, U, O>
+{
+    pub(super) fn validate_bet(
+        &self,
+        simulation: &Simulation,
+        game: &Game,
+        amount: Amount,
+        event: Event,
+        coefficient: Coefficient,
+    ) -> Result<()> {
+        check_active(simulation)?;
+        if game.simulation_id() != simulation.id() {
+            return Err(BetValidationError::GameNotFound.into());
+        }
+        if game.round() != simulation.round() {
+            return Err(BetValidationError::GameNotInCurrentRound.into());
+        }
+        if self
+            .game_stat_repo
+            .score_by_game_id(game.id(), true)
+            .is_some()
+        {
+            return Err(BetValidationError::GameAlreadyPlayed.into());
+        }
+        debug!("Game is open");
+
+        let stake = amount.clear_value();
+        if stake < MIN_BET_AMOUNT {
+            return Err(BetValidationError::StakeTooLow {
+                min: MIN_BET_AMOUNT,
+            }
+            .into());
+        }
+        let max_stake = self.exposure.max_stake.min(MAX_BET_AMOUNT);
+        if stake > max_stake {
+            return Err(BetValidationError::StakeTooHigh { max: max_stake }.into());
+        }
+        let balance = simulation.balance().clear_value();
+        if stake > balance {
+            return Err(BetValidationError::InsufficientFunds { balance }.into());
+        }
+        debug!("Stake is allowed");
+
+        let bets = self.bet_repo.bets_by_game_id(game.id())?;
+        if bets.iter().any(|b| b.event() == event) {
+            return Err(BetValidationError::DuplicateSelection.into());
+        }
+
+        let offered = self
+            .offers(game)?
+            .into_iter()
+            .find(|(e, _)| *e == event)
+            .map(|(_, c)| c)
+            .ok_or(BetValidationError::MarketClosed)?;
+        if offered != coefficient {
+            return Err(BetValidationError::CoefficientChanged { current: offered }.into());
+        }
+        debug!("Market is open");
+
+        Ok(())
+    }
+}
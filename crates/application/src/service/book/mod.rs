@@ -0,0 +1,257 @@
+use anyhow::Result;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt;
+use tracing::debug;
+
+use super::bet::liability;
+use super::check_admin;
+use crate::{
+    config::AdminConfig,
+    repository::{IBetRepo, IGameRepo, ISimulationRepo, IUserRepo},
+    usecase::ShowBookReport,
+};
+use domain::{
+    entity::{Bet, Game, Simulation, User},
+    value_object::{Amount, Event, EventTotal, Id, Margin},
+};
+
+/// Outcomes of a game offered together, exactly one of them coming in.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Market {
+    Winner,
+    Total(u8),
+}
+
+impl Market {
+    pub fn of(event: Event) -> Self {
+        match event {
+            Event::WDL(_) => Self::Winner,
+            Event::T(EventTotal { total, .. }) => Self::Total(total),
+        }
+    }
+
+    /// Stable name used by the API.
+    pub fn code(self) -> String {
+        match self {
+            Self::Winner => "winner".to_string(),
+            Self::Total(total) => format!("total_{}", total),
+        }
+    }
+}
+
+impl fmt::Display for Market {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Winner => write!(f, "Исход матча"),
+            Self::Total(total) => write!(f, "Тотал {}", total),
+        }
+    }
+}
+
+/// What the book kept on one market. Money is as the house sees it, `revenue` being stakes
+/// taken less winnings paid out on the settled bets.
+pub struct MarketHold {
+    pub market: Market,
+    pub bets: usize,
+    pub turnover: Amount,
+    pub revenue: Amount,
+    /// `revenue` per unit of settled stake.
+    pub hold: f64,
+}
+
+/// Simulation that took the most off the book.
+pub struct BookWinner {
+    pub simulation_id: Id<Simulation>,
+    pub name: String,
+    pub login: String,
+    pub staked: Amount,
+    pub profit: Amount,
+}
+
+/// Game with unsettled bets and what they could cost the book.
+pub struct OpenGame {
+    pub simulation_id: Id<Simulation>,
+    pub game_id: Id<Game>,
+    pub round: u32,
+    pub bets: usize,
+    pub staked: Amount,
+    /// Loss of the book with every market of the game settled the worst way for it.
+    pub liability: Amount,
+}
+
+/// House-side results across every simulation, archived ones included.
+pub struct BookReport {
+    pub simulations: usize,
+    pub bets: usize,
+    /// Stakes of every bet, settled or not.
+    pub turnover: Amount,
+    pub settled_turnover: Amount,
+    /// Gross gaming revenue: settled stakes less winnings paid out.
+    pub revenue: Amount,
+    /// `revenue` per unit of settled stake, the margin the book actually made.
+    pub hold: f64,
+    /// Margin the coefficients are priced with.
+    pub margin: Margin,
+    /// Ordered the way markets are offered.
+    pub markets: Vec<MarketHold>,
+    /// Most profitable simulations for their players, best first.
+    pub winners: Vec<BookWinner>,
+    /// Largest liability first.
+    pub exposure: Vec<OpenGame>,
+}
+
+/// Bets of one simulation.
+struct Standing {
+    simulation: Simulation,
+    tally: Tally,
+}
+
+#[derive(Default)]
+struct Tally {
+    bets: usize,
+    turnover: i64,
+    settled: i64,
+    revenue: i64,
+}
+
+impl Tally {
+    fn add(&mut self, bet: Bet) {
+        let stake = bet.amount().clear_value();
+        self.bets += 1;
+        self.turnover += stake;
+        let Some(is_won) = bet.is_won() else {
+            return;
+        };
+        self.settled += stake;
+        self.revenue += stake;
+        if is_won {
+            self.revenue -= bet.payout().clear_value();
+        }
+    }
+
+    fn hold(&self) -> f64 {
+        if self.settled == 0 {
+            return 0.0;
+        }
+
+        self.revenue as f64 / self.settled as f64
+    }
+}
+
+pub struct BookService<S: ISimulationRepo, U: IUserRepo, G: IGameRepo, B: IBetRepo> {
+    simulation_repo: S,
+    user_repo: U,
+    game_repo: G,
+    bet_repo: B,
+    margin: Margin,
+    config: AdminConfig,
+}
+
+impl<S: ISimulationRepo, U: IUserRepo, G: IGameRepo, B: IBetRepo> ShowBookReport
+    for BookService<S, U, G, B>
+{
+    fn book_report(&self, requester: &User) -> Result<BookReport> {
+        check_admin(&self.config, requester)?;
+
+        let simulations = self.simulation_repo.all_simulations()?;
+        let mut total = Tally::default();
+        let mut markets = BTreeMap::<Market, Tally>::new();
+        let mut standings = vec![];
+        let mut exposure = vec![];
+        for simulation in simulations.iter() {
+            let bets = self.bet_repo.bets_by_simulation_id(simulation.id())?;
+            let mut standing = Standing {
+                simulation: simulation.clone(),
+                tally: Tally::default(),
+            };
+            let mut open = BTreeMap::<Id<Game>, Vec<Bet>>::new();
+            for bet in bets {
+                total.add(bet);
+                markets.entry(Market::of(bet.event())).or_default().add(bet);
+                standing.tally.add(bet);
+                if bet.is_won().is_none() {
+                    open.entry(bet.game_id()).or_default().push(bet);
+                }
+            }
+            for (game_id, bets) in open {
+                exposure.push(OpenGame {
+                    simulation_id: simulation.id(),
+                    game_id,
+                    round: self.game_repo.game_by_id(game_id)?.round(),
+                    bets: bets.len(),
+                    staked: Amount::new(bets.iter().map(|b| b.amount().clear_value()).sum(), None)?,
+                    liability: Amount::new(liability::worst_case(&bets), None)?,
+                });
+            }
+            standings.push(standing);
+        }
+        debug!(
+            simulations = simulations.len(),
+            "Bets of every simulation tallied"
+        );
+
+        // The book's loss is the player's profit.
+        standings.retain(|s| s.tally.revenue < 0);
+        standings.sort_by_key(|s| s.tally.revenue);
+        let mut winners = vec![];
+        for standing in standings.into_iter().take(self.config.top_winners) {
+            let user = self.user_repo.user_by_id(standing.simulation.user_id())?;
+            winners.push(BookWinner {
+                simulation_id: standing.simulation.id(),
+                name: standing.simulation.name().to_string(),
+                login: user.login().to_string(),
+                staked: Amount::new(standing.tally.settled, None)?,
+                profit: Amount::new(-standing.tally.revenue, None)?,
+            });
+        }
+        exposure.sort_by_key(|g| Reverse(g.liability.clear_value()));
+
+        Ok(BookReport {
+            simulations: simulations.len(),
+            bets: total.bets,
+            turnover: Amount::new(total.turnover, None)?,
+            settled_turnover: Amount::new(total.settled, None)?,
+            revenue: Amount::new(total.revenue, None)?,
+            hold: total.hold(),
+            margin: self.margin,
+            markets: markets
+                .into_iter()
+                .map(|(market, tally)| {
+                    Ok(MarketHold {
+                        market,
+                        bets: tally.bets,
+                        turnover: Amount::new(tally.turnover, None)?,
+                        revenue: Amount::new(tally.revenue, None)?,
+                        hold: tally.hold(),
+                    })
+                })
+                .collect::<Result<_>>()?,
+            winners,
+            exposure,
+        })
+    }
+}
+
+impl<S: ISimulationRepo, U: IUserRepo, G: IGameRepo, B: IBetRepo> BookService<S, U, G, B> {
+    pub fn new(
+        simulation_repo: S,
+        user_repo: U,
+        game_repo: G,
+        bet_repo: B,
+        margin: Margin,
+        config: AdminConfig,
+    ) -> Self {
+        Self {
+            simulation_repo,
+            user_repo,
+            game_repo,
+            bet_repo,
+            margin,
+            config,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,229 @@
+use std::cmp::Ordering;
+use uuid::Uuid;
+
+use super::super::*;
+use crate::error::{AppError, Entity, ErrorKind, classify};
+use crate::repository::{MockIBetRepo, MockIGameRepo, MockISimulationRepo, MockIUserRepo};
+use domain::value_object::{MIN_BALANCE_AMOUNT, MIN_BET_AMOUNT, Winner};
+
+type Service = BookService<MockISimulationRepo, MockIUserRepo, MockIGameRepo, MockIBetRepo>;
+
+struct Run {
+    simulation: Simulation,
+    game: Game,
+    bets: Vec<Bet>,
+}
+
+fn total(total: u8, ordering: Ordering) -> Event {
+    Event::T(EventTotal { total, ordering })
+}
+
+/// One game in the second round; bets are `(event, amount, coefficient, is_won)`.
+fn run(name: &str, bets: &[(Event, i64, f64, Option<bool>)]) -> Run {
+    let simulation = Simulation::new(
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        name.to_string(),
+        Amount::new(100_000, Some(MIN_BALANCE_AMOUNT)).unwrap(),
+        Some(2),
+    );
+    let game = Game::new(
+        Uuid::now_v7().into(),
+        simulation.id(),
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        2,
+    );
+    let bets = bets
+        .iter()
+        .map(|&(event, amount, coefficient, is_won)| {
+            Bet::new(
+                Uuid::now_v7().into(),
+                simulation.id(),
+                Amount::new(amount, Some(MIN_BET_AMOUNT)).unwrap(),
+                coefficient.try_into().unwrap(),
+                game.id(),
+                event,
+                is_won,
+            )
+        })
+        .collect();
+
+    Run {
+        simulation,
+        game,
+        bets,
+    }
+}
+
+/// The book wins 150.00 off A, loses 200.00 to B who has 20.00 on the draw still open, and
+/// 60.00 to the archived C.
+fn runs() -> Vec<Run> {
+    let mut archived = run("C", &[(total(3, Ordering::Less), 4000, 2.5, Some(true))]);
+    archived.simulation.archive(1_700_000_000);
+    vec![
+        run(
+            "A",
+            &[
+                (Event::WDL(Winner::W1), 10000, 2.0, Some(false)),
+                (total(2, Ordering::Greater), 5000, 3.0, Some(false)),
+            ],
+        ),
+        run(
+            "B",
+            &[
+                (Event::WDL(Winner::W1), 10000, 3.0, Some(true)),
+                (Event::WDL(Winner::X), 2000, 2.0, None),
+            ],
+        ),
+        archived,
+    ]
+}
+
+fn service(runs: Vec<Run>, top_winners: usize) -> Service {
+    let simulations = runs
+        .iter()
+        .map(|r| r.simulation.clone())
+        .collect::<Vec<_>>();
+    let mut sim_repo = MockISimulationRepo::new();
+    let all = simulations.clone();
+    sim_repo
+        .expect_all_simulations()
+        .returning(move || Ok(all.clone()));
+    let mut user_repo = MockIUserRepo::new();
+    user_repo.expect_user_by_id().returning(move |id| {
+        simulations
+            .iter()
+            .find(|s| s.user_id() == id)
+            .map(|s| User::new(id, format!("user-{}", s.name()), String::new()))
+            .ok_or_else(|| AppError::NotFound(Entity::User).into())
+    });
+    let games = runs.iter().map(|r| r.game).collect::<Vec<_>>();
+    let mut game_repo = MockIGameRepo::new();
+    game_repo
+        .expect_game_by_id()
+        .returning(move |id| Ok(*games.iter().find(|g| g.id() == id).unwrap()));
+    let bets = runs.into_iter().flat_map(|r| r.bets).collect::<Vec<_>>();
+    let mut bet_repo = MockIBetRepo::new();
+    bet_repo
+        .expect_bets_by_simulation_id()
+        .returning(move |id| {
+            Ok(bets
+                .iter()
+                .filter(|b| b.simulation_id() == id)
+                .copied()
+                .collect())
+        });
+
+    BookService::new(
+        sim_repo,
+        user_repo,
+        game_repo,
+        bet_repo,
+        Margin::try_from(0.07).unwrap(),
+        AdminConfig {
+            logins: vec!["root".to_string()],
+            top_winners,
+        },
+    )
+}
+
+fn admin() -> User {
+    User::new(Uuid::now_v7().into(), "root".to_string(), String::new())
+}
+
+#[test]
+fn totals_of_every_simulation() {
+    let bs = service(runs(), 10);
+
+    let report = bs.book_report(&admin()).unwrap();
+
+    assert_eq!(report.simulations, 3);
+    assert_eq!(report.bets, 5);
+    assert_eq!(report.turnover.clear_value(), 31000);
+    assert_eq!(report.settled_turnover.clear_value(), 29000);
+    assert_eq!(report.revenue.clear_value(), -11000);
+    assert!((report.hold + 11000. / 29000.).abs() < 1e-9);
+    assert!((f64::from(report.margin) - 0.07).abs() < 1e-9);
+}
+
+#[test]
+fn hold_per_market() {
+    let bs = service(runs(), 10);
+
+    let report = bs.book_report(&admin()).unwrap();
+
+    let markets = report
+        .markets
+        .iter()
+        .map(|m| {
+            (
+                m.market,
+                m.bets,
+                m.turnover.clear_value(),
+                m.revenue.clear_value(),
+                m.hold,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        markets,
+        [
+            (Market::Winner, 3, 22000, -10000, -0.5),
+            (Market::Total(2), 1, 5000, 5000, 1.0),
+            (Market::Total(3), 1, 4000, -6000, -1.5),
+        ]
+    );
+}
+
+#[test]
+fn biggest_winners() {
+    let bs = service(runs(), 10);
+    let top = service(runs(), 1);
+
+    let winners = bs.book_report(&admin()).unwrap().winners;
+    let top = top.book_report(&admin()).unwrap().winners;
+
+    let winners = winners
+        .iter()
+        .map(|w| {
+            (
+                w.login.as_str(),
+                w.staked.clear_value(),
+                w.profit.clear_value(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(winners, [("user-B", 10000, 20000), ("user-C", 4000, 6000)]);
+    assert_eq!(top.len(), 1);
+    assert_eq!(top[0].name, "B");
+}
+
+#[test]
+fn exposure_on_open_games() {
+    let runs = runs();
+    let open = runs[1].game.id();
+    let bs = service(runs, 10);
+
+    let exposure = bs.book_report(&admin()).unwrap().exposure;
+
+    assert_eq!(exposure.len(), 1);
+    assert!(exposure[0].game_id == open);
+    assert_eq!(exposure[0].round, 2);
+    assert_eq!(exposure[0].bets, 1);
+    assert_eq!(exposure[0].staked.clear_value(), 2000);
+    assert_eq!(exposure[0].liability.clear_value(), 2000);
+}
+
+#[test]
+fn admins_only() {
+    let bs = service(runs(), 10);
+    let player = User::new(Uuid::now_v7().into(), "user-A".to_string(), String::new());
+
+    let res = bs.book_report(&player);
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Forbidden, "admin_only")
+    );
+}
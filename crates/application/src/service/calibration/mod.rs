@@ -0,0 +1,184 @@
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet};
+use tracing::debug;
+
+use super::book::Market;
+use super::check_admin;
+use crate::{
+    config::AdminConfig,
+    repository::{IForecastRepo, IGameStatRepo},
+    usecase::ShowCalibration,
+};
+use domain::{
+    entity::{Forecast, Game, User},
+    value_object::{Event, Id},
+};
+
+/// Equal-width probability ranges the forecasts are bucketed in.
+const BUCKETS: usize = 10;
+
+/// Probability below which an outcome that came in isn't penalised any further.
+const MIN_PROBABILITY: f64 = 1e-15;
+
+/// Forecasts that fell in one probability range and how often their outcomes came in,
+/// the two matching for a well calibrated model.
+pub struct ReliabilityBucket {
+    pub lower: f64,
+    pub upper: f64,
+    pub forecasts: usize,
+    pub mean_probability: f64,
+    pub frequency: f64,
+}
+
+/// How the model did on one market of the played games.
+pub struct MarketCalibration {
+    pub market: Market,
+    pub games: usize,
+    /// Mean squared error of the outcome probabilities of a game, 0 for a perfect model.
+    pub brier: f64,
+    /// Mean negative log probability of the outcome that came in.
+    pub log_loss: f64,
+    /// Ascending, empty ones left out.
+    pub buckets: Vec<ReliabilityBucket>,
+}
+
+/// Pre-game model probabilities of every played game checked against the results.
+pub struct CalibrationReport {
+    pub games: usize,
+    /// Ordered the way markets are offered.
+    pub markets: Vec<MarketCalibration>,
+}
+
+#[derive(Default, Clone, Copy)]
+struct Bucket {
+    forecasts: usize,
+    probability: f64,
+    hits: usize,
+}
+
+#[derive(Default)]
+struct Score {
+    games: usize,
+    brier: f64,
+    log_loss: f64,
+    buckets: [Bucket; BUCKETS],
+}
+
+impl Score {
+    /// `outcomes` are the forecasts of a market of one game, each with whether it came in.
+    fn add(&mut self, outcomes: &[(f64, bool)]) {
+        self.games += 1;
+        for &(probability, hit) in outcomes {
+            let o = if hit { 1. } else { 0. };
+            self.brier += (probability - o).powi(2);
+            if hit {
+                self.log_loss -= probability.max(MIN_PROBABILITY).ln();
+            }
+            let bucket =
+                &mut self.buckets[((probability * BUCKETS as f64) as usize).min(BUCKETS - 1)];
+            bucket.forecasts += 1;
+            bucket.probability += probability;
+            bucket.hits += hit as usize;
+        }
+    }
+
+    fn into_calibration(self, market: Market) -> MarketCalibration {
+        let games = self.games as f64;
+        let buckets = self
+            .buckets
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| b.forecasts > 0)
+            .map(|(i, b)| ReliabilityBucket {
+                lower: i as f64 / BUCKETS as f64,
+                upper: (i + 1) as f64 / BUCKETS as f64,
+                forecasts: b.forecasts,
+                mean_probability: b.probability / b.forecasts as f64,
+                frequency: b.hits as f64 / b.forecasts as f64,
+            })
+            .collect();
+
+        MarketCalibration {
+            market,
+            games: self.games,
+            brier: self.brier / games,
+            log_loss: self.log_loss / games,
+            buckets,
+        }
+    }
+}
+
+pub struct CalibrationService<F: IForecastRepo, GS: IGameStatRepo> {
+    forecast_repo: F,
+    game_stat_repo: GS,
+    config: AdminConfig,
+}
+
+impl<F: IForecastRepo, GS: IGameStatRepo> ShowCalibration for CalibrationService<F, GS> {
+    fn calibration(&self, requester: &User) -> Result<CalibrationReport> {
+        check_admin(&self.config, requester)?;
+
+        let forecasts = self.forecast_repo.all_forecasts()?;
+        debug!(forecasts = forecasts.len(), "Got forecasts");
+        let mut by_game = BTreeMap::<(Market, Id<Game>), Vec<Forecast>>::new();
+        for forecast in forecasts {
+            by_game
+                .entry((Market::of(forecast.event()), forecast.game_id()))
+                .or_default()
+                .push(forecast);
+        }
+
+        let mut games = BTreeSet::new();
+        let mut markets = BTreeMap::<Market, Score>::new();
+        for ((market, game_id), forecasts) in by_game {
+            let mut outcomes = vec![];
+            for forecast in forecasts {
+                let Some(hit) = self.came_in(game_id, forecast.event()) else {
+                    break;
+                };
+                outcomes.push((forecast.probability(), hit));
+            }
+            if outcomes.is_empty() {
+                continue;
+            }
+            games.insert(game_id);
+            markets.entry(market).or_default().add(&outcomes);
+        }
+        debug!(games = games.len(), "Forecasts checked against results");
+
+        Ok(CalibrationReport {
+            games: games.len(),
+            markets: markets
+                .into_iter()
+                .map(|(market, score)| score.into_calibration(market))
+                .collect(),
+        })
+    }
+}
+
+impl<F: IForecastRepo, GS: IGameStatRepo> CalibrationService<F, GS> {
+    pub fn new(forecast_repo: F, game_stat_repo: GS, config: AdminConfig) -> Self {
+        Self {
+            forecast_repo,
+            game_stat_repo,
+            config,
+        }
+    }
+
+    /// Whether `event` came in, `None` for a game not played yet.
+    fn came_in(&self, game_id: Id<Game>, event: Event) -> Option<bool> {
+        match event {
+            Event::WDL(winner) => self
+                .game_stat_repo
+                .winner_by_game_id(game_id, true)
+                .map(|w| w == winner),
+            Event::T(total) => self
+                .game_stat_repo
+                .score_by_game_id(game_id, true)
+                .map(|(h, g)| (h + g).cmp(&total.total) == total.ordering),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,165 @@
+use std::cmp::Ordering;
+use uuid::Uuid;
+
+use super::super::*;
+use crate::error::{ErrorKind, classify};
+use crate::repository::{MockIForecastRepo, MockIGameStatRepo};
+use domain::entity::Simulation;
+use domain::value_object::{EventTotal, Winner};
+
+type Service = CalibrationService<MockIForecastRepo, MockIGameStatRepo>;
+
+struct Played {
+    game_id: Id<Game>,
+    score: Option<(u8, u8)>,
+    forecasts: Vec<(Event, f64)>,
+}
+
+fn total(total: u8, ordering: Ordering) -> Event {
+    Event::T(EventTotal { total, ordering })
+}
+
+fn winner(probs: [f64; 3]) -> Vec<(Event, f64)> {
+    [Winner::W1, Winner::X, Winner::W2]
+        .into_iter()
+        .map(Event::WDL)
+        .zip(probs)
+        .collect()
+}
+
+fn played(score: Option<(u8, u8)>, forecasts: Vec<(Event, f64)>) -> Played {
+    Played {
+        game_id: Uuid::now_v7().into(),
+        score,
+        forecasts,
+    }
+}
+
+/// The home side wins 3:1 the first game, the second is drawn and the third not played yet.
+fn games() -> Vec<Played> {
+    let mut first = winner([0.5, 0.3, 0.2]);
+    first.extend([
+        (total(2, Ordering::Greater), 0.7),
+        (total(2, Ordering::Equal), 0.2),
+        (total(2, Ordering::Less), 0.1),
+    ]);
+    vec![
+        played(Some((3, 1)), first),
+        played(Some((1, 1)), winner([0.6, 0.2, 0.2])),
+        played(None, winner([0.1, 0.1, 0.8])),
+    ]
+}
+
+fn service(games: Vec<Played>) -> Service {
+    let simulation_id = <Id<Simulation>>::from(Uuid::now_v7());
+    let forecasts = games
+        .iter()
+        .flat_map(|g| {
+            g.forecasts.iter().map(|&(event, probability)| {
+                Forecast::new(
+                    Uuid::now_v7().into(),
+                    simulation_id,
+                    g.game_id,
+                    event,
+                    probability,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    let mut forecast_repo = MockIForecastRepo::new();
+    forecast_repo
+        .expect_all_forecasts()
+        .returning(move || Ok(forecasts.clone()));
+    let scores = games
+        .iter()
+        .map(|g| (g.game_id, g.score))
+        .collect::<Vec<_>>();
+    let score = move |id| scores.iter().find(|s| s.0 == id).unwrap().1;
+    let winner_score = score.clone();
+    let mut gs_repo = MockIGameStatRepo::new();
+    gs_repo
+        .expect_score_by_game_id()
+        .returning(move |id, _| score(id));
+    gs_repo.expect_winner_by_game_id().returning(move |id, _| {
+        winner_score(id).map(|(h, g)| match h.cmp(&g) {
+            Ordering::Greater => Winner::W1,
+            Ordering::Equal => Winner::X,
+            Ordering::Less => Winner::W2,
+        })
+    });
+
+    CalibrationService::new(
+        forecast_repo,
+        gs_repo,
+        AdminConfig {
+            logins: vec!["root".to_string()],
+            top_winners: 10,
+        },
+    )
+}
+
+fn admin() -> User {
+    User::new(Uuid::now_v7().into(), "root".to_string(), String::new())
+}
+
+#[test]
+fn scores_per_market() {
+    let cs = service(games());
+
+    let report = cs.calibration(&admin()).unwrap();
+
+    assert_eq!(report.games, 2);
+    let markets = report
+        .markets
+        .iter()
+        .map(|m| (m.market, m.games))
+        .collect::<Vec<_>>();
+    assert_eq!(markets, [(Market::Winner, 2), (Market::Total(2), 1)]);
+    let (winner, total) = (&report.markets[0], &report.markets[1]);
+    assert!((winner.brier - (0.38 + 1.04) / 2.).abs() < 1e-9);
+    assert!((winner.log_loss + (0.5f64.ln() + 0.2f64.ln()) / 2.).abs() < 1e-9);
+    assert!((total.brier - 0.14).abs() < 1e-9);
+    assert!((total.log_loss + 0.7f64.ln()).abs() < 1e-9);
+}
+
+#[test]
+fn reliability_buckets() {
+    let cs = service(games());
+
+    let report = cs.calibration(&admin()).unwrap();
+
+    let buckets = report.markets[0]
+        .buckets
+        .iter()
+        .map(|b| (b.lower, b.forecasts, b.frequency))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        buckets,
+        [(0.2, 3, 1. / 3.), (0.3, 1, 0.), (0.5, 1, 1.), (0.6, 1, 0.)]
+    );
+    assert!((report.markets[0].buckets[0].mean_probability - 0.2).abs() < 1e-9);
+    assert!((report.markets[0].buckets[0].upper - 0.3).abs() < 1e-9);
+}
+
+#[test]
+fn certain_miss_is_finite() {
+    let cs = service(vec![played(Some((0, 2)), winner([1., 0., 0.]))]);
+
+    let report = cs.calibration(&admin()).unwrap();
+
+    assert!(report.markets[0].log_loss.is_finite());
+    assert!((report.markets[0].brier - 2.).abs() < 1e-9);
+}
+
+#[test]
+fn admins_only() {
+    let cs = service(games());
+    let player = User::new(Uuid::now_v7().into(), "player".to_string(), String::new());
+
+    let res = cs.calibration(&player);
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Forbidden, "admin_only")
+    );
+}
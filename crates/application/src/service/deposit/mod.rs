@@ -0,0 +1,137 @@
+use anyhow::{Result, bail};
+use tracing::{debug, info};
+
+use super::{check_active, now};
+use crate::{
+    config::{DepositConfig, SetupConfig},
+    error::AppError,
+    repository::{IBetLimitRepo, IDepositRepo, ISimulationRepo, IUnitOfWork},
+    service::BetValidationError,
+    usecase::ManageDeposits,
+};
+use domain::{
+    entity::{Deposit, Simulation},
+    value_object::{Amount, Id, LimitKind},
+};
+
+pub struct DepositSummary {
+    pub balance: Amount,
+    /// Sum of all deposits, the starting balance excluded.
+    pub deposited: Amount,
+    /// Starting balance plus everything deposited.
+    pub put_in: Amount,
+    /// Balance minus the money put in, negative while the simulation is at a loss.
+    pub net: Amount,
+    pub deposits: usize,
+}
+
+pub struct DepositService<D: IDepositRepo, S: ISimulationRepo, L: IBetLimitRepo, U: IUnitOfWork> {
+    deposit_repo: D,
+    simulation_repo: S,
+    bet_limit_repo: L,
+    unit_of_work: U,
+    config: DepositConfig,
+    setup: SetupConfig,
+}
+
+impl<D: IDepositRepo, S: ISimulationRepo, L: IBetLimitRepo, U: IUnitOfWork> ManageDeposits
+    for DepositService<D, S, L, U>
+{
+    fn deposit(&self, simulation_id: Id<Simulation>, amount: Amount) -> Result<Deposit> {
+        let value = amount.clear_value();
+        if value < self.config.min_amount || value > self.config.max_amount {
+            bail!(AppError::InvalidDeposit {
+                min: self.config.min_amount,
+                max: self.config.max_amount,
+            });
+        }
+
+        let id = self.deposit_repo.next_id();
+        let deposited_at = now();
+        let mut deposit = Deposit::new(id, simulation_id, amount, 0, deposited_at);
+        self.unit_of_work.atomically(&mut || {
+            let mut simulation = self.simulation_repo.simulation_by_id(simulation_id)?;
+            check_active(&simulation)?;
+            let limits = self
+                .bet_limit_repo
+                .limits_by_simulation_id(simulation_id)?
+                .in_round(simulation.round());
+            if let Some(until) = limits.cool_off_until.filter(|&until| now() < until) {
+                bail!(BetValidationError::CoolingOff { until });
+            }
+            if let Some(max) = limits.max_round_deposit {
+                let deposited = self
+                    .deposit_repo
+                    .deposits_by_simulation_id(simulation_id)?
+                    .iter()
+                    .filter(|d| d.round() == simulation.round())
+                    .map(|d| d.amount().clear_value())
+                    .sum::<i64>();
+                if deposited + value > max.clear_value() {
+                    bail!(BetValidationError::LimitExceeded {
+                        limit: LimitKind::RoundDeposit,
+                        max: max.clear_value(),
+                    });
+                }
+            }
+            simulation.deposit(amount)?;
+            deposit = Deposit::new(id, simulation_id, amount, simulation.round(), deposited_at);
+            self.deposit_repo.add(deposit)?;
+            debug!("Deposit added to repo");
+            self.simulation_repo.update_by_id(simulation)?;
+            debug!("Balance updated in repo");
+
+            Ok(())
+        })?;
+        info!(amount = value, "Deposit made");
+
+        Ok(deposit)
+    }
+
+    fn deposits(&self, simulation_id: Id<Simulation>) -> Result<Vec<Deposit>> {
+        self.deposit_repo.deposits_by_simulation_id(simulation_id)
+    }
+
+    fn summary(&self, simulation_id: Id<Simulation>) -> Result<DepositSummary> {
+        let simulation = self.simulation_repo.simulation_by_id(simulation_id)?;
+        let deposits = self.deposit_repo.deposits_by_simulation_id(simulation_id)?;
+        let deposited = deposits
+            .iter()
+            .map(|d| d.amount().clear_value())
+            .sum::<i64>();
+        let put_in = self.setup.balance.clear_value() + deposited;
+
+        Ok(DepositSummary {
+            balance: simulation.balance(),
+            deposited: Amount::new(deposited, None)?,
+            put_in: Amount::new(put_in, None)?,
+            net: Amount::new(simulation.balance().clear_value() - put_in, None)?,
+            deposits: deposits.len(),
+        })
+    }
+}
+
+impl<D: IDepositRepo, S: ISimulationRepo, L: IBetLimitRepo, U: IUnitOfWork>
+    DepositService<D, S, L, U>
+{
+    pub fn new(
+        deposit_repo: D,
+        simulation_repo: S,
+        bet_limit_repo: L,
+        unit_of_work: U,
+        config: DepositConfig,
+        setup: SetupConfig,
+    ) -> Self {
+        Self {
+            deposit_repo,
+            simulation_repo,
+            bet_limit_repo,
+            unit_of_work,
+            config,
+            setup,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
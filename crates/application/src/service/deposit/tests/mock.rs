@@ -0,0 +1,212 @@
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use super::super::*;
+use crate::error::{ErrorKind, classify};
+use crate::repository::{FakeUnitOfWork, MockIBetLimitRepo, MockIDepositRepo, MockISimulationRepo};
+use crate::service::now;
+use domain::value_object::{BetLimits, MIN_BALANCE_AMOUNT};
+
+type Service =
+    DepositService<MockIDepositRepo, MockISimulationRepo, MockIBetLimitRepo, FakeUnitOfWork>;
+type Shared<T> = Arc<Mutex<T>>;
+
+const START_BALANCE: i64 = 100_000;
+
+fn amount(value: i64) -> Amount {
+    Amount::new(value, None).unwrap()
+}
+
+/// Simulation in round 3 holding `balance` under `limits`; the returned cells hold the
+/// deposits and the simulation as stored.
+fn service(
+    balance: i64,
+    deposits: Vec<i64>,
+    archived: bool,
+    limits: BetLimits,
+) -> (Service, Shared<Vec<Deposit>>, Shared<Simulation>) {
+    let sim_id = Uuid::now_v7().into();
+    let mut simulation = Simulation::new(
+        sim_id,
+        Uuid::now_v7().into(),
+        "main".to_string(),
+        Amount::new(balance, Some(MIN_BALANCE_AMOUNT)).unwrap(),
+        Some(3),
+    );
+    if archived {
+        simulation.archive(1);
+    }
+    let simulation = Arc::new(Mutex::new(simulation));
+    let deposits = deposits
+        .into_iter()
+        .map(|value| Deposit::new(Uuid::now_v7().into(), sim_id, amount(value), 1, 0))
+        .collect::<Vec<_>>();
+    let deposits = Arc::new(Mutex::new(deposits));
+
+    let mut deposit_repo = MockIDepositRepo::new();
+    deposit_repo
+        .expect_next_id()
+        .returning(|| Uuid::now_v7().into());
+    let rec = deposits.clone();
+    deposit_repo.expect_add().returning(move |d| {
+        rec.lock().unwrap().push(d);
+        Ok(())
+    });
+    let rec = deposits.clone();
+    deposit_repo
+        .expect_deposits_by_simulation_id()
+        .returning(move |_| Ok(rec.lock().unwrap().clone()));
+    let mut sim_repo = MockISimulationRepo::new();
+    let rec = simulation.clone();
+    sim_repo
+        .expect_simulation_by_id()
+        .returning(move |_| Ok(rec.lock().unwrap().clone()));
+    let rec = simulation.clone();
+    sim_repo.expect_update_by_id().returning(move |s| {
+        *rec.lock().unwrap() = s;
+        Ok(())
+    });
+    let mut bet_limit_repo = MockIBetLimitRepo::new();
+    bet_limit_repo
+        .expect_limits_by_simulation_id()
+        .returning(move |_| Ok(limits));
+    let config = DepositConfig {
+        min_amount: 10_00,
+        max_amount: 50_000,
+    };
+    let setup = SetupConfig {
+        balance: amount(START_BALANCE),
+    };
+
+    (
+        DepositService::new(
+            deposit_repo,
+            sim_repo,
+            bet_limit_repo,
+            FakeUnitOfWork,
+            config,
+            setup,
+        ),
+        deposits,
+        simulation,
+    )
+}
+
+#[test]
+fn deposit_tops_up_balance() {
+    let (ds, deposits, simulation) = service(0, vec![], false, BetLimits::default());
+    let sim_id = simulation.lock().unwrap().id();
+
+    let deposit = ds.deposit(sim_id, amount(20_000)).unwrap();
+
+    assert_eq!(deposit.amount().clear_value(), 20_000);
+    assert_eq!(deposit.round(), 3);
+    assert!(deposit.simulation_id() == sim_id);
+    assert!(deposits.lock().unwrap().as_slice() == [deposit]);
+    assert_eq!(simulation.lock().unwrap().balance().clear_value(), 20_000);
+}
+
+#[test]
+fn deposit_out_of_bounds() {
+    let (ds, deposits, simulation) = service(0, vec![], false, BetLimits::default());
+    let sim_id = simulation.lock().unwrap().id();
+
+    for value in [0, 9_99, 50_001] {
+        let err = ds.deposit(sim_id, amount(value)).err().unwrap();
+
+        assert_eq!(classify(&err), (ErrorKind::Validation, "invalid_deposit"));
+    }
+    assert!(deposits.lock().unwrap().is_empty());
+    assert_eq!(simulation.lock().unwrap().balance().clear_value(), 0);
+}
+
+#[test]
+fn deposit_overflowing_balance() {
+    let (ds, deposits, simulation) = service(i64::MAX - 10_00, vec![], false, BetLimits::default());
+    let sim_id = simulation.lock().unwrap().id();
+
+    let err = ds.deposit(sim_id, amount(20_00)).err().unwrap();
+
+    assert_eq!(classify(&err), (ErrorKind::Validation, "invalid_amount"));
+    assert!(deposits.lock().unwrap().is_empty());
+    assert_eq!(
+        simulation.lock().unwrap().balance().clear_value(),
+        i64::MAX - 10_00
+    );
+}
+
+#[test]
+fn deposit_into_archived_simulation() {
+    let (ds, deposits, simulation) = service(0, vec![], true, BetLimits::default());
+    let sim_id = simulation.lock().unwrap().id();
+
+    let err = ds.deposit(sim_id, amount(10_000)).err().unwrap();
+
+    assert_eq!(classify(&err), (ErrorKind::Conflict, "simulation_archived"));
+    assert!(deposits.lock().unwrap().is_empty());
+}
+
+#[test]
+fn deposit_while_cooling_off() {
+    let limits = BetLimits {
+        cool_off_until: Some(now() + 3600),
+        ..BetLimits::default()
+    };
+    let (ds, deposits, simulation) = service(0, vec![], false, limits);
+    let sim_id = simulation.lock().unwrap().id();
+
+    let err = ds.deposit(sim_id, amount(10_000)).err().unwrap();
+
+    assert_eq!(classify(&err), (ErrorKind::Forbidden, "cooling_off"));
+    assert!(deposits.lock().unwrap().is_empty());
+    assert_eq!(simulation.lock().unwrap().balance().clear_value(), 0);
+}
+
+#[test]
+fn deposit_after_cool_off() {
+    let limits = BetLimits {
+        cool_off_until: Some(now() - 1),
+        ..BetLimits::default()
+    };
+    let (ds, _, simulation) = service(0, vec![], false, limits);
+    let sim_id = simulation.lock().unwrap().id();
+
+    ds.deposit(sim_id, amount(10_000)).unwrap();
+
+    assert_eq!(simulation.lock().unwrap().balance().clear_value(), 10_000);
+}
+
+#[test]
+fn deposit_over_round_limit() {
+    let limits = BetLimits {
+        max_round_deposit: Some(amount(30_000)),
+        ..BetLimits::default()
+    };
+    // Deposits of earlier rounds don't count.
+    let (ds, deposits, simulation) = service(0, vec![20_000], false, limits);
+    let sim_id = simulation.lock().unwrap().id();
+
+    ds.deposit(sim_id, amount(30_000)).unwrap();
+    let err = ds.deposit(sim_id, amount(10_00)).err().unwrap();
+
+    assert_eq!(
+        classify(&err),
+        (ErrorKind::Forbidden, "round_deposit_limit_exceeded")
+    );
+    assert_eq!(deposits.lock().unwrap().len(), 2);
+    assert_eq!(simulation.lock().unwrap().balance().clear_value(), 30_000);
+}
+
+#[test]
+fn summary_counts_start_balance_and_deposits() {
+    let (ds, _, simulation) = service(90_000, vec![30_000, 20_000], false, BetLimits::default());
+    let sim_id = simulation.lock().unwrap().id();
+
+    let summary = ds.summary(sim_id).unwrap();
+
+    assert_eq!(summary.balance.clear_value(), 90_000);
+    assert_eq!(summary.deposited.clear_value(), 50_000);
+    assert_eq!(summary.put_in.clear_value(), START_BALANCE + 50_000);
+    assert_eq!(summary.net.clear_value(), 90_000 - START_BALANCE - 50_000);
+    assert_eq!(summary.deposits, 2);
+}
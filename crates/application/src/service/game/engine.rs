@@ -0,0 +1,188 @@
+use rand::{Rng, rng};
+use rand_distr::{Distribution, Gamma, Poisson};
+
+use super::super::bet::BetCalculator;
+use crate::config::{CoefficientConfig, EngineConfig, EngineModel};
+use domain::value_object::{Deviation, PastResults, Winner};
+
+/// Mean goals a team is given however little it has scored, so that it can still score.
+const MIN_MEAN_GOALS: f64 = 0.5;
+
+/// What a game is played from, as of before it is played.
+pub struct Form {
+    pub home_res: PastResults,
+    pub guest_res: PastResults,
+    pub h2h_res: PastResults,
+    /// Mean goals of the home team in its tracked games.
+    pub home_avg_goals: f64,
+    pub guest_avg_goals: f64,
+    /// Mean goals of the home and the guest team in their tracked head-to-heads.
+    pub h2h_avg_goals: (f64, f64),
+}
+
+/// The "true" world the games are played in, whatever the book's model thinks of them.
+pub trait MatchEngine: Send + Sync {
+    /// Goals of the home and the guest team.
+    fn play(&self, form: &Form) -> (u8, u8);
+}
+
+pub fn engine(config: EngineConfig, coefficient: &CoefficientConfig) -> Box<dyn MatchEngine> {
+    match config.model {
+        EngineModel::Classic => Box::new(ClassicEngine {
+            alpha: coefficient.alpha,
+            tracked_games: coefficient.tracked_games,
+            deviation_min: coefficient.deviation_min,
+            deviation_max: coefficient.deviation_max,
+        }),
+        EngineModel::Poisson => Box::new(GoalsEngine::new(0.)),
+        EngineModel::NegativeBinomial => Box::new(GoalsEngine::new(config.dispersion)),
+    }
+}
+
+/// Winner drawn from the probabilities the book prices with, each scaled by a random
+/// deviation, then the score picked around the averages of the teams to fit it.
+pub struct ClassicEngine {
+    alpha: i32,
+    tracked_games: u8,
+    deviation_min: f64,
+    deviation_max: f64,
+}
+
+impl MatchEngine for ClassicEngine {
+    fn play(&self, form: &Form) -> (u8, u8) {
+        let winner = Self::randomize_winner(
+            form.home_res.clone(),
+            form.guest_res.clone(),
+            form.h2h_res.clone(),
+            self.alpha,
+            self.tracked_games,
+            self.deviation_min,
+            self.deviation_max,
+        );
+
+        Self::randomize_totals(
+            winner,
+            form.home_avg_goals + form.h2h_avg_goals.0,
+            form.guest_avg_goals + form.h2h_avg_goals.1,
+        )
+    }
+}
+
+impl ClassicEngine {
+    fn rand_event(probs: &[f64]) -> usize {
+        let mut rand_num = rng().random_range(0.0..=probs.iter().sum());
+        for (ind, &prob) in probs.iter().enumerate() {
+            if prob > rand_num {
+                return ind;
+            }
+            rand_num -= prob;
+        }
+
+        probs.len()
+    }
+
+    pub fn randomize_winner(
+        home_res: PastResults,
+        guest_res: PastResults,
+        h2h_res: PastResults,
+        alpha: i32,
+        tracked_games: u8,
+        deviation_min: f64,
+        deviation_max: f64,
+    ) -> Winner {
+        let probs =
+            BetCalculator::winner_probabilities(home_res, guest_res, h2h_res, alpha, tracked_games)
+                .into_iter()
+                .map(|(_, p)| p * Deviation::generate(deviation_min, deviation_max).value())
+                .collect::<Vec<_>>();
+
+        match Self::rand_event(&probs) {
+            0 => Winner::W1,
+            1 => Winner::X,
+            _ => Winner::W2,
+        }
+    }
+
+    pub fn randomize_totals(
+        winner: Winner,
+        home_team_avg_goals: f64,
+        guest_team_avg_goals: f64,
+    ) -> (u8, u8) {
+        let (home_team_goals, guest_team_goals) = match winner {
+            Winner::W1 => {
+                let rand_home_team_goals = rng()
+                    .random_range((home_team_avg_goals - 1.).max(1.)..=(home_team_avg_goals + 1.));
+                let home_team_goals = rand_home_team_goals.round_ties_even();
+                let rand_guest_team_goals = rng().random_range(
+                    (guest_team_avg_goals - 1.)
+                        .max(0.)
+                        .min(home_team_goals - 1.)
+                        ..=(guest_team_avg_goals + 1.).min(home_team_goals - 1.),
+                );
+                let guest_team_goals = rand_guest_team_goals.round_ties_even() as u8;
+
+                (home_team_goals as u8, guest_team_goals)
+            }
+            Winner::X => {
+                let avg_goals = (home_team_avg_goals + guest_team_avg_goals) / 2.;
+                let rand_goals = rng().random_range((avg_goals - 1.).max(0.)..=(avg_goals + 1.));
+                let goals = rand_goals.round_ties_even() as u8;
+
+                (goals, goals)
+            }
+            Winner::W2 => {
+                let rand_guest_team_goals = rng().random_range(
+                    (guest_team_avg_goals - 1.).max(1.)..=(guest_team_avg_goals + 1.),
+                );
+                let guest_team_goals = rand_guest_team_goals.round_ties_even();
+                let rand_home_team_goals = rng().random_range(
+                    (home_team_avg_goals - 1.)
+                        .max(0.)
+                        .min(guest_team_goals - 1.)
+                        ..=(home_team_avg_goals + 1.).min(guest_team_goals - 1.),
+                );
+                let home_team_goals = rand_home_team_goals.round_ties_even() as u8;
+
+                (home_team_goals, guest_team_goals as u8)
+            }
+        };
+
+        (home_team_goals, guest_team_goals)
+    }
+}
+
+/// Goals of each team drawn on their own with its average as the mean, Poisson distributed
+/// while `dispersion` is zero and negative binomial above it.
+pub struct GoalsEngine {
+    dispersion: f64,
+}
+
+impl MatchEngine for GoalsEngine {
+    fn play(&self, form: &Form) -> (u8, u8) {
+        (
+            self.goals(form.home_avg_goals),
+            self.goals(form.guest_avg_goals),
+        )
+    }
+}
+
+impl GoalsEngine {
+    pub fn new(dispersion: f64) -> Self {
+        Self { dispersion }
+    }
+
+    /// A negative binomial is a Poisson whose mean is itself gamma distributed.
+    pub fn goals(&self, mean: f64) -> u8 {
+        let mut mean = mean.max(MIN_MEAN_GOALS);
+        if self.dispersion > 0.
+            && let Ok(gamma) = Gamma::new(1. / self.dispersion, mean * self.dispersion)
+        {
+            mean = gamma.sample(&mut rng());
+        }
+        match Poisson::new(mean) {
+            Ok(poisson) => poisson.sample(&mut rng()).min(u8::MAX as f64) as u8,
+            // Only a gamma draw too close to zero for a Poisson ends up here.
+            Err(_) => 0,
+        }
+    }
+}
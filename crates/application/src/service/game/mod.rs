@@ -1,41 +1,57 @@
 use std::fmt;
 
 use anyhow::{Result, bail};
-use rand::{Rng, rng};
 use serde::Serialize;
 use tracing::{debug, info};
 
+use super::bet::BetCalculator;
+use super::check_active;
 use crate::usecase::RandomizeRound;
 use crate::{
-    config::CoefficientConfig,
-    repository::{IGameRepo, IGameStatRepo, ITeamRepo},
+    config::{CoefficientConfig, EngineConfig},
+    error::AppError,
+    repository::{IForecastRepo, IGameRepo, IGameStatRepo, ITeamRepo},
 };
-use domain::entity::{Game, GameStat, Simulation, Team};
-use domain::value_object::{Deviation, Id, PastResults, Winner};
+use domain::entity::{Forecast, Game, GameStat, Simulation, Team};
+use domain::value_object::{Id, PastResults, PastTotals};
 
-pub struct GameService<G: IGameRepo, GS: IGameStatRepo, T: ITeamRepo> {
+mod engine;
+
+use engine::{Form, MatchEngine};
+
+pub struct GameService<G: IGameRepo, GS: IGameStatRepo, T: ITeamRepo, F: IForecastRepo> {
     team_repo: T,
     game_repo: G,
     game_stat_repo: GS,
+    forecast_repo: F,
     config: CoefficientConfig,
+    engine: Box<dyn MatchEngine>,
 }
 
-impl<G: IGameRepo, GS: IGameStatRepo, T: ITeamRepo> RandomizeRound for GameService<G, GS, T> {
+impl<G: IGameRepo, GS: IGameStatRepo, T: ITeamRepo, F: IForecastRepo> RandomizeRound
+    for GameService<G, GS, T, F>
+{
+    /// Also records the model probabilities the game was priced with before it was played.
     fn randomize_game(&self, game: &Game) -> Result<DisplayedGameStat> {
-        let winner = self.randomize_winner(game)?;
-        debug!("Winner randomized");
-        let (home_team_total, guest_team_total) = self.randomize_totals(game, winner)?;
-        debug!("Score randomized");
+        let forecasts = self.forecasts(game)?;
+        debug!("Got forecasts");
+        let form = self.form(game)?;
+        debug!("Got form");
+        let (home_team_total, guest_team_total) = self.engine.play(&form);
+        debug!("Game played");
         let stat_id = self.game_stat_repo.next_id();
         let game_stat = GameStat::new(stat_id, game.id(), home_team_total, guest_team_total);
         let dgs = DisplayedGameStat::new(&game_stat, &self.team_repo, &self.game_repo)?;
         self.game_stat_repo.add(game_stat)?;
         debug!("Game stat added");
+        self.forecast_repo.add_all(forecasts)?;
+        debug!("Forecasts added");
 
         Ok(dgs)
     }
 
     fn randomize_round(&self, simulation: &Simulation) -> Result<Vec<DisplayedGameStat>> {
+        check_active(simulation)?;
         info!("Checking if last round was randomized");
         self.check_last_round_randomized(simulation.round(), simulation.id())?;
         info!("Last round wasn't randomized");
@@ -55,16 +71,96 @@ impl<G: IGameRepo, GS: IGameStatRepo, T: ITeamRepo> RandomizeRound for GameServi
     }
 }
 
-impl<G: IGameRepo, GS: IGameStatRepo, T: ITeamRepo> GameService<G, GS, T> {
-    pub fn new(game_repo: G, game_stat_repo: GS, team_repo: T, config: CoefficientConfig) -> Self {
+impl<G: IGameRepo, GS: IGameStatRepo, T: ITeamRepo, F: IForecastRepo> GameService<G, GS, T, F> {
+    pub fn new(
+        game_repo: G,
+        game_stat_repo: GS,
+        team_repo: T,
+        forecast_repo: F,
+        config: CoefficientConfig,
+        engine: EngineConfig,
+    ) -> Self {
         Self {
             team_repo,
             game_repo,
             game_stat_repo,
+            forecast_repo,
+            engine: engine::engine(engine, &config),
             config,
         }
     }
 
+    /// Model probabilities the coefficients of `game` are priced from.
+    fn forecasts(&self, game: &Game) -> Result<Vec<Forecast>> {
+        let home_res = self.past_results_by_team_id(game.home_team_id(), game.simulation_id())?;
+        let guest_res = self.past_results_by_team_id(game.guest_team_id(), game.simulation_id())?;
+        let h2h_res = self.h2h_results_by_game(game)?;
+        let mut probs = BetCalculator::winner_probabilities(
+            home_res,
+            guest_res,
+            h2h_res,
+            self.config.alpha,
+            self.config.tracked_games,
+        );
+        for &total in self.config.totals.iter() {
+            let totals = ((self.h2h_totals(game, total)?
+                + self.past_totals(game.home_team_id(), game.simulation_id(), total)?)?
+                + self.past_totals(game.guest_team_id(), game.simulation_id(), total)?)?;
+            probs.append(&mut BetCalculator::total_probabilities(total, totals));
+        }
+
+        Ok(probs
+            .into_iter()
+            .map(|(event, probability)| {
+                Forecast::new(
+                    self.forecast_repo.next_id(),
+                    game.simulation_id(),
+                    game.id(),
+                    event,
+                    probability,
+                )
+            })
+            .collect())
+    }
+
+    fn past_totals(
+        &self,
+        team_id: Id<Team>,
+        simulation_id: Id<Simulation>,
+        total: u8,
+    ) -> Result<PastTotals> {
+        let games_id = self.game_repo.games_id_by_team_id(
+            team_id,
+            simulation_id,
+            self.config.tracked_games,
+        )?;
+        let mut past_totals = PastTotals::new(total);
+        for (game_id, is_home) in games_id {
+            if let Some(score) = self.game_stat_repo.score_by_game_id(game_id, is_home) {
+                past_totals.add_total(score.0 + score.1);
+            }
+        }
+
+        Ok(past_totals)
+    }
+
+    fn h2h_totals(&self, game: &Game, total: u8) -> Result<PastTotals> {
+        let h2hs_id = self.game_repo.h2hs_id_by_team_id(
+            game.home_team_id(),
+            game.guest_team_id(),
+            game.simulation_id(),
+            self.config.tracked_games,
+        )?;
+        let mut past_totals = PastTotals::new(total);
+        for (game_id, is_home) in h2hs_id {
+            if let Some(score) = self.game_stat_repo.score_by_game_id(game_id, is_home) {
+                past_totals.add_total(score.0 + score.1);
+            }
+        }
+
+        Ok(past_totals)
+    }
+
     fn past_results_by_team_id(
         &self,
         team_id: Id<Team>,
@@ -94,7 +190,7 @@ impl<G: IGameRepo, GS: IGameStatRepo, T: ITeamRepo> GameService<G, GS, T> {
                 .ok()
                 .is_some()
             {
-                bail!("Last round already randomized");
+                bail!(AppError::RoundAlreadyRandomized);
             }
         }
 
@@ -161,131 +257,16 @@ impl<G: IGameRepo, GS: IGameStatRepo, T: ITeamRepo> GameService<G, GS, T> {
         ))
     }
 
-    fn randomize_winner(&self, game: &Game) -> Result<Winner> {
-        let home_res = self.past_results_by_team_id(game.home_team_id(), game.simulation_id())?;
-        let guest_res = self.past_results_by_team_id(game.guest_team_id(), game.simulation_id())?;
-        let h2h_res = self.h2h_results_by_game(game)?;
-
-        Ok(GameRandomizer::randomize_winner(
-            home_res,
-            guest_res,
-            h2h_res,
-            self.config.alpha,
-            self.config.tracked_games,
-            self.config.deviation_min,
-            self.config.deviation_max,
-        ))
-    }
-
-    fn randomize_totals(&self, game: &Game, winner: Winner) -> Result<(u8, u8)> {
-        let h2h_avg_goals = self.h2h_avg_goals_by_game(game)?;
-        let home_team_avg_goals =
-            self.avg_goals_by_team_id(game.home_team_id(), game.simulation_id())? + h2h_avg_goals.0;
-        let guest_team_avg_goals = self
-            .avg_goals_by_team_id(game.guest_team_id(), game.simulation_id())?
-            + h2h_avg_goals.1;
-
-        Ok(GameRandomizer::randomize_totals(
-            winner,
-            home_team_avg_goals,
-            guest_team_avg_goals,
-        ))
-    }
-}
-
-struct GameRandomizer;
-
-impl GameRandomizer {
-    fn rand_event(probs: &[f64]) -> usize {
-        let mut rand_num = rng().random_range(0.0..=probs.iter().sum());
-        for (ind, &prob) in probs.iter().enumerate() {
-            if prob > rand_num {
-                return ind;
-            }
-            rand_num -= prob;
-        }
-
-        probs.len()
-    }
-
-    pub fn randomize_winner(
-        home_res: PastResults,
-        guest_res: PastResults,
-        h2h_res: PastResults,
-        alpha: i32,
-        tracked_games: u8,
-        deviation_min: f64,
-        deviation_max: f64,
-    ) -> Winner {
-        let prob_base = h2h_res.pts_diff() as f64 / alpha as f64;
-        let win_prob = ((((home_res.wins + 1) + (guest_res.loses + 1)) as f64
-            / 2.
-            / (tracked_games as u32 + 3) as f64)
-            + prob_base)
-            * Deviation::generate(deviation_min, deviation_max).value();
-        let draw_prob = (((home_res.draws + 1) + (guest_res.draws + 1)) as f64
-            / 2.
-            / (tracked_games as u32 + 3) as f64)
-            * Deviation::generate(deviation_min, deviation_max).value();
-        let lose_prob = ((((home_res.loses + 1) + (guest_res.wins + 1)) as f64
-            / 2.
-            / (tracked_games as u32 + 3) as f64)
-            - prob_base)
-            * Deviation::generate(deviation_min, deviation_max).value();
-        let probs = [win_prob, draw_prob, lose_prob];
-
-        match Self::rand_event(&probs) {
-            0 => Winner::W1,
-            1 => Winner::X,
-            _ => Winner::W2,
-        }
-    }
-
-    pub fn randomize_totals(
-        winner: Winner,
-        home_team_avg_goals: f64,
-        guest_team_avg_goals: f64,
-    ) -> (u8, u8) {
-        let (home_team_goals, guest_team_goals) = match winner {
-            Winner::W1 => {
-                let rand_home_team_goals = rng()
-                    .random_range((home_team_avg_goals - 1.).max(1.)..=(home_team_avg_goals + 1.));
-                let home_team_goals = rand_home_team_goals.round_ties_even();
-                let rand_guest_team_goals = rng().random_range(
-                    (guest_team_avg_goals - 1.)
-                        .max(0.)
-                        .min(home_team_goals - 1.)
-                        ..=(guest_team_avg_goals + 1.).min(home_team_goals - 1.),
-                );
-                let guest_team_goals = rand_guest_team_goals.round_ties_even() as u8;
-
-                (home_team_goals as u8, guest_team_goals)
-            }
-            Winner::X => {
-                let avg_goals = (home_team_avg_goals + guest_team_avg_goals) / 2.;
-                let rand_goals = rng().random_range((avg_goals - 1.).max(0.)..=(avg_goals + 1.));
-                let goals = rand_goals.round_ties_even() as u8;
-
-                (goals, goals)
-            }
-            Winner::W2 => {
-                let rand_guest_team_goals = rng().random_range(
-                    (guest_team_avg_goals - 1.).max(1.)..=(guest_team_avg_goals + 1.),
-                );
-                let guest_team_goals = rand_guest_team_goals.round_ties_even();
-                let rand_home_team_goals = rng().random_range(
-                    (home_team_avg_goals - 1.)
-                        .max(0.)
-                        .min(guest_team_goals - 1.)
-                        ..=(home_team_avg_goals + 1.).min(guest_team_goals - 1.),
-                );
-                let home_team_goals = rand_home_team_goals.round_ties_even() as u8;
-
-                (home_team_goals, guest_team_goals as u8)
-            }
-        };
-
-        (home_team_goals, guest_team_goals)
+    fn form(&self, game: &Game) -> Result<Form> {
+        Ok(Form {
+            home_res: self.past_results_by_team_id(game.home_team_id(), game.simulation_id())?,
+            guest_res: self.past_results_by_team_id(game.guest_team_id(), game.simulation_id())?,
+            h2h_res: self.h2h_results_by_game(game)?,
+            home_avg_goals: self.avg_goals_by_team_id(game.home_team_id(), game.simulation_id())?,
+            guest_avg_goals: self
+                .avg_goals_by_team_id(game.guest_team_id(), game.simulation_id())?,
+            h2h_avg_goals: self.h2h_avg_goals_by_game(game)?,
+        })
     }
 }
 
@@ -1,3 +1,6 @@
+use domain::value_object::Winner;
+
+use super::super::engine::ClassicEngine;
 use super::super::*;
 
 #[test]
@@ -6,7 +9,7 @@ fn randomize_totals_winner1() {
     let home_team_avg_goals = 2.8;
     let guest_team_avg_goals = 3.1;
 
-    let res = GameRandomizer::randomize_totals(winner, home_team_avg_goals, guest_team_avg_goals);
+    let res = ClassicEngine::randomize_totals(winner, home_team_avg_goals, guest_team_avg_goals);
 
     assert!(res.0 > res.1)
 }
@@ -17,7 +20,7 @@ fn randomize_totals_draw() {
     let home_team_avg_goals = 0.1;
     let guest_team_avg_goals = 3.3;
 
-    let res = GameRandomizer::randomize_totals(winner, home_team_avg_goals, guest_team_avg_goals);
+    let res = ClassicEngine::randomize_totals(winner, home_team_avg_goals, guest_team_avg_goals);
 
     assert!(res.0 == res.1)
 }
@@ -28,7 +31,7 @@ fn randomize_totals_winner2() {
     let home_team_avg_goals = 0.1;
     let guest_team_avg_goals = 3.3;
 
-    let res = GameRandomizer::randomize_totals(winner, home_team_avg_goals, guest_team_avg_goals);
+    let res = ClassicEngine::randomize_totals(winner, home_team_avg_goals, guest_team_avg_goals);
 
     assert!(res.0 < res.1)
 }
@@ -57,7 +60,7 @@ fn randomize_winner_small_alpha() {
     let mut pr = PastResults::new();
 
     for _ in 1..40 {
-        pr.add_result(GameRandomizer::randomize_winner(
+        pr.add_result(ClassicEngine::randomize_winner(
             home_res.clone(),
             guest_res.clone(),
             h2h_res.clone(),
@@ -96,7 +99,7 @@ fn randomize_winner_big_alpha() {
     let mut pr = PastResults::new();
 
     for _ in 1..40 {
-        pr.add_result(GameRandomizer::randomize_winner(
+        pr.add_result(ClassicEngine::randomize_winner(
             home_res.clone(),
             guest_res.clone(),
             h2h_res.clone(),
@@ -0,0 +1,48 @@
+use super::super::engine::{Form, GoalsEngine, MatchEngine};
+use super::super::*;
+
+const GAMES: usize = 20_000;
+
+/// Sample mean and variance of the goals of a team with `mean` goals on average.
+fn moments(engine: &GoalsEngine, mean: f64) -> (f64, f64) {
+    let goals = (0..GAMES)
+        .map(|_| engine.goals(mean) as f64)
+        .collect::<Vec<_>>();
+    let m = goals.iter().sum::<f64>() / GAMES as f64;
+    let v = goals.iter().map(|g| (g - m).powi(2)).sum::<f64>() / GAMES as f64;
+
+    (m, v)
+}
+
+#[test]
+fn poisson_goals() {
+    let (mean, variance) = moments(&GoalsEngine::new(0.), 2.5);
+
+    assert!((mean - 2.5).abs() < 0.1);
+    assert!((variance - 2.5).abs() < 0.25);
+}
+
+#[test]
+fn negative_binomial_goals() {
+    let (mean, variance) = moments(&GoalsEngine::new(0.4), 2.5);
+
+    assert!((mean - 2.5).abs() < 0.1);
+    assert!((variance - (2.5 + 0.4 * 2.5 * 2.5)).abs() < 0.5);
+}
+
+#[test]
+fn goalless_team_can_score() {
+    let engine = GoalsEngine::new(0.);
+    let form = Form {
+        home_res: PastResults::new(),
+        guest_res: PastResults::new(),
+        h2h_res: PastResults::new(),
+        home_avg_goals: 0.,
+        guest_avg_goals: 0.,
+        h2h_avg_goals: (0., 0.),
+    };
+
+    let scored = (0..1000).any(|_| engine.play(&form) != (0, 0));
+
+    assert!(scored);
+}
@@ -1,14 +1,15 @@
-use std::net::Ipv4Addr;
-
 use anyhow::anyhow;
 use domain::value_object::{Amount, Margin};
 use uuid::Uuid;
 
 use super::super::*;
-use crate::repository::{MockIGameRepo, MockIGameStatRepo, MockITeamRepo};
+use crate::config::{EngineModel, MarginMethod, ShadingConfig};
+use crate::error::{ErrorKind, classify};
+use crate::repository::{MockIForecastRepo, MockIGameRepo, MockIGameStatRepo, MockITeamRepo};
+use domain::entity::Forecast;
+use domain::value_object::{Event, Winner};
 
-#[test]
-fn randomize_game_success() {
+fn randomize_game(engine: EngineConfig) -> Result<DisplayedGameStat> {
     let game = Game::new(
         Uuid::now_v7().into(),
         Uuid::now_v7().into(),
@@ -23,9 +24,7 @@ fn randomize_game_success() {
     game_repo
         .expect_h2hs_id_by_team_id()
         .returning(|_, _, _, _| Ok(vec![(<Id<Game>>::from(Uuid::now_v7()), true)]));
-    game_repo
-        .expect_game_by_id()
-        .returning(move |_| Ok(game.clone()));
+    game_repo.expect_game_by_id().returning(move |_| Ok(game));
     let mut gs_repo = MockIGameStatRepo::new();
     gs_repo
         .expect_winner_by_game_id()
@@ -44,21 +43,45 @@ fn randomize_game_success() {
     team_repo
         .expect_team_by_id()
         .returning(|_| Ok(Team::new(<Id<Team>>::from(Uuid::now_v7()), "CSKA".into())));
+    let mut forecast_repo = MockIForecastRepo::new();
+    forecast_repo
+        .expect_next_id()
+        .returning(|| <Id<Forecast>>::from(Uuid::now_v7()));
+    forecast_repo.expect_add_all().returning(|_| Ok(()));
     let config = CoefficientConfig {
         tracked_games: 5,
         margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
         alpha: 15,
         totals: vec![2, 3],
         deviation_min: 0.8,
         deviation_max: 1.2,
+        shading: ShadingConfig::default(),
     };
-    let gs = GameService::new(game_repo, gs_repo, team_repo, config);
+    let gs = GameService::new(game_repo, gs_repo, team_repo, forecast_repo, config, engine);
 
-    let res = gs.randomize_game(&game);
+    gs.randomize_game(&game)
+}
+
+#[test]
+fn randomize_game_success() {
+    let res = randomize_game(EngineConfig::default());
 
     assert!(res.is_ok());
 }
 
+#[test]
+fn randomize_game_by_goals() {
+    for model in [EngineModel::Poisson, EngineModel::NegativeBinomial] {
+        let res = randomize_game(EngineConfig {
+            model,
+            dispersion: 0.1,
+        });
+
+        assert!(res.is_ok());
+    }
+}
+
 #[test]
 fn randomize_game_failure() {
     let game = Game::new(
@@ -75,9 +98,7 @@ fn randomize_game_failure() {
     game_repo
         .expect_h2hs_id_by_team_id()
         .returning(|_, _, _, _| Ok(vec![(<Id<Game>>::from(Uuid::now_v7()), true)]));
-    game_repo
-        .expect_game_by_id()
-        .returning(move |_| Ok(game.clone()));
+    game_repo.expect_game_by_id().returning(move |_| Ok(game));
     let mut gs_repo = MockIGameStatRepo::new();
     gs_repo
         .expect_winner_by_game_id()
@@ -96,15 +117,29 @@ fn randomize_game_failure() {
     team_repo
         .expect_team_by_id()
         .returning(|_| Ok(Team::new(<Id<Team>>::from(Uuid::now_v7()), "CSKA".into())));
+    let mut forecast_repo = MockIForecastRepo::new();
+    forecast_repo
+        .expect_next_id()
+        .returning(|| <Id<Forecast>>::from(Uuid::now_v7()));
+    forecast_repo.expect_add_all().returning(|_| Ok(()));
     let config = CoefficientConfig {
         tracked_games: 5,
         margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
         alpha: 15,
         totals: vec![2, 3],
         deviation_min: 0.8,
         deviation_max: 1.2,
+        shading: ShadingConfig::default(),
     };
-    let gs = GameService::new(game_repo, gs_repo, team_repo, config);
+    let gs = GameService::new(
+        game_repo,
+        gs_repo,
+        team_repo,
+        forecast_repo,
+        config,
+        EngineConfig::default(),
+    );
 
     let res = gs.randomize_game(&game);
 
@@ -131,15 +166,25 @@ fn randomize_round_success() {
     let config = CoefficientConfig {
         tracked_games: 5,
         margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
         alpha: 15,
         totals: vec![2, 3],
         deviation_min: 0.8,
         deviation_max: 1.2,
+        shading: ShadingConfig::default(),
     };
-    let gs = GameService::new(game_repo, gs_repo, team_repo, config);
+    let gs = GameService::new(
+        game_repo,
+        gs_repo,
+        team_repo,
+        MockIForecastRepo::new(),
+        config,
+        EngineConfig::default(),
+    );
     let simulation = Simulation::new(
         Uuid::now_v7().into(),
-        std::net::IpAddr::V4(Ipv4Addr::LOCALHOST),
+        Uuid::now_v7().into(),
+        "default".to_string(),
         Amount::new(1000, None).unwrap(),
         None,
     );
@@ -168,15 +213,25 @@ fn randomize_round_failure() {
     let config = CoefficientConfig {
         tracked_games: 5,
         margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
         alpha: 15,
         totals: vec![2, 3],
         deviation_min: 0.8,
         deviation_max: 1.2,
+        shading: ShadingConfig::default(),
     };
-    let gs = GameService::new(game_repo, gs_repo, team_repo, config);
+    let gs = GameService::new(
+        game_repo,
+        gs_repo,
+        team_repo,
+        MockIForecastRepo::new(),
+        config,
+        EngineConfig::default(),
+    );
     let simulation = Simulation::new(
         Uuid::now_v7().into(),
-        std::net::IpAddr::V4(Ipv4Addr::LOCALHOST),
+        Uuid::now_v7().into(),
+        "default".to_string(),
         Amount::new(1000, None).unwrap(),
         None,
     );
@@ -184,8 +239,91 @@ fn randomize_round_failure() {
     let res = gs.randomize_round(&simulation);
 
     assert!(res.is_err());
+    let err = res.err().unwrap();
+    assert_eq!(err.to_string(), "Last round already randomized");
     assert_eq!(
-        res.err().unwrap().to_string(),
-        "Last round already randomized"
+        classify(&err),
+        (ErrorKind::Conflict, "round_already_randomized")
+    );
+}
+
+#[test]
+fn randomize_game_records_forecasts() {
+    let game = Game::new(
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        1,
+    );
+    let mut game_repo = MockIGameRepo::new();
+    game_repo
+        .expect_games_id_by_team_id()
+        .returning(|_, _, _| Ok(vec![(<Id<Game>>::from(Uuid::now_v7()), true)]));
+    game_repo
+        .expect_h2hs_id_by_team_id()
+        .returning(|_, _, _, _| Ok(vec![]));
+    game_repo.expect_game_by_id().returning(move |_| Ok(game));
+    let mut gs_repo = MockIGameStatRepo::new();
+    gs_repo
+        .expect_winner_by_game_id()
+        .returning(|_, _| Some(Winner::W1));
+    gs_repo
+        .expect_score_by_game_id()
+        .returning(|_, _| Some((3u8, 1u8)));
+    gs_repo
+        .expect_goals_by_game_id()
+        .returning(|_, _| Some(3u8));
+    gs_repo
+        .expect_next_id()
+        .returning(|| <Id<GameStat>>::from(Uuid::now_v7()));
+    gs_repo.expect_add().returning(|_| Ok(()));
+    let mut team_repo = MockITeamRepo::new();
+    team_repo
+        .expect_team_by_id()
+        .returning(|_| Ok(Team::new(<Id<Team>>::from(Uuid::now_v7()), "CSKA".into())));
+    let mut forecast_repo = MockIForecastRepo::new();
+    forecast_repo
+        .expect_next_id()
+        .returning(|| <Id<Forecast>>::from(Uuid::now_v7()));
+    let game_id = game.id();
+    forecast_repo
+        .expect_add_all()
+        .times(1)
+        .withf(move |forecasts| {
+            let sum = |winner: bool| {
+                forecasts
+                    .iter()
+                    .filter(|f| matches!(f.event(), Event::WDL(_)) == winner)
+                    .map(|f| f.probability())
+                    .sum::<f64>()
+            };
+            forecasts.len() == 9
+                && forecasts.iter().all(|f| f.game_id() == game_id)
+                && (sum(true) - 1.).abs() < 1e-9
+                && (sum(false) - 2.).abs() < 1e-9
+        })
+        .returning(|_| Ok(()));
+    let config = CoefficientConfig {
+        tracked_games: 5,
+        margin: Margin::try_from(0.12).unwrap(),
+        margin_method: MarginMethod::Proportional,
+        alpha: 15,
+        totals: vec![2, 3],
+        deviation_min: 0.8,
+        deviation_max: 1.2,
+        shading: ShadingConfig::default(),
+    };
+    let gs = GameService::new(
+        game_repo,
+        gs_repo,
+        team_repo,
+        forecast_repo,
+        config,
+        EngineConfig::default(),
     );
+
+    let res = gs.randomize_game(&game);
+
+    assert!(res.is_ok());
 }
@@ -1,2 +1,3 @@
 mod mock;
 mod classic;
+mod goals;
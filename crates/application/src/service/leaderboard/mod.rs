@@ -0,0 +1,203 @@
+use anyhow::{Result, bail};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use tracing::debug;
+
+use crate::{
+    config::LeaderboardConfig,
+    error::AppError,
+    repository::{IBetRepo, IDepositRepo, ISimulationRepo, IUserRepo},
+    usecase::ShowLeaderboard,
+};
+use domain::{
+    entity::Simulation,
+    value_object::{Amount, Id},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RankingMetric {
+    Balance,
+    Roi,
+    Profit,
+}
+
+impl FromStr for RankingMetric {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "balance" => Ok(Self::Balance),
+            "roi" => Ok(Self::Roi),
+            "profit" => Ok(Self::Profit),
+            _ => Err(AppError::UnsupportedMetric),
+        }
+    }
+}
+
+pub struct LeaderboardQuery {
+    pub metric: RankingMetric,
+    /// Only bets of the last `rounds` rounds of every simulation count, all of them if `None`.
+    pub rounds: Option<u32>,
+    /// Numbered from 1.
+    pub page: usize,
+    /// The configured page size if `None`.
+    pub per_page: Option<usize>,
+}
+
+pub struct LeaderboardEntry {
+    pub rank: usize,
+    pub simulation_id: Id<Simulation>,
+    pub name: String,
+    pub login: String,
+    pub round: u32,
+    pub balance: Amount,
+    /// Sum of all deposits, taken off the balance when ranking by it.
+    pub deposited: Amount,
+    pub staked: Amount,
+    pub profit: Amount,
+    pub roi: f64,
+    pub bets: usize,
+}
+
+pub struct LeaderboardPage {
+    pub entries: Vec<LeaderboardEntry>,
+    pub page: usize,
+    pub per_page: usize,
+    /// Ranked simulations on all pages.
+    pub total: usize,
+}
+
+/// Settled bets of one simulation inside the window.
+struct Standing {
+    simulation: Simulation,
+    deposited: i64,
+    staked: i64,
+    profit: i64,
+    bets: usize,
+}
+
+impl Standing {
+    /// Balance the simulation earned itself, deposits taken off.
+    fn net(&self) -> i64 {
+        self.simulation.balance().clear_value() - self.deposited
+    }
+
+    fn roi(&self) -> f64 {
+        if self.staked == 0 {
+            return 0.0;
+        }
+
+        self.profit as f64 / self.staked as f64
+    }
+}
+
+pub struct LeaderboardService<S: ISimulationRepo, U: IUserRepo, B: IBetRepo, D: IDepositRepo> {
+    simulation_repo: S,
+    user_repo: U,
+    bet_repo: B,
+    deposit_repo: D,
+    config: LeaderboardConfig,
+}
+
+impl<S: ISimulationRepo, U: IUserRepo, B: IBetRepo, D: IDepositRepo> ShowLeaderboard
+    for LeaderboardService<S, U, B, D>
+{
+    /// Balance is ranked as it is now less the deposits, the window only limits which bets are
+    /// counted.
+    fn leaderboard(&self, query: &LeaderboardQuery) -> Result<LeaderboardPage> {
+        let per_page = query.per_page.unwrap_or(self.config.page_size);
+        if query.page == 0 || per_page == 0 || per_page > self.config.max_page_size {
+            bail!(AppError::InvalidPage {
+                max_per_page: self.config.max_page_size
+            });
+        }
+
+        let settled = self
+            .bet_repo
+            .settled_totals(query.rounds)?
+            .into_iter()
+            .map(|t| (t.simulation_id, t))
+            .collect::<BTreeMap<_, _>>();
+        let deposited = self
+            .deposit_repo
+            .deposited_totals()?
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+        debug!("Totals of active simulations counted");
+
+        let mut standings = vec![];
+        for simulation in self.simulation_repo.active_simulations()? {
+            let settled = settled.get(&simulation.id());
+            let bets = settled.map_or(0, |t| t.bets);
+            if simulation.round() < self.config.min_rounds || bets < self.config.min_bets {
+                continue;
+            }
+            let staked = settled.map_or(0, |t| t.staked);
+            standings.push(Standing {
+                deposited: deposited
+                    .get(&simulation.id())
+                    .map_or(0, |d| d.clear_value()),
+                staked,
+                profit: settled.map_or(0, |t| t.returned) - staked,
+                bets,
+                simulation,
+            });
+        }
+        standings.sort_by(|a, b| match query.metric {
+            RankingMetric::Balance => b.net().cmp(&a.net()),
+            RankingMetric::Roi => b.roi().total_cmp(&a.roi()),
+            RankingMetric::Profit => b.profit.cmp(&a.profit),
+        });
+        let total = standings.len();
+        debug!(total, "Simulations ranked");
+
+        let mut entries = vec![];
+        let skip = (query.page - 1).saturating_mul(per_page);
+        for (pos, standing) in standings.into_iter().enumerate().skip(skip).take(per_page) {
+            let user = self.user_repo.user_by_id(standing.simulation.user_id())?;
+            entries.push(LeaderboardEntry {
+                rank: pos + 1,
+                simulation_id: standing.simulation.id(),
+                name: standing.simulation.name().to_string(),
+                login: user.login().to_string(),
+                round: standing.simulation.round(),
+                balance: standing.simulation.balance(),
+                deposited: Amount::new(standing.deposited, None)?,
+                staked: Amount::new(standing.staked, None)?,
+                profit: Amount::new(standing.profit, None)?,
+                roi: standing.roi(),
+                bets: standing.bets,
+            });
+        }
+
+        Ok(LeaderboardPage {
+            entries,
+            page: query.page,
+            per_page,
+            total,
+        })
+    }
+}
+
+impl<S: ISimulationRepo, U: IUserRepo, B: IBetRepo, D: IDepositRepo>
+    LeaderboardService<S, U, B, D>
+{
+    pub fn new(
+        simulation_repo: S,
+        user_repo: U,
+        bet_repo: B,
+        deposit_repo: D,
+        config: LeaderboardConfig,
+    ) -> Self {
+        Self {
+            simulation_repo,
+            user_repo,
+            bet_repo,
+            deposit_repo,
+            config,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
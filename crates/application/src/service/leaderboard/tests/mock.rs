@@ -0,0 +1,239 @@
+use uuid::Uuid;
+
+use super::super::*;
+use crate::error::{Entity, ErrorKind, classify};
+use crate::repository::{
+    MockIBetRepo, MockIDepositRepo, MockISimulationRepo, MockIUserRepo, SettledTotals,
+};
+use domain::{entity::User, value_object::MIN_BALANCE_AMOUNT};
+
+type Service =
+    LeaderboardService<MockISimulationRepo, MockIUserRepo, MockIBetRepo, MockIDepositRepo>;
+
+struct Run {
+    simulation: Simulation,
+    /// Settled bets of both rounds.
+    settled: SettledTotals,
+    /// Settled bets of the last round.
+    recent: SettledTotals,
+    deposited: Option<Amount>,
+}
+
+/// Simulation in round 2 with `(bets, staked, returned)` of its settled bets.
+fn run(name: &str, balance: i64, settled: (usize, i64, i64), recent: (usize, i64, i64)) -> Run {
+    let simulation = Simulation::new(
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        name.to_string(),
+        Amount::new(balance, Some(MIN_BALANCE_AMOUNT)).unwrap(),
+        Some(2),
+    );
+    let totals = |(bets, staked, returned)| SettledTotals {
+        simulation_id: simulation.id(),
+        bets,
+        staked,
+        returned,
+    };
+
+    Run {
+        settled: totals(settled),
+        recent: totals(recent),
+        simulation,
+        deposited: None,
+    }
+}
+
+/// Balance ranks A, B, C; profit C, B, A; ROI B, C, A. Over the last round profit ranks
+/// B, A, C.
+fn runs() -> Vec<Run> {
+    vec![
+        run("A", 150000, (2, 2000, 2000), (1, 1000, 0)),
+        run("B", 120000, (1, 2000, 4000), (1, 2000, 4000)),
+        run("C", 100000, (2, 12000, 15000), (1, 2000, 0)),
+    ]
+}
+
+fn config(min_bets: usize, min_rounds: u32) -> LeaderboardConfig {
+    LeaderboardConfig {
+        min_bets,
+        min_rounds,
+        page_size: 20,
+        max_page_size: 50,
+    }
+}
+
+fn service(runs: Vec<Run>, config: LeaderboardConfig) -> Service {
+    let simulations = runs
+        .iter()
+        .map(|r| r.simulation.clone())
+        .collect::<Vec<_>>();
+    let mut sim_repo = MockISimulationRepo::new();
+    let active = simulations.clone();
+    sim_repo
+        .expect_active_simulations()
+        .returning(move || Ok(active.clone()));
+    let mut user_repo = MockIUserRepo::new();
+    user_repo.expect_user_by_id().returning(move |id| {
+        simulations
+            .iter()
+            .find(|s| s.user_id() == id)
+            .map(|s| User::new(id, format!("user-{}", s.name()), String::new()))
+            .ok_or_else(|| AppError::NotFound(Entity::User).into())
+    });
+    let settled = runs.iter().map(|r| r.settled).collect::<Vec<_>>();
+    let recent = runs.iter().map(|r| r.recent).collect::<Vec<_>>();
+    let mut bet_repo = MockIBetRepo::new();
+    bet_repo
+        .expect_settled_totals()
+        .returning(move |rounds| match rounds {
+            None => Ok(settled.clone()),
+            Some(1) => Ok(recent.clone()),
+            Some(_) => unimplemented!(),
+        });
+    let deposited = runs
+        .iter()
+        .filter_map(|r| r.deposited.map(|d| (r.simulation.id(), d)))
+        .collect::<Vec<_>>();
+    let mut deposit_repo = MockIDepositRepo::new();
+    deposit_repo
+        .expect_deposited_totals()
+        .returning(move || Ok(deposited.clone()));
+
+    LeaderboardService::new(sim_repo, user_repo, bet_repo, deposit_repo, config)
+}
+
+fn query(metric: RankingMetric) -> LeaderboardQuery {
+    LeaderboardQuery {
+        metric,
+        rounds: None,
+        page: 1,
+        per_page: None,
+    }
+}
+
+fn names(page: &LeaderboardPage) -> Vec<&str> {
+    page.entries.iter().map(|e| e.name.as_str()).collect()
+}
+
+#[test]
+fn rank_by_metric() {
+    let ls = service(runs(), config(1, 1));
+
+    let by_balance = ls.leaderboard(&query(RankingMetric::Balance)).unwrap();
+    let by_profit = ls.leaderboard(&query(RankingMetric::Profit)).unwrap();
+    let by_roi = ls.leaderboard(&query(RankingMetric::Roi)).unwrap();
+
+    assert_eq!(names(&by_balance), ["A", "B", "C"]);
+    assert_eq!(names(&by_profit), ["C", "B", "A"]);
+    assert_eq!(names(&by_roi), ["B", "C", "A"]);
+    assert_eq!(
+        by_roi.entries.iter().map(|e| e.rank).collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+}
+
+#[test]
+fn balance_ranked_net_of_deposits() {
+    let mut runs = runs();
+    runs[0].deposited = Some(Amount::new(60000, None).unwrap());
+    let ls = service(runs, config(1, 1));
+
+    let page = ls.leaderboard(&query(RankingMetric::Balance)).unwrap();
+
+    assert_eq!(names(&page), ["B", "C", "A"]);
+    let a = &page.entries[2];
+    assert_eq!(a.balance.clear_value(), 150000);
+    assert_eq!(a.deposited.clear_value(), 60000);
+}
+
+#[test]
+fn entry_stats() {
+    let ls = service(runs(), config(1, 1));
+
+    let page = ls.leaderboard(&query(RankingMetric::Profit)).unwrap();
+
+    let c = &page.entries[0];
+    assert_eq!(c.login, "user-C");
+    assert_eq!(c.round, 2);
+    assert_eq!(c.bets, 2);
+    assert_eq!(c.staked.clear_value(), 12000);
+    assert_eq!(c.profit.clear_value(), 3000);
+    assert!((c.roi - 0.25).abs() < 1e-9);
+    let b = &page.entries[1];
+    assert_eq!(b.bets, 1);
+}
+
+#[test]
+fn rank_last_rounds() {
+    let ls = service(runs(), config(1, 1));
+    let mut query = query(RankingMetric::Profit);
+    query.rounds = Some(1);
+
+    let page = ls.leaderboard(&query).unwrap();
+
+    assert_eq!(names(&page), ["B", "A", "C"]);
+    assert_eq!(page.entries[1].profit.clear_value(), -1000);
+}
+
+#[test]
+fn activity_thresholds() {
+    let by_bets = service(runs(), config(2, 1));
+    let by_rounds = service(runs(), config(0, 3));
+
+    let page = by_bets.leaderboard(&query(RankingMetric::Roi)).unwrap();
+    let empty = by_rounds.leaderboard(&query(RankingMetric::Roi)).unwrap();
+
+    assert_eq!(names(&page), ["C", "A"]);
+    assert_eq!(page.total, 2);
+    assert!(empty.entries.is_empty());
+    assert_eq!(empty.total, 0);
+}
+
+#[test]
+fn paginate() {
+    let ls = service(runs(), config(1, 1));
+    let mut query = query(RankingMetric::Balance);
+    query.page = 2;
+    query.per_page = Some(2);
+
+    let page = ls.leaderboard(&query).unwrap();
+    query.page = 3;
+    let past_end = ls.leaderboard(&query).unwrap();
+
+    assert_eq!(names(&page), ["C"]);
+    assert_eq!(page.entries[0].rank, 3);
+    assert_eq!((page.page, page.per_page, page.total), (2, 2, 3));
+    assert!(past_end.entries.is_empty());
+}
+
+#[test]
+fn invalid_page() {
+    let ls = service(runs(), config(1, 1));
+
+    for (page, per_page) in [(0, None), (1, Some(0)), (1, Some(51))] {
+        let query = LeaderboardQuery {
+            metric: RankingMetric::Balance,
+            rounds: None,
+            page,
+            per_page,
+        };
+
+        let res = ls.leaderboard(&query);
+
+        assert_eq!(
+            classify(&res.err().unwrap()),
+            (ErrorKind::Validation, "invalid_page")
+        );
+    }
+}
+
+#[test]
+fn metric_from_str() {
+    assert_eq!("roi".parse::<RankingMetric>().unwrap(), RankingMetric::Roi);
+    assert_eq!(
+        "profit".parse::<RankingMetric>().unwrap(),
+        RankingMetric::Profit
+    );
+    assert!("balance".parse::<RankingMetric>().is_ok());
+    assert!("wins".parse::<RankingMetric>().is_err());
+}
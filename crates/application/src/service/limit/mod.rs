@@ -0,0 +1,87 @@
+use anyhow::{Result, bail};
+use tracing::info;
+
+use super::{check_active, now};
+use crate::{
+    config::LimitConfig,
+    error::AppError,
+    repository::{IBetLimitRepo, ISimulationRepo},
+    usecase::ManageLimits,
+};
+use domain::{
+    entity::Simulation,
+    value_object::{Amount, BetLimits, Id},
+};
+
+pub struct LimitService<L: IBetLimitRepo, S: ISimulationRepo> {
+    bet_limit_repo: L,
+    simulation_repo: S,
+    config: LimitConfig,
+}
+
+impl<L: IBetLimitRepo, S: ISimulationRepo> ManageLimits for LimitService<L, S> {
+    fn limits(&self, simulation_id: Id<Simulation>) -> Result<BetLimits> {
+        let simulation = self.simulation_repo.simulation_by_id(simulation_id)?;
+
+        Ok(self
+            .bet_limit_repo
+            .limits_by_simulation_id(simulation_id)?
+            .in_round(simulation.round()))
+    }
+
+    fn set_limits(&self, simulation_id: Id<Simulation>, limits: BetLimits) -> Result<BetLimits> {
+        let is_positive = |amount: Option<Amount>| amount.is_none_or(|a| a.clear_value() > 0);
+        if !is_positive(limits.max_stake)
+            || !is_positive(limits.max_round_stake)
+            || !is_positive(limits.max_round_deposit)
+            || limits
+                .loss_limit
+                .is_some_and(|l| l.amount.clear_value() <= 0 || l.rounds == 0)
+        {
+            bail!(AppError::InvalidLimits);
+        }
+        let simulation = self.simulation_repo.simulation_by_id(simulation_id)?;
+        check_active(&simulation)?;
+
+        let limits = self
+            .bet_limit_repo
+            .limits_by_simulation_id(simulation_id)?
+            .change(limits, simulation.round(), self.config.relax_delay_rounds);
+        self.bet_limit_repo.set_limits(simulation_id, limits)?;
+        info!(pending = limits.pending.map(|p| p.from_round), "Limits set");
+
+        Ok(limits)
+    }
+
+    fn cool_off(&self, simulation_id: Id<Simulation>, secs: i64) -> Result<BetLimits> {
+        if secs <= 0 {
+            bail!(AppError::InvalidLimits);
+        }
+        let simulation = self.simulation_repo.simulation_by_id(simulation_id)?;
+        check_active(&simulation)?;
+
+        let mut limits = self
+            .bet_limit_repo
+            .limits_by_simulation_id(simulation_id)?
+            .in_round(simulation.round());
+        let until = now().saturating_add(secs);
+        limits.cool_off_until = limits.cool_off_until.max(Some(until));
+        self.bet_limit_repo.set_limits(simulation_id, limits)?;
+        info!(until, "Cool-off started");
+
+        Ok(limits)
+    }
+}
+
+impl<L: IBetLimitRepo, S: ISimulationRepo> LimitService<L, S> {
+    pub fn new(bet_limit_repo: L, simulation_repo: S, config: LimitConfig) -> Self {
+        Self {
+            bet_limit_repo,
+            simulation_repo,
+            config,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,219 @@
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use super::super::*;
+use crate::error::{ErrorKind, classify};
+use crate::repository::{MockIBetLimitRepo, MockISimulationRepo};
+use domain::value_object::{LossLimit, MIN_BALANCE_AMOUNT};
+
+type Service = LimitService<MockIBetLimitRepo, MockISimulationRepo>;
+
+const DELAY: u32 = 3;
+
+fn amount(value: i64) -> Amount {
+    Amount::new(value, None).unwrap()
+}
+
+/// Simulation in round 1; the returned cell holds the limits as stored.
+fn service(stored: BetLimits, archived: bool) -> (Service, Arc<Mutex<BetLimits>>) {
+    let stored = Arc::new(Mutex::new(stored));
+    (service_in_round(stored.clone(), archived, 1), stored)
+}
+
+fn service_in_round(stored: Arc<Mutex<BetLimits>>, archived: bool, round: u32) -> Service {
+    let mut bet_limit_repo = MockIBetLimitRepo::new();
+    let rec = stored.clone();
+    bet_limit_repo
+        .expect_limits_by_simulation_id()
+        .returning(move |_| Ok(*rec.lock().unwrap()));
+    let rec = stored.clone();
+    bet_limit_repo.expect_set_limits().returning(move |_, l| {
+        *rec.lock().unwrap() = l;
+        Ok(())
+    });
+    let mut sim_repo = MockISimulationRepo::new();
+    sim_repo.expect_simulation_by_id().returning(move |id| {
+        let mut simulation = Simulation::new(
+            id,
+            Uuid::now_v7().into(),
+            "main".to_string(),
+            Amount::new(10000, Some(MIN_BALANCE_AMOUNT)).unwrap(),
+            Some(round),
+        );
+        if archived {
+            simulation.archive(1);
+        }
+        Ok(simulation)
+    });
+
+    let config = LimitConfig {
+        relax_delay_rounds: DELAY,
+    };
+
+    LimitService::new(bet_limit_repo, sim_repo, config)
+}
+
+fn limits() -> BetLimits {
+    BetLimits {
+        max_stake: Some(amount(5000)),
+        max_round_stake: None,
+        loss_limit: Some(LossLimit {
+            amount: amount(20000),
+            rounds: 5,
+        }),
+        max_round_deposit: None,
+        cool_off_until: None,
+        pending: None,
+    }
+}
+
+#[test]
+fn set_limits_keeps_cool_off() {
+    let until = now() + 3600;
+    let current = BetLimits {
+        cool_off_until: Some(until),
+        ..Default::default()
+    };
+    let (ls, stored) = service(current, false);
+    let mut new = limits();
+    new.cool_off_until = None;
+
+    let res = ls.set_limits(Uuid::now_v7().into(), new).unwrap();
+
+    assert_eq!(res.cool_off_until, Some(until));
+    assert_eq!(res.max_stake.unwrap().clear_value(), 5000);
+    assert!(*stored.lock().unwrap() == res);
+}
+
+#[test]
+fn set_invalid_limits() {
+    let zero_stake = BetLimits {
+        max_stake: Some(amount(0)),
+        ..limits()
+    };
+    let no_rounds = BetLimits {
+        loss_limit: Some(LossLimit {
+            amount: amount(1000),
+            rounds: 0,
+        }),
+        ..limits()
+    };
+
+    let zero_deposit = BetLimits {
+        max_round_deposit: Some(amount(0)),
+        ..limits()
+    };
+
+    for limits in [zero_stake, no_rounds, zero_deposit] {
+        let (ls, stored) = service(BetLimits::default(), false);
+
+        let res = ls.set_limits(Uuid::now_v7().into(), limits);
+
+        assert_eq!(
+            classify(&res.err().unwrap()),
+            (ErrorKind::Validation, "invalid_limits")
+        );
+        assert!(*stored.lock().unwrap() == BetLimits::default());
+    }
+}
+
+#[test]
+fn tightened_limits_apply_at_once() {
+    let (ls, stored) = service(limits(), false);
+    let tighter = BetLimits {
+        max_stake: Some(amount(3000)),
+        max_round_stake: Some(amount(10000)),
+        loss_limit: Some(LossLimit {
+            amount: amount(10000),
+            rounds: 6,
+        }),
+        max_round_deposit: Some(amount(50000)),
+        ..Default::default()
+    };
+
+    let res = ls.set_limits(Uuid::now_v7().into(), tighter).unwrap();
+
+    assert!(res == tighter);
+    assert!(*stored.lock().unwrap() == tighter);
+}
+
+#[test]
+fn relaxed_limits_wait_for_delay() {
+    let (ls, stored) = service(limits(), false);
+    let simulation_id = Uuid::now_v7().into();
+    let relaxed = BetLimits {
+        max_stake: None,
+        max_round_deposit: Some(amount(50000)),
+        ..limits()
+    };
+
+    let res = ls.set_limits(simulation_id, relaxed).unwrap();
+    let waiting = service_in_round(stored.clone(), false, DELAY)
+        .limits(simulation_id)
+        .unwrap();
+    let due = service_in_round(stored, false, 1 + DELAY)
+        .limits(simulation_id)
+        .unwrap();
+
+    assert!(res.max_stake == limits().max_stake, "relaxation is delayed");
+    assert_eq!(res.max_round_deposit.unwrap().clear_value(), 50000);
+    assert_eq!(res.pending.unwrap().from_round, 1 + DELAY);
+    assert!(waiting == res);
+    assert!(due == relaxed);
+}
+
+#[test]
+fn shorter_loss_window_is_relaxation() {
+    let (ls, _) = service(limits(), false);
+    let shorter = BetLimits {
+        loss_limit: Some(LossLimit {
+            amount: amount(20000),
+            rounds: 2,
+        }),
+        ..limits()
+    };
+
+    let res = ls.set_limits(Uuid::now_v7().into(), shorter).unwrap();
+
+    assert!(res.loss_limit == limits().loss_limit);
+    assert!(res.pending.unwrap().loss_limit == shorter.loss_limit);
+}
+
+#[test]
+fn set_limits_archived() {
+    let (ls, _) = service(BetLimits::default(), true);
+
+    let res = ls.set_limits(Uuid::now_v7().into(), limits());
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Conflict, "simulation_archived")
+    );
+}
+
+#[test]
+fn cool_off_is_never_shortened() {
+    let (ls, stored) = service(limits(), false);
+    let simulation_id = Uuid::now_v7().into();
+
+    let long = ls.cool_off(simulation_id, 7200).unwrap();
+    let short = ls.cool_off(simulation_id, 60).unwrap();
+
+    let until = long.cool_off_until.unwrap();
+    assert!(until >= now() + 7199);
+    assert_eq!(short.cool_off_until, Some(until));
+    assert!(*stored.lock().unwrap() == short);
+    assert!(short.max_stake == limits().max_stake, "limits are kept");
+}
+
+#[test]
+fn cool_off_invalid() {
+    let (ls, _) = service(BetLimits::default(), false);
+
+    let res = ls.cool_off(Uuid::now_v7().into(), 0);
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Validation, "invalid_limits")
+    );
+}
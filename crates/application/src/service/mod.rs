@@ -1,7 +1,68 @@
+use anyhow::{Result, bail};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::AdminConfig;
+use crate::error::AppError;
+use domain::entity::{Simulation, User};
+
+mod achievement;
+mod auth;
+mod autoplay;
 mod bet;
+mod book;
+mod calibration;
+mod deposit;
 mod game;
+mod leaderboard;
+mod limit;
+mod preference;
 mod simulation;
+mod transfer;
 
-pub use bet::BetService;
+pub use achievement::AchievementService;
+pub use auth::AuthService;
+pub use autoplay::{AutoplayService, StrategySummary};
+pub use bet::{
+    BetService, BetValidationError, ClosingLine, LiabilityScope, OddsHistory, StakeAdvice,
+};
+pub use book::{BookReport, BookService, BookWinner, Market, MarketHold, OpenGame};
+pub use calibration::{
+    CalibrationReport, CalibrationService, MarketCalibration, ReliabilityBucket,
+};
+pub use deposit::{DepositService, DepositSummary};
 pub use game::{DisplayedGameStat, GameService};
+pub use leaderboard::{
+    LeaderboardEntry, LeaderboardPage, LeaderboardQuery, LeaderboardService, RankingMetric,
+};
+pub use limit::LimitService;
+pub use preference::PreferenceService;
 pub use simulation::{DisplayedGame, SimulationService};
+pub use transfer::{
+    BetRecord, ConfigSnapshot, DOCUMENT_VERSION, DepositRecord, DocumentFormat, GameRecord,
+    SimulationDocument, TeamRecord, TransferService,
+};
+
+/// Current unix timestamp in seconds.
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+/// Archived runs only allow reading, every change is refused.
+fn check_active(simulation: &Simulation) -> Result<()> {
+    if simulation.is_archived() {
+        bail!(AppError::SimulationArchived);
+    }
+
+    Ok(())
+}
+
+/// Reports across every user are for the logins listed in the admin config.
+fn check_admin(config: &AdminConfig, user: &User) -> Result<()> {
+    if !config.logins.iter().any(|l| l == user.login()) {
+        bail!(AppError::AdminOnly);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use tracing::info;
+
+use crate::{repository::IUserRepo, usecase::ManagePreferences};
+use domain::{
+    entity::User,
+    value_object::{Id, OddsFormat},
+};
+
+pub struct PreferenceService<U: IUserRepo> {
+    user_repo: U,
+}
+
+impl<U: IUserRepo> ManagePreferences for PreferenceService<U> {
+    fn odds_format(&self, user_id: Id<User>) -> Result<OddsFormat> {
+        Ok(self.user_repo.user_by_id(user_id)?.odds_format())
+    }
+
+    fn set_odds_format(&self, user_id: Id<User>, odds_format: OddsFormat) -> Result<User> {
+        let mut user = self.user_repo.user_by_id(user_id)?;
+        user.set_odds_format(odds_format);
+        self.user_repo.update_by_id(user.clone())?;
+        info!(odds_format = odds_format.code(), "Odds format set");
+
+        Ok(user)
+    }
+}
+
+impl<U: IUserRepo> PreferenceService<U> {
+    pub fn new(user_repo: U) -> Self {
+        Self { user_repo }
+    }
+}
+
+#[cfg(test)]
+mod tests;
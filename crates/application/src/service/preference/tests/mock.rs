@@ -0,0 +1,70 @@
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use super::super::*;
+use crate::error::{AppError, Entity};
+use crate::repository::MockIUserRepo;
+
+/// The returned cell holds the user as stored.
+fn service() -> (PreferenceService<MockIUserRepo>, Arc<Mutex<User>>) {
+    let stored = Arc::new(Mutex::new(User::new(
+        Uuid::now_v7().into(),
+        "user".to_string(),
+        String::new(),
+    )));
+    let mut user_repo = MockIUserRepo::new();
+    let rec = stored.clone();
+    user_repo.expect_user_by_id().returning(move |id| {
+        let user = rec.lock().unwrap().clone();
+        if user.id() == id {
+            Ok(user)
+        } else {
+            Err(AppError::NotFound(Entity::User).into())
+        }
+    });
+    let rec = stored.clone();
+    user_repo.expect_update_by_id().returning(move |u| {
+        *rec.lock().unwrap() = u;
+        Ok(())
+    });
+
+    (PreferenceService::new(user_repo), stored)
+}
+
+#[test]
+fn decimal_by_default() {
+    let (service, stored) = service();
+    let user_id = stored.lock().unwrap().id();
+
+    assert_eq!(service.odds_format(user_id).unwrap(), OddsFormat::Decimal);
+}
+
+#[test]
+fn odds_format_stored() {
+    let (service, stored) = service();
+    let user_id = stored.lock().unwrap().id();
+
+    let user = service
+        .set_odds_format(user_id, OddsFormat::American)
+        .unwrap();
+
+    assert_eq!(user.odds_format(), OddsFormat::American);
+    assert_eq!(stored.lock().unwrap().odds_format(), OddsFormat::American);
+    assert_eq!(service.odds_format(user_id).unwrap(), OddsFormat::American);
+}
+
+#[test]
+fn unknown_user_not_found() {
+    let (service, stored) = service();
+
+    let err = service
+        .set_odds_format(Uuid::now_v7().into(), OddsFormat::Malay)
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err.downcast::<AppError>().unwrap(),
+        AppError::NotFound(Entity::User)
+    );
+    assert_eq!(stored.lock().unwrap().odds_format(), OddsFormat::Decimal);
+}
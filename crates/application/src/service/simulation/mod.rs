@@ -3,20 +3,23 @@ use rand::rng;
 use rand::seq::SliceRandom;
 use serde::Serialize;
 use std::fmt;
-use std::net::IpAddr;
 use tracing::{debug, info};
 
+use super::{check_active, now};
 use crate::{
     config::SetupConfig,
-    repository::{IGameRepo, IGameStatRepo, ISimulationRepo, ITeamRepo},
-    usecase::{CreateRound, Start},
+    error::{AppError, Entity},
+    repository::{IGameRepo, IGameStatRepo, ISimulationRepo, ITeamRepo, IUnitOfWork},
+    usecase::{CreateRound, ManageSimulations, Start},
 };
 use domain::{
-    entity::{Game, Simulation, Team},
+    entity::{Game, Simulation, Team, User},
     value_object::Id,
 };
 
 const TEAMS_PER_GAME: usize = 2;
+const MAX_NAME_LEN: usize = 64;
+const DEFAULT_NAME: &str = "default";
 
 #[derive(Serialize)]
 pub struct DisplayedGame {
@@ -45,46 +48,143 @@ impl fmt::Display for DisplayedGame {
     }
 }
 
-pub struct SimulationService<G: IGameRepo, T: ITeamRepo, GS: IGameStatRepo, S: ISimulationRepo> {
+pub struct SimulationService<
+    G: IGameRepo,
+    T: ITeamRepo,
+    GS: IGameStatRepo,
+    S: ISimulationRepo,
+    U: IUnitOfWork,
+> {
     game_repo: G,
     team_repo: T,
     game_stat_repo: GS,
     simulation_repo: S,
+    unit_of_work: U,
     config: SetupConfig,
 }
 
-impl<G: IGameRepo, T: ITeamRepo, GS: IGameStatRepo, S: ISimulationRepo> Start
-    for SimulationService<G, T, GS, S>
+impl<G: IGameRepo, T: ITeamRepo, GS: IGameStatRepo, S: ISimulationRepo, U: IUnitOfWork> Start
+    for SimulationService<G, T, GS, S, U>
 {
-    fn start(&self, ip: IpAddr) -> Result<Simulation> {
-        if let Some(simulation) = self.simulation_repo.simulation_by_ip(ip) {
+    fn start(&self, user_id: Id<User>) -> Result<Simulation> {
+        let latest = self
+            .simulation_repo
+            .simulations_by_user_id(user_id)?
+            .into_iter()
+            .filter(|s| !s.is_archived())
+            .max_by_key(|s| s.id());
+        if let Some(simulation) = latest {
             info!("Continue exist game");
             Ok(simulation)
         } else {
             info!("Start new game");
-            let id = self.simulation_repo.next_id();
-            let simulation = Simulation::new(id, ip, self.config.balance, None);
-            self.simulation_repo.add(simulation)?;
-
-            Ok(simulation)
+            self.create(user_id, DEFAULT_NAME)
         }
     }
 
     fn restart(&self, simulation_id: Id<Simulation>) -> Result<Simulation> {
-        let simulation = self.simulation_repo.simulation_by_id(simulation_id)?;
-        let simulation =
-            Simulation::new(simulation.id(), simulation.ip(), self.config.balance, None);
-        self.simulation_repo.update_by_id(simulation)?;
+        let mut archived = self.simulation_repo.simulation_by_id(simulation_id)?;
+        check_active(&archived)?;
+        archived.archive(now());
+        let simulation = Simulation::new(
+            self.simulation_repo.next_id(),
+            archived.user_id(),
+            archived.name().to_string(),
+            self.config.balance,
+            None,
+        );
+        self.unit_of_work.atomically(&mut || {
+            self.simulation_repo.update_by_id(archived.clone())?;
+            debug!("Previous run archived");
+            self.simulation_repo.add(simulation.clone())
+        })?;
         debug!("Game restarted");
 
         Ok(simulation)
     }
 }
 
-impl<G: IGameRepo, T: ITeamRepo, GS: IGameStatRepo, S: ISimulationRepo> CreateRound
-    for SimulationService<G, T, GS, S>
+impl<G: IGameRepo, T: ITeamRepo, GS: IGameStatRepo, S: ISimulationRepo, U: IUnitOfWork>
+    ManageSimulations for SimulationService<G, T, GS, S, U>
+{
+    fn create(&self, user_id: Id<User>, name: &str) -> Result<Simulation> {
+        let name = check_name(&self.simulation_repo, user_id, name)?;
+        let id = self.simulation_repo.next_id();
+        let simulation = Simulation::new(id, user_id, name, self.config.balance, None);
+        self.simulation_repo.add(simulation.clone())?;
+        info!(name = simulation.name(), "Simulation created");
+
+        Ok(simulation)
+    }
+
+    fn list(&self, user_id: Id<User>) -> Result<Vec<Simulation>> {
+        let mut simulations = self.simulation_repo.simulations_by_user_id(user_id)?;
+        simulations.retain(|s| !s.is_archived());
+        simulations.sort_by_key(|s| s.id());
+
+        Ok(simulations)
+    }
+
+    fn list_archived(&self, user_id: Id<User>) -> Result<Vec<Simulation>> {
+        let mut simulations = self.simulation_repo.simulations_by_user_id(user_id)?;
+        simulations.retain(|s| s.is_archived());
+        simulations.sort_by_key(|s| s.id());
+
+        Ok(simulations)
+    }
+
+    fn archived(&self, user_id: Id<User>, simulation_id: Id<Simulation>) -> Result<Simulation> {
+        let simulation = self.simulation(user_id, simulation_id)?;
+        if !simulation.is_archived() {
+            bail!(AppError::NotFound(Entity::Simulation));
+        }
+
+        Ok(simulation)
+    }
+
+    fn simulation(&self, user_id: Id<User>, simulation_id: Id<Simulation>) -> Result<Simulation> {
+        let simulation = self.simulation_repo.simulation_by_id(simulation_id)?;
+        if simulation.user_id() != user_id {
+            // Someone else's simulation is reported the same way as a missing one.
+            bail!(AppError::NotFound(Entity::Simulation));
+        }
+
+        Ok(simulation)
+    }
+
+    fn rename(
+        &self,
+        user_id: Id<User>,
+        simulation_id: Id<Simulation>,
+        name: &str,
+    ) -> Result<Simulation> {
+        let mut simulation = self.simulation(user_id, simulation_id)?;
+        check_active(&simulation)?;
+        if simulation.name() == name.trim() {
+            return Ok(simulation);
+        }
+        let name = check_name(&self.simulation_repo, user_id, name)?;
+        simulation.rename(name);
+        self.simulation_repo.update_by_id(simulation.clone())?;
+        info!(name = simulation.name(), "Simulation renamed");
+
+        Ok(simulation)
+    }
+
+    fn delete(&self, user_id: Id<User>, simulation_id: Id<Simulation>) -> Result<()> {
+        let simulation = self.simulation(user_id, simulation_id)?;
+        self.simulation_repo.remove_by_id(simulation.id());
+        info!(name = simulation.name(), "Simulation deleted");
+
+        Ok(())
+    }
+}
+
+impl<G: IGameRepo, T: ITeamRepo, GS: IGameStatRepo, S: ISimulationRepo, U: IUnitOfWork> CreateRound
+    for SimulationService<G, T, GS, S, U>
 {
     fn create_round(&self, simulation: &mut Simulation) -> Result<Vec<DisplayedGame>> {
+        check_active(simulation)?;
         let mut round = simulation.round();
         let simulation_id = simulation.id();
         info!("Checking if last round was randomized");
@@ -92,7 +192,7 @@ impl<G: IGameRepo, T: ITeamRepo, GS: IGameStatRepo, S: ISimulationRepo> CreateRo
         info!("Last round was randomized");
         round += 1;
         simulation.increment_round();
-        self.simulation_repo.update_by_id(*simulation)?;
+        self.simulation_repo.update_by_id(simulation.clone())?;
         debug!("Round incremented in simulation repo");
         let mut teams = self.team_repo.all_teams_id();
         teams.shuffle(&mut rng());
@@ -111,14 +211,15 @@ impl<G: IGameRepo, T: ITeamRepo, GS: IGameStatRepo, S: ISimulationRepo> CreateRo
     }
 }
 
-impl<G: IGameRepo, T: ITeamRepo, GS: IGameStatRepo, S: ISimulationRepo>
-    SimulationService<G, T, GS, S>
+impl<G: IGameRepo, T: ITeamRepo, GS: IGameStatRepo, S: ISimulationRepo, U: IUnitOfWork>
+    SimulationService<G, T, GS, S, U>
 {
     pub fn new(
         game_repo: G,
         team_repo: T,
         game_stat_repo: GS,
         simulation_repo: S,
+        unit_of_work: U,
         config: SetupConfig,
     ) -> Self {
         Self {
@@ -126,6 +227,7 @@ impl<G: IGameRepo, T: ITeamRepo, GS: IGameStatRepo, S: ISimulationRepo>
             team_repo,
             game_stat_repo,
             simulation_repo,
+            unit_of_work,
             config,
         }
     }
@@ -139,7 +241,7 @@ impl<G: IGameRepo, T: ITeamRepo, GS: IGameStatRepo, S: ISimulationRepo>
                 .ok()
                 .is_none()
             {
-                bail!("Last round didn't random");
+                bail!(AppError::RoundNotRandomized);
             }
         }
 
@@ -147,5 +249,28 @@ impl<G: IGameRepo, T: ITeamRepo, GS: IGameStatRepo, S: ISimulationRepo>
     }
 }
 
+/// Trimmed `name` if it is valid and not used by another active simulation of the user.
+pub(super) fn check_name(
+    simulation_repo: &impl ISimulationRepo,
+    user_id: Id<User>,
+    name: &str,
+) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+        bail!(AppError::InvalidSimulationName {
+            max_len: MAX_NAME_LEN
+        });
+    }
+    if simulation_repo
+        .simulations_by_user_id(user_id)?
+        .iter()
+        .any(|s| !s.is_archived() && s.name() == name)
+    {
+        bail!(AppError::SimulationNameTaken);
+    }
+
+    Ok(name.to_string())
+}
+
 #[cfg(test)]
 mod tests;
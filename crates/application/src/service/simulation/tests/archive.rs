@@ -0,0 +1,154 @@
+use domain::value_object::Amount;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use super::super::*;
+use super::manage::{service, simulation};
+use crate::error::{ErrorKind, classify};
+use crate::repository::{
+    FakeUnitOfWork, MockIGameRepo, MockIGameStatRepo, MockISimulationRepo, MockITeamRepo,
+};
+
+fn archived(user_id: Id<User>, name: &str) -> Simulation {
+    let mut simulation = simulation(user_id, name);
+    simulation.archive(1_700_000_000);
+    simulation
+}
+
+#[test]
+fn restart_archives_previous_run() {
+    let user_id = Uuid::now_v7().into();
+    let current = simulation(user_id, "martingale");
+    let current_id = current.id();
+    let updated = Arc::new(Mutex::new(None));
+    let added = Arc::new(Mutex::new(None));
+    let mut sim_repo = MockISimulationRepo::new();
+    sim_repo
+        .expect_simulation_by_id()
+        .returning(move |_| Ok(current.clone()));
+    sim_repo
+        .expect_next_id()
+        .returning(|| <Id<Simulation>>::from(Uuid::now_v7()));
+    let rec = updated.clone();
+    sim_repo.expect_update_by_id().returning(move |s| {
+        *rec.lock().unwrap() = Some(s);
+        Ok(())
+    });
+    let rec = added.clone();
+    sim_repo.expect_add().returning(move |s| {
+        *rec.lock().unwrap() = Some(s);
+        Ok(())
+    });
+    let config = SetupConfig {
+        balance: Amount::new(10000, None).unwrap(),
+    };
+    let ss = SimulationService::new(
+        MockIGameRepo::new(),
+        MockITeamRepo::new(),
+        MockIGameStatRepo::new(),
+        sim_repo,
+        FakeUnitOfWork,
+        config,
+    );
+
+    let res = ss.restart(current_id).unwrap();
+
+    let updated = updated.lock().unwrap().take().unwrap();
+    assert!(updated.id() == current_id);
+    assert!(updated.is_archived());
+    let added = added.lock().unwrap().take().unwrap();
+    assert!(added == res);
+    assert!(res.id() != current_id);
+    assert!(!res.is_archived());
+    assert_eq!(res.name(), "martingale");
+    assert_eq!(res.round(), 0);
+    assert_eq!(res.balance().clear_value(), 10000);
+}
+
+#[test]
+fn restart_archived_run() {
+    let old = archived(Uuid::now_v7().into(), "old");
+    let old_id = old.id();
+    let ss = service(vec![old]);
+
+    let res = ss.restart(old_id);
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Conflict, "simulation_archived")
+    );
+}
+
+#[test]
+fn archived_run_is_read_only() {
+    let user_id = Uuid::now_v7().into();
+    let mut old = archived(user_id, "old");
+    let old_id = old.id();
+    let ss = service(vec![old.clone()]);
+
+    let renamed = ss.rename(user_id, old_id, "new");
+    let round = ss.create_round(&mut old);
+
+    for res in [renamed.map(|_| ()), round.map(|_| ())] {
+        assert_eq!(
+            classify(&res.err().unwrap()),
+            (ErrorKind::Conflict, "simulation_archived")
+        );
+    }
+}
+
+#[test]
+fn list_separates_archived() {
+    let user_id = Uuid::now_v7().into();
+    let ss = service(vec![
+        archived(user_id, "default"),
+        simulation(user_id, "default"),
+        archived(Uuid::now_v7().into(), "other"),
+    ]);
+
+    let active = ss.list(user_id).unwrap();
+    let archives = ss.list_archived(user_id).unwrap();
+
+    assert_eq!(active.len(), 1);
+    assert!(!active[0].is_archived());
+    assert_eq!(archives.len(), 1);
+    assert!(archives[0].is_archived());
+    assert!(archives[0].user_id() == user_id);
+}
+
+#[test]
+fn archived_of_active_run() {
+    let user_id = Uuid::now_v7().into();
+    let current = simulation(user_id, "current");
+    let current_id = current.id();
+    let ss = service(vec![current]);
+
+    let res = ss.archived(user_id, current_id);
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::NotFound, "simulation_not_found")
+    );
+}
+
+#[test]
+fn archived_name_is_free() {
+    let user_id = Uuid::now_v7().into();
+    let ss = service(vec![archived(user_id, "conservative")]);
+
+    let res = ss.create(user_id, "conservative");
+
+    assert!(res.is_ok());
+}
+
+#[test]
+fn start_skips_archived() {
+    let user_id = Uuid::now_v7().into();
+    let current = simulation(user_id, "current");
+    let latest = archived(user_id, "latest");
+    let ss = service(vec![latest, current]);
+
+    let res = ss.start(user_id).unwrap();
+
+    assert_eq!(res.name(), "current");
+}
@@ -0,0 +1,228 @@
+use domain::value_object::Amount;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use super::super::*;
+use crate::error::{ErrorKind, classify};
+use crate::repository::{
+    FakeUnitOfWork, MockIGameRepo, MockIGameStatRepo, MockISimulationRepo, MockITeamRepo,
+};
+
+pub(super) type Service = SimulationService<
+    MockIGameRepo,
+    MockITeamRepo,
+    MockIGameStatRepo,
+    MockISimulationRepo,
+    FakeUnitOfWork,
+>;
+
+pub(super) fn simulation(user_id: Id<User>, name: &str) -> Simulation {
+    Simulation::new(
+        Uuid::now_v7().into(),
+        user_id,
+        name.to_string(),
+        Amount::new(1000, None).unwrap(),
+        None,
+    )
+}
+
+pub(super) fn service(existing: Vec<Simulation>) -> Service {
+    let mut sim_repo = MockISimulationRepo::new();
+    let by_user = existing.clone();
+    sim_repo
+        .expect_simulations_by_user_id()
+        .returning(move |user_id| {
+            Ok(by_user
+                .iter()
+                .filter(|s| s.user_id() == user_id)
+                .cloned()
+                .collect())
+        });
+    sim_repo.expect_simulation_by_id().returning(move |id| {
+        existing
+            .iter()
+            .find(|s| s.id() == id)
+            .cloned()
+            .ok_or_else(|| AppError::NotFound(Entity::Simulation).into())
+    });
+    sim_repo
+        .expect_next_id()
+        .returning(|| <Id<Simulation>>::from(Uuid::now_v7()));
+    sim_repo.expect_add().returning(|_| Ok(()));
+    sim_repo.expect_update_by_id().returning(|_| Ok(()));
+    sim_repo.expect_remove_by_id().returning(|_| ());
+    let config = SetupConfig {
+        balance: Amount::new(10000, None).unwrap(),
+    };
+
+    SimulationService::new(
+        MockIGameRepo::new(),
+        MockITeamRepo::new(),
+        MockIGameStatRepo::new(),
+        sim_repo,
+        FakeUnitOfWork,
+        config,
+    )
+}
+
+#[test]
+fn create_success() {
+    let user_id = Uuid::now_v7().into();
+    let ss = service(vec![simulation(user_id, "conservative")]);
+
+    let res = ss.create(user_id, "  martingale test ").unwrap();
+
+    assert_eq!(res.name(), "martingale test");
+    assert!(res.user_id() == user_id);
+    assert_eq!(res.balance().clear_value(), 10000);
+}
+
+#[test]
+fn create_name_taken() {
+    let user_id = Uuid::now_v7().into();
+    let ss = service(vec![simulation(user_id, "conservative")]);
+
+    let res = ss.create(user_id, "conservative");
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Conflict, "simulation_name_taken")
+    );
+}
+
+#[test]
+fn same_name_for_other_user() {
+    let ss = service(vec![simulation(Uuid::now_v7().into(), "conservative")]);
+
+    let res = ss.create(Uuid::now_v7().into(), "conservative");
+
+    assert!(res.is_ok());
+}
+
+#[test]
+fn create_invalid_name() {
+    let ss = service(vec![]);
+
+    for name in ["", "   ", &"x".repeat(65)] {
+        let res = ss.create(Uuid::now_v7().into(), name);
+
+        assert_eq!(
+            classify(&res.err().unwrap()),
+            (ErrorKind::Validation, "invalid_simulation_name")
+        );
+    }
+}
+
+#[test]
+fn list_in_creation_order() {
+    let user_id = Uuid::now_v7().into();
+    let first = simulation(user_id, "first");
+    let second = simulation(user_id, "second");
+    let ss = service(vec![
+        second,
+        simulation(Uuid::now_v7().into(), "other"),
+        first,
+    ]);
+
+    let res = ss.list(user_id).unwrap();
+
+    let names = res.iter().map(|s| s.name()).collect::<Vec<_>>();
+    assert_eq!(names, ["first", "second"]);
+}
+
+#[test]
+fn start_continues_latest() {
+    let user_id = Uuid::now_v7().into();
+    let first = simulation(user_id, "first");
+    let second = simulation(user_id, "second");
+    let ss = service(vec![second, first]);
+
+    let res = ss.start(user_id).unwrap();
+
+    assert_eq!(res.name(), "second");
+}
+
+#[test]
+fn simulation_of_other_user() {
+    let other = simulation(Uuid::now_v7().into(), "other");
+    let other_id = other.id();
+    let ss = service(vec![other]);
+
+    let res = ss.simulation(Uuid::now_v7().into(), other_id);
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::NotFound, "simulation_not_found")
+    );
+}
+
+#[test]
+fn rename_success() {
+    let user_id = Uuid::now_v7().into();
+    let current = simulation(user_id, "old");
+    let current_id = current.id();
+    let ss = service(vec![current]);
+
+    let res = ss.rename(user_id, current_id, "new").unwrap();
+
+    assert_eq!(res.name(), "new");
+    assert!(res.id() == current_id);
+}
+
+#[test]
+fn rename_to_taken_name() {
+    let user_id = Uuid::now_v7().into();
+    let current = simulation(user_id, "old");
+    let current_id = current.id();
+    let ss = service(vec![current, simulation(user_id, "new")]);
+
+    let res = ss.rename(user_id, current_id, "new");
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Conflict, "simulation_name_taken")
+    );
+}
+
+#[test]
+fn delete_success() {
+    let user_id = Uuid::now_v7().into();
+    let current = simulation(user_id, "old");
+    let current_id = current.id();
+    let removed = Arc::new(Mutex::new(None));
+    let mut sim_repo = MockISimulationRepo::new();
+    sim_repo
+        .expect_simulation_by_id()
+        .returning(move |_| Ok(current.clone()));
+    let flag = removed.clone();
+    sim_repo
+        .expect_remove_by_id()
+        .returning(move |id| *flag.lock().unwrap() = Some(id));
+    let config = SetupConfig {
+        balance: Amount::new(10000, None).unwrap(),
+    };
+    let ss = SimulationService::new(
+        MockIGameRepo::new(),
+        MockITeamRepo::new(),
+        MockIGameStatRepo::new(),
+        sim_repo,
+        FakeUnitOfWork,
+        config,
+    );
+
+    let res = ss.delete(user_id, current_id);
+
+    assert!(res.is_ok());
+    assert!(*removed.lock().unwrap() == Some(current_id));
+}
+
+#[test]
+fn delete_of_other_user() {
+    let other = simulation(Uuid::now_v7().into(), "other");
+    let other_id = other.id();
+    let ss = service(vec![other]);
+
+    let res = ss.delete(Uuid::now_v7().into(), other_id);
+
+    assert!(res.is_err());
+}
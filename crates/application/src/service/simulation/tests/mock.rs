@@ -1,32 +1,40 @@
-use std::net::Ipv4Addr;
-
 use anyhow::anyhow;
 use domain::value_object::Amount;
 use uuid::Uuid;
 
 use super::super::*;
-use crate::repository::{MockIGameRepo, MockIGameStatRepo, MockISimulationRepo, MockITeamRepo};
+use crate::repository::{
+    FakeUnitOfWork, MockIGameRepo, MockIGameStatRepo, MockISimulationRepo, MockITeamRepo,
+};
 
 #[test]
 fn start_success() {
     let game_repo = MockIGameRepo::new();
     let gs_repo = MockIGameStatRepo::new();
     let mut sim_repo = MockISimulationRepo::new();
-    sim_repo.expect_simulation_by_ip().returning(|_| {
-        Some(Simulation::new(
+    sim_repo.expect_simulations_by_user_id().returning(|_| {
+        Ok(vec![Simulation::new(
+            Uuid::now_v7().into(),
             Uuid::now_v7().into(),
-            std::net::IpAddr::V4(Ipv4Addr::LOCALHOST),
+            "default".to_string(),
             Amount::new(1000, None).unwrap(),
             None,
-        ))
+        )])
     });
     let team_repo = MockITeamRepo::new();
     let config = SetupConfig {
         balance: Amount::new(10000, None).unwrap(),
     };
-    let ss = SimulationService::new(game_repo, team_repo, gs_repo, sim_repo, config);
+    let ss = SimulationService::new(
+        game_repo,
+        team_repo,
+        gs_repo,
+        sim_repo,
+        FakeUnitOfWork,
+        config,
+    );
 
-    let res = ss.start(std::net::IpAddr::V4(Ipv4Addr::LOCALHOST));
+    let res = ss.start(Uuid::now_v7().into());
 
     assert!(res.is_ok());
 }
@@ -36,7 +44,9 @@ fn start_failure() {
     let game_repo = MockIGameRepo::new();
     let gs_repo = MockIGameStatRepo::new();
     let mut sim_repo = MockISimulationRepo::new();
-    sim_repo.expect_simulation_by_ip().returning(|_| None);
+    sim_repo
+        .expect_simulations_by_user_id()
+        .returning(|_| Ok(vec![]));
     sim_repo
         .expect_next_id()
         .returning(|| <Id<Simulation>>::from(Uuid::now_v7()));
@@ -47,9 +57,16 @@ fn start_failure() {
     let config = SetupConfig {
         balance: Amount::new(10000, None).unwrap(),
     };
-    let ss = SimulationService::new(game_repo, team_repo, gs_repo, sim_repo, config);
+    let ss = SimulationService::new(
+        game_repo,
+        team_repo,
+        gs_repo,
+        sim_repo,
+        FakeUnitOfWork,
+        config,
+    );
 
-    let res = ss.start(std::net::IpAddr::V4(Ipv4Addr::LOCALHOST));
+    let res = ss.start(Uuid::now_v7().into());
 
     assert!(res.is_err());
     assert_eq!(res.err().unwrap().to_string(), "unable to add");
@@ -61,10 +78,15 @@ fn restart_success() {
     let gs_repo = MockIGameStatRepo::new();
     let mut sim_repo = MockISimulationRepo::new();
     sim_repo.expect_update_by_id().returning(|_| Ok(()));
+    sim_repo
+        .expect_next_id()
+        .returning(|| <Id<Simulation>>::from(Uuid::now_v7()));
+    sim_repo.expect_add().returning(|_| Ok(()));
     sim_repo.expect_simulation_by_id().returning(|_| {
         Ok(Simulation::new(
             Uuid::now_v7().into(),
-            std::net::IpAddr::V4(Ipv4Addr::LOCALHOST),
+            Uuid::now_v7().into(),
+            "default".to_string(),
             Amount::new(1000, None).unwrap(),
             None,
         ))
@@ -73,7 +95,14 @@ fn restart_success() {
     let config = SetupConfig {
         balance: Amount::new(10000, None).unwrap(),
     };
-    let ss = SimulationService::new(game_repo, team_repo, gs_repo, sim_repo, config);
+    let ss = SimulationService::new(
+        game_repo,
+        team_repo,
+        gs_repo,
+        sim_repo,
+        FakeUnitOfWork,
+        config,
+    );
 
     let res = ss.restart(Uuid::now_v7().into());
 
@@ -88,10 +117,14 @@ fn restart_failure() {
     sim_repo
         .expect_update_by_id()
         .returning(|_| Err(anyhow!("unable to update")));
+    sim_repo
+        .expect_next_id()
+        .returning(|| <Id<Simulation>>::from(Uuid::now_v7()));
     sim_repo.expect_simulation_by_id().returning(|_| {
         Ok(Simulation::new(
             Uuid::now_v7().into(),
-            std::net::IpAddr::V4(Ipv4Addr::LOCALHOST),
+            Uuid::now_v7().into(),
+            "default".to_string(),
             Amount::new(1000, None).unwrap(),
             None,
         ))
@@ -100,7 +133,14 @@ fn restart_failure() {
     let config = SetupConfig {
         balance: Amount::new(10000, None).unwrap(),
     };
-    let ss = SimulationService::new(game_repo, team_repo, gs_repo, sim_repo, config);
+    let ss = SimulationService::new(
+        game_repo,
+        team_repo,
+        gs_repo,
+        sim_repo,
+        FakeUnitOfWork,
+        config,
+    );
 
     let res = ss.restart(Uuid::now_v7().into());
 
@@ -134,10 +174,18 @@ fn create_round_success() {
     let config = SetupConfig {
         balance: Amount::new(10000, None).unwrap(),
     };
-    let ss = SimulationService::new(game_repo, team_repo, gs_repo, sim_repo, config);
+    let ss = SimulationService::new(
+        game_repo,
+        team_repo,
+        gs_repo,
+        sim_repo,
+        FakeUnitOfWork,
+        config,
+    );
     let mut simulation = Simulation::new(
         Uuid::now_v7().into(),
-        std::net::IpAddr::V4(Ipv4Addr::LOCALHOST),
+        Uuid::now_v7().into(),
+        "default".to_string(),
         Amount::new(1000, None).unwrap(),
         None,
     );
@@ -175,10 +223,18 @@ fn create_round_failure() {
     let config = SetupConfig {
         balance: Amount::new(10000, None).unwrap(),
     };
-    let ss = SimulationService::new(game_repo, team_repo, gs_repo, sim_repo, config);
+    let ss = SimulationService::new(
+        game_repo,
+        team_repo,
+        gs_repo,
+        sim_repo,
+        FakeUnitOfWork,
+        config,
+    );
     let mut simulation = Simulation::new(
         Uuid::now_v7().into(),
-        std::net::IpAddr::V4(Ipv4Addr::LOCALHOST),
+        Uuid::now_v7().into(),
+        "default".to_string(),
         Amount::new(1000, None).unwrap(),
         None,
     );
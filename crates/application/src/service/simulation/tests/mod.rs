@@ -1 +1,3 @@
 mod mock;
+mod manage;
+mod archive;
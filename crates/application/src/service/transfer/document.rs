@@ -0,0 +1,144 @@
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::{
+    config::{CoefficientConfig, SetupConfig},
+    error::AppError,
+};
+use domain::value_object::Event;
+
+/// Version written into every exported document, bumped on incompatible changes.
+pub const DOCUMENT_VERSION: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DocumentFormat {
+    Json,
+    MessagePack,
+}
+
+impl DocumentFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::MessagePack => "msgpack",
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::MessagePack => "application/msgpack",
+        }
+    }
+}
+
+impl FromStr for DocumentFormat {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "msgpack" => Ok(Self::MessagePack),
+            _ => Err(AppError::UnsupportedFormat),
+        }
+    }
+}
+
+/// Portable copy of a whole simulation with everything needed to replay it elsewhere.
+///
+/// There is no seed: the engine draws every result from the thread's RNG and has none to
+/// keep. Played games carry their scores, so the history is restored as it was, but games
+/// played after import, in any round left unplayed too, come out differently each time.
+#[derive(Serialize, Deserialize)]
+pub struct SimulationDocument {
+    pub version: u32,
+    pub name: String,
+    pub round: u32,
+    pub balance: i64,
+    pub config: ConfigSnapshot,
+    pub teams: Vec<TeamRecord>,
+    pub games: Vec<GameRecord>,
+    pub bets: Vec<BetRecord>,
+    /// Missing from documents exported before deposits existed.
+    #[serde(default)]
+    pub deposits: Vec<DepositRecord>,
+}
+
+/// Settings the simulation was played with, kept for reference only.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ConfigSnapshot {
+    pub setup: SetupConfig,
+    pub coefficient: CoefficientConfig,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TeamRecord {
+    pub id: Uuid,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GameRecord {
+    pub id: Uuid,
+    pub home_team_id: Uuid,
+    pub guest_team_id: Uuid,
+    pub round: u32,
+    /// Home and guest goals, absent until the game is played.
+    pub score: Option<(u8, u8)>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BetRecord {
+    pub game_id: Uuid,
+    pub amount: i64,
+    pub coefficient: i32,
+    pub event: Event,
+    pub is_won: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DepositRecord {
+    pub amount: i64,
+    pub round: u32,
+    pub deposited_at: i64,
+}
+
+impl SimulationDocument {
+    pub fn encode(&self, format: DocumentFormat) -> Result<Vec<u8>> {
+        let bytes = match format {
+            DocumentFormat::Json => serde_json::to_vec_pretty(self)?,
+            DocumentFormat::MessagePack => rmp_serde::to_vec_named(self)?,
+        };
+
+        Ok(bytes)
+    }
+
+    /// Checks the version first, so a newer layout is reported as such, not as malformed.
+    pub fn decode(bytes: &[u8], format: DocumentFormat) -> Result<Self> {
+        let header: VersionHeader = parse(bytes, format)?;
+        if header.version != DOCUMENT_VERSION {
+            bail!(AppError::UnsupportedDocumentVersion {
+                version: header.version
+            });
+        }
+        let document = parse(bytes, format)?;
+
+        Ok(document)
+    }
+}
+
+#[derive(Deserialize)]
+struct VersionHeader {
+    version: u32,
+}
+
+fn parse<T: DeserializeOwned>(bytes: &[u8], format: DocumentFormat) -> Result<T> {
+    let value = match format {
+        DocumentFormat::Json => serde_json::from_slice(bytes).ok(),
+        DocumentFormat::MessagePack => rmp_serde::from_slice(bytes).ok(),
+    };
+
+    value.ok_or_else(|| AppError::InvalidDocument.into())
+}
@@ -0,0 +1,422 @@
+use anyhow::{Result, bail};
+use std::collections::{BTreeMap, btree_map::Entry};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::simulation::check_name;
+use crate::{
+    error::{AppError, Entity},
+    repository::{
+        IBetRepo, IDepositRepo, IGameRepo, IGameStatRepo, ISimulationRepo, ITeamRepo, IUnitOfWork,
+    },
+    usecase::Transfer,
+};
+use domain::{
+    entity::{Bet, Deposit, Game, GameStat, Simulation, Team, User},
+    value_object::{Amount, Coefficient, Id, MAX_BET_AMOUNT, MIN_BALANCE_AMOUNT, MIN_BET_AMOUNT},
+};
+
+mod document;
+
+pub use document::{
+    BetRecord, ConfigSnapshot, DOCUMENT_VERSION, DepositRecord, DocumentFormat, GameRecord,
+    SimulationDocument, TeamRecord,
+};
+
+pub struct TransferService<
+    T: ITeamRepo,
+    S: ISimulationRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    B: IBetRepo,
+    D: IDepositRepo,
+    U: IUnitOfWork,
+> {
+    team_repo: T,
+    simulation_repo: S,
+    game_repo: G,
+    game_stat_repo: GS,
+    bet_repo: B,
+    deposit_repo: D,
+    unit_of_work: U,
+    config: ConfigSnapshot,
+}
+
+impl<
+    T: ITeamRepo,
+    S: ISimulationRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    B: IBetRepo,
+    D: IDepositRepo,
+    U: IUnitOfWork,
+> Transfer for TransferService<T, S, G, GS, B, D, U>
+{
+    fn export(&self, simulation: &Simulation) -> Result<SimulationDocument> {
+        let mut teams = BTreeMap::new();
+        let mut games = vec![];
+        let mut bets = vec![];
+        for round in 1..=simulation.round() {
+            for game_id in self.game_repo.games_id_by_round(round, simulation.id())? {
+                let game = self.game_repo.game_by_id(game_id)?;
+                for team_id in [game.home_team_id(), game.guest_team_id()] {
+                    if let Entry::Vacant(entry) = teams.entry(team_id.value()) {
+                        let team = self.team_repo.team_by_id(team_id)?;
+                        entry.insert(TeamRecord {
+                            id: team_id.value(),
+                            name: team.name().to_string(),
+                        });
+                    }
+                }
+                let score = self
+                    .game_stat_repo
+                    .game_stat_by_game_id(game_id)
+                    .ok()
+                    .map(|gs| (gs.home_team_total(), gs.guest_team_total()));
+                games.push(GameRecord {
+                    id: game_id.value(),
+                    home_team_id: game.home_team_id().value(),
+                    guest_team_id: game.guest_team_id().value(),
+                    round,
+                    score,
+                });
+                for bet in self.bet_repo.bets_by_game_id(game_id)? {
+                    bets.push(BetRecord {
+                        game_id: game_id.value(),
+                        amount: bet.amount().clear_value(),
+                        coefficient: bet.coefficient().clear_value(),
+                        event: bet.event(),
+                        is_won: bet.is_won(),
+                    });
+                }
+            }
+        }
+        let deposits = self
+            .deposit_repo
+            .deposits_by_simulation_id(simulation.id())?
+            .into_iter()
+            .map(|d| DepositRecord {
+                amount: d.amount().clear_value(),
+                round: d.round(),
+                deposited_at: d.deposited_at(),
+            })
+            .collect::<Vec<_>>();
+        debug!(
+            games = games.len(),
+            bets = bets.len(),
+            deposits = deposits.len(),
+            "Simulation collected"
+        );
+
+        Ok(SimulationDocument {
+            version: DOCUMENT_VERSION,
+            name: simulation.name().to_string(),
+            round: simulation.round(),
+            balance: simulation.balance().clear_value(),
+            config: self.config.clone(),
+            teams: teams.into_values().collect(),
+            games,
+            bets,
+            deposits,
+        })
+    }
+
+    fn import(
+        &self,
+        user_id: Id<User>,
+        document: SimulationDocument,
+        name: Option<String>,
+    ) -> Result<Simulation> {
+        let name = check_name(
+            &self.simulation_repo,
+            user_id,
+            name.as_deref().unwrap_or(&document.name),
+        )?;
+        let balance = Amount::new(document.balance, Some(MIN_BALANCE_AMOUNT))
+            .map_err(|_| AppError::InvalidDocument)?;
+        let simulation = Simulation::new(
+            self.simulation_repo.next_id(),
+            user_id,
+            name,
+            balance,
+            Some(document.round),
+        );
+        let teams = self.resolve_teams(&document.teams)?;
+        debug!("Teams resolved");
+
+        let mut games = BTreeMap::new();
+        let mut game_stats = vec![];
+        for record in &document.games {
+            let (Some(&home_team_id), Some(&guest_team_id)) = (
+                teams.get(&record.home_team_id),
+                teams.get(&record.guest_team_id),
+            ) else {
+                bail!(AppError::InvalidDocument);
+            };
+            if record.round == 0 || record.round > document.round {
+                bail!(AppError::InvalidDocument);
+            }
+            let game = Game::new(
+                self.game_repo.next_id(),
+                simulation.id(),
+                home_team_id,
+                guest_team_id,
+                record.round,
+            );
+            if let Some((home, guest)) = record.score {
+                let id = self.game_stat_repo.next_id();
+                game_stats.push(GameStat::new(id, game.id(), home, guest));
+            }
+            if games.insert(record.id, (game, record.score)).is_some() {
+                bail!(AppError::InvalidDocument);
+            }
+        }
+        let mut bets = vec![];
+        for record in &document.bets {
+            let &(game, score) = games
+                .get(&record.game_id)
+                .ok_or(AppError::InvalidDocument)?;
+            if !(MIN_BET_AMOUNT..=MAX_BET_AMOUNT).contains(&record.amount) {
+                bail!(AppError::ImportedStakeOutOfRange {
+                    min: MIN_BET_AMOUNT,
+                    max: MAX_BET_AMOUNT,
+                });
+            }
+            if record.is_won.is_some() && record.is_won != score.map(|s| record.event.came_in(s)) {
+                bail!(AppError::ImportedBetMismatch);
+            }
+            let amount = Amount::new(record.amount, Some(MIN_BET_AMOUNT))?;
+            let coefficient =
+                Coefficient::try_from(record.coefficient).map_err(|_| AppError::InvalidDocument)?;
+            let bet = Bet::new(
+                self.bet_repo.next_id(),
+                simulation.id(),
+                amount,
+                coefficient,
+                game.id(),
+                record.event,
+                record.is_won,
+            );
+            bets.push(bet);
+        }
+        let mut deposits = vec![];
+        for record in &document.deposits {
+            if record.amount <= 0 || record.round > document.round {
+                bail!(AppError::InvalidDocument);
+            }
+            deposits.push(Deposit::new(
+                self.deposit_repo.next_id(),
+                simulation.id(),
+                Amount::new(record.amount, None)?,
+                record.round,
+                record.deposited_at,
+            ));
+        }
+        debug!("Document validated");
+
+        self.unit_of_work.atomically(&mut || {
+            self.simulation_repo.add(simulation.clone())?;
+            for &(game, _) in games.values() {
+                self.game_repo.add(game)?;
+            }
+            for &game_stat in &game_stats {
+                self.game_stat_repo.add(game_stat)?;
+            }
+            for &bet in &bets {
+                self.bet_repo.add(bet)?;
+            }
+            for &deposit in &deposits {
+                self.deposit_repo.add(deposit)?;
+            }
+
+            Ok(())
+        })?;
+        info!(name = simulation.name(), "Simulation imported");
+
+        Ok(simulation)
+    }
+
+    fn fork(&self, simulation: &Simulation, round: u32, name: &str) -> Result<Simulation> {
+        if round > simulation.round() {
+            bail!(AppError::InvalidForkRound {
+                max: simulation.round()
+            });
+        }
+        let name = check_name(&self.simulation_repo, simulation.user_id(), name)?;
+
+        let history = self
+            .game_repo
+            .games_by_simulation_id(simulation.id(), simulation.round())?;
+        let rounds = history
+            .iter()
+            .map(|g| (g.id(), g.round()))
+            .collect::<BTreeMap<_, _>>();
+        // Bets of the dropped rounds are undone: their stakes return, their payouts go away.
+        let mut balance = simulation.balance().clear_value();
+        let mut kept_bets = vec![];
+        for bet in self.bet_repo.bets_by_simulation_id(simulation.id())? {
+            if rounds.get(&bet.game_id()).is_some_and(|&r| r <= round) {
+                kept_bets.push(bet);
+                continue;
+            }
+            balance += bet.amount().clear_value();
+            if bet.is_won() == Some(true) {
+                balance -= bet.payout().clear_value();
+            }
+        }
+        // Money deposited after the fork round was never put in.
+        let mut kept_deposits = vec![];
+        for deposit in self
+            .deposit_repo
+            .deposits_by_simulation_id(simulation.id())?
+        {
+            if deposit.round() <= round {
+                kept_deposits.push(deposit);
+            } else {
+                balance -= deposit.amount().clear_value();
+            }
+        }
+        let fork = Simulation::new(
+            self.simulation_repo.next_id(),
+            simulation.user_id(),
+            name,
+            Amount::new(balance, Some(MIN_BALANCE_AMOUNT))?,
+            Some(round),
+        );
+
+        let mut games = BTreeMap::new();
+        for game in history.iter().filter(|g| g.round() <= round) {
+            let copy = Game::new(
+                self.game_repo.next_id(),
+                fork.id(),
+                game.home_team_id(),
+                game.guest_team_id(),
+                game.round(),
+            );
+            games.insert(game.id(), copy);
+        }
+        let game_stats = self
+            .game_stat_repo
+            .game_stats_by_simulation_id(simulation.id(), round)?
+            .into_iter()
+            .filter_map(|gs| {
+                let game = games.get(&gs.game_id())?;
+                Some(GameStat::new(
+                    self.game_stat_repo.next_id(),
+                    game.id(),
+                    gs.home_team_total(),
+                    gs.guest_team_total(),
+                ))
+            })
+            .collect::<Vec<_>>();
+        let bets = kept_bets
+            .into_iter()
+            .filter_map(|bet| {
+                let game = games.get(&bet.game_id())?;
+                Some(Bet::new(
+                    self.bet_repo.next_id(),
+                    fork.id(),
+                    bet.amount(),
+                    bet.coefficient(),
+                    game.id(),
+                    bet.event(),
+                    bet.is_won(),
+                ))
+            })
+            .collect::<Vec<_>>();
+        let deposits = kept_deposits
+            .into_iter()
+            .map(|d| {
+                Deposit::new(
+                    self.deposit_repo.next_id(),
+                    fork.id(),
+                    d.amount(),
+                    d.round(),
+                    d.deposited_at(),
+                )
+            })
+            .collect::<Vec<_>>();
+        debug!(
+            games = games.len(),
+            bets = bets.len(),
+            deposits = deposits.len(),
+            "Simulation history copied"
+        );
+
+        self.unit_of_work.atomically(&mut || {
+            self.simulation_repo.add(fork.clone())?;
+            self.game_repo.add_all(games.values().copied().collect())?;
+            self.game_stat_repo.add_all(game_stats.clone())?;
+            self.bet_repo.add_all(bets.clone())?;
+            for &deposit in &deposits {
+                self.deposit_repo.add(deposit)?;
+            }
+
+            Ok(())
+        })?;
+        info!(
+            from = simulation.name(),
+            name = fork.name(),
+            round,
+            "Simulation forked"
+        );
+
+        Ok(fork)
+    }
+}
+
+impl<
+    T: ITeamRepo,
+    S: ISimulationRepo,
+    G: IGameRepo,
+    GS: IGameStatRepo,
+    B: IBetRepo,
+    D: IDepositRepo,
+    U: IUnitOfWork,
+> TransferService<T, S, G, GS, B, D, U>
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        team_repo: T,
+        simulation_repo: S,
+        game_repo: G,
+        game_stat_repo: GS,
+        bet_repo: B,
+        deposit_repo: D,
+        unit_of_work: U,
+        config: ConfigSnapshot,
+    ) -> Self {
+        Self {
+            team_repo,
+            simulation_repo,
+            game_repo,
+            game_stat_repo,
+            bet_repo,
+            deposit_repo,
+            unit_of_work,
+            config,
+        }
+    }
+
+    /// Local team for every exported one, matched by id and then by name.
+    fn resolve_teams(&self, records: &[TeamRecord]) -> Result<BTreeMap<Uuid, Id<Team>>> {
+        let mut local = vec![];
+        for id in self.team_repo.all_teams_id() {
+            local.push(self.team_repo.team_by_id(id)?);
+        }
+        let mut teams = BTreeMap::new();
+        for record in records {
+            let team = local
+                .iter()
+                .find(|t| t.id().value() == record.id)
+                .or_else(|| local.iter().find(|t| t.name() == record.name))
+                .ok_or(AppError::NotFound(Entity::Team))?;
+            teams.insert(record.id, team.id());
+        }
+
+        Ok(teams)
+    }
+}
+
+#[cfg(test)]
+mod tests;
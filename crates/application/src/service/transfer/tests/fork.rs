@@ -0,0 +1,266 @@
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use super::super::*;
+use super::mock::{SPARTAK, Service, ZENIT, config, team_repo};
+use crate::error::{ErrorKind, classify};
+use crate::repository::{
+    FakeUnitOfWork, MockIBetRepo, MockIDepositRepo, MockIGameRepo, MockIGameStatRepo,
+    MockISimulationRepo,
+};
+use domain::value_object::{Event, MIN_BET_AMOUNT, Winner};
+
+struct History {
+    simulation: Simulation,
+    games: Vec<Game>,
+    game_stats: Vec<GameStat>,
+    bets: Vec<Bet>,
+    deposits: Vec<Deposit>,
+}
+
+#[derive(Default)]
+struct Added {
+    simulations: Vec<Simulation>,
+    games: Vec<Game>,
+    game_stats: Vec<GameStat>,
+    bets: Vec<Bet>,
+    deposits: Vec<Deposit>,
+}
+
+/// Two played rounds from a balance of 100.00: a won bet in the first, a won and a lost bet
+/// in the second, 20.00 deposited in each.
+fn history() -> History {
+    let user_id = <Id<User>>::from(Uuid::now_v7());
+    let balance = Amount::new(14500, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(
+        Uuid::now_v7().into(),
+        user_id,
+        "main".to_string(),
+        balance,
+        Some(2),
+    );
+    let spartak = Uuid::parse_str(SPARTAK).unwrap().into();
+    let zenit = Uuid::parse_str(ZENIT).unwrap().into();
+    let games = vec![
+        Game::new(Uuid::now_v7().into(), simulation.id(), spartak, zenit, 1),
+        Game::new(Uuid::now_v7().into(), simulation.id(), zenit, spartak, 2),
+    ];
+    let game_stats = games
+        .iter()
+        .map(|g| GameStat::new(Uuid::now_v7().into(), g.id(), 2, 1))
+        .collect();
+    let bet = |game: &Game, amount: i64, coefficient: f64, is_won: bool| {
+        Bet::new(
+            Uuid::now_v7().into(),
+            simulation.id(),
+            Amount::new(amount, Some(MIN_BET_AMOUNT)).unwrap(),
+            coefficient.try_into().unwrap(),
+            game.id(),
+            Event::WDL(Winner::W1),
+            Some(is_won),
+        )
+    };
+    let bets = vec![
+        bet(&games[0], 1000, 2.0, true),
+        bet(&games[1], 2000, 1.5, true),
+        bet(&games[1], 1500, 3.0, false),
+    ];
+    let deposits = [1, 2]
+        .map(|round| {
+            let amount = Amount::new(2000, None).unwrap();
+            Deposit::new(Uuid::now_v7().into(), simulation.id(), amount, round, 0)
+        })
+        .to_vec();
+
+    History {
+        simulation,
+        games,
+        game_stats,
+        bets,
+        deposits,
+    }
+}
+
+fn fork_service(history: &History, existing: Vec<Simulation>) -> (Service, Arc<Mutex<Added>>) {
+    let added = Arc::new(Mutex::new(Added::default()));
+    let mut sim_repo = MockISimulationRepo::new();
+    sim_repo
+        .expect_simulations_by_user_id()
+        .returning(move |_| Ok(existing.clone()));
+    sim_repo
+        .expect_next_id()
+        .returning(|| <Id<Simulation>>::from(Uuid::now_v7()));
+    let rec = added.clone();
+    sim_repo.expect_add().returning(move |s| {
+        rec.lock().unwrap().simulations.push(s);
+        Ok(())
+    });
+    let mut game_repo = MockIGameRepo::new();
+    let games = history.games.clone();
+    game_repo
+        .expect_games_by_simulation_id()
+        .returning(move |_, max_round| {
+            Ok(games
+                .iter()
+                .filter(|g| g.round() <= max_round)
+                .copied()
+                .collect())
+        });
+    game_repo
+        .expect_next_id()
+        .returning(|| <Id<Game>>::from(Uuid::now_v7()));
+    let rec = added.clone();
+    game_repo.expect_add_all().returning(move |g| {
+        rec.lock().unwrap().games.extend(g);
+        Ok(())
+    });
+    let mut gs_repo = MockIGameStatRepo::new();
+    let (games, game_stats) = (history.games.clone(), history.game_stats.clone());
+    gs_repo
+        .expect_game_stats_by_simulation_id()
+        .returning(move |_, max_round| {
+            Ok(game_stats
+                .iter()
+                .filter(|gs| {
+                    games
+                        .iter()
+                        .any(|g| g.id() == gs.game_id() && g.round() <= max_round)
+                })
+                .copied()
+                .collect())
+        });
+    gs_repo
+        .expect_next_id()
+        .returning(|| <Id<GameStat>>::from(Uuid::now_v7()));
+    let rec = added.clone();
+    gs_repo.expect_add_all().returning(move |gs| {
+        rec.lock().unwrap().game_stats.extend(gs);
+        Ok(())
+    });
+    let mut bet_repo = MockIBetRepo::new();
+    let bets = history.bets.clone();
+    bet_repo
+        .expect_bets_by_simulation_id()
+        .returning(move |_| Ok(bets.clone()));
+    bet_repo
+        .expect_next_id()
+        .returning(|| <Id<Bet>>::from(Uuid::now_v7()));
+    let rec = added.clone();
+    bet_repo.expect_add_all().returning(move |b| {
+        rec.lock().unwrap().bets.extend(b);
+        Ok(())
+    });
+    let mut deposit_repo = MockIDepositRepo::new();
+    let deposits = history.deposits.clone();
+    deposit_repo
+        .expect_deposits_by_simulation_id()
+        .returning(move |_| Ok(deposits.clone()));
+    deposit_repo
+        .expect_next_id()
+        .returning(|| <Id<Deposit>>::from(Uuid::now_v7()));
+    let rec = added.clone();
+    deposit_repo.expect_add().returning(move |d| {
+        rec.lock().unwrap().deposits.push(d);
+        Ok(())
+    });
+    let service = TransferService::new(
+        team_repo(),
+        sim_repo,
+        game_repo,
+        gs_repo,
+        bet_repo,
+        deposit_repo,
+        FakeUnitOfWork,
+        config(),
+    );
+
+    (service, added)
+}
+
+#[test]
+fn fork_success() {
+    let history = history();
+    let (service, added) = fork_service(&history, vec![history.simulation.clone()]);
+
+    let fork = service.fork(&history.simulation, 1, "what-if").unwrap();
+
+    // Second round undone: +2000 +1500 stakes back, -3000 payout and -2000 deposit taken away.
+    assert_eq!(fork.balance().clear_value(), 13000);
+    assert_eq!(fork.round(), 1);
+    assert_eq!(fork.name(), "what-if");
+    assert!(fork.id() != history.simulation.id());
+    assert!(!fork.is_archived());
+    let added = added.lock().unwrap();
+    assert_eq!(added.simulations.len(), 1);
+    assert_eq!(added.games.len(), 1);
+    assert!(added.games[0].id() != history.games[0].id());
+    assert!(added.games[0].simulation_id() == fork.id());
+    assert_eq!(added.games[0].round(), 1);
+    assert_eq!(added.game_stats.len(), 1);
+    assert!(added.game_stats[0].game_id() == added.games[0].id());
+    assert_eq!(added.bets.len(), 1);
+    assert!(added.bets[0].game_id() == added.games[0].id());
+    assert!(added.bets[0].simulation_id() == fork.id());
+    assert_eq!(added.bets[0].is_won(), Some(true));
+    assert_eq!(added.deposits.len(), 1);
+    assert!(added.deposits[0].simulation_id() == fork.id());
+    assert_eq!(added.deposits[0].round(), 1);
+}
+
+#[test]
+fn fork_at_current_round_copies_everything() {
+    let history = history();
+    let (service, added) = fork_service(&history, vec![history.simulation.clone()]);
+
+    let fork = service.fork(&history.simulation, 2, "copy").unwrap();
+
+    assert!(fork.balance() == history.simulation.balance());
+    let added = added.lock().unwrap();
+    assert_eq!(added.games.len(), 2);
+    assert_eq!(added.game_stats.len(), 2);
+    assert_eq!(added.bets.len(), 3);
+    assert_eq!(added.deposits.len(), 2);
+}
+
+#[test]
+fn fork_at_start() {
+    let history = history();
+    let (service, added) = fork_service(&history, vec![history.simulation.clone()]);
+
+    let fork = service.fork(&history.simulation, 0, "fresh").unwrap();
+
+    assert_eq!(fork.balance().clear_value(), 10000);
+    assert_eq!(fork.round(), 0);
+    let added = added.lock().unwrap();
+    assert!(added.games.is_empty());
+    assert!(added.bets.is_empty());
+    assert!(added.deposits.is_empty());
+}
+
+#[test]
+fn fork_future_round() {
+    let history = history();
+    let (service, added) = fork_service(&history, vec![history.simulation.clone()]);
+
+    let res = service.fork(&history.simulation, 3, "future");
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Validation, "invalid_fork_round")
+    );
+    assert!(added.lock().unwrap().simulations.is_empty());
+}
+
+#[test]
+fn fork_name_taken() {
+    let history = history();
+    let (service, added) = fork_service(&history, vec![history.simulation.clone()]);
+
+    let res = service.fork(&history.simulation, 1, "main");
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::Conflict, "simulation_name_taken")
+    );
+    assert!(added.lock().unwrap().simulations.is_empty());
+}
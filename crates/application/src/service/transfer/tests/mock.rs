@@ -0,0 +1,481 @@
+use std::cmp::Ordering;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use super::super::*;
+use crate::config::{CoefficientConfig, MarginMethod, SetupConfig, ShadingConfig};
+use crate::error::{ErrorKind, classify};
+use crate::repository::{
+    FakeUnitOfWork, MockIBetRepo, MockIDepositRepo, MockIGameRepo, MockIGameStatRepo,
+    MockISimulationRepo, MockITeamRepo,
+};
+use domain::value_object::{Event, EventTotal, Margin, Winner};
+
+pub(super) type Service = TransferService<
+    MockITeamRepo,
+    MockISimulationRepo,
+    MockIGameRepo,
+    MockIGameStatRepo,
+    MockIBetRepo,
+    MockIDepositRepo,
+    FakeUnitOfWork,
+>;
+
+pub(super) const SPARTAK: &str = "123e4567-e89b-12d3-a456-426614174000";
+pub(super) const ZENIT: &str = "123e4567-e89b-12d3-a456-426614174001";
+
+pub(super) fn config() -> ConfigSnapshot {
+    ConfigSnapshot {
+        setup: SetupConfig {
+            balance: Amount::new(10000, None).unwrap(),
+        },
+        coefficient: CoefficientConfig {
+            tracked_games: 5,
+            margin: Margin::try_from(0.12).unwrap(),
+            margin_method: MarginMethod::Proportional,
+            alpha: 15,
+            totals: vec![2, 3],
+            deviation_min: 0.8,
+            deviation_max: 1.2,
+            shading: ShadingConfig::default(),
+        },
+    }
+}
+
+pub(super) fn team_repo() -> MockITeamRepo {
+    let teams = [(SPARTAK, "Спартак"), (ZENIT, "Зенит")]
+        .map(|(id, name)| Team::new(Uuid::parse_str(id).unwrap().into(), name.into()));
+    let mut team_repo = MockITeamRepo::new();
+    let ids = teams.iter().map(|t| t.id()).collect::<Vec<_>>();
+    team_repo
+        .expect_all_teams_id()
+        .returning(move || ids.clone());
+    team_repo.expect_team_by_id().returning(move |id| {
+        teams
+            .iter()
+            .find(|t| t.id() == id)
+            .cloned()
+            .ok_or_else(|| AppError::NotFound(Entity::Team).into())
+    });
+
+    team_repo
+}
+
+fn document() -> SimulationDocument {
+    let game_id = Uuid::now_v7();
+    SimulationDocument {
+        version: DOCUMENT_VERSION,
+        name: "shared".to_string(),
+        round: 1,
+        balance: 9000,
+        config: config(),
+        teams: vec![
+            TeamRecord {
+                id: Uuid::parse_str(SPARTAK).unwrap(),
+                name: "Спартак".to_string(),
+            },
+            TeamRecord {
+                id: Uuid::parse_str(ZENIT).unwrap(),
+                name: "Зенит".to_string(),
+            },
+        ],
+        games: vec![GameRecord {
+            id: game_id,
+            home_team_id: Uuid::parse_str(SPARTAK).unwrap(),
+            guest_team_id: Uuid::parse_str(ZENIT).unwrap(),
+            round: 1,
+            score: Some((2, 1)),
+        }],
+        bets: vec![BetRecord {
+            game_id,
+            amount: 1000,
+            coefficient: 189,
+            event: Event::T(EventTotal {
+                total: 3,
+                ordering: Ordering::Greater,
+            }),
+            is_won: Some(false),
+        }],
+        deposits: vec![DepositRecord {
+            amount: 5000,
+            round: 1,
+            deposited_at: 1_700_000_000,
+        }],
+    }
+}
+
+struct Added {
+    simulations: Vec<Simulation>,
+    games: Vec<Game>,
+    game_stats: Vec<GameStat>,
+    bets: Vec<Bet>,
+    deposits: Vec<Deposit>,
+}
+
+fn import_service(existing: Vec<Simulation>) -> (Service, Arc<Mutex<Added>>) {
+    let added = Arc::new(Mutex::new(Added {
+        simulations: vec![],
+        games: vec![],
+        game_stats: vec![],
+        bets: vec![],
+        deposits: vec![],
+    }));
+    let mut sim_repo = MockISimulationRepo::new();
+    sim_repo
+        .expect_simulations_by_user_id()
+        .returning(move |_| Ok(existing.clone()));
+    sim_repo
+        .expect_next_id()
+        .returning(|| <Id<Simulation>>::from(Uuid::now_v7()));
+    let rec = added.clone();
+    sim_repo.expect_add().returning(move |s| {
+        rec.lock().unwrap().simulations.push(s);
+        Ok(())
+    });
+    let mut game_repo = MockIGameRepo::new();
+    game_repo
+        .expect_next_id()
+        .returning(|| <Id<Game>>::from(Uuid::now_v7()));
+    let rec = added.clone();
+    game_repo.expect_add().returning(move |g| {
+        rec.lock().unwrap().games.push(g);
+        Ok(())
+    });
+    let mut gs_repo = MockIGameStatRepo::new();
+    gs_repo
+        .expect_next_id()
+        .returning(|| <Id<GameStat>>::from(Uuid::now_v7()));
+    let rec = added.clone();
+    gs_repo.expect_add().returning(move |gs| {
+        rec.lock().unwrap().game_stats.push(gs);
+        Ok(())
+    });
+    let mut bet_repo = MockIBetRepo::new();
+    bet_repo
+        .expect_next_id()
+        .returning(|| <Id<Bet>>::from(Uuid::now_v7()));
+    let rec = added.clone();
+    bet_repo.expect_add().returning(move |b| {
+        rec.lock().unwrap().bets.push(b);
+        Ok(())
+    });
+    let mut deposit_repo = MockIDepositRepo::new();
+    deposit_repo
+        .expect_next_id()
+        .returning(|| <Id<Deposit>>::from(Uuid::now_v7()));
+    let rec = added.clone();
+    deposit_repo.expect_add().returning(move |d| {
+        rec.lock().unwrap().deposits.push(d);
+        Ok(())
+    });
+    let service = TransferService::new(
+        team_repo(),
+        sim_repo,
+        game_repo,
+        gs_repo,
+        bet_repo,
+        deposit_repo,
+        FakeUnitOfWork,
+        config(),
+    );
+
+    (service, added)
+}
+
+#[test]
+fn export_success() {
+    let simulation = Simulation::new(
+        Uuid::now_v7().into(),
+        Uuid::now_v7().into(),
+        "exported".to_string(),
+        Amount::new(8000, None).unwrap(),
+        Some(1),
+    );
+    let game = Game::new(
+        Uuid::now_v7().into(),
+        simulation.id(),
+        Uuid::parse_str(SPARTAK).unwrap().into(),
+        Uuid::parse_str(ZENIT).unwrap().into(),
+        1,
+    );
+    let simulation_id = simulation.id();
+    let mut game_repo = MockIGameRepo::new();
+    game_repo
+        .expect_games_id_by_round()
+        .returning(move |_, _| Ok(vec![game.id()]));
+    game_repo.expect_game_by_id().returning(move |_| Ok(game));
+    let mut gs_repo = MockIGameStatRepo::new();
+    gs_repo
+        .expect_game_stat_by_game_id()
+        .returning(|game_id| Ok(GameStat::new(Uuid::now_v7().into(), game_id, 0, 3)));
+    let mut bet_repo = MockIBetRepo::new();
+    bet_repo.expect_bets_by_game_id().returning(move |game_id| {
+        Ok(vec![Bet::new(
+            Uuid::now_v7().into(),
+            simulation_id,
+            Amount::new(2000, None).unwrap(),
+            250.try_into().unwrap(),
+            game_id,
+            Event::WDL(Winner::W2),
+            Some(true),
+        )])
+    });
+    let mut deposit_repo = MockIDepositRepo::new();
+    deposit_repo
+        .expect_deposits_by_simulation_id()
+        .returning(move |_| {
+            Ok(vec![Deposit::new(
+                Uuid::now_v7().into(),
+                simulation_id,
+                Amount::new(3000, None).unwrap(),
+                1,
+                1_700_000_000,
+            )])
+        });
+    let ts = TransferService::new(
+        team_repo(),
+        MockISimulationRepo::new(),
+        game_repo,
+        gs_repo,
+        bet_repo,
+        deposit_repo,
+        FakeUnitOfWork,
+        config(),
+    );
+
+    let res = ts.export(&simulation).unwrap();
+
+    assert_eq!(res.version, DOCUMENT_VERSION);
+    assert_eq!(res.name, "exported");
+    assert_eq!(res.balance, 8000);
+    assert_eq!(res.teams.len(), 2);
+    assert_eq!(res.games.len(), 1);
+    assert_eq!(res.games[0].score, Some((0, 3)));
+    assert_eq!(res.bets.len(), 1);
+    assert_eq!(res.bets[0].game_id, game.id().value());
+    assert_eq!(res.bets[0].event, Event::WDL(Winner::W2));
+    assert_eq!(res.deposits.len(), 1);
+    assert_eq!(res.deposits[0].amount, 3000);
+    assert_eq!(res.deposits[0].deposited_at, 1_700_000_000);
+}
+
+#[test]
+fn import_success() {
+    let (ts, added) = import_service(vec![]);
+    let user_id = Uuid::now_v7().into();
+    let document = document();
+    let exported_game = document.games[0].id;
+
+    let res = ts.import(user_id, document, None).unwrap();
+
+    assert_eq!(res.name(), "shared");
+    assert!(res.user_id() == user_id);
+    assert_eq!(res.round(), 1);
+    assert_eq!(res.balance().clear_value(), 9000);
+    let added = added.lock().unwrap();
+    assert_eq!(added.simulations.len(), 1);
+    assert_eq!(added.games.len(), 1);
+    let game = added.games[0];
+    assert!(game.id().value() != exported_game);
+    assert!(game.simulation_id() == res.id());
+    assert_eq!(added.game_stats.len(), 1);
+    assert!(added.game_stats[0].game_id() == game.id());
+    assert_eq!(added.bets.len(), 1);
+    assert!(added.bets[0].game_id() == game.id());
+    assert_eq!(added.bets[0].is_won(), Some(false));
+    assert_eq!(added.deposits.len(), 1);
+    assert!(added.deposits[0].simulation_id() == res.id());
+    assert_eq!(added.deposits[0].amount().clear_value(), 5000);
+}
+
+#[test]
+fn import_with_name() {
+    let user_id = Uuid::now_v7().into();
+    let existing = Simulation::new(
+        Uuid::now_v7().into(),
+        user_id,
+        "shared".to_string(),
+        Amount::new(1000, None).unwrap(),
+        None,
+    );
+    let (ts, _) = import_service(vec![existing]);
+
+    let taken = ts.import(user_id, document(), None);
+    let renamed = ts.import(user_id, document(), Some("bug report".to_string()));
+
+    assert_eq!(
+        classify(&taken.err().unwrap()),
+        (ErrorKind::Conflict, "simulation_name_taken")
+    );
+    assert_eq!(renamed.unwrap().name(), "bug report");
+}
+
+#[test]
+fn import_matches_team_by_name() {
+    let (ts, added) = import_service(vec![]);
+    let mut document = document();
+    let foreign = Uuid::now_v7();
+    document.teams[0].id = foreign;
+    document.games[0].home_team_id = foreign;
+
+    let res = ts.import(Uuid::now_v7().into(), document, None);
+
+    assert!(res.is_ok());
+    let home_team_id = added.lock().unwrap().games[0].home_team_id();
+    assert_eq!(home_team_id.value(), Uuid::parse_str(SPARTAK).unwrap());
+}
+
+#[test]
+fn import_unknown_team() {
+    let (ts, added) = import_service(vec![]);
+    let mut document = document();
+    document.teams[0].name = "Арсенал".to_string();
+    document.teams[0].id = Uuid::now_v7();
+
+    let res = ts.import(Uuid::now_v7().into(), document, None);
+
+    assert_eq!(
+        classify(&res.err().unwrap()),
+        (ErrorKind::NotFound, "team_not_found")
+    );
+    assert!(added.lock().unwrap().simulations.is_empty());
+}
+
+#[test]
+fn import_inconsistent_document() {
+    let mut orphan_bet = document();
+    orphan_bet.bets[0].game_id = Uuid::now_v7();
+    let mut future_game = document();
+    future_game.games[0].round = 2;
+    let mut bad_coefficient = document();
+    bad_coefficient.bets[0].coefficient = 100;
+    let mut future_deposit = document();
+    future_deposit.deposits[0].round = 2;
+    let mut empty_deposit = document();
+    empty_deposit.deposits[0].amount = 0;
+
+    for document in [
+        orphan_bet,
+        future_game,
+        bad_coefficient,
+        future_deposit,
+        empty_deposit,
+    ] {
+        let (ts, added) = import_service(vec![]);
+
+        let res = ts.import(Uuid::now_v7().into(), document, None);
+
+        assert_eq!(
+            classify(&res.err().unwrap()),
+            (ErrorKind::Validation, "invalid_document")
+        );
+        assert!(added.lock().unwrap().simulations.is_empty());
+    }
+}
+
+#[test]
+fn import_stake_out_of_range() {
+    for amount in [MIN_BET_AMOUNT - 1, MAX_BET_AMOUNT + 1] {
+        let mut document = document();
+        document.bets[0].amount = amount;
+        let (ts, added) = import_service(vec![]);
+
+        let res = ts.import(Uuid::now_v7().into(), document, None);
+
+        assert_eq!(
+            classify(&res.err().unwrap()),
+            (ErrorKind::Unprocessable, "imported_stake_out_of_range")
+        );
+        assert!(added.lock().unwrap().simulations.is_empty());
+    }
+}
+
+#[test]
+fn import_bet_settled_against_score() {
+    let mut won_instead = document();
+    won_instead.bets[0].is_won = Some(true);
+    let mut unplayed = document();
+    unplayed.games[0].score = None;
+
+    for document in [won_instead, unplayed] {
+        let (ts, added) = import_service(vec![]);
+
+        let res = ts.import(Uuid::now_v7().into(), document, None);
+
+        assert_eq!(
+            classify(&res.err().unwrap()),
+            (ErrorKind::Unprocessable, "imported_bet_mismatch")
+        );
+        assert!(added.lock().unwrap().simulations.is_empty());
+    }
+}
+
+#[test]
+fn encode_decode_round_trip() {
+    for format in [DocumentFormat::Json, DocumentFormat::MessagePack] {
+        let document = document();
+
+        let bytes = document.encode(format).unwrap();
+        let res = SimulationDocument::decode(&bytes, format).unwrap();
+
+        assert_eq!(res.name, document.name);
+        assert_eq!(res.balance, document.balance);
+        assert_eq!(res.games[0].id, document.games[0].id);
+        assert_eq!(res.games[0].score, document.games[0].score);
+        assert_eq!(res.bets[0].event, document.bets[0].event);
+        assert_eq!(res.config.coefficient.totals, vec![2, 3]);
+        assert_eq!(res.deposits[0].amount, document.deposits[0].amount);
+    }
+}
+
+#[test]
+fn decode_without_deposits() {
+    let mut json = serde_json::to_value(document()).unwrap();
+    json.as_object_mut().unwrap().remove("deposits");
+    let bytes = serde_json::to_vec(&json).unwrap();
+
+    let res = SimulationDocument::decode(&bytes, DocumentFormat::Json).unwrap();
+
+    assert!(res.deposits.is_empty());
+}
+
+#[test]
+fn decode_unsupported_version() {
+    for format in [DocumentFormat::Json, DocumentFormat::MessagePack] {
+        let mut document = document();
+        document.version = DOCUMENT_VERSION + 1;
+        let bytes = document.encode(format).unwrap();
+
+        let res = SimulationDocument::decode(&bytes, format);
+
+        assert_eq!(
+            classify(&res.err().unwrap()),
+            (ErrorKind::Validation, "unsupported_document_version")
+        );
+    }
+}
+
+#[test]
+fn decode_malformed() {
+    let json = document().encode(DocumentFormat::Json).unwrap();
+
+    let wrong_format = SimulationDocument::decode(&json, DocumentFormat::MessagePack);
+    let truncated = SimulationDocument::decode(&json[..json.len() / 2], DocumentFormat::Json);
+
+    for res in [wrong_format, truncated] {
+        assert_eq!(
+            classify(&res.err().unwrap()),
+            (ErrorKind::Validation, "invalid_document")
+        );
+    }
+}
+
+#[test]
+fn format_from_str() {
+    assert_eq!("json".parse(), Ok(DocumentFormat::Json));
+    assert_eq!("msgpack".parse(), Ok(DocumentFormat::MessagePack));
+    assert_eq!(
+        "xml".parse::<DocumentFormat>(),
+        Err(AppError::UnsupportedFormat)
+    );
+}
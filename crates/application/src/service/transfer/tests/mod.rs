@@ -0,0 +1,2 @@
+mod mock;
+mod fork;
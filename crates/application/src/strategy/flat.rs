@@ -0,0 +1,29 @@
+use super::{Budget, Offer, Stake, Strategy, favourites};
+use domain::value_object::{Amount, StrategyKind};
+
+/// Same stake on the favourite of every game.
+pub struct Flat {
+    stake: i64,
+}
+
+impl Flat {
+    /// `stake` is in cents.
+    pub fn new(stake: i64) -> Self {
+        Self { stake }
+    }
+}
+
+impl Strategy for Flat {
+    fn kind(&self) -> StrategyKind {
+        StrategyKind::Flat
+    }
+
+    fn decide(&mut self, offers: &[Offer], balance: Amount) -> Vec<Stake> {
+        let mut budget = Budget::new(balance);
+
+        favourites(offers)
+            .into_iter()
+            .filter_map(|offer| budget.take(offer, self.stake))
+            .collect()
+    }
+}
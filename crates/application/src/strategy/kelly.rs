@@ -0,0 +1,46 @@
+use super::{Budget, Offer, Stake, Strategy, edge};
+use domain::value_object::{Amount, StrategyKind};
+
+/// Kelly-sized bet on the best positive-edge offer of every game, the edge measured against the
+/// engine's probability of the event. Offers priced straight from that probability carry the
+/// whole margin, so the strategy only bets where the price has drifted past it.
+pub struct Kelly {
+    fraction: f64,
+}
+
+impl Kelly {
+    /// `fraction` of the full Kelly stake is bet, 0.5 being the usual "half Kelly".
+    pub fn new(fraction: f64) -> Self {
+        Self { fraction }
+    }
+}
+
+impl Strategy for Kelly {
+    fn kind(&self) -> StrategyKind {
+        StrategyKind::Kelly
+    }
+
+    fn decide(&mut self, offers: &[Offer], balance: Amount) -> Vec<Stake> {
+        let mut best: Vec<(Offer, f64)> = vec![];
+        for &offer in offers {
+            let odds = f64::from(offer.coefficient) - 1.;
+            let share = edge(offer.probability, offer.coefficient) / odds * self.fraction;
+            if share <= 0. {
+                continue;
+            }
+            match best.iter_mut().find(|(o, _)| o.game_id == offer.game_id) {
+                Some(pick) if share > pick.1 => *pick = (offer, share),
+                Some(_) => {}
+                None => best.push((offer, share)),
+            }
+        }
+
+        let mut budget = Budget::new(balance);
+        best.into_iter()
+            .filter_map(|(offer, share)| {
+                let value = (balance.clear_value() as f64 * share) as i64;
+                budget.take(offer, value)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,47 @@
+use super::{Budget, Offer, Stake, Strategy, favourites};
+use domain::value_object::{Amount, StrategyKind};
+
+/// Single bet on the round's safest favourite, doubling the stake after every losing round
+/// and going back to the base stake after a win.
+pub struct Martingale {
+    base_stake: i64,
+    stake: i64,
+}
+
+impl Martingale {
+    /// `base_stake` is in cents.
+    pub fn new(base_stake: i64) -> Self {
+        Self {
+            base_stake,
+            stake: base_stake,
+        }
+    }
+}
+
+impl Strategy for Martingale {
+    fn kind(&self) -> StrategyKind {
+        StrategyKind::Martingale
+    }
+
+    fn decide(&mut self, offers: &[Offer], balance: Amount) -> Vec<Stake> {
+        let mut budget = Budget::new(balance);
+
+        favourites(offers)
+            .into_iter()
+            .min_by_key(|o| o.coefficient.clear_value())
+            .and_then(|offer| budget.take(offer, self.stake))
+            .into_iter()
+            .collect()
+    }
+
+    fn settle(&mut self, staked: Amount, returned: Amount) {
+        if staked.clear_value() == 0 {
+            return;
+        }
+        self.stake = if returned.clear_value() > 0 {
+            self.base_stake
+        } else {
+            self.stake.saturating_mul(2)
+        };
+    }
+}
@@ -0,0 +1,102 @@
+use crate::config::AutoplayConfig;
+use domain::{
+    entity::Game,
+    value_object::{Amount, Coefficient, Event, Id, MAX_BET_AMOUNT, MIN_BET_AMOUNT, StrategyKind},
+};
+
+mod flat;
+mod kelly;
+mod martingale;
+mod value;
+
+pub use flat::Flat;
+pub use kelly::Kelly;
+pub use martingale::Martingale;
+pub use value::Value;
+
+/// Price offered on an event of one of the round's games.
+#[derive(Clone, Copy)]
+pub struct Offer {
+    pub game_id: Id<Game>,
+    pub event: Event,
+    pub coefficient: Coefficient,
+    /// Probability the offer is priced from before the margin is applied.
+    pub probability: f64,
+}
+
+/// Bet a strategy decided to place.
+#[derive(Clone, Copy)]
+pub struct Stake {
+    pub offer: Offer,
+    pub amount: Amount,
+}
+
+/// Betting bot deciding on its own which of the round's offers to back and with how much.
+pub trait Strategy {
+    fn kind(&self) -> StrategyKind;
+
+    /// Bets for the round, their total never exceeds `balance`.
+    fn decide(&mut self, offers: &[Offer], balance: Amount) -> Vec<Stake>;
+
+    /// Reports what the bets of the last round brought: `returned` holds payouts with stakes.
+    fn settle(&mut self, _staked: Amount, _returned: Amount) {}
+}
+
+/// Built-in strategy of `kind` with the configured stakes.
+pub fn strategy(kind: StrategyKind, config: &AutoplayConfig) -> Box<dyn Strategy + Send> {
+    match kind {
+        StrategyKind::Flat => Box::new(Flat::new(config.flat_stake)),
+        StrategyKind::Martingale => Box::new(Martingale::new(config.martingale_stake)),
+        StrategyKind::Kelly => Box::new(Kelly::new(config.kelly_fraction)),
+        StrategyKind::Value => Box::new(Value::new(config.value_stake, config.value_min_edge)),
+    }
+}
+
+/// Money left for betting in the round, handing out stakes within the bet bounds.
+struct Budget(i64);
+
+impl Budget {
+    fn new(balance: Amount) -> Self {
+        Self(balance.clear_value())
+    }
+
+    /// `value` cut down to what is left, `None` if that is below the minimal bet.
+    fn take(&mut self, offer: Offer, value: i64) -> Option<Stake> {
+        let value = value.min(self.0).min(MAX_BET_AMOUNT);
+        if value < MIN_BET_AMOUNT {
+            return None;
+        }
+        self.0 -= value;
+
+        Some(Stake {
+            offer,
+            amount: Amount::new(value, None).ok()?,
+        })
+    }
+}
+
+/// Lowest priced 1X2 outcome of every game, in the order the games were offered.
+fn favourites(offers: &[Offer]) -> Vec<Offer> {
+    let mut favourites: Vec<Offer> = vec![];
+    for &offer in offers.iter().filter(|o| matches!(o.event, Event::WDL(_))) {
+        match favourites.iter_mut().find(|f| f.game_id == offer.game_id) {
+            Some(favourite)
+                if offer.coefficient.clear_value() < favourite.coefficient.clear_value() =>
+            {
+                *favourite = offer
+            }
+            Some(_) => {}
+            None => favourites.push(offer),
+        }
+    }
+
+    favourites
+}
+
+/// Expected return over the stake of backing an event with `probability` at `coefficient`.
+fn edge(probability: f64, coefficient: Coefficient) -> f64 {
+    probability * f64::from(coefficient) - 1.
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,138 @@
+use super::super::*;
+use super::market::{game, offer};
+use domain::value_object::Winner;
+
+fn amount(value: i64) -> Amount {
+    Amount::new(value, None).unwrap()
+}
+
+fn total(stakes: &[Stake]) -> i64 {
+    stakes.iter().map(|s| s.amount.clear_value()).sum()
+}
+
+fn round() -> Vec<Offer> {
+    [
+        game([0.5, 0.3, 0.2, 0.3, 0.3, 0.4], 0.05),
+        game([0.2, 0.3, 0.5, 0.6, 0.2, 0.2], 0.05),
+        game([0.6, 0.25, 0.15, 0.3, 0.3, 0.4], 0.05),
+    ]
+    .concat()
+}
+
+/// Round whose first game offers the home win above what the engine's 50% is worth, its 1X2
+/// still adding up to more than 100%.
+fn round_with_edge() -> Vec<Offer> {
+    let mut offers = round();
+    offers[0] = offer(offers[0].game_id, Event::WDL(Winner::W1), 2.1, 0.5);
+    let overround = offers[..3]
+        .iter()
+        .map(|o| 1. / f64::from(o.coefficient))
+        .sum::<f64>();
+    assert!(overround > 1.);
+    offers
+}
+
+#[test]
+fn flat_backs_every_favourite() {
+    let offers = round();
+
+    let stakes = Flat::new(20_00).decide(&offers, amount(100_000));
+
+    assert_eq!(stakes.len(), 3);
+    assert!(stakes.iter().all(|s| s.amount.clear_value() == 20_00));
+    assert_eq!(stakes[0].offer.event, Event::WDL(Winner::W1));
+    assert_eq!(stakes[1].offer.event, Event::WDL(Winner::W2));
+}
+
+#[test]
+fn flat_stays_within_balance() {
+    let stakes = Flat::new(20_00).decide(&round(), amount(45_00));
+
+    assert_eq!(stakes.len(), 2);
+    assert_eq!(total(&stakes), 40_00);
+}
+
+#[test]
+fn martingale_doubles_after_loss_and_resets_after_win() {
+    let offers = round();
+    let mut bot = Martingale::new(10_00);
+    let balance = amount(100_000);
+
+    let stakes = bot.decide(&offers, balance);
+    assert_eq!(stakes.len(), 1);
+    assert_eq!(stakes[0].amount.clear_value(), 10_00);
+    assert!(stakes[0].offer.game_id == offers[12].game_id);
+
+    bot.settle(amount(10_00), amount(0));
+    assert_eq!(bot.decide(&offers, balance)[0].amount.clear_value(), 20_00);
+    bot.settle(amount(20_00), amount(0));
+    assert_eq!(bot.decide(&offers, balance)[0].amount.clear_value(), 40_00);
+
+    bot.settle(amount(40_00), amount(66_00));
+    assert_eq!(bot.decide(&offers, balance)[0].amount.clear_value(), 10_00);
+}
+
+#[test]
+fn martingale_keeps_stake_over_skipped_round() {
+    let mut bot = Martingale::new(10_00);
+    bot.settle(amount(10_00), amount(0));
+
+    bot.settle(amount(0), amount(0));
+
+    assert_eq!(
+        bot.decide(&round(), amount(100_000))[0]
+            .amount
+            .clear_value(),
+        20_00
+    );
+}
+
+#[test]
+fn kelly_and_value_sit_out_even_margin() {
+    let offers = round();
+
+    assert!(Kelly::new(0.5).decide(&offers, amount(100_000)).is_empty());
+    assert!(
+        Value::new(20_00, 0.)
+            .decide(&offers, amount(100_000))
+            .is_empty()
+    );
+}
+
+#[test]
+fn kelly_sizes_stake_by_edge() {
+    let offers = round_with_edge();
+    let share = edge(0.5, offers[0].coefficient) / 1.1 * 0.5;
+
+    let stakes = Kelly::new(0.5).decide(&offers, amount(100_000));
+
+    assert_eq!(stakes.len(), 1);
+    assert_eq!(stakes[0].offer.event, Event::WDL(Winner::W1));
+    assert_eq!(stakes[0].amount.clear_value(), (100_000. * share) as i64);
+}
+
+#[test]
+fn value_backs_offers_above_min_edge() {
+    let offers = round_with_edge();
+
+    let stakes = Value::new(20_00, 0.).decide(&offers, amount(100_000));
+    assert_eq!(stakes.len(), 1);
+    assert_eq!(stakes[0].offer.event, Event::WDL(Winner::W1));
+    assert!(stakes[0].offer.game_id == offers[0].game_id);
+    assert_eq!(stakes[0].amount.clear_value(), 20_00);
+
+    assert!(
+        Value::new(20_00, 0.1)
+            .decide(&offers, amount(100_000))
+            .is_empty()
+    );
+}
+
+#[test]
+fn strategy_follows_kind() {
+    let config = AutoplayConfig::default();
+
+    for kind in StrategyKind::ALL {
+        assert_eq!(strategy(kind, &config).kind(), kind);
+    }
+}
@@ -0,0 +1,61 @@
+use std::cmp::Ordering;
+
+use super::super::*;
+use domain::value_object::{EventTotal, Winner};
+
+pub(super) fn offer(game_id: Id<Game>, event: Event, coefficient: f64, probability: f64) -> Offer {
+    Offer {
+        game_id,
+        event,
+        coefficient: coefficient.try_into().unwrap(),
+        probability,
+    }
+}
+
+/// 1X2 and a total line of one game priced from the engine's `probabilities` with an even
+/// `margin`.
+pub(super) fn game(probabilities: [f64; 6], margin: f64) -> Vec<Offer> {
+    let game_id = Id::new();
+    let events = [
+        Event::WDL(Winner::W1),
+        Event::WDL(Winner::X),
+        Event::WDL(Winner::W2),
+        total(Ordering::Greater),
+        total(Ordering::Equal),
+        total(Ordering::Less),
+    ];
+
+    events
+        .into_iter()
+        .zip(probabilities)
+        .map(|(event, p)| offer(game_id, event, (1. - margin) / p, p))
+        .collect()
+}
+
+fn total(ordering: Ordering) -> Event {
+    Event::T(EventTotal { total: 2, ordering })
+}
+
+#[test]
+fn favourite_is_lowest_priced_outcome() {
+    let home = game([0.5, 0.3, 0.2, 0.3, 0.3, 0.4], 0.05);
+    let away = game([0.2, 0.3, 0.5, 0.6, 0.2, 0.2], 0.05);
+    let offers = [home.clone(), away.clone()].concat();
+
+    let favourites = favourites(&offers);
+
+    assert_eq!(favourites.len(), 2);
+    assert_eq!(favourites[0].event, Event::WDL(Winner::W1));
+    assert!(favourites[0].game_id == home[0].game_id);
+    assert_eq!(favourites[1].event, Event::WDL(Winner::W2));
+    assert!(favourites[1].game_id == away[0].game_id);
+}
+
+#[test]
+fn margin_leaves_no_edge_at_model_price() {
+    let offers = game([0.5, 0.3, 0.2, 0.25, 0.25, 0.5], 0.05);
+
+    for offer in offers {
+        assert!(edge(offer.probability, offer.coefficient) < 0.);
+    }
+}
@@ -0,0 +1,2 @@
+mod bots;
+mod market;
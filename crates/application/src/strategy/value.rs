@@ -0,0 +1,32 @@
+use super::{Budget, Offer, Stake, Strategy, edge};
+use domain::value_object::{Amount, StrategyKind};
+
+/// Same stake on every offer whose edge, against the engine's probability of the event, reaches
+/// the configured minimum.
+pub struct Value {
+    stake: i64,
+    min_edge: f64,
+}
+
+impl Value {
+    /// `stake` is in cents.
+    pub fn new(stake: i64, min_edge: f64) -> Self {
+        Self { stake, min_edge }
+    }
+}
+
+impl Strategy for Value {
+    fn kind(&self) -> StrategyKind {
+        StrategyKind::Value
+    }
+
+    fn decide(&mut self, offers: &[Offer], balance: Amount) -> Vec<Stake> {
+        let mut budget = Budget::new(balance);
+
+        offers
+            .iter()
+            .filter(|o| edge(o.probability, o.coefficient) >= self.min_edge)
+            .filter_map(|&offer| budget.take(offer, self.stake))
+            .collect()
+    }
+}
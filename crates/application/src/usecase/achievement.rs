@@ -0,0 +1,17 @@
+use anyhow::Result;
+
+use domain::{
+    entity::{Achievement, Simulation},
+    value_object::Id,
+};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait TrackAchievements {
+    /// Checks every rule against the current state of `simulation_id` and persists the ones
+    /// unlocked for the first time, which are returned.
+    fn evaluate(&self, simulation_id: Id<Simulation>) -> Result<Vec<Achievement>>;
+
+    fn achievements(&self, simulation_id: Id<Simulation>) -> Result<Vec<Achievement>>;
+}
@@ -0,0 +1,16 @@
+use anyhow::Result;
+
+use domain::entity::{Session, User};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait Authenticate {
+    fn register(&self, login: &str, password: &str) -> Result<User>;
+
+    fn login(&self, login: &str, password: &str) -> Result<Session>;
+
+    fn logout(&self, token: &str) -> Result<()>;
+
+    fn authenticate(&self, token: &str) -> Result<User>;
+}
@@ -0,0 +1,26 @@
+use anyhow::Result;
+
+use crate::service::StrategySummary;
+use domain::{
+    entity::{AutoplayRun, Simulation},
+    value_object::{Id, StrategyKind},
+};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait Autoplay {
+    /// Lets `strategy` create, bet on and play up to `rounds` rounds, stopping early once the
+    /// balance can't cover the minimal bet. The run is recorded and returned.
+    fn autoplay(
+        &self,
+        simulation_id: Id<Simulation>,
+        strategy: StrategyKind,
+        rounds: u32,
+    ) -> Result<AutoplayRun>;
+
+    fn runs(&self, simulation_id: Id<Simulation>) -> Result<Vec<AutoplayRun>>;
+
+    /// Results of all the runs of the simulation added up per strategy.
+    fn summaries(&self, simulation_id: Id<Simulation>) -> Result<Vec<StrategySummary>>;
+}
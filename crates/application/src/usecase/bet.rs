@@ -1,35 +1,79 @@
 use anyhow::Result;
 
+use crate::service::{ClosingLine, OddsHistory, StakeAdvice};
 use domain::entity::{Bet, Game, Simulation};
-use domain::value_object::{Amount, BetStatistics, Coefficient, Event};
+use domain::value_object::{Amount, BetStatistics, Coefficient, Event, Id, LimitWarning};
 #[cfg(test)]
 use mockall::automock;
 
 #[cfg_attr(test, automock)]
 pub trait MakeBet {
+    /// Warns about the limits of the simulation the bet brings close.
     fn make_bet(
         &self,
-        game: &Game,
+        simulation_id: Id<Simulation>,
+        game_id: Id<Game>,
         amount: Amount,
         event: Event,
         coefficient: Coefficient,
-    ) -> Result<()>;
+    ) -> Result<Vec<LimitWarning>>;
 
-    fn calculate_coefficients(&self, game: &Game) -> Result<Vec<(Event, Coefficient)>>;
+    /// Offers on a game of the current round of `simulation`, any other game not being found.
+    fn calculate_coefficients(
+        &self,
+        simulation: &Simulation,
+        game_id: Id<Game>,
+    ) -> Result<Vec<(Event, Coefficient)>>;
 
     fn calculate_winner_coefficients(&self, game: &Game) -> Result<Vec<(Event, Coefficient)>>;
 
     fn calculate_total_coefficients(&self, game: &Game) -> Result<Vec<(Event, Coefficient)>>;
+
+    /// Largest stake the book still takes on each of `offers` of the game, found the way
+    /// `calculate_coefficients` finds it.
+    fn max_stakes(
+        &self,
+        simulation: &Simulation,
+        game_id: Id<Game>,
+        offers: &[(Event, Coefficient)],
+    ) -> Result<Vec<Amount>>;
 }
 
 #[cfg_attr(test, automock)]
 pub trait CalculateBet {
-    fn calculate_bets(&self) -> Result<Amount>;
+    fn calculate_bets(&self, simulation_id: Id<Simulation>) -> Result<Amount>;
 
     fn calculate_bet(&self, bet: Bet, simulation: &mut Simulation) -> Result<Amount>;
 }
 
 #[cfg_attr(test, automock)]
 pub trait MakeReport {
-    fn make_report(&self, start_balance: Amount) -> BetStatistics;
+    fn make_report(
+        &self,
+        simulation_id: Id<Simulation>,
+        start_balance: Amount,
+    ) -> Result<BetStatistics>;
+
+    /// Every bet of the simulation, oldest first.
+    fn bets(&self, simulation_id: Id<Simulation>) -> Result<Vec<Bet>>;
+
+    /// Every bet of the simulation with the closing price of its event, oldest first.
+    fn closing_lines(&self, simulation_id: Id<Simulation>) -> Result<Vec<ClosingLine>>;
+}
+
+#[cfg_attr(test, automock)]
+pub trait AdviseStakes {
+    /// Every offer on a game of the current round of `simulation` set against the engine's
+    /// probability of it, Kelly stakes sized for the balance of `simulation`.
+    fn advise(&self, simulation: &Simulation, game_id: Id<Game>) -> Result<Vec<StakeAdvice>>;
+}
+
+#[cfg_attr(test, automock)]
+pub trait ShowOddsHistory {
+    /// Prices offered on each event of a game of the simulation, in the order they are offered.
+    fn odds_history(
+        &self,
+        simulation_id: Id<Simulation>,
+        game_id: Id<Game>,
+    ) -> Result<Vec<OddsHistory>>;
 }
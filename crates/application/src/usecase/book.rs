@@ -0,0 +1,12 @@
+use anyhow::Result;
+
+use crate::service::BookReport;
+use domain::entity::User;
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait ShowBookReport {
+    /// House-side results of every simulation, refused unless `requester` is an admin.
+    fn book_report(&self, requester: &User) -> Result<BookReport>;
+}
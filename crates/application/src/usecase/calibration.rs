@@ -0,0 +1,13 @@
+use anyhow::Result;
+
+use crate::service::CalibrationReport;
+use domain::entity::User;
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait ShowCalibration {
+    /// Pre-game forecasts of every played game against the results, refused unless
+    /// `requester` is an admin.
+    fn calibration(&self, requester: &User) -> Result<CalibrationReport>;
+}
@@ -0,0 +1,20 @@
+use anyhow::Result;
+
+use crate::service::DepositSummary;
+use domain::{
+    entity::{Deposit, Simulation},
+    value_object::{Amount, Id},
+};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait ManageDeposits {
+    /// Tops the balance up and records the deposit.
+    fn deposit(&self, simulation_id: Id<Simulation>, amount: Amount) -> Result<Deposit>;
+
+    fn deposits(&self, simulation_id: Id<Simulation>) -> Result<Vec<Deposit>>;
+
+    /// Balance against all the money put into the simulation so far.
+    fn summary(&self, simulation_id: Id<Simulation>) -> Result<DepositSummary>;
+}
@@ -0,0 +1,10 @@
+use anyhow::Result;
+
+use crate::service::{LeaderboardPage, LeaderboardQuery};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait ShowLeaderboard {
+    fn leaderboard(&self, query: &LeaderboardQuery) -> Result<LeaderboardPage>;
+}
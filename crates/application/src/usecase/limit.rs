@@ -0,0 +1,21 @@
+use anyhow::Result;
+
+use domain::{
+    entity::Simulation,
+    value_object::{BetLimits, Id},
+};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait ManageLimits {
+    /// Limits in force in the current round, with a relaxation still waiting for its round.
+    fn limits(&self, simulation_id: Id<Simulation>) -> Result<BetLimits>;
+
+    /// Replaces the stake, loss and deposit limits, a running cool-off is kept as it is.
+    /// Tightened limits apply at once, relaxed ones only after the configured delay.
+    fn set_limits(&self, simulation_id: Id<Simulation>, limits: BetLimits) -> Result<BetLimits>;
+
+    /// Blocks betting for `secs` seconds; a cool-off can be extended but never shortened.
+    fn cool_off(&self, simulation_id: Id<Simulation>, secs: i64) -> Result<BetLimits>;
+}
@@ -1,10 +1,39 @@
+mod achievement;
+mod auth;
+mod autoplay;
 mod bet;
+mod book;
+mod calibration;
+mod deposit;
 mod game;
+mod leaderboard;
+mod limit;
+mod preference;
 mod simulation;
+mod transfer;
 
+pub use achievement::TrackAchievements;
+pub use auth::Authenticate;
+pub use autoplay::Autoplay;
+pub use bet::AdviseStakes;
 pub use bet::CalculateBet;
 pub use bet::MakeBet;
 pub use bet::MakeReport;
+pub use bet::ShowOddsHistory;
+#[cfg(test)]
+pub use bet::{MockAdviseStakes, MockCalculateBet, MockMakeBet};
+pub use book::ShowBookReport;
+pub use calibration::ShowCalibration;
+pub use deposit::ManageDeposits;
+#[cfg(test)]
+pub use game::MockRandomizeRound;
 pub use game::RandomizeRound;
+pub use leaderboard::ShowLeaderboard;
+pub use limit::ManageLimits;
+pub use preference::ManagePreferences;
 pub use simulation::CreateRound;
+pub use simulation::ManageSimulations;
+#[cfg(test)]
+pub use simulation::MockCreateRound;
 pub use simulation::Start;
+pub use transfer::Transfer;
@@ -0,0 +1,15 @@
+use anyhow::Result;
+
+use domain::{
+    entity::User,
+    value_object::{Id, OddsFormat},
+};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait ManagePreferences {
+    fn odds_format(&self, user_id: Id<User>) -> Result<OddsFormat>;
+
+    fn set_odds_format(&self, user_id: Id<User>, odds_format: OddsFormat) -> Result<User>;
+}
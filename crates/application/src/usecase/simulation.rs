@@ -1,8 +1,10 @@
 use anyhow::Result;
-use std::net::IpAddr;
 
 use crate::service::DisplayedGame;
-use domain::{entity::Simulation, value_object::Id};
+use domain::{
+    entity::{Simulation, User},
+    value_object::Id,
+};
 #[cfg(test)]
 use mockall::automock;
 
@@ -11,9 +13,35 @@ pub trait CreateRound {
     fn create_round(&self, simulation: &mut Simulation) -> Result<Vec<DisplayedGame>>;
 }
 
+#[cfg_attr(test, automock)]
+pub trait ManageSimulations {
+    fn create(&self, user_id: Id<User>, name: &str) -> Result<Simulation>;
+
+    /// Active simulations of `user_id`, archived runs are left out.
+    fn list(&self, user_id: Id<User>) -> Result<Vec<Simulation>>;
+
+    fn list_archived(&self, user_id: Id<User>) -> Result<Vec<Simulation>>;
+
+    /// Archived run `simulation_id` if it belongs to `user_id`.
+    fn archived(&self, user_id: Id<User>, simulation_id: Id<Simulation>) -> Result<Simulation>;
+
+    /// Simulation `simulation_id` if it belongs to `user_id`.
+    fn simulation(&self, user_id: Id<User>, simulation_id: Id<Simulation>) -> Result<Simulation>;
+
+    fn rename(
+        &self,
+        user_id: Id<User>,
+        simulation_id: Id<Simulation>,
+        name: &str,
+    ) -> Result<Simulation>;
+
+    fn delete(&self, user_id: Id<User>, simulation_id: Id<Simulation>) -> Result<()>;
+}
+
 #[cfg_attr(test, automock)]
 pub trait Start {
-    fn start(&self, ip: IpAddr) -> Result<Simulation>;
+    fn start(&self, user_id: Id<User>) -> Result<Simulation>;
 
+    /// Archives `simulation_id` and starts a fresh run under the same name.
     fn restart(&self, simulation_id: Id<Simulation>) -> Result<Simulation>;
 }
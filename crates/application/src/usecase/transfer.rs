@@ -0,0 +1,25 @@
+use anyhow::Result;
+
+use crate::service::SimulationDocument;
+use domain::{
+    entity::{Simulation, User},
+    value_object::Id,
+};
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub trait Transfer {
+    fn export(&self, simulation: &Simulation) -> Result<SimulationDocument>;
+
+    /// Copies `document` into a new simulation of `user_id`, named `name` or as exported.
+    fn import(
+        &self,
+        user_id: Id<User>,
+        document: SimulationDocument,
+        name: Option<String>,
+    ) -> Result<Simulation>;
+
+    /// Copies `simulation` as it was after `round` into a new active simulation called `name`.
+    fn fork(&self, simulation: &Simulation, round: u32, name: &str) -> Result<Simulation>;
+}
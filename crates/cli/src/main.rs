@@ -5,27 +5,47 @@ use dotenv::dotenv;
 use enum_try_from::impl_enum_try_from;
 use std::collections::BTreeMap;
 use std::fmt;
+use std::fs;
 use std::io;
-use std::net::{IpAddr, Ipv4Addr};
 use std::path::Path;
 use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::warn;
 use tracing::{debug, error, info};
 
-use application::config::SetupConfig;
-use application::service::{BetService, GameService, SimulationService};
-use application::usecase::{CalculateBet, CreateRound, MakeBet, MakeReport, RandomizeRound, Start};
-use db::init_pool;
-use db::repository::{BetRepo, GameRepo, GameStatRepo, SimulationRepo, TeamRepo};
+use application::config::{CoefficientConfig, ExposureConfig};
+use application::service::{
+    AchievementService, AuthService, AutoplayService, BetService, BetValidationError, BookService,
+    CalibrationService, ConfigSnapshot, DepositService, DocumentFormat, GameService,
+    LeaderboardQuery, LeaderboardService, LiabilityScope, LimitService, PreferenceService,
+    RankingMetric, SimulationDocument, SimulationService, TransferService,
+};
+use application::usecase::{
+    AdviseStakes, Authenticate, Autoplay, CalculateBet, CreateRound, MakeBet, MakeReport,
+    ManageDeposits, ManageLimits, ManagePreferences, ManageSimulations, RandomizeRound,
+    ShowBookReport, ShowCalibration, ShowLeaderboard, ShowOddsHistory, Start, TrackAchievements,
+    Transfer,
+};
 use domain::entity::{Game, Simulation, Team};
-use domain::value_object::{Amount, Coefficient, Event, Id, MIN_BALANCE_AMOUNT, MIN_BET_AMOUNT};
+use domain::value_object::{
+    Amount, BetLimits, BetStatistics, Coefficient, Event, Id, LimitKind, LossLimit, OddsFormat,
+    StrategyKind,
+};
+use infrastructure::storage::{
+    AchievementRepo, AutoplayRunRepo, BetLimitRepo, BetRepo, DepositRepo, ForecastRepo, GameRepo,
+    GameStatRepo, OddsSnapshotRepo, SessionRepo, SimulationRepo, Storage, TeamRepo, UnitOfWork,
+    UserRepo,
+};
 use infrastructure::{config, logger};
 
 #[derive(Parser)]
 #[command(version, about = "The best betting emulator!", long_about = None)]
 struct CliArgs {
-    #[arg(long, default_value_t = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)))]
-    pub ip: IpAddr,
+    #[arg(long)]
+    pub login: String,
+    /// Create the account before logging in
+    #[arg(long)]
+    pub register: bool,
 }
 
 impl_enum_try_from!(
@@ -40,6 +60,26 @@ impl_enum_try_from!(
         CalculateCoefficients,
         MakeReport,
         CheckBalance,
+        ListSimulations,
+        NewSimulation,
+        SwitchSimulation,
+        RenameSimulation,
+        DeleteSimulation,
+        ShowArchives,
+        ExportSimulation,
+        ImportSimulation,
+        ForkSimulation,
+        ShowLeaderboard,
+        ShowAchievements,
+        ManageLimits,
+        Deposit,
+        Autoplay,
+        AdviseStakes,
+        ShowBets,
+        ChooseOddsFormat,
+        ShowBookReport,
+        ShowCalibration,
+        ShowOddsHistory,
         Exit,
     },
     u8,
@@ -56,13 +96,15 @@ struct GameInfo {
 
 impl fmt::Display for GameInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.home_team_score.is_some() && self.guest_team_score.is_some() {
+        if let (Some(home_team_score), Some(guest_team_score)) =
+            (self.home_team_score, self.guest_team_score)
+        {
             write!(
                 f,
                 "{} {} - {} {}",
                 self.home_team.name(),
-                self.home_team_score.unwrap(),
-                self.guest_team_score.unwrap(),
+                home_team_score,
+                guest_team_score,
                 self.guest_team.name()
             )
         } else {
@@ -76,14 +118,77 @@ impl fmt::Display for GameInfo {
     }
 }
 
+type AppAutoplayService = AutoplayService<
+    SimulationService<GameRepo, TeamRepo, GameStatRepo, SimulationRepo, UnitOfWork>,
+    BetService<
+        BetRepo,
+        GameRepo,
+        GameStatRepo,
+        SimulationRepo,
+        BetLimitRepo,
+        UnitOfWork,
+        OddsSnapshotRepo,
+    >,
+    BetService<
+        BetRepo,
+        GameRepo,
+        GameStatRepo,
+        SimulationRepo,
+        BetLimitRepo,
+        UnitOfWork,
+        OddsSnapshotRepo,
+    >,
+    BetService<
+        BetRepo,
+        GameRepo,
+        GameStatRepo,
+        SimulationRepo,
+        BetLimitRepo,
+        UnitOfWork,
+        OddsSnapshotRepo,
+    >,
+    GameService<GameRepo, GameStatRepo, TeamRepo, ForecastRepo>,
+    GameRepo,
+    BetRepo,
+    SimulationRepo,
+    AutoplayRunRepo,
+>;
+
 struct App {
-    sim_service: SimulationService<GameRepo, TeamRepo, GameStatRepo, SimulationRepo>,
-    game_service: GameService<GameRepo, GameStatRepo, TeamRepo>,
-    bet_service: BetService<BetRepo, GameRepo, GameStatRepo, SimulationRepo>,
+    sim_service: SimulationService<GameRepo, TeamRepo, GameStatRepo, SimulationRepo, UnitOfWork>,
+    game_service: GameService<GameRepo, GameStatRepo, TeamRepo, ForecastRepo>,
+    bet_service: BetService<
+        BetRepo,
+        GameRepo,
+        GameStatRepo,
+        SimulationRepo,
+        BetLimitRepo,
+        UnitOfWork,
+        OddsSnapshotRepo,
+    >,
+    auth_service: AuthService<UserRepo, SessionRepo>,
+    transfer_service: TransferService<
+        TeamRepo,
+        SimulationRepo,
+        GameRepo,
+        GameStatRepo,
+        BetRepo,
+        DepositRepo,
+        UnitOfWork,
+    >,
+    leaderboard_service: LeaderboardService<SimulationRepo, UserRepo, BetRepo, DepositRepo>,
+    achievement_service: AchievementService<AchievementRepo, SimulationRepo, BetRepo>,
+    limit_service: LimitService<BetLimitRepo, SimulationRepo>,
+    deposit_service: DepositService<DepositRepo, SimulationRepo, BetLimitRepo, UnitOfWork>,
+    autoplay_service: AppAutoplayService,
+    preference_service: PreferenceService<UserRepo>,
+    book_service: BookService<SimulationRepo, UserRepo, GameRepo, BetRepo>,
+    calibration_service: CalibrationService<ForecastRepo, GameStatRepo>,
+    token: String,
+    odds_format: OddsFormat,
     simulation: Simulation,
     games: BTreeMap<Id<Game>, GameInfo>,
     game_poses: Vec<Id<Game>>,
-    setup_config: SetupConfig,
 }
 
 impl App {
@@ -98,41 +203,152 @@ impl App {
         let games = BTreeMap::new();
         let game_poses = vec![];
 
-        let pool = init_pool();
-        let game_repo = GameRepo::new(pool.clone());
-        let bet_repo = BetRepo::new(pool.clone());
-        let game_stat_repo = GameStatRepo::new(pool.clone());
-        let simulation_repo = SimulationRepo::new(pool.clone());
-        let bet_service = BetService::new(
-            bet_repo,
+        let storage = Storage::from(config.storage);
+        let bet_service = new_bet_service(&storage, coefficient_config.clone(), config.exposure);
+        debug!("Bet service started");
+
+        let game_repo = storage.game_repo();
+        let game_stat_repo = storage.game_stat_repo();
+        let team_repo = storage.team_repo();
+        let game_service = GameService::new(
             game_repo,
             game_stat_repo,
-            simulation_repo,
+            team_repo,
+            storage.forecast_repo(),
             coefficient_config.clone(),
+            config.engine,
         );
-        debug!("Bet service started");
-
-        let game_repo = GameRepo::new(pool.clone());
-        let game_stat_repo = GameStatRepo::new(pool.clone());
-        let team_repo = TeamRepo::new(pool.clone());
-        let game_service =
-            GameService::new(game_repo, game_stat_repo, team_repo, coefficient_config);
         debug!("Game service started");
 
-        let team_repo = TeamRepo::new(pool.clone());
-        let game_repo = GameRepo::new(pool.clone());
-        let simulation_repo = SimulationRepo::new(pool.clone());
-        let game_stat_repo = GameStatRepo::new(pool.clone());
+        let team_repo = storage.team_repo();
+        let game_repo = storage.game_repo();
+        let simulation_repo = storage.simulation_repo();
+        let game_stat_repo = storage.game_stat_repo();
         let sim_service = SimulationService::new(
             game_repo,
             team_repo,
             game_stat_repo,
             simulation_repo,
+            storage.unit_of_work(),
             setup_config,
         );
         debug!("Simulation service started");
 
-        let simulation = sim_service.start(cli_args.ip)?;
+        let auth_service =
+            AuthService::new(storage.user_repo(), storage.session_repo(), config.auth);
+        debug!("Auth service started");
+
+        let transfer_service = TransferService::new(
+            storage.team_repo(),
+            storage.simulation_repo(),
+            storage.game_repo(),
+            storage.game_stat_repo(),
+            storage.bet_repo(),
+            storage.deposit_repo(),
+            storage.unit_of_work(),
+            ConfigSnapshot {
+                setup: setup_config,
+                coefficient: coefficient_config.clone(),
+            },
+        );
+        debug!("Transfer service started");
+
+        let leaderboard_service = LeaderboardService::new(
+            storage.simulation_repo(),
+            storage.user_repo(),
+            storage.bet_repo(),
+            storage.deposit_repo(),
+            config.leaderboard,
+        );
+        debug!("Leaderboard service started");
+
+        let achievement_service = AchievementService::new(
+            storage.achievement_repo(),
+            storage.simulation_repo(),
+            storage.bet_repo(),
+            setup_config,
+        );
+        debug!("Achievement service started");
+
+        let limit_service = LimitService::new(
+            storage.bet_limit_repo(),
+            storage.simulation_repo(),
+            config.limit,
+        );
+        debug!("Limit service started");
+
+        let deposit_service = DepositService::new(
+            storage.deposit_repo(),
+            storage.simulation_repo(),
+            storage.bet_limit_repo(),
+            storage.unit_of_work(),
+            config.deposit,
+            setup_config,
+        );
+        debug!("Deposit service started");
+
+        let autoplay_service = AutoplayService::new(
+            SimulationService::new(
+                storage.game_repo(),
+                storage.team_repo(),
+                storage.game_stat_repo(),
+                storage.simulation_repo(),
+                storage.unit_of_work(),
+                setup_config,
+            ),
+            new_bet_service(&storage, coefficient_config.clone(), config.exposure),
+            new_bet_service(&storage, coefficient_config.clone(), config.exposure),
+            new_bet_service(&storage, coefficient_config.clone(), config.exposure),
+            GameService::new(
+                storage.game_repo(),
+                storage.game_stat_repo(),
+                storage.team_repo(),
+                storage.forecast_repo(),
+                coefficient_config.clone(),
+                config.engine,
+            ),
+            storage.game_repo(),
+            storage.bet_repo(),
+            storage.simulation_repo(),
+            storage.autoplay_run_repo(),
+            config.autoplay,
+        );
+        debug!("Autoplay service started");
+
+        let preference_service = PreferenceService::new(storage.user_repo());
+        debug!("Preference service started");
+
+        let book_service = BookService::new(
+            storage.simulation_repo(),
+            storage.user_repo(),
+            storage.game_repo(),
+            storage.bet_repo(),
+            coefficient_config.margin,
+            config.admin.clone(),
+        );
+        debug!("Book service started");
+
+        let calibration_service = CalibrationService::new(
+            storage.forecast_repo(),
+            storage.game_stat_repo(),
+            config.admin,
+        );
+        debug!("Calibration service started");
+
+        println!("Введите пароль: ");
+        let mut password = String::new();
+        io::stdin().read_line(&mut password)?;
+        let password = password.trim_end_matches(['\r', '\n']);
+        if cli_args.register {
+            auth_service.register(&cli_args.login, password)?;
+            println!("Пользователь {} зарегистрирован", cli_args.login);
+        }
+        let session = auth_service.login(&cli_args.login, password)?;
+        let token = session.token().to_string();
+        info!(login = cli_args.login, "User logged in");
+        let odds_format = preference_service.odds_format(session.user_id())?;
+
+        let simulation = sim_service.start(session.user_id())?;
         debug!("Current round: {}", simulation.round());
         info!(
             balance = f64::from(simulation.balance()),
@@ -144,9 +360,20 @@ impl App {
             game_service,
             bet_service,
             sim_service,
+            auth_service,
+            transfer_service,
+            leaderboard_service,
+            achievement_service,
+            limit_service,
+            deposit_service,
+            autoplay_service,
+            preference_service,
+            book_service,
+            calibration_service,
+            token,
+            odds_format,
             game_poses,
             games,
-            setup_config,
         })
     }
 
@@ -165,6 +392,32 @@ impl App {
         );
         println!("{}. Статистика ставок", Command::MakeReport as u8);
         println!("{}. Посмотреть баланс", Command::CheckBalance as u8);
+        println!("{}. Список симуляций", Command::ListSimulations as u8);
+        println!("{}. Новая симуляция", Command::NewSimulation as u8);
+        println!("{}. Переключить симуляцию", Command::SwitchSimulation as u8);
+        println!(
+            "{}. Переименовать симуляцию",
+            Command::RenameSimulation as u8
+        );
+        println!("{}. Удалить симуляцию", Command::DeleteSimulation as u8);
+        println!("{}. Архив симуляций", Command::ShowArchives as u8);
+        println!("{}. Экспорт симуляции", Command::ExportSimulation as u8);
+        println!("{}. Импорт симуляции", Command::ImportSimulation as u8);
+        println!("{}. Ответвить симуляцию", Command::ForkSimulation as u8);
+        println!("{}. Таблица лидеров", Command::ShowLeaderboard as u8);
+        println!("{}. Достижения", Command::ShowAchievements as u8);
+        println!("{}. Лимиты и пауза в ставках", Command::ManageLimits as u8);
+        println!("{}. Пополнить баланс", Command::Deposit as u8);
+        println!("{}. Автоигра стратегией", Command::Autoplay as u8);
+        println!("{}. Советы по ставкам (Келли)", Command::AdviseStakes as u8);
+        println!("{}. История ставок", Command::ShowBets as u8);
+        println!("{}. Формат коэффициентов", Command::ChooseOddsFormat as u8);
+        println!("{}. Отчёт букмекера", Command::ShowBookReport as u8);
+        println!("{}. Калибровка модели", Command::ShowCalibration as u8);
+        println!(
+            "{}. Движение коэффициентов матча",
+            Command::ShowOddsHistory as u8
+        );
         println!("{}. Выход", Command::Exit as u8);
         println!("--------------");
     }
@@ -177,18 +430,40 @@ impl App {
             Command::CreateRound => self.create_round(),
             Command::MakeReport => self.make_report(),
             Command::CheckBalance => self.check_balance(),
-            Command::Exit => Ok(()),
+            Command::ListSimulations => self.list_simulations().map(|_| ()),
+            Command::NewSimulation => self.new_simulation(),
+            Command::SwitchSimulation => self.switch_simulation(),
+            Command::RenameSimulation => self.rename_simulation(),
+            Command::DeleteSimulation => self.delete_simulation(),
+            Command::ShowArchives => self.show_archives(),
+            Command::ExportSimulation => self.export_simulation(),
+            Command::ImportSimulation => self.import_simulation(),
+            Command::ForkSimulation => self.fork_simulation(),
+            Command::ShowLeaderboard => self.show_leaderboard(),
+            Command::ShowAchievements => self.show_achievements(),
+            Command::ManageLimits => self.manage_limits(),
+            Command::Deposit => self.deposit(),
+            Command::Autoplay => self.autoplay(),
+            Command::AdviseStakes => self.advise_stakes(),
+            Command::ShowBets => self.show_bets(),
+            Command::ChooseOddsFormat => self.choose_odds_format(),
+            Command::ShowBookReport => self.show_book_report(),
+            Command::ShowCalibration => self.show_calibration(),
+            Command::ShowOddsHistory => self.show_odds_history(),
+            Command::Exit => self.auth_service.logout(&self.token),
             _ => bail!("Undefined command"),
         }
     }
 
     fn restart(&mut self) -> Result<()> {
         debug!("Perform restart operation");
-        self.simulation = self.sim_service.restart(self.simulation.id())?;
+        let simulation = self.sim_service.restart(self.simulation.id())?;
         info!(
-            balance = f64::from(self.simulation.balance()),
+            balance = f64::from(simulation.balance()),
             "Restart successful"
         );
+        println!("Предыдущий прогон перенесён в архив");
+        self.select_simulation(simulation);
 
         Ok(())
     }
@@ -206,13 +481,14 @@ impl App {
         for game_stat in games_stat {
             println!("{}", game_stat);
         }
-        let profit = self.bet_service.calculate_bets()?;
+        let profit = self.bet_service.calculate_bets(self.simulation.id())?;
         info!(profit = f64::from(profit), "Credit to balance");
         println!(
             "Доход по итогам ставок на матчи этого тура: {}",
             f64::from(profit)
         );
         self.simulation.process_bet(profit)?;
+        self.announce_achievements()?;
         self.create_round()?;
 
         Ok(())
@@ -220,39 +496,30 @@ impl App {
 
     fn calculate_coefficients(&mut self) -> Result<()> {
         debug!("Perform calculate coefficients operation");
-        if self.game_poses.is_empty() {
-            info!("Round didn't create");
-            println!("Сначала посмотрите матчи тура!");
+        let Some(game) = self.choose_game()? else {
             return Ok(());
-        }
+        };
         let mut buffer = String::new();
-        println!("Введите номер матча: ");
-        io::stdin().read_line(&mut buffer)?;
-        let game_pos = buffer.trim().parse::<usize>()?;
-        let game_id = self.game_poses.get(game_pos).ok_or_else(|| {
-            println!("Такого матча нет");
-            warn!("Incorrect game pos");
-            anyhow!("Incorrect game pos")
-        })?;
-        let game_info = self.games.get(&game_id).unwrap();
-        let game = Game::new(
-            *game_id,
-            self.simulation.id(),
-            game_info.home_team.id(),
-            game_info.guest_team.id(),
-            self.simulation.round(),
-        );
-        info!("Game selected");
-        let offers = self.bet_service.calculate_coefficients(&game)?;
-        for (i, (event, coefficient)) in offers.iter().enumerate() {
-            println!("{}. {} за {}", i, event, f64::from(*coefficient));
+        let offers = self
+            .bet_service
+            .calculate_coefficients(&self.simulation, game.id())?;
+        let max_stakes = self
+            .bet_service
+            .max_stakes(&self.simulation, game.id(), &offers)?;
+        for (i, ((event, coefficient), max_stake)) in offers.iter().zip(max_stakes).enumerate() {
+            println!(
+                "{}. {} за {} (не больше {})",
+                i,
+                event,
+                self.odds_format.render(*coefficient),
+                f64::from(max_stake)
+            );
         }
         info!("Coefficients calculated");
         println!(
             "Введите номер события, на которое хотите сделать ставку \
             или любое другое число, чтобы вернуться в меню:"
         );
-        buffer.clear();
         io::stdin().read_line(&mut buffer)?;
         let event_pos = buffer.trim().parse::<usize>()?;
         if event_pos < offers.len() {
@@ -286,22 +553,17 @@ impl App {
             );
             self.game_poses.push(game.id);
         }
+        self.announce_achievements()?;
 
         Ok(())
     }
 
     fn make_report(&mut self) -> Result<()> {
         debug!("Perform make report operation");
-        let stat = self.bet_service.make_report(self.setup_config.balance);
-        print!(
-            "Ваша статистика:\nНачальный баланс: {}\nМинимальный проигравший коэффициент: ",
-            f64::from(stat.start_balance())
-        );
-        if let Some(c) = stat.min_coefficient_lose() {
-            println!("{}", f64::from(c));
-        } else {
-            println!("-");
-        }
+        let put_in = self.deposit_service.summary(self.simulation.id())?.put_in;
+        let stat = self.bet_service.make_report(self.simulation.id(), put_in)?;
+        println!("Ваша статистика:");
+        print_report(&stat);
 
         Ok(())
     }
@@ -313,23 +575,35 @@ impl App {
         let mut buffer = String::new();
         io::stdin().read_line(&mut buffer)?;
         let value = buffer.trim().parse::<f64>()?;
-        let value = Amount::new_with_casting(value, Some(MIN_BALANCE_AMOUNT))?;
+        let value = Amount::new_with_casting(value, None)?;
         debug!("Sum of bet parsed");
-        if MIN_BET_AMOUNT <= value.clear_value()
-            && value.clear_value() <= self.simulation.balance().clear_value()
-        {
-            info!("All conditions to make bet passed");
-            self.simulation.make_bet(value)?;
-            info!("Bet made");
-            self.bet_service.make_bet(game, value, event, coefficient)
-        } else if MIN_BET_AMOUNT <= value.clear_value() {
-            bail!("Haven't enough money");
-        } else {
-            bail!(
-                "Minimal bet is {}",
-                f64::from(Amount::new(MIN_BET_AMOUNT, None).unwrap())
+        let warnings = match self.bet_service.make_bet(
+            self.simulation.id(),
+            game.id(),
+            value,
+            event,
+            coefficient,
+        ) {
+            Ok(warnings) => warnings,
+            Err(error) => {
+                if let Some(reason) = error.downcast_ref::<BetValidationError>() {
+                    println!("Ставка не принята: {}", describe_rejection(reason));
+                }
+                return Err(error);
+            }
+        };
+        self.simulation.make_bet(value)?;
+        info!("Bet made");
+        for warning in warnings {
+            println!(
+                "Внимание: лимит на {} почти исчерпан, {} из {}",
+                limit_name(warning.kind),
+                f64::from(warning.used),
+                f64::from(warning.max)
             );
         }
+
+        Ok(())
     }
 
     fn check_balance(&self) -> Result<()> {
@@ -338,6 +612,743 @@ impl App {
 
         Ok(())
     }
+
+    fn list_simulations(&self) -> Result<Vec<Simulation>> {
+        debug!("Perform list simulations operation");
+        let simulations = self.sim_service.list(self.simulation.user_id())?;
+        println!("Ваши симуляции:");
+        for (i, simulation) in simulations.iter().enumerate() {
+            let mark = if simulation.id() == self.simulation.id() {
+                "*"
+            } else {
+                " "
+            };
+            println!(
+                "{}{}. {} (тур {}, баланс {})",
+                mark,
+                i,
+                simulation.name(),
+                simulation.round(),
+                f64::from(simulation.balance())
+            );
+        }
+
+        Ok(simulations)
+    }
+
+    fn new_simulation(&mut self) -> Result<()> {
+        debug!("Perform new simulation operation");
+        let name = read_line("Введите название симуляции: ")?;
+        let simulation = self.sim_service.create(self.simulation.user_id(), &name)?;
+        info!(name = simulation.name(), "Simulation created");
+        self.select_simulation(simulation);
+
+        Ok(())
+    }
+
+    fn switch_simulation(&mut self) -> Result<()> {
+        debug!("Perform switch simulation operation");
+        let simulation = self.choose_simulation()?;
+        info!(name = simulation.name(), "Simulation switched");
+        self.select_simulation(simulation);
+
+        Ok(())
+    }
+
+    fn rename_simulation(&mut self) -> Result<()> {
+        debug!("Perform rename simulation operation");
+        let name = read_line("Введите новое название: ")?;
+        self.simulation =
+            self.sim_service
+                .rename(self.simulation.user_id(), self.simulation.id(), &name)?;
+        info!(name = self.simulation.name(), "Simulation renamed");
+
+        Ok(())
+    }
+
+    fn delete_simulation(&mut self) -> Result<()> {
+        debug!("Perform delete simulation operation");
+        let simulation = self.choose_simulation()?;
+        let user_id = simulation.user_id();
+        self.sim_service.delete(user_id, simulation.id())?;
+        info!(name = simulation.name(), "Simulation deleted");
+        if simulation.id() == self.simulation.id() {
+            let current = self.sim_service.start(user_id)?;
+            self.select_simulation(current);
+        }
+
+        Ok(())
+    }
+
+    fn show_archives(&mut self) -> Result<()> {
+        debug!("Perform show archives operation");
+        let archives = self.sim_service.list_archived(self.simulation.user_id())?;
+        if archives.is_empty() {
+            println!("Архив пуст");
+            return Ok(());
+        }
+        println!("Архив симуляций:");
+        for (i, simulation) in archives.iter().enumerate() {
+            println!(
+                "{}. {} (тур {}, баланс {})",
+                i,
+                simulation.name(),
+                simulation.round(),
+                f64::from(simulation.balance())
+            );
+        }
+        let pos = read_line("Введите номер прогона: ")?.parse::<usize>()?;
+        let simulation = archives.get(pos).ok_or_else(|| {
+            println!("Такого прогона нет");
+            warn!("Incorrect archive pos");
+            anyhow!("Incorrect archive pos")
+        })?;
+        let put_in = self.deposit_service.summary(simulation.id())?.put_in;
+        let stat = self.bet_service.make_report(simulation.id(), put_in)?;
+        println!("Итоги прогона {}:", simulation.name());
+        print_report(&stat);
+
+        Ok(())
+    }
+
+    fn export_simulation(&mut self) -> Result<()> {
+        debug!("Perform export operation");
+        let path = read_line("Введите путь к файлу (.json или .msgpack): ")?;
+        let format = document_format(&path)?;
+        let document = self.transfer_service.export(&self.simulation)?;
+        fs::write(&path, document.encode(format)?)?;
+        info!(path, "Simulation exported");
+        println!("Симуляция сохранена в {}", path);
+
+        Ok(())
+    }
+
+    fn import_simulation(&mut self) -> Result<()> {
+        debug!("Perform import operation");
+        let path = read_line("Введите путь к файлу (.json или .msgpack): ")?;
+        let document = SimulationDocument::decode(&fs::read(&path)?, document_format(&path)?)?;
+        let name = read_line("Введите название (пусто - как в файле): ")?;
+        let name = (!name.is_empty()).then_some(name);
+        let simulation = self
+            .transfer_service
+            .import(self.simulation.user_id(), document, name)?;
+        info!(name = simulation.name(), "Simulation imported");
+        self.select_simulation(simulation);
+
+        Ok(())
+    }
+
+    fn fork_simulation(&mut self) -> Result<()> {
+        debug!("Perform fork operation");
+        let prompt = format!("Введите тур (0-{}): ", self.simulation.round());
+        let round = read_line(&prompt)?.parse::<u32>()?;
+        let name = read_line("Введите название новой симуляции: ")?;
+        let simulation = self.transfer_service.fork(&self.simulation, round, &name)?;
+        info!(name = simulation.name(), "Simulation forked");
+        self.select_simulation(simulation);
+
+        Ok(())
+    }
+
+    fn show_leaderboard(&mut self) -> Result<()> {
+        debug!("Perform show leaderboard operation");
+        let metric = read_line("Критерий (balance, roi, profit; пусто - balance): ")?;
+        let metric = match metric.as_str() {
+            "" => RankingMetric::Balance,
+            metric => metric.parse()?,
+        };
+        let rounds = read_line("Учитывать последние N туров (пусто - все): ")?;
+        let rounds = (!rounds.is_empty())
+            .then(|| rounds.parse::<u32>())
+            .transpose()?;
+        let page = read_line("Страница (пусто - 1): ")?;
+        let page = if page.is_empty() { 1 } else { page.parse()? };
+        let leaderboard = self.leaderboard_service.leaderboard(&LeaderboardQuery {
+            metric,
+            rounds,
+            page,
+            per_page: None,
+        })?;
+        if leaderboard.entries.is_empty() {
+            println!("Таблица пуста");
+            return Ok(());
+        }
+        println!(
+            "Таблица лидеров (страница {}, всего {}):",
+            leaderboard.page, leaderboard.total
+        );
+        for entry in &leaderboard.entries {
+            println!(
+                "{}. {} / {} (тур {}): баланс {}, пополнено {}, прибыль {}, ROI {:.1}%, ставок {}",
+                entry.rank,
+                entry.login,
+                entry.name,
+                entry.round,
+                f64::from(entry.balance),
+                f64::from(entry.deposited),
+                f64::from(entry.profit),
+                entry.roi * 100.0,
+                entry.bets
+            );
+        }
+
+        Ok(())
+    }
+
+    fn manage_limits(&mut self) -> Result<()> {
+        debug!("Perform manage limits operation");
+        let limits = self.limit_service.limits(self.simulation.id())?;
+        print_limits(&limits);
+
+        let read_amount = |prompt: &str| -> Result<Option<Amount>> {
+            let value = read_line(prompt)?;
+            (!value.is_empty())
+                .then(|| Amount::new_with_casting(value.parse()?, None))
+                .transpose()
+        };
+        let max_stake = read_amount("Максимальная ставка (пусто - без лимита): ")?;
+        let max_round_stake = read_amount("Максимум ставок за тур (пусто - без лимита): ")?;
+        let loss_limit = match read_amount("Лимит проигрыша (пусто - без лимита): ")?
+        {
+            Some(amount) => Some(LossLimit {
+                amount,
+                rounds: read_line("За сколько последних туров: ")?.parse()?,
+            }),
+            None => None,
+        };
+        let max_round_deposit = read_amount("Максимум пополнений за тур (пусто - без лимита): ")?;
+        let limits = BetLimits {
+            max_stake,
+            max_round_stake,
+            loss_limit,
+            max_round_deposit,
+            ..limits
+        };
+        let limits = self
+            .limit_service
+            .set_limits(self.simulation.id(), limits)?;
+        info!("Limits set");
+        if let Some(pending) = limits.pending {
+            println!(
+                "Ослабленные лимиты вступят в силу с {} тура",
+                pending.from_round
+            );
+        }
+
+        let hours = read_line("Приостановить ставки на N часов (пусто - нет): ")?;
+        if !hours.is_empty() {
+            let secs = hours.parse::<i64>()?.saturating_mul(60 * 60);
+            let limits = self.limit_service.cool_off(self.simulation.id(), secs)?;
+            info!("Cool-off started");
+            print_limits(&limits);
+        }
+
+        Ok(())
+    }
+
+    fn deposit(&mut self) -> Result<()> {
+        debug!("Perform deposit operation");
+        let value = read_line("Введите сумму пополнения: ")?.parse::<f64>()?;
+        let amount = Amount::new_with_casting(value, None)?;
+        self.deposit_service.deposit(self.simulation.id(), amount)?;
+        self.simulation.deposit(amount)?;
+        info!(value, "Deposit made");
+
+        let summary = self.deposit_service.summary(self.simulation.id())?;
+        println!("Ваш баланс: {}", f64::from(summary.balance));
+        println!(
+            "Всего внесено: {} (пополнений: {} на {})",
+            f64::from(summary.put_in),
+            summary.deposits,
+            f64::from(summary.deposited)
+        );
+        println!("Итог: {}", f64::from(summary.net));
+
+        Ok(())
+    }
+
+    fn autoplay(&mut self) -> Result<()> {
+        debug!("Perform autoplay operation");
+        for (pos, strategy) in StrategyKind::ALL.iter().enumerate() {
+            println!("{}. {}", pos, strategy);
+        }
+        let pos = read_line("Введите номер стратегии: ")?.parse::<usize>()?;
+        let strategy = *StrategyKind::ALL.get(pos).ok_or_else(|| {
+            println!("Такой стратегии нет");
+            warn!("Incorrect strategy pos");
+            anyhow!("Incorrect strategy pos")
+        })?;
+        let rounds = read_line("Сколько туров сыграть: ")?.parse::<u32>()?;
+        let run = self
+            .autoplay_service
+            .autoplay(self.simulation.id(), strategy, rounds)?;
+        info!(rounds = run.rounds(), bets = run.bets(), "Autoplay done");
+        let simulation = self
+            .sim_service
+            .simulation(self.simulation.user_id(), self.simulation.id())?;
+        self.select_simulation(simulation);
+
+        println!(
+            "Сыграно туров: {} (с {}), ставок: {}",
+            run.rounds(),
+            run.first_round(),
+            run.bets()
+        );
+        println!(
+            "Поставлено: {}, возвращено: {}",
+            f64::from(run.staked()),
+            f64::from(run.returned())
+        );
+        println!(
+            "Баланс: {} -> {}",
+            f64::from(run.start_balance()),
+            f64::from(run.end_balance())
+        );
+        println!("Итоги стратегий в этой симуляции:");
+        for summary in self.autoplay_service.summaries(self.simulation.id())? {
+            println!(
+                "{}: запусков {}, туров {}, ставок {}, прибыль {}",
+                summary.strategy,
+                summary.runs,
+                summary.rounds,
+                summary.bets,
+                f64::from(summary.profit)
+            );
+        }
+        self.announce_achievements()
+    }
+
+    fn show_achievements(&mut self) -> Result<()> {
+        debug!("Perform show achievements operation");
+        let achievements = self
+            .achievement_service
+            .achievements(self.simulation.id())?;
+        if achievements.is_empty() {
+            println!("Достижений пока нет");
+            return Ok(());
+        }
+        println!("Достижения:");
+        for achievement in achievements {
+            println!("{} (тур {})", achievement.kind(), achievement.round());
+        }
+
+        Ok(())
+    }
+
+    fn announce_achievements(&self) -> Result<()> {
+        for achievement in self.achievement_service.evaluate(self.simulation.id())? {
+            println!("Получено достижение: {}", achievement.kind());
+        }
+
+        Ok(())
+    }
+
+    /// Game of the current round picked by its number, `None` if the round isn't shown yet.
+    fn choose_game(&self) -> Result<Option<Game>> {
+        if self.game_poses.is_empty() {
+            info!("Round didn't create");
+            println!("Сначала посмотрите матчи тура!");
+            return Ok(None);
+        }
+        let game_pos = read_line("Введите номер матча: ")?.parse::<usize>()?;
+        let game_id = self.game_poses.get(game_pos).ok_or_else(|| {
+            println!("Такого матча нет");
+            warn!("Incorrect game pos");
+            anyhow!("Incorrect game pos")
+        })?;
+        let game_info = self.games.get(game_id).unwrap();
+        let game = Game::new(
+            *game_id,
+            self.simulation.id(),
+            game_info.home_team.id(),
+            game_info.guest_team.id(),
+            self.simulation.round(),
+        );
+        info!("Game selected");
+
+        Ok(Some(game))
+    }
+
+    fn advise_stakes(&mut self) -> Result<()> {
+        debug!("Perform advise stakes operation");
+        let Some(game) = self.choose_game()? else {
+            return Ok(());
+        };
+        let advice = self.bet_service.advise(&self.simulation, game.id())?;
+        info!("Stakes advised");
+        println!(
+            "Баланс: {}. Событие: коэффициент, вероятность по коэффициенту / по модели, \
+            ожидаемый доход на единицу ставки, доля и ставка по Келли",
+            f64::from(self.simulation.balance())
+        );
+        let no_edge = advice.iter().all(|a| a.kelly_fraction <= 0.);
+        for a in advice {
+            println!(
+                "{}: {}, {:.1}% / {:.1}%, {:+.3}, {:.1}% -> {}",
+                a.event,
+                self.odds_format.render(a.coefficient),
+                a.implied_probability * 100.,
+                a.model_probability * 100.,
+                a.expected_value,
+                a.kelly_fraction * 100.,
+                f64::from(a.kelly_stake)
+            );
+        }
+        if no_edge {
+            println!("С учётом маржи ожидаемый доход отрицателен, Келли советует не ставить");
+        }
+
+        Ok(())
+    }
+
+    fn show_bets(&mut self) -> Result<()> {
+        debug!("Perform show bets operation");
+        let lines = self.bet_service.closing_lines(self.simulation.id())?;
+        if lines.is_empty() {
+            println!("Ставок пока нет");
+            return Ok(());
+        }
+        println!("Ваши ставки ({}):", self.odds_format);
+        for (i, line) in lines.iter().enumerate() {
+            let bet = line.bet;
+            let status = match bet.is_won() {
+                Some(true) => "выиграна",
+                Some(false) => "проиграна",
+                None => "не рассчитана",
+            };
+            let clv = match (line.closing, line.clv()) {
+                (Some(closing), Some(clv)) => format!(
+                    ", закрытие {}, CLV {:+.2}%",
+                    self.odds_format.render(closing),
+                    clv * 100.
+                ),
+                _ => String::new(),
+            };
+            println!(
+                "{}. {} за {}, сумма {}: {}{}",
+                i,
+                bet.event(),
+                self.odds_format.render(bet.coefficient()),
+                f64::from(bet.amount()),
+                status,
+                clv
+            );
+        }
+
+        Ok(())
+    }
+
+    fn show_odds_history(&mut self) -> Result<()> {
+        debug!("Perform show odds history operation");
+        let Some(game) = self.choose_game()? else {
+            return Ok(());
+        };
+        let history = self
+            .bet_service
+            .odds_history(self.simulation.id(), game.id())?;
+        if history.is_empty() {
+            println!("Коэффициенты на матч ещё не предлагались");
+            return Ok(());
+        }
+        for h in history {
+            let prices = h
+                .snapshots
+                .iter()
+                .map(|s| self.odds_format.render(s.coefficient()))
+                .collect::<Vec<_>>();
+            println!(
+                "{}: открытие {}, закрытие {} ({})",
+                h.event,
+                self.odds_format.render(h.opening()),
+                self.odds_format.render(h.closing()),
+                prices.join(" -> ")
+            );
+        }
+
+        Ok(())
+    }
+
+    fn choose_odds_format(&mut self) -> Result<()> {
+        debug!("Perform choose odds format operation");
+        let example = Coefficient::try_from(2.5)?;
+        for (pos, format) in OddsFormat::ALL.iter().enumerate() {
+            let mark = if *format == self.odds_format {
+                "*"
+            } else {
+                " "
+            };
+            println!("{}{}. {}: {}", mark, pos, format, format.render(example));
+        }
+        let pos = read_line("Введите номер формата: ")?.parse::<usize>()?;
+        let odds_format = *OddsFormat::ALL.get(pos).ok_or_else(|| {
+            println!("Такого формата нет");
+            warn!("Incorrect odds format pos");
+            anyhow!("Incorrect odds format pos")
+        })?;
+        let user = self
+            .preference_service
+            .set_odds_format(self.simulation.user_id(), odds_format)?;
+        self.odds_format = user.odds_format();
+        println!("Коэффициенты показываются в формате: {}", self.odds_format);
+
+        Ok(())
+    }
+
+    fn show_book_report(&mut self) -> Result<()> {
+        debug!("Perform show book report operation");
+        let user = self.auth_service.authenticate(&self.token)?;
+        let report = self.book_service.book_report(&user)?;
+        println!(
+            "Симуляций {}, ставок {}, оборот {} (рассчитано {})",
+            report.simulations,
+            report.bets,
+            f64::from(report.turnover),
+            f64::from(report.settled_turnover)
+        );
+        println!(
+            "Доход букмекера {}, маржа {:.2}% при заложенной {:.2}%",
+            f64::from(report.revenue),
+            report.hold * 100.0,
+            f64::from(report.margin) * 100.0
+        );
+        println!("По рынкам:");
+        for market in &report.markets {
+            println!(
+                "  {}: ставок {}, оборот {}, доход {}, маржа {:.2}%",
+                market.market,
+                market.bets,
+                f64::from(market.turnover),
+                f64::from(market.revenue),
+                market.hold * 100.0
+            );
+        }
+        println!("Крупнейшие выигрыши игроков:");
+        for winner in &report.winners {
+            println!(
+                "  {} / {}: прибыль {} при ставках на {}",
+                winner.login,
+                winner.name,
+                f64::from(winner.profit),
+                f64::from(winner.staked)
+            );
+        }
+        println!("Открытые матчи:");
+        for game in &report.exposure {
+            println!(
+                "  тур {}, матч {}: ставок {} на {}, риск {}",
+                game.round,
+                game.game_id.value(),
+                game.bets,
+                f64::from(game.staked),
+                f64::from(game.liability)
+            );
+        }
+
+        Ok(())
+    }
+
+    fn show_calibration(&mut self) -> Result<()> {
+        debug!("Perform show calibration operation");
+        let user = self.auth_service.authenticate(&self.token)?;
+        let report = self.calibration_service.calibration(&user)?;
+        println!("Сыграно матчей с прогнозом: {}", report.games);
+        for market in &report.markets {
+            println!(
+                "{}: матчей {}, Brier {:.4}, log loss {:.4}",
+                market.market, market.games, market.brier, market.log_loss
+            );
+            for bucket in &market.buckets {
+                println!(
+                    "  {:.0}-{:.0}%: прогнозов {}, в среднем {:.1}%, сыграло {:.1}%",
+                    bucket.lower * 100.0,
+                    bucket.upper * 100.0,
+                    bucket.forecasts,
+                    bucket.mean_probability * 100.0,
+                    bucket.frequency * 100.0
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn choose_simulation(&self) -> Result<Simulation> {
+        let simulations = self.list_simulations()?;
+        let pos = read_line("Введите номер симуляции: ")?.parse::<usize>()?;
+
+        simulations.into_iter().nth(pos).ok_or_else(|| {
+            println!("Такой симуляции нет");
+            warn!("Incorrect simulation pos");
+            anyhow!("Incorrect simulation pos")
+        })
+    }
+
+    fn select_simulation(&mut self, simulation: Simulation) {
+        println!("Текущая симуляция: {}", simulation.name());
+        self.simulation = simulation;
+        self.games.clear();
+        self.game_poses.clear();
+    }
+}
+
+fn print_report(stat: &BetStatistics) {
+    print!(
+        "Начальный баланс: {}\nМинимальный проигравший коэффициент: ",
+        f64::from(stat.start_balance())
+    );
+    if let Some(c) = stat.min_coefficient_lose() {
+        println!("{}", f64::from(c));
+    } else {
+        println!("-");
+    }
+    if let Some(clv) = stat.clv() {
+        println!("Средний CLV: {:+.2}%", clv * 100.);
+    }
+}
+
+/// Format picked by the file extension.
+fn document_format(path: &str) -> Result<DocumentFormat> {
+    let extension = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default();
+
+    Ok(extension.parse()?)
+}
+
+fn read_line(prompt: &str) -> Result<String> {
+    println!("{}", prompt);
+    let mut buffer = String::new();
+    io::stdin().read_line(&mut buffer)?;
+
+    Ok(buffer.trim().to_string())
+}
+
+fn describe_rejection(reason: &BetValidationError) -> String {
+    let as_float = |value: i64| f64::from(Amount::new(value, None).unwrap());
+    match reason {
+        BetValidationError::StakeTooLow { min } => {
+            format!("минимальная ставка {}", as_float(*min))
+        }
+        BetValidationError::StakeTooHigh { max } => {
+            format!("максимальная ставка {}", as_float(*max))
+        }
+        BetValidationError::PayoutTooHigh { max } => {
+            format!("максимальная выплата {}", as_float(*max))
+        }
+        BetValidationError::LiabilityExceeded { scope, max_stake } => {
+            let scope = match scope {
+                LiabilityScope::Event => "событие",
+                LiabilityScope::Game => "матч",
+                LiabilityScope::Round => "тур",
+            };
+            format!(
+                "лимит ответственности букмекера на {} позволяет поставить не больше {}",
+                scope,
+                as_float(*max_stake)
+            )
+        }
+        BetValidationError::InsufficientFunds { balance } => {
+            format!("недостаточно средств, баланс {}", as_float(*balance))
+        }
+        BetValidationError::GameNotFound => "такого матча нет".to_string(),
+        BetValidationError::GameNotInCurrentRound => "матч не из текущего тура".to_string(),
+        BetValidationError::GameAlreadyPlayed => "матч уже сыгран".to_string(),
+        BetValidationError::DuplicateSelection => "ставка на это событие уже сделана".to_string(),
+        BetValidationError::MarketClosed => "на это событие ставки не принимаются".to_string(),
+        BetValidationError::CoefficientChanged { current } => {
+            format!("коэффициент изменился, текущий {}", f64::from(*current))
+        }
+        BetValidationError::LimitExceeded { limit, max } => {
+            format!(
+                "превышен лимит на {} в {}",
+                limit_name(*limit),
+                as_float(*max)
+            )
+        }
+        BetValidationError::CoolingOff { until } => {
+            format!("ставки приостановлены ещё на {} мин.", minutes_left(*until))
+        }
+    }
+}
+
+fn limit_name(limit: LimitKind) -> &'static str {
+    match limit {
+        LimitKind::Stake => "ставку",
+        LimitKind::RoundStake => "ставки за тур",
+        LimitKind::Loss => "проигрыш",
+        LimitKind::RoundDeposit => "пополнения за тур",
+    }
+}
+
+fn minutes_left(until: i64) -> i64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64);
+
+    ((until - now).max(0) + 59) / 60
+}
+
+fn print_limits(limits: &BetLimits) {
+    let show =
+        |amount: Option<Amount>| amount.map_or("нет".to_string(), |a| f64::from(a).to_string());
+    println!("Максимальная ставка: {}", show(limits.max_stake));
+    println!("Максимум ставок за тур: {}", show(limits.max_round_stake));
+    match limits.loss_limit {
+        Some(limit) => println!(
+            "Лимит проигрыша: {} за {} туров",
+            f64::from(limit.amount),
+            limit.rounds
+        ),
+        None => println!("Лимит проигрыша: нет"),
+    }
+    println!(
+        "Максимум пополнений за тур: {}",
+        show(limits.max_round_deposit)
+    );
+    if let Some(pending) = limits.pending {
+        println!(
+            "С {} тура: ставка {}, ставки за тур {}, пополнения за тур {}, проигрыш {}",
+            pending.from_round,
+            show(pending.max_stake),
+            show(pending.max_round_stake),
+            show(pending.max_round_deposit),
+            pending.loss_limit.map_or("нет".to_string(), |l| format!(
+                "{} за {} туров",
+                f64::from(l.amount),
+                l.rounds
+            ))
+        );
+    }
+    if let Some(until) = limits.cool_off_until.filter(|&u| minutes_left(u) > 0) {
+        println!("Ставки приостановлены ещё на {} мин.", minutes_left(until));
+    }
+}
+
+fn new_bet_service(
+    storage: &Storage,
+    config: CoefficientConfig,
+    exposure: ExposureConfig,
+) -> BetService<
+    BetRepo,
+    GameRepo,
+    GameStatRepo,
+    SimulationRepo,
+    BetLimitRepo,
+    UnitOfWork,
+    OddsSnapshotRepo,
+> {
+    BetService::new(
+        storage.bet_repo(),
+        storage.game_repo(),
+        storage.game_stat_repo(),
+        storage.simulation_repo(),
+        storage.bet_limit_repo(),
+        storage.unit_of_work(),
+        storage.odds_snapshot_repo(),
+        config,
+        exposure,
+    )
 }
 
 fn main() -> ExitCode {
@@ -357,19 +1368,19 @@ fn main() -> ExitCode {
         App::show_menu();
 
         buffer.clear();
-        if let Err(_) = io::stdin().read_line(&mut buffer) {
+        if io::stdin().read_line(&mut buffer).is_err() {
             println!("Строка не считана. Попробуйте ещё раз");
             continue;
         }
 
         let dig_buf = buffer.trim().parse::<u8>();
-        if let Err(_) = dig_buf {
+        if dig_buf.is_err() {
             println!("Число не распознано. Попробуйте ещё раз");
             continue;
         }
 
         let cmd_buf = Command::try_from(dig_buf.unwrap());
-        if let Err(_) = cmd_buf {
+        if cmd_buf.is_err() {
             println!("Команда не распознана. Попробуйте ещё раз");
             continue;
         }
@@ -0,0 +1,12 @@
+-- This file should undo anything in `up.sql`
+-- Simulations get the ip of their legacy user back, any other the login of its owner.
+ALTER TABLE Simulation ADD COLUMN ip TEXT UNIQUE;
+UPDATE Simulation SET ip = CASE
+        WHEN Users.login LIKE 'legacy-%' THEN substr(Users.login, 8)
+        ELSE Users.login
+    END
+    FROM Users WHERE Users.id = Simulation.user_id;
+ALTER TABLE Simulation ALTER COLUMN ip SET NOT NULL;
+ALTER TABLE Simulation DROP COLUMN user_id;
+DROP TABLE Session;
+DROP TABLE Users;
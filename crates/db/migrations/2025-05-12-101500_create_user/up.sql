@@ -0,0 +1,22 @@
+-- Your SQL goes here
+CREATE TABLE Users (
+    id UUID PRIMARY KEY,
+    login TEXT UNIQUE NOT NULL,
+    password_hash TEXT NOT NULL
+);
+
+CREATE TABLE Session (
+    token TEXT PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES Users (id) ON DELETE CASCADE,
+    expires_at BIGINT NOT NULL
+);
+
+-- Simulations were keyed by client ip. Each one is handed to a legacy user
+-- logging in as `legacy-<ip>`, whose empty password hash never verifies.
+ALTER TABLE Simulation ADD COLUMN user_id UUID UNIQUE REFERENCES Users (id) ON DELETE CASCADE;
+INSERT INTO Users (id, login, password_hash)
+    SELECT gen_random_uuid(), 'legacy-' || ip, '' FROM Simulation;
+UPDATE Simulation SET user_id = Users.id
+    FROM Users WHERE Users.login = 'legacy-' || Simulation.ip;
+ALTER TABLE Simulation ALTER COLUMN user_id SET NOT NULL;
+ALTER TABLE Simulation DROP COLUMN ip;
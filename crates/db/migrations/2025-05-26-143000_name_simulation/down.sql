@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM Simulation a USING Simulation b WHERE a.user_id = b.user_id AND a.id < b.id;
+ALTER TABLE Simulation DROP CONSTRAINT simulation_user_id_name_key;
+ALTER TABLE Simulation DROP COLUMN name;
+ALTER TABLE Simulation ADD CONSTRAINT simulation_user_id_key UNIQUE (user_id);
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE Simulation DROP CONSTRAINT simulation_user_id_key;
+ALTER TABLE Simulation ADD COLUMN name TEXT NOT NULL DEFAULT 'default';
+ALTER TABLE Simulation ALTER COLUMN name DROP DEFAULT;
+ALTER TABLE Simulation ADD CONSTRAINT simulation_user_id_name_key UNIQUE (user_id, name);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM Simulation WHERE archived_at IS NOT NULL;
+DROP INDEX simulation_active_name_key;
+ALTER TABLE Simulation ADD CONSTRAINT simulation_user_id_name_key UNIQUE (user_id, name);
+ALTER TABLE Simulation DROP COLUMN archived_at;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE Simulation ADD COLUMN archived_at BIGINT;
+ALTER TABLE Simulation DROP CONSTRAINT simulation_user_id_name_key;
+CREATE UNIQUE INDEX simulation_active_name_key ON Simulation (user_id, name)
+    WHERE archived_at IS NULL;
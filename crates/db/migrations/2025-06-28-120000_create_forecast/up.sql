@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE Forecast (
+    id UUID PRIMARY KEY,
+    simulation_id UUID NOT NULL REFERENCES Simulation (id) ON DELETE CASCADE,
+    game_id UUID NOT NULL REFERENCES Game (id) ON DELETE CASCADE,
+    event BYTEA NOT NULL,
+    probability DOUBLE PRECISION NOT NULL
+);
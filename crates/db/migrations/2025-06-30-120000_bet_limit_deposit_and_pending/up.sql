@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE BetLimit ADD COLUMN max_round_deposit BIGINT;
+ALTER TABLE BetLimit ADD COLUMN pending_max_stake BIGINT;
+ALTER TABLE BetLimit ADD COLUMN pending_max_round_stake BIGINT;
+ALTER TABLE BetLimit ADD COLUMN pending_loss_limit BIGINT;
+ALTER TABLE BetLimit ADD COLUMN pending_loss_limit_rounds BIGINT;
+ALTER TABLE BetLimit ADD COLUMN pending_max_round_deposit BIGINT;
+ALTER TABLE BetLimit ADD COLUMN pending_from_round BIGINT;
@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE Team (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL
+);
+INSERT INTO team (id, name) VALUES
+('123e4567-e89b-12d3-a456-426614174000', 'Спартак'),
+('123e4567-e89b-12d3-a456-426614174001', 'Зенит'),
+('123e4567-e89b-12d3-a456-426614174002', 'Динамо'),
+('123e4567-e89b-12d3-a456-426614174003', 'Локомотив'),
+('123e4567-e89b-12d3-a456-426614174004', 'Краснодар'),
+('123e4567-e89b-12d3-a456-426614174005', 'Ростов'),
+('123e4567-e89b-12d3-a456-426614174006', 'Сочи'),
+('123e4567-e89b-12d3-a456-426614174007', 'Урал'),
+('123e4567-e89b-12d3-a456-426614174008', 'Ахмат'),
+('123e4567-e89b-12d3-a456-426614174009', 'Рубин'),
+('123e4567-e89b-12d3-a456-426614174010', 'Крылья Советов'),
+('123e4567-e89b-12d3-a456-426614174011', 'Тамбов'),
+('123e4567-e89b-12d3-a456-426614174012', 'Уфа'),
+('123e4567-e89b-12d3-a456-426614174013', 'Химки'),
+('123e4567-e89b-12d3-a456-426614174014', 'Ротор');
\ No newline at end of file
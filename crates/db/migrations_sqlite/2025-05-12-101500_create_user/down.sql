@@ -0,0 +1,22 @@
+-- This file should undo anything in `up.sql`
+-- Simulations get the ip of their legacy user back, any other the login of its owner.
+PRAGMA foreign_keys = OFF;
+BEGIN;
+CREATE TABLE Simulation_new (
+    id TEXT NOT NULL PRIMARY KEY,
+    ip TEXT UNIQUE NOT NULL,
+    round BIGINT NOT NULL,
+    balance BIGINT NOT NULL
+);
+INSERT INTO Simulation_new (id, ip, round, balance)
+    SELECT Simulation.id, CASE
+            WHEN Users.login LIKE 'legacy-%' THEN substr(Users.login, 8)
+            ELSE Users.login
+        END, round, balance
+    FROM Simulation JOIN Users ON Users.id = Simulation.user_id;
+DROP TABLE Simulation;
+ALTER TABLE Simulation_new RENAME TO Simulation;
+DROP TABLE Session;
+DROP TABLE Users;
+COMMIT;
+PRAGMA foreign_keys = ON;
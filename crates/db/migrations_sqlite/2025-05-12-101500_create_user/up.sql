@@ -0,0 +1,40 @@
+-- Your SQL goes here
+-- SQLite can't drop a unique column, so the table is rebuilt.
+-- Foreign keys are switched off, otherwise dropping the old table would
+-- cascade to games and bets.
+PRAGMA foreign_keys = OFF;
+BEGIN;
+CREATE TABLE Users (
+    id TEXT NOT NULL PRIMARY KEY,
+    login TEXT UNIQUE NOT NULL,
+    password_hash TEXT NOT NULL
+);
+
+CREATE TABLE Session (
+    token TEXT NOT NULL PRIMARY KEY,
+    user_id TEXT NOT NULL REFERENCES Users (id) ON DELETE CASCADE,
+    expires_at BIGINT NOT NULL
+);
+
+-- Simulations were keyed by client ip. Each one is handed to a legacy user
+-- logging in as `legacy-<ip>`, whose empty password hash never verifies.
+INSERT INTO Users (id, login, password_hash)
+    SELECT lower(
+        substr(h, 1, 8) || '-' || substr(h, 9, 4) || '-' || substr(h, 13, 4) || '-'
+            || substr(h, 17, 4) || '-' || substr(h, 21, 12)
+    ), 'legacy-' || ip, ''
+    FROM (SELECT hex(randomblob(16)) AS h, ip FROM Simulation);
+CREATE TABLE Simulation_new (
+    id TEXT NOT NULL PRIMARY KEY,
+    user_id TEXT UNIQUE NOT NULL REFERENCES Users (id) ON DELETE CASCADE,
+    round BIGINT NOT NULL,
+    balance BIGINT NOT NULL
+);
+INSERT INTO Simulation_new (id, user_id, round, balance)
+    SELECT Simulation.id, Users.id, round, balance
+    FROM Simulation JOIN Users ON Users.login = 'legacy-' || Simulation.ip;
+DROP TABLE Simulation;
+ALTER TABLE Simulation_new RENAME TO Simulation;
+PRAGMA foreign_key_check;
+COMMIT;
+PRAGMA foreign_keys = ON;
@@ -0,0 +1,18 @@
+-- This file should undo anything in `up.sql`
+PRAGMA foreign_keys = OFF;
+BEGIN;
+DELETE FROM Simulation WHERE EXISTS (
+    SELECT 1 FROM Simulation b WHERE b.user_id = Simulation.user_id AND b.id > Simulation.id
+);
+CREATE TABLE Simulation_new (
+    id TEXT NOT NULL PRIMARY KEY,
+    user_id TEXT UNIQUE NOT NULL REFERENCES Users (id) ON DELETE CASCADE,
+    round BIGINT NOT NULL,
+    balance BIGINT NOT NULL
+);
+INSERT INTO Simulation_new (id, user_id, round, balance)
+    SELECT id, user_id, round, balance FROM Simulation;
+DROP TABLE Simulation;
+ALTER TABLE Simulation_new RENAME TO Simulation;
+COMMIT;
+PRAGMA foreign_keys = ON;
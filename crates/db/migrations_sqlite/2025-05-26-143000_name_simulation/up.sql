@@ -0,0 +1,21 @@
+-- Your SQL goes here
+-- SQLite can't drop a unique column constraint, so the table is rebuilt.
+-- Foreign keys are switched off, otherwise dropping the old table would
+-- cascade to games and bets.
+PRAGMA foreign_keys = OFF;
+BEGIN;
+CREATE TABLE Simulation_new (
+    id TEXT NOT NULL PRIMARY KEY,
+    user_id TEXT NOT NULL REFERENCES Users (id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    round BIGINT NOT NULL,
+    balance BIGINT NOT NULL,
+    UNIQUE (user_id, name)
+);
+INSERT INTO Simulation_new (id, user_id, name, round, balance)
+    SELECT id, user_id, 'default', round, balance FROM Simulation;
+DROP TABLE Simulation;
+ALTER TABLE Simulation_new RENAME TO Simulation;
+PRAGMA foreign_key_check;
+COMMIT;
+PRAGMA foreign_keys = ON;
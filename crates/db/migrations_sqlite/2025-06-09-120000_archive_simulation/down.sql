@@ -0,0 +1,19 @@
+-- This file should undo anything in `up.sql`
+-- Archived runs go first, while foreign keys still cascade to their games and bets.
+DELETE FROM Simulation WHERE archived_at IS NOT NULL;
+PRAGMA foreign_keys = OFF;
+BEGIN;
+CREATE TABLE Simulation_new (
+    id TEXT NOT NULL PRIMARY KEY,
+    user_id TEXT NOT NULL REFERENCES Users (id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    round BIGINT NOT NULL,
+    balance BIGINT NOT NULL,
+    UNIQUE (user_id, name)
+);
+INSERT INTO Simulation_new (id, user_id, name, round, balance)
+    SELECT id, user_id, name, round, balance FROM Simulation;
+DROP TABLE Simulation;
+ALTER TABLE Simulation_new RENAME TO Simulation;
+COMMIT;
+PRAGMA foreign_keys = ON;
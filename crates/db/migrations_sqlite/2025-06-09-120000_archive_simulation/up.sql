@@ -0,0 +1,23 @@
+-- Your SQL goes here
+-- Names only have to be unique among active simulations, which needs a partial
+-- index instead of the table constraint, so the table is rebuilt the same way
+-- as in name_simulation.
+PRAGMA foreign_keys = OFF;
+BEGIN;
+CREATE TABLE Simulation_new (
+    id TEXT NOT NULL PRIMARY KEY,
+    user_id TEXT NOT NULL REFERENCES Users (id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    round BIGINT NOT NULL,
+    balance BIGINT NOT NULL,
+    archived_at BIGINT
+);
+INSERT INTO Simulation_new (id, user_id, name, round, balance)
+    SELECT id, user_id, name, round, balance FROM Simulation;
+DROP TABLE Simulation;
+ALTER TABLE Simulation_new RENAME TO Simulation;
+CREATE UNIQUE INDEX simulation_active_name_key ON Simulation (user_id, name)
+    WHERE archived_at IS NULL;
+PRAGMA foreign_key_check;
+COMMIT;
+PRAGMA foreign_keys = ON;
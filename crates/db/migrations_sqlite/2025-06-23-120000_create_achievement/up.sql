@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE Achievement (
+    id TEXT NOT NULL PRIMARY KEY,
+    simulation_id TEXT NOT NULL REFERENCES Simulation (id) ON DELETE CASCADE,
+    kind TEXT NOT NULL,
+    round BIGINT NOT NULL,
+    unlocked_at BIGINT NOT NULL,
+    UNIQUE (simulation_id, kind)
+);
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE BetLimit (
+    simulation_id TEXT NOT NULL PRIMARY KEY REFERENCES Simulation (id) ON DELETE CASCADE,
+    max_stake BIGINT,
+    max_round_stake BIGINT,
+    loss_limit BIGINT,
+    loss_limit_rounds BIGINT,
+    cool_off_until BIGINT
+);
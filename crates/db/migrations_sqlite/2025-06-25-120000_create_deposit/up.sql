@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE Deposit (
+    id TEXT NOT NULL PRIMARY KEY,
+    simulation_id TEXT NOT NULL REFERENCES Simulation (id) ON DELETE CASCADE,
+    amount BIGINT NOT NULL,
+    round BIGINT NOT NULL,
+    deposited_at BIGINT NOT NULL
+);
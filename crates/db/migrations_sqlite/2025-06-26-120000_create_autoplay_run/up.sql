@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE AutoplayRun (
+    id TEXT NOT NULL PRIMARY KEY,
+    simulation_id TEXT NOT NULL REFERENCES Simulation (id) ON DELETE CASCADE,
+    strategy TEXT NOT NULL,
+    first_round BIGINT NOT NULL,
+    rounds BIGINT NOT NULL,
+    bets BIGINT NOT NULL,
+    staked BIGINT NOT NULL,
+    returned BIGINT NOT NULL,
+    start_balance BIGINT NOT NULL,
+    end_balance BIGINT NOT NULL,
+    finished_at BIGINT NOT NULL
+);
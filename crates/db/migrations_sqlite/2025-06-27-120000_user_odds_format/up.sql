@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE Users ADD COLUMN odds_format TEXT NOT NULL DEFAULT 'decimal';
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE Forecast (
+    id TEXT NOT NULL PRIMARY KEY,
+    simulation_id TEXT NOT NULL REFERENCES Simulation (id) ON DELETE CASCADE,
+    game_id TEXT NOT NULL REFERENCES Game (id) ON DELETE CASCADE,
+    event BLOB NOT NULL,
+    probability DOUBLE NOT NULL
+);
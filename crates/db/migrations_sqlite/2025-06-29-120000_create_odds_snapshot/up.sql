@@ -0,0 +1,11 @@
+-- Your SQL goes here
+CREATE TABLE OddsSnapshot (
+    id TEXT NOT NULL PRIMARY KEY,
+    simulation_id TEXT NOT NULL REFERENCES Simulation (id) ON DELETE CASCADE,
+    game_id TEXT NOT NULL REFERENCES Game (id) ON DELETE CASCADE,
+    event BLOB NOT NULL,
+    coefficient INTEGER NOT NULL,
+    created_at BIGINT NOT NULL
+);
+
+CREATE INDEX odds_snapshot_game_id ON OddsSnapshot (game_id);
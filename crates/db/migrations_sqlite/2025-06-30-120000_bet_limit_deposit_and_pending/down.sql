@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE BetLimit DROP COLUMN pending_from_round;
+ALTER TABLE BetLimit DROP COLUMN pending_max_round_deposit;
+ALTER TABLE BetLimit DROP COLUMN pending_loss_limit_rounds;
+ALTER TABLE BetLimit DROP COLUMN pending_loss_limit;
+ALTER TABLE BetLimit DROP COLUMN pending_max_round_stake;
+ALTER TABLE BetLimit DROP COLUMN pending_max_stake;
+ALTER TABLE BetLimit DROP COLUMN max_round_deposit;
@@ -1,21 +1,131 @@
 pub mod models;
+mod pool;
 pub mod repository;
 pub mod schema;
+pub mod sql_types;
+
+pub use pool::{DBPool, DBPooledConnection};
 
 use diesel::pg::PgConnection;
-use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::prelude::*;
+use diesel::r2d2::{self, ManageConnection, Pool, R2D2Connection};
+#[cfg(feature = "sqlite")]
+use diesel::sqlite::SqliteConnection;
 use dotenv::dotenv;
 use std::env;
 
-pub type DBPool = Pool<ConnectionManager<PgConnection>>;
+#[derive(diesel::MultiConnection)]
+pub enum DBConnection {
+    Postgres(PgConnection),
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteConnection),
+}
+
+/// Opens connections to the backend selected by the scheme of the database url.
+pub struct DBConnectionManager {
+    database_url: String,
+}
+
+impl DBConnectionManager {
+    pub fn new(database_url: impl Into<String>) -> Self {
+        let database_url = database_url.into();
+        Self { database_url }
+    }
+
+    fn is_postgres(&self) -> bool {
+        self.database_url.starts_with("postgres://")
+            || self.database_url.starts_with("postgresql://")
+    }
+}
+
+impl ManageConnection for DBConnectionManager {
+    type Connection = DBConnection;
+    type Error = r2d2::Error;
+
+    fn connect(&self) -> Result<DBConnection, r2d2::Error> {
+        if self.is_postgres() {
+            return PgConnection::establish(&self.database_url)
+                .map(DBConnection::Postgres)
+                .map_err(r2d2::Error::ConnectionError);
+        }
+        #[cfg(feature = "sqlite")]
+        {
+            sqlite::establish(&self.database_url)
+        }
+        #[cfg(not(feature = "sqlite"))]
+        {
+            Err(r2d2::Error::ConnectionError(
+                ConnectionError::InvalidConnectionUrl(format!(
+                    "Unsupported database url: {}",
+                    self.database_url
+                )),
+            ))
+        }
+    }
+
+    fn is_valid(&self, conn: &mut DBConnection) -> Result<(), r2d2::Error> {
+        conn.ping().map_err(r2d2::Error::QueryError)
+    }
+
+    fn has_broken(&self, conn: &mut DBConnection) -> bool {
+        conn.is_broken()
+    }
+}
 
 pub fn init_pool() -> DBPool {
     dotenv().ok();
     let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
 
-    let manager = ConnectionManager::<PgConnection>::new(database_url);
-    Pool::builder()
+    let manager = DBConnectionManager::new(database_url);
+    #[cfg(feature = "sqlite")]
+    if !manager.is_postgres() {
+        sqlite::run_migrations(&manager).expect("Failed to migrate SQLite database");
+    }
+    let pool = Pool::builder()
         .max_size(16)
         .build(manager)
-        .expect("Failed to create DB pool")
+        .expect("Failed to create DB pool");
+
+    DBPool::new(pool)
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use anyhow::{Result, anyhow};
+    use diesel::prelude::*;
+    use diesel::r2d2::{self, ManageConnection};
+    use diesel::sqlite::SqliteConnection;
+    use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
+    use std::sync::Mutex;
+
+    use super::{DBConnection, DBConnectionManager};
+
+    const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations_sqlite");
+    static MIGRATION_LOCK: Mutex<()> = Mutex::new(());
+
+    pub fn establish(database_url: &str) -> Result<DBConnection, r2d2::Error> {
+        let mut conn =
+            SqliteConnection::establish(database_url).map_err(r2d2::Error::ConnectionError)?;
+        // Foreign keys are off by default in SQLite; the busy timeout lets pooled
+        // connections wait for each other instead of failing with `database is locked`.
+        diesel::sql_query("PRAGMA foreign_keys = ON")
+            .execute(&mut conn)
+            .map_err(r2d2::Error::QueryError)?;
+        diesel::sql_query("PRAGMA busy_timeout = 5000")
+            .execute(&mut conn)
+            .map_err(r2d2::Error::QueryError)?;
+
+        Ok(DBConnection::Sqlite(conn))
+    }
+
+    pub fn run_migrations(manager: &DBConnectionManager) -> Result<()> {
+        let _guard = MIGRATION_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let DBConnection::Sqlite(mut conn) = manager.connect()? else {
+            unreachable!("SQLite url opened a different backend");
+        };
+        conn.run_pending_migrations(MIGRATIONS)
+            .map_err(|e| anyhow!("{e}"))?;
+
+        Ok(())
+    }
 }
@@ -1,54 +1,157 @@
 use diesel::prelude::*;
-use uuid::Uuid;
+
+use crate::sql_types::DBUuid;
 
 #[derive(Queryable, Selectable)]
 #[diesel(table_name = crate::schema::team)]
-#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(check_for_backend(crate::MultiBackend))]
 pub struct TeamPostgres {
-    pub id: Uuid,
+    pub id: DBUuid,
     pub name: String,
 }
 
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = crate::schema::achievement)]
+#[diesel(check_for_backend(crate::MultiBackend))]
+pub struct AchievementPostgres {
+    pub id: DBUuid,
+    pub simulation_id: DBUuid,
+    pub kind: String,
+    pub round: i64,
+    pub unlocked_at: i64,
+}
+
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = crate::schema::autoplayrun)]
+#[diesel(check_for_backend(crate::MultiBackend))]
+pub struct AutoplayRunPostgres {
+    pub id: DBUuid,
+    pub simulation_id: DBUuid,
+    pub strategy: String,
+    pub first_round: i64,
+    pub rounds: i64,
+    pub bets: i64,
+    pub staked: i64,
+    pub returned: i64,
+    pub start_balance: i64,
+    pub end_balance: i64,
+    pub finished_at: i64,
+}
+
 #[derive(Queryable, Selectable, Insertable)]
 #[diesel(table_name = crate::schema::bet)]
-#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(check_for_backend(crate::MultiBackend))]
 pub struct BetPostgres {
-    pub id: Uuid,
-    pub simulation_id: Uuid,
+    pub id: DBUuid,
+    pub simulation_id: DBUuid,
     pub amount: i64,
     pub coefficient: i32,
-    pub game_id: Uuid,
+    pub game_id: DBUuid,
     pub event: Vec<u8>,
     pub is_won: Option<bool>,
 }
 
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = crate::schema::betlimit)]
+#[diesel(check_for_backend(crate::MultiBackend))]
+pub struct BetLimitPostgres {
+    pub simulation_id: DBUuid,
+    pub max_stake: Option<i64>,
+    pub max_round_stake: Option<i64>,
+    pub loss_limit: Option<i64>,
+    pub loss_limit_rounds: Option<i64>,
+    pub cool_off_until: Option<i64>,
+    pub max_round_deposit: Option<i64>,
+    pub pending_max_stake: Option<i64>,
+    pub pending_max_round_stake: Option<i64>,
+    pub pending_loss_limit: Option<i64>,
+    pub pending_loss_limit_rounds: Option<i64>,
+    pub pending_max_round_deposit: Option<i64>,
+    pub pending_from_round: Option<i64>,
+}
+
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = crate::schema::deposit)]
+#[diesel(check_for_backend(crate::MultiBackend))]
+pub struct DepositPostgres {
+    pub id: DBUuid,
+    pub simulation_id: DBUuid,
+    pub amount: i64,
+    pub round: i64,
+    pub deposited_at: i64,
+}
+
 #[derive(Queryable, Selectable, Insertable)]
 #[diesel(table_name = crate::schema::gamestat)]
-#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(check_for_backend(crate::MultiBackend))]
 pub struct GameStatPostgres {
-    pub id: Uuid,
-    pub game_id: Uuid,
+    pub id: DBUuid,
+    pub game_id: DBUuid,
     pub home_team_total: i16,
     pub guest_team_total: i16,
 }
 
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = crate::schema::forecast)]
+#[diesel(check_for_backend(crate::MultiBackend))]
+pub struct ForecastPostgres {
+    pub id: DBUuid,
+    pub simulation_id: DBUuid,
+    pub game_id: DBUuid,
+    pub event: Vec<u8>,
+    pub probability: f64,
+}
+
 #[derive(Queryable, Selectable, Insertable)]
 #[diesel(table_name = crate::schema::game)]
-#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(check_for_backend(crate::MultiBackend))]
 pub struct GamePostgres {
-    pub id: Uuid,
-    pub simulation_id: Uuid,
-    pub home_team_id: Uuid,
-    pub guest_team_id: Uuid,
+    pub id: DBUuid,
+    pub simulation_id: DBUuid,
+    pub home_team_id: DBUuid,
+    pub guest_team_id: DBUuid,
     pub round: i64,
 }
 
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = crate::schema::oddssnapshot)]
+#[diesel(check_for_backend(crate::MultiBackend))]
+pub struct OddsSnapshotPostgres {
+    pub id: DBUuid,
+    pub simulation_id: DBUuid,
+    pub game_id: DBUuid,
+    pub event: Vec<u8>,
+    pub coefficient: i32,
+    pub created_at: i64,
+}
+
 #[derive(Queryable, Selectable, Insertable)]
 #[diesel(table_name = crate::schema::simulation)]
-#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(check_for_backend(crate::MultiBackend))]
 pub struct SimulationPostgres {
-    pub id: Uuid,
-    pub ip: String,
+    pub id: DBUuid,
     pub round: i64,
     pub balance: i64,
+    pub user_id: DBUuid,
+    pub name: String,
+    pub archived_at: Option<i64>,
+}
+
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = crate::schema::users)]
+#[diesel(check_for_backend(crate::MultiBackend))]
+pub struct UserPostgres {
+    pub id: DBUuid,
+    pub login: String,
+    pub password_hash: String,
+    pub odds_format: String,
+}
+
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = crate::schema::session)]
+#[diesel(check_for_backend(crate::MultiBackend))]
+pub struct SessionPostgres {
+    pub token: String,
+    pub user_id: DBUuid,
+    pub expires_at: i64,
 }
@@ -0,0 +1,125 @@
+use anyhow::{Result, bail};
+use diesel::connection::{Connection, TransactionManager};
+use diesel::r2d2::{Pool, PooledConnection};
+use std::cell::RefCell;
+use std::ops::{Deref, DerefMut};
+
+use crate::{DBConnection, DBConnectionManager};
+
+type DBTransactionManager = <DBConnection as Connection>::TransactionManager;
+
+thread_local! {
+    /// Connection of the transaction running on this thread: `None` outside of a
+    /// transaction, `Some(None)` while a repository is using the connection.
+    static TRANSACTION: RefCell<Option<Option<PooledConnection<DBConnectionManager>>>> =
+        const { RefCell::new(None) };
+}
+
+/// Connection pool shared by the repositories.
+///
+/// While [`DBPool::atomically`] runs on a thread, every connection taken from any
+/// pool on that thread is the one holding the open transaction.
+#[derive(Clone)]
+pub struct DBPool(Pool<DBConnectionManager>);
+
+impl DBPool {
+    pub fn new(pool: Pool<DBConnectionManager>) -> Self {
+        Self(pool)
+    }
+
+    pub fn get(&self) -> Result<DBPooledConnection> {
+        let transaction = TRANSACTION.with_borrow_mut(|tx| tx.as_mut().map(Option::take));
+        match transaction {
+            None => Ok(DBPooledConnection::Pooled(self.0.get()?)),
+            Some(Some(connection)) => Ok(DBPooledConnection::Transaction(Some(connection))),
+            Some(None) => bail!("Transaction connection is already in use"),
+        }
+    }
+
+    /// Runs `work` inside one database transaction, committing it if `work`
+    /// succeeds and rolling it back otherwise. Nested calls join the outer one.
+    pub fn atomically(&self, work: &mut dyn FnMut() -> Result<()>) -> Result<()> {
+        if TRANSACTION.with_borrow(Option::is_some) {
+            return work();
+        }
+        let mut connection = self.0.get()?;
+        DBTransactionManager::begin_transaction(&mut connection)?;
+        let transaction = ActiveTransaction::start(connection);
+        let result = work();
+        let mut connection = transaction.finish()?;
+        match result {
+            Ok(()) => DBTransactionManager::commit_transaction(&mut connection)?,
+            Err(_) => DBTransactionManager::rollback_transaction(&mut connection)?,
+        }
+
+        result
+    }
+}
+
+/// Puts the transaction connection into the thread slot and takes it back,
+/// rolling back if the work panicked.
+struct ActiveTransaction;
+
+impl ActiveTransaction {
+    fn start(connection: PooledConnection<DBConnectionManager>) -> Self {
+        TRANSACTION.with_borrow_mut(|tx| *tx = Some(Some(connection)));
+        Self
+    }
+
+    fn finish(self) -> Result<PooledConnection<DBConnectionManager>> {
+        let connection = TRANSACTION.with_borrow_mut(Option::take).flatten();
+        std::mem::forget(self);
+        match connection {
+            Some(connection) => Ok(connection),
+            None => bail!("Transaction connection wasn't returned"),
+        }
+    }
+}
+
+impl Drop for ActiveTransaction {
+    fn drop(&mut self) {
+        if let Some(Some(mut connection)) = TRANSACTION.with_borrow_mut(Option::take) {
+            let _ = DBTransactionManager::rollback_transaction(&mut connection);
+        }
+    }
+}
+
+/// Connection handed out by [`DBPool::get`].
+pub enum DBPooledConnection {
+    Pooled(PooledConnection<DBConnectionManager>),
+    /// Borrowed from the running transaction and given back on drop.
+    Transaction(Option<PooledConnection<DBConnectionManager>>),
+}
+
+impl Deref for DBPooledConnection {
+    type Target = DBConnection;
+
+    fn deref(&self) -> &DBConnection {
+        match self {
+            Self::Pooled(connection) => connection,
+            Self::Transaction(connection) => connection.as_ref().unwrap(),
+        }
+    }
+}
+
+impl DerefMut for DBPooledConnection {
+    fn deref_mut(&mut self) -> &mut DBConnection {
+        match self {
+            Self::Pooled(connection) => connection,
+            Self::Transaction(connection) => connection.as_mut().unwrap(),
+        }
+    }
+}
+
+impl Drop for DBPooledConnection {
+    fn drop(&mut self) {
+        if let Self::Transaction(connection) = self {
+            let connection = connection.take();
+            TRANSACTION.with_borrow_mut(|tx| {
+                if let Some(slot) = tx {
+                    *slot = connection;
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use anyhow::{Result, anyhow};
+use diesel::prelude::*;
+
+use crate::DBPool;
+use crate::models::AchievementPostgres;
+use crate::sql_types::DBUuid;
+use application::repository::IAchievementRepo;
+use domain::{
+    entity::{Achievement, Simulation},
+    value_object::Id,
+};
+
+impl From<Achievement> for AchievementPostgres {
+    fn from(a: Achievement) -> Self {
+        Self {
+            id: a.id().into(),
+            simulation_id: a.simulation_id().into(),
+            kind: a.kind().code().to_string(),
+            round: a.round() as i64,
+            unlocked_at: a.unlocked_at(),
+        }
+    }
+}
+
+impl TryFrom<AchievementPostgres> for Achievement {
+    type Error = anyhow::Error;
+
+    fn try_from(a: AchievementPostgres) -> Result<Self> {
+        let kind = a
+            .kind
+            .parse()
+            .map_err(|_| anyhow!("Unknown achievement kind {}", a.kind))?;
+
+        Ok(Achievement::new(
+            a.id.into(),
+            a.simulation_id.into(),
+            kind,
+            a.round as u32,
+            a.unlocked_at,
+        ))
+    }
+}
+
+pub struct AchievementRepo {
+    pool: DBPool,
+}
+
+impl AchievementRepo {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl IAchievementRepo for AchievementRepo {
+    fn add(&self, achievement: Achievement) -> Result<()> {
+        use crate::schema::achievement;
+
+        let mut connection = self.pool.get()?;
+        let achievement = AchievementPostgres::from(achievement);
+        diesel::insert_into(achievement::table)
+            .values(&achievement)
+            .execute(&mut *connection)?;
+
+        Ok(())
+    }
+
+    fn achievements_by_simulation_id(&self, sim_id: Id<Simulation>) -> Result<Vec<Achievement>> {
+        use crate::schema::achievement::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        achievement
+            .filter(simulation_id.eq(DBUuid::from(sim_id)))
+            .order(round.asc())
+            .select(AchievementPostgres::as_select())
+            .load(&mut *connection)?
+            .into_iter()
+            .map(Achievement::try_from)
+            .collect()
+    }
+
+    fn next_id(&self) -> Id<Achievement> {
+        Id::new()
+    }
+}
@@ -0,0 +1,96 @@
+use anyhow::{Result, anyhow};
+use diesel::prelude::*;
+
+use crate::DBPool;
+use crate::models::AutoplayRunPostgres;
+use crate::sql_types::DBUuid;
+use application::repository::IAutoplayRunRepo;
+use domain::{
+    entity::{AutoplayRun, Simulation},
+    value_object::{Amount, Id},
+};
+
+impl From<AutoplayRun> for AutoplayRunPostgres {
+    fn from(r: AutoplayRun) -> Self {
+        Self {
+            id: r.id().into(),
+            simulation_id: r.simulation_id().into(),
+            strategy: r.strategy().code().to_string(),
+            first_round: r.first_round() as i64,
+            rounds: r.rounds() as i64,
+            bets: r.bets() as i64,
+            staked: r.staked().clear_value(),
+            returned: r.returned().clear_value(),
+            start_balance: r.start_balance().clear_value(),
+            end_balance: r.end_balance().clear_value(),
+            finished_at: r.finished_at(),
+        }
+    }
+}
+
+impl TryFrom<AutoplayRunPostgres> for AutoplayRun {
+    type Error = anyhow::Error;
+
+    fn try_from(r: AutoplayRunPostgres) -> Result<Self> {
+        let strategy = r
+            .strategy
+            .parse()
+            .map_err(|_| anyhow!("Unknown strategy {}", r.strategy))?;
+
+        Ok(AutoplayRun::new(
+            r.id.into(),
+            r.simulation_id.into(),
+            strategy,
+            r.first_round as u32,
+            r.rounds as u32,
+            r.bets as u32,
+            Amount::new(r.staked, None)?,
+            Amount::new(r.returned, None)?,
+            Amount::new(r.start_balance, None)?,
+            Amount::new(r.end_balance, None)?,
+            r.finished_at,
+        ))
+    }
+}
+
+pub struct AutoplayRunRepo {
+    pool: DBPool,
+}
+
+impl AutoplayRunRepo {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl IAutoplayRunRepo for AutoplayRunRepo {
+    fn add(&self, run: AutoplayRun) -> Result<()> {
+        use crate::schema::autoplayrun;
+
+        let mut connection = self.pool.get()?;
+        let run = AutoplayRunPostgres::from(run);
+        diesel::insert_into(autoplayrun::table)
+            .values(&run)
+            .execute(&mut *connection)?;
+
+        Ok(())
+    }
+
+    fn runs_by_simulation_id(&self, sim_id: Id<Simulation>) -> Result<Vec<AutoplayRun>> {
+        use crate::schema::autoplayrun::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        autoplayrun
+            .filter(simulation_id.eq(DBUuid::from(sim_id)))
+            .order((finished_at.asc(), first_round.asc()))
+            .select(AutoplayRunPostgres::as_select())
+            .load(&mut *connection)?
+            .into_iter()
+            .map(AutoplayRun::try_from)
+            .collect()
+    }
+
+    fn next_id(&self) -> Id<AutoplayRun> {
+        Id::new()
+    }
+}
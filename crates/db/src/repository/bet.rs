@@ -4,20 +4,21 @@ use rmp_serde;
 
 use crate::DBPool;
 use crate::models::BetPostgres;
-use application::repository::IBetRepo;
+use crate::sql_types::DBUuid;
+use application::repository::{IBetRepo, SettledTotals};
 use domain::{
-    entity::Bet,
+    entity::{Bet, Game, Simulation},
     value_object::{Amount, Coefficient, Id, MIN_BET_AMOUNT},
 };
 
 impl From<Bet> for BetPostgres {
     fn from(b: Bet) -> Self {
         Self {
-            id: b.id().value(),
-            simulation_id: b.simulation_id().value(),
+            id: b.id().into(),
+            simulation_id: b.simulation_id().into(),
             amount: b.amount().clear_value(),
             coefficient: b.coefficient().clear_value(),
-            game_id: b.game_id().value(),
+            game_id: b.game_id().into(),
             event: rmp_serde::to_vec(&b.event()).unwrap(),
             is_won: b.is_won(),
         }
@@ -56,19 +57,37 @@ impl IBetRepo for BetRepo {
         let bet = BetPostgres::from(bet);
         diesel::insert_into(bet::table)
             .values(&bet)
-            .execute(&mut connection)?;
+            .execute(&mut *connection)?;
 
         Ok(())
     }
 
-    fn min_coefficient_lose(&self) -> Option<Coefficient> {
+    fn add_all(&self, bets: Vec<Bet>) -> Result<()> {
+        use crate::schema::bet;
+
+        let mut connection = self.pool.get()?;
+        connection.transaction(|connection| {
+            for bet in bets {
+                diesel::insert_into(bet::table)
+                    .values(&BetPostgres::from(bet))
+                    .execute(connection)?;
+            }
+
+            diesel::QueryResult::Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    fn min_coefficient_lose(&self, sim_id: Id<Simulation>) -> Option<Coefficient> {
         use crate::schema::bet::dsl::*;
 
         let mut connection = self.pool.get().unwrap();
         let value = bet
+            .filter(simulation_id.eq(DBUuid::from(sim_id)))
             .filter(is_won.eq(Some(false)))
             .select(min(coefficient))
-            .first::<Option<i32>>(&mut connection)
+            .first::<Option<i32>>(&mut *connection)
             .ok()
             .flatten();
 
@@ -82,13 +101,14 @@ impl IBetRepo for BetRepo {
         Id::new()
     }
 
-    fn not_calculated_bets(&self) -> Vec<Bet> {
+    fn not_calculated_bets(&self, sim_id: Id<Simulation>) -> Vec<Bet> {
         use crate::schema::bet::dsl::*;
 
         let mut connection = self.pool.get().unwrap();
-        bet.filter(is_won.is_null())
+        bet.filter(simulation_id.eq(DBUuid::from(sim_id)))
+            .filter(is_won.is_null())
             .select(BetPostgres::as_select())
-            .load(&mut connection)
+            .load(&mut *connection)
             .ok()
             .unwrap_or_default()
             .into_iter()
@@ -96,6 +116,72 @@ impl IBetRepo for BetRepo {
             .collect()
     }
 
+    fn bets_by_game_id(&self, g_id: Id<Game>) -> Result<Vec<Bet>> {
+        use crate::schema::bet::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        let recs = bet
+            .filter(game_id.eq(DBUuid::from(g_id)))
+            .select(BetPostgres::as_select())
+            .load(&mut *connection)?
+            .into_iter()
+            .map(|b: BetPostgres| b.into())
+            .collect();
+
+        Ok(recs)
+    }
+
+    fn bets_by_simulation_id(&self, sim_id: Id<Simulation>) -> Result<Vec<Bet>> {
+        use crate::schema::bet::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        let recs = bet
+            .filter(simulation_id.eq(DBUuid::from(sim_id)))
+            .select(BetPostgres::as_select())
+            .load(&mut *connection)?
+            .into_iter()
+            .map(|b: BetPostgres| b.into())
+            .collect();
+
+        Ok(recs)
+    }
+
+    fn settled_totals(&self, rounds: Option<u32>) -> Result<Vec<SettledTotals>> {
+        use crate::schema::{bet, game, simulation};
+        use diesel::{dsl::sql, sql_types::BigInt};
+
+        let mut connection = self.pool.get()?;
+        let window = rounds.map_or(i64::MAX, i64::from);
+        // Payouts are counted the way `Bet::payout` does: whole units times the coefficient.
+        let recs = bet::table
+            .inner_join(game::table)
+            .inner_join(simulation::table)
+            .filter(simulation::archived_at.is_null())
+            .filter(bet::is_won.is_not_null())
+            .filter(game::round.gt(simulation::round - window))
+            .group_by(bet::simulation_id)
+            .select((
+                bet::simulation_id,
+                sql::<BigInt>("CAST(COUNT(*) AS BIGINT)"),
+                sql::<BigInt>("CAST(SUM(bet.amount) AS BIGINT)"),
+                sql::<BigInt>(
+                    "CAST(SUM(CASE WHEN bet.is_won THEN bet.amount / 100 * bet.coefficient \
+                     ELSE 0 END) AS BIGINT)",
+                ),
+            ))
+            .load::<(DBUuid, i64, i64, i64)>(&mut *connection)?
+            .into_iter()
+            .map(|(sim_id, bets, staked, returned)| SettledTotals {
+                simulation_id: sim_id.into(),
+                bets: bets as usize,
+                staked,
+                returned,
+            })
+            .collect();
+
+        Ok(recs)
+    }
+
     fn update_status(&self, bet: Bet) -> Result<()> {
         use crate::schema::bet::{
             self,
@@ -107,7 +193,7 @@ impl IBetRepo for BetRepo {
         diesel::update(bet::table)
             .filter(id.eq(&bet.id))
             .set(is_won.eq(bet.is_won))
-            .execute(&mut connection)?;
+            .execute(&mut *connection)?;
 
         Ok(())
     }
@@ -0,0 +1,118 @@
+use anyhow::Result;
+use diesel::prelude::*;
+
+use super::not_found;
+use crate::DBPool;
+use crate::models::BetLimitPostgres;
+use crate::sql_types::DBUuid;
+use application::{error::Entity, repository::IBetLimitRepo};
+use domain::{
+    entity::Simulation,
+    value_object::{Amount, BetLimits, Id, LossLimit, PendingLimits},
+};
+
+impl BetLimitPostgres {
+    fn new(simulation_id: Id<Simulation>, limits: BetLimits) -> Self {
+        let pending = limits.pending;
+        let pending_loss_limit = pending.and_then(|p| p.loss_limit);
+        Self {
+            simulation_id: simulation_id.into(),
+            max_stake: limits.max_stake.map(Amount::clear_value),
+            max_round_stake: limits.max_round_stake.map(Amount::clear_value),
+            loss_limit: limits.loss_limit.map(|l| l.amount.clear_value()),
+            loss_limit_rounds: limits.loss_limit.map(|l| l.rounds as i64),
+            cool_off_until: limits.cool_off_until,
+            max_round_deposit: limits.max_round_deposit.map(Amount::clear_value),
+            pending_max_stake: pending.and_then(|p| p.max_stake).map(Amount::clear_value),
+            pending_max_round_stake: pending
+                .and_then(|p| p.max_round_stake)
+                .map(Amount::clear_value),
+            pending_loss_limit: pending_loss_limit.map(|l| l.amount.clear_value()),
+            pending_loss_limit_rounds: pending_loss_limit.map(|l| l.rounds as i64),
+            pending_max_round_deposit: pending
+                .and_then(|p| p.max_round_deposit)
+                .map(Amount::clear_value),
+            pending_from_round: pending.map(|p| p.from_round as i64),
+        }
+    }
+}
+
+impl TryFrom<BetLimitPostgres> for BetLimits {
+    type Error = anyhow::Error;
+
+    fn try_from(l: BetLimitPostgres) -> Result<Self> {
+        let amount = |value: Option<i64>| value.map(|v| Amount::new(v, None)).transpose();
+        let loss_limit = |value: Option<i64>, rounds: Option<i64>| -> Result<_> {
+            Ok(match (amount(value)?, rounds) {
+                (Some(amount), Some(rounds)) => Some(LossLimit {
+                    amount,
+                    rounds: rounds as u32,
+                }),
+                _ => None,
+            })
+        };
+        let pending = match l.pending_from_round {
+            Some(from_round) => Some(PendingLimits {
+                max_stake: amount(l.pending_max_stake)?,
+                max_round_stake: amount(l.pending_max_round_stake)?,
+                loss_limit: loss_limit(l.pending_loss_limit, l.pending_loss_limit_rounds)?,
+                max_round_deposit: amount(l.pending_max_round_deposit)?,
+                from_round: from_round as u32,
+            }),
+            None => None,
+        };
+
+        Ok(BetLimits {
+            max_stake: amount(l.max_stake)?,
+            max_round_stake: amount(l.max_round_stake)?,
+            loss_limit: loss_limit(l.loss_limit, l.loss_limit_rounds)?,
+            max_round_deposit: amount(l.max_round_deposit)?,
+            cool_off_until: l.cool_off_until,
+            pending,
+        })
+    }
+}
+
+pub struct BetLimitRepo {
+    pool: DBPool,
+}
+
+impl BetLimitRepo {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl IBetLimitRepo for BetLimitRepo {
+    fn limits_by_simulation_id(&self, sim_id: Id<Simulation>) -> Result<BetLimits> {
+        use crate::schema::betlimit::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        betlimit
+            .filter(simulation_id.eq(DBUuid::from(sim_id)))
+            .select(BetLimitPostgres::as_select())
+            .first(&mut *connection)
+            .optional()?
+            .map_or(Ok(BetLimits::default()), BetLimits::try_from)
+    }
+
+    fn set_limits(&self, sim_id: Id<Simulation>, limits: BetLimits) -> Result<()> {
+        use crate::schema::{betlimit, simulation};
+
+        let mut connection = self.pool.get()?;
+        let rec = BetLimitPostgres::new(sim_id, limits);
+        connection.transaction(|connection| {
+            simulation::table
+                .find(DBUuid::from(sim_id))
+                .select(simulation::id)
+                .first::<DBUuid>(connection)
+                .map_err(not_found(Entity::Simulation))?;
+            diesel::delete(betlimit::table.find(DBUuid::from(sim_id))).execute(connection)?;
+            diesel::insert_into(betlimit::table)
+                .values(&rec)
+                .execute(connection)?;
+
+            anyhow::Ok(())
+        })
+    }
+}
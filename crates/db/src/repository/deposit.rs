@@ -0,0 +1,98 @@
+use anyhow::Result;
+use diesel::prelude::*;
+
+use crate::DBPool;
+use crate::models::DepositPostgres;
+use crate::sql_types::DBUuid;
+use application::repository::IDepositRepo;
+use domain::{
+    entity::{Deposit, Simulation},
+    value_object::{Amount, Id},
+};
+
+impl From<Deposit> for DepositPostgres {
+    fn from(d: Deposit) -> Self {
+        Self {
+            id: d.id().into(),
+            simulation_id: d.simulation_id().into(),
+            amount: d.amount().clear_value(),
+            round: d.round() as i64,
+            deposited_at: d.deposited_at(),
+        }
+    }
+}
+
+impl TryFrom<DepositPostgres> for Deposit {
+    type Error = anyhow::Error;
+
+    fn try_from(d: DepositPostgres) -> Result<Self> {
+        Ok(Deposit::new(
+            d.id.into(),
+            d.simulation_id.into(),
+            Amount::new(d.amount, None)?,
+            d.round as u32,
+            d.deposited_at,
+        ))
+    }
+}
+
+pub struct DepositRepo {
+    pool: DBPool,
+}
+
+impl DepositRepo {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl IDepositRepo for DepositRepo {
+    fn add(&self, deposit: Deposit) -> Result<()> {
+        use crate::schema::deposit;
+
+        let mut connection = self.pool.get()?;
+        let deposit = DepositPostgres::from(deposit);
+        diesel::insert_into(deposit::table)
+            .values(&deposit)
+            .execute(&mut *connection)?;
+
+        Ok(())
+    }
+
+    fn deposits_by_simulation_id(&self, sim_id: Id<Simulation>) -> Result<Vec<Deposit>> {
+        use crate::schema::deposit::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        deposit
+            .filter(simulation_id.eq(DBUuid::from(sim_id)))
+            .order((round.asc(), deposited_at.asc()))
+            .select(DepositPostgres::as_select())
+            .load(&mut *connection)?
+            .into_iter()
+            .map(Deposit::try_from)
+            .collect()
+    }
+
+    fn deposited_totals(&self) -> Result<Vec<(Id<Simulation>, Amount)>> {
+        use crate::schema::{deposit, simulation};
+        use diesel::{dsl::sql, sql_types::BigInt};
+
+        let mut connection = self.pool.get()?;
+        deposit::table
+            .inner_join(simulation::table)
+            .filter(simulation::archived_at.is_null())
+            .group_by(deposit::simulation_id)
+            .select((
+                deposit::simulation_id,
+                sql::<BigInt>("CAST(SUM(deposit.amount) AS BIGINT)"),
+            ))
+            .load::<(DBUuid, i64)>(&mut *connection)?
+            .into_iter()
+            .map(|(sim_id, amount)| Ok((sim_id.into(), Amount::new(amount, None)?)))
+            .collect()
+    }
+
+    fn next_id(&self) -> Id<Deposit> {
+        Id::new()
+    }
+}
@@ -0,0 +1,78 @@
+use anyhow::Result;
+use diesel::prelude::*;
+
+use crate::DBPool;
+use crate::models::ForecastPostgres;
+use application::repository::IForecastRepo;
+use domain::{entity::Forecast, value_object::Id};
+
+impl From<Forecast> for ForecastPostgres {
+    fn from(f: Forecast) -> Self {
+        Self {
+            id: f.id().into(),
+            simulation_id: f.simulation_id().into(),
+            game_id: f.game_id().into(),
+            event: rmp_serde::to_vec(&f.event()).unwrap(),
+            probability: f.probability(),
+        }
+    }
+}
+
+impl TryFrom<ForecastPostgres> for Forecast {
+    type Error = anyhow::Error;
+
+    fn try_from(f: ForecastPostgres) -> Result<Self> {
+        Ok(Forecast::new(
+            f.id.into(),
+            f.simulation_id.into(),
+            f.game_id.into(),
+            rmp_serde::from_slice(&f.event)?,
+            f.probability,
+        ))
+    }
+}
+
+pub struct ForecastRepo {
+    pool: DBPool,
+}
+
+impl ForecastRepo {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl IForecastRepo for ForecastRepo {
+    fn add_all(&self, forecasts: Vec<Forecast>) -> Result<()> {
+        use crate::schema::forecast;
+
+        let mut connection = self.pool.get()?;
+        connection.transaction(|connection| {
+            for forecast in forecasts {
+                diesel::insert_into(forecast::table)
+                    .values(&ForecastPostgres::from(forecast))
+                    .execute(connection)?;
+            }
+
+            diesel::QueryResult::Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    fn all_forecasts(&self) -> Result<Vec<Forecast>> {
+        use crate::schema::forecast::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        forecast
+            .select(ForecastPostgres::as_select())
+            .load(&mut *connection)?
+            .into_iter()
+            .map(Forecast::try_from)
+            .collect()
+    }
+
+    fn next_id(&self) -> Id<Forecast> {
+        Id::new()
+    }
+}
@@ -1,9 +1,11 @@
 use anyhow::Result;
 use diesel::prelude::*;
-use uuid::Uuid;
 
+use super::not_found;
 use crate::DBPool;
 use crate::models::GamePostgres;
+use crate::sql_types::DBUuid;
+use application::error::Entity;
 use application::repository::IGameRepo;
 use domain::{
     entity::{Game, Simulation, Team},
@@ -13,10 +15,10 @@ use domain::{
 impl From<Game> for GamePostgres {
     fn from(g: Game) -> Self {
         Self {
-            id: g.id().value(),
-            simulation_id: g.simulation_id().value(),
-            home_team_id: g.home_team_id().value(),
-            guest_team_id: g.guest_team_id().value(),
+            id: g.id().into(),
+            simulation_id: g.simulation_id().into(),
+            home_team_id: g.home_team_id().into(),
+            guest_team_id: g.guest_team_id().into(),
             round: g.round() as i64,
         }
     }
@@ -52,7 +54,24 @@ impl IGameRepo for GameRepo {
         let game = GamePostgres::from(game);
         diesel::insert_into(game::table)
             .values(&game)
-            .execute(&mut connection)?;
+            .execute(&mut *connection)?;
+
+        Ok(())
+    }
+
+    fn add_all(&self, games: Vec<Game>) -> Result<()> {
+        use crate::schema::game;
+
+        let mut connection = self.pool.get()?;
+        connection.transaction(|connection| {
+            for game in games {
+                diesel::insert_into(game::table)
+                    .values(&GamePostgres::from(game))
+                    .execute(connection)?;
+            }
+
+            diesel::QueryResult::Ok(())
+        })?;
 
         Ok(())
     }
@@ -62,9 +81,10 @@ impl IGameRepo for GameRepo {
 
         let mut connection = self.pool.get()?;
         let rec = game
-            .filter(id.eq(game_id.value()))
+            .filter(id.eq(DBUuid::from(game_id)))
             .select(GamePostgres::as_select())
-            .first(&mut connection)?;
+            .first(&mut *connection)
+            .map_err(not_found(Entity::Game))?;
 
         Ok(rec.into())
     }
@@ -74,12 +94,29 @@ impl IGameRepo for GameRepo {
 
         let mut connection = self.pool.get()?;
         let recs = game
-            .filter(simulation_id.eq(sim_id.value()))
+            .filter(simulation_id.eq(DBUuid::from(sim_id)))
             .filter(round.eq(rnd as i64))
             .select(id)
-            .load(&mut connection)?
+            .load(&mut *connection)?
+            .into_iter()
+            .map(|elem: DBUuid| elem.into())
+            .collect();
+
+        Ok(recs)
+    }
+
+    fn games_by_simulation_id(&self, sim_id: Id<Simulation>, max_round: u32) -> Result<Vec<Game>> {
+        use crate::schema::game::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        let recs = game
+            .filter(simulation_id.eq(DBUuid::from(sim_id)))
+            .filter(round.le(max_round as i64))
+            .order(round.asc())
+            .select(GamePostgres::as_select())
+            .load(&mut *connection)?
             .into_iter()
-            .map(|elem: Uuid| elem.into())
+            .map(Game::from)
             .collect();
 
         Ok(recs)
@@ -95,24 +132,24 @@ impl IGameRepo for GameRepo {
 
         let mut connection = self.pool.get()?;
         let mut games = game
-            .filter(simulation_id.eq(sim_id.value()))
-            .filter(home_team_id.eq(team_id.value()))
+            .filter(simulation_id.eq(DBUuid::from(sim_id)))
+            .filter(home_team_id.eq(DBUuid::from(team_id)))
             .select(id)
             .order(round.desc())
             .limit(cnt as i64)
-            .load(&mut connection)?
+            .load(&mut *connection)?
             .into_iter()
-            .map(|elem: Uuid| (elem.into(), true))
+            .map(|elem: DBUuid| (elem.into(), true))
             .collect::<Vec<(Id<Game>, bool)>>();
         let mut guest_games = game
-            .filter(simulation_id.eq(sim_id.value()))
-            .filter(guest_team_id.eq(team_id.value()))
+            .filter(simulation_id.eq(DBUuid::from(sim_id)))
+            .filter(guest_team_id.eq(DBUuid::from(team_id)))
             .select(id)
             .order(round.desc())
             .limit(cnt as i64)
-            .load(&mut connection)?
+            .load(&mut *connection)?
             .into_iter()
-            .map(|elem: Uuid| (elem.into(), false))
+            .map(|elem: DBUuid| (elem.into(), false))
             .collect();
 
         games.append(&mut guest_games);
@@ -131,26 +168,26 @@ impl IGameRepo for GameRepo {
 
         let mut connection = self.pool.get()?;
         let mut games = game
-            .filter(simulation_id.eq(sim_id.value()))
-            .filter(home_team_id.eq(ht_id.value()))
-            .filter(guest_team_id.eq(gt_id.value()))
+            .filter(simulation_id.eq(DBUuid::from(sim_id)))
+            .filter(home_team_id.eq(DBUuid::from(ht_id)))
+            .filter(guest_team_id.eq(DBUuid::from(gt_id)))
             .select(id)
             .order(round.desc())
             .limit(cnt as i64)
-            .load(&mut connection)?
+            .load(&mut *connection)?
             .into_iter()
-            .map(|elem: Uuid| (elem.into(), true))
+            .map(|elem: DBUuid| (elem.into(), true))
             .collect::<Vec<(Id<Game>, bool)>>();
         let mut inverse_games = game
-            .filter(simulation_id.eq(sim_id.value()))
-            .filter(home_team_id.eq(gt_id.value()))
-            .filter(guest_team_id.eq(ht_id.value()))
+            .filter(simulation_id.eq(DBUuid::from(sim_id)))
+            .filter(home_team_id.eq(DBUuid::from(gt_id)))
+            .filter(guest_team_id.eq(DBUuid::from(ht_id)))
             .select(id)
             .order(round.desc())
             .limit(cnt as i64)
-            .load(&mut connection)?
+            .load(&mut *connection)?
             .into_iter()
-            .map(|elem: Uuid| (elem.into(), false))
+            .map(|elem: DBUuid| (elem.into(), false))
             .collect();
 
         games.append(&mut inverse_games);
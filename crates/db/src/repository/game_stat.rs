@@ -2,19 +2,22 @@ use anyhow::Result;
 use diesel::prelude::*;
 use std::cmp::Ordering;
 
+use super::not_found;
 use crate::DBPool;
 use crate::models::GameStatPostgres;
+use crate::sql_types::DBUuid;
+use application::error::Entity;
 use application::repository::IGameStatRepo;
 use domain::{
-    entity::{Game, GameStat},
+    entity::{Game, GameStat, Simulation},
     value_object::{Id, Winner},
 };
 
 impl From<GameStat> for GameStatPostgres {
     fn from(g: GameStat) -> Self {
         Self {
-            id: g.id().value(),
-            game_id: g.game_id().value(),
+            id: g.id().into(),
+            game_id: g.game_id().into(),
             home_team_total: g.home_team_total() as i16,
             guest_team_total: g.guest_team_total() as i16,
         }
@@ -50,19 +53,58 @@ impl IGameStatRepo for GameStatRepo {
         let game_stat = GameStatPostgres::from(game_stat);
         diesel::insert_into(gamestat::table)
             .values(&game_stat)
-            .execute(&mut connection)?;
+            .execute(&mut *connection)?;
 
         Ok(())
     }
 
+    fn add_all(&self, game_stats: Vec<GameStat>) -> Result<()> {
+        use crate::schema::gamestat;
+
+        let mut connection = self.pool.get()?;
+        connection.transaction(|connection| {
+            for game_stat in game_stats {
+                diesel::insert_into(gamestat::table)
+                    .values(&GameStatPostgres::from(game_stat))
+                    .execute(connection)?;
+            }
+
+            diesel::QueryResult::Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    fn game_stats_by_simulation_id(
+        &self,
+        sim_id: Id<Simulation>,
+        max_round: u32,
+    ) -> Result<Vec<GameStat>> {
+        use crate::schema::{game, gamestat};
+
+        let mut connection = self.pool.get()?;
+        let recs = gamestat::table
+            .inner_join(game::table)
+            .filter(game::simulation_id.eq(DBUuid::from(sim_id)))
+            .filter(game::round.le(max_round as i64))
+            .select(GameStatPostgres::as_select())
+            .load(&mut *connection)?
+            .into_iter()
+            .map(GameStat::from)
+            .collect();
+
+        Ok(recs)
+    }
+
     fn game_stat_by_game_id(&self, g_id: Id<Game>) -> Result<GameStat> {
         use crate::schema::gamestat::dsl::*;
 
         let mut connection = self.pool.get()?;
         let rec = gamestat
-            .filter(game_id.eq(g_id.value()))
+            .filter(game_id.eq(DBUuid::from(g_id)))
             .select(GameStatPostgres::as_select())
-            .first(&mut connection)?;
+            .first(&mut *connection)
+            .map_err(not_found(Entity::GameStat))?;
 
         Ok(rec.into())
     }
@@ -80,9 +122,9 @@ impl IGameStatRepo for GameStatRepo {
 
         let mut connection = self.pool.get().unwrap();
         let rec = gamestat
-            .filter(game_id.eq(g_id.value()))
+            .filter(game_id.eq(DBUuid::from(g_id)))
             .select(GameStatPostgres::as_select())
-            .first(&mut connection)
+            .first(&mut *connection)
             .ok();
         if let Some(rec) = rec {
             let score = if is_home {
@@ -0,0 +1,86 @@
+use anyhow::Result;
+use diesel::prelude::*;
+
+use crate::DBPool;
+use crate::models::OddsSnapshotPostgres;
+use crate::sql_types::DBUuid;
+use application::repository::IOddsSnapshotRepo;
+use domain::{
+    entity::{Game, OddsSnapshot},
+    value_object::Id,
+};
+
+impl From<OddsSnapshot> for OddsSnapshotPostgres {
+    fn from(s: OddsSnapshot) -> Self {
+        Self {
+            id: s.id().into(),
+            simulation_id: s.simulation_id().into(),
+            game_id: s.game_id().into(),
+            event: rmp_serde::to_vec(&s.event()).unwrap(),
+            coefficient: s.coefficient().clear_value(),
+            created_at: s.created_at(),
+        }
+    }
+}
+
+impl TryFrom<OddsSnapshotPostgres> for OddsSnapshot {
+    type Error = anyhow::Error;
+
+    fn try_from(s: OddsSnapshotPostgres) -> Result<Self> {
+        Ok(OddsSnapshot::new(
+            s.id.into(),
+            s.simulation_id.into(),
+            s.game_id.into(),
+            rmp_serde::from_slice(&s.event)?,
+            s.coefficient.try_into()?,
+            s.created_at,
+        ))
+    }
+}
+
+pub struct OddsSnapshotRepo {
+    pool: DBPool,
+}
+
+impl OddsSnapshotRepo {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl IOddsSnapshotRepo for OddsSnapshotRepo {
+    fn add_all(&self, snapshots: Vec<OddsSnapshot>) -> Result<()> {
+        use crate::schema::oddssnapshot;
+
+        let mut connection = self.pool.get()?;
+        connection.transaction(|connection| {
+            for snapshot in snapshots {
+                diesel::insert_into(oddssnapshot::table)
+                    .values(&OddsSnapshotPostgres::from(snapshot))
+                    .execute(connection)?;
+            }
+
+            diesel::QueryResult::Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    fn snapshots_by_game_id(&self, g_id: Id<Game>) -> Result<Vec<OddsSnapshot>> {
+        use crate::schema::oddssnapshot::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        oddssnapshot
+            .filter(game_id.eq(DBUuid::from(g_id)))
+            .order((created_at.asc(), id.asc()))
+            .select(OddsSnapshotPostgres::as_select())
+            .load(&mut *connection)?
+            .into_iter()
+            .map(OddsSnapshot::try_from)
+            .collect()
+    }
+
+    fn next_id(&self) -> Id<OddsSnapshot> {
+        Id::new()
+    }
+}
@@ -0,0 +1,69 @@
+use anyhow::Result;
+use diesel::prelude::*;
+
+use crate::DBPool;
+use crate::models::SessionPostgres;
+use application::repository::ISessionRepo;
+use domain::entity::Session;
+
+impl From<Session> for SessionPostgres {
+    fn from(s: Session) -> Self {
+        Self {
+            token: s.token().to_string(),
+            user_id: s.user_id().into(),
+            expires_at: s.expires_at(),
+        }
+    }
+}
+
+impl From<SessionPostgres> for Session {
+    fn from(s: SessionPostgres) -> Self {
+        Session::new(s.token, s.user_id.into(), s.expires_at)
+    }
+}
+
+pub struct SessionRepo {
+    pool: DBPool,
+}
+
+impl SessionRepo {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl ISessionRepo for SessionRepo {
+    fn add(&self, session: Session) -> Result<()> {
+        use crate::schema::session;
+
+        let mut connection = self.pool.get()?;
+        let session = SessionPostgres::from(session);
+        diesel::insert_into(session::table)
+            .values(&session)
+            .execute(&mut *connection)?;
+
+        Ok(())
+    }
+
+    fn session_by_token(&self, q_token: &str) -> Option<Session> {
+        use crate::schema::session::dsl::*;
+
+        let mut connection = self.pool.get().unwrap();
+        let rec = session
+            .filter(token.eq(q_token))
+            .select(SessionPostgres::as_select())
+            .first::<SessionPostgres>(&mut *connection)
+            .ok();
+
+        rec.map(|s| s.into())
+    }
+
+    fn remove_by_token(&self, q_token: &str) -> Result<()> {
+        use crate::schema::session::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        diesel::delete(session.filter(token.eq(q_token))).execute(&mut *connection)?;
+
+        Ok(())
+    }
+}
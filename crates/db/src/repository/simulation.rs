@@ -1,34 +1,44 @@
 use anyhow::Result;
 use diesel::prelude::*;
-use std::net::IpAddr;
 
+use super::not_found;
 use crate::DBPool;
 use crate::models::SimulationPostgres;
+use crate::sql_types::DBUuid;
+use application::error::Entity;
 use application::repository::ISimulationRepo;
 use domain::{
-    entity::Simulation,
+    entity::{Simulation, User},
     value_object::{Amount, Id, MIN_BALANCE_AMOUNT},
 };
 
 impl From<Simulation> for SimulationPostgres {
     fn from(s: Simulation) -> Self {
         Self {
-            id: s.id().value(),
-            ip: s.ip().to_string(),
+            id: s.id().into(),
             round: s.round() as i64,
             balance: s.balance().clear_value(),
+            user_id: s.user_id().into(),
+            name: s.name().to_string(),
+            archived_at: s.archived_at(),
         }
     }
 }
 
 impl From<SimulationPostgres> for Simulation {
     fn from(s: SimulationPostgres) -> Self {
-        Simulation::new(
+        let mut simulation = Simulation::new(
             s.id.into(),
-            s.ip.parse().unwrap(),
+            s.user_id.into(),
+            s.name,
             Amount::new(s.balance, Some(MIN_BALANCE_AMOUNT)).unwrap(),
             Some(s.round as u32),
-        )
+        );
+        if let Some(at) = s.archived_at {
+            simulation.archive(at);
+        }
+
+        simulation
     }
 }
 
@@ -50,7 +60,7 @@ impl ISimulationRepo for SimulationRepo {
         let simulation = SimulationPostgres::from(simulation);
         diesel::insert_into(simulation::table)
             .values(&simulation)
-            .execute(&mut connection)?;
+            .execute(&mut *connection)?;
 
         Ok(())
     }
@@ -63,22 +73,52 @@ impl ISimulationRepo for SimulationRepo {
         use crate::schema::simulation::dsl::*;
 
         let mut connection = self.pool.get().unwrap();
-        let _ = diesel::delete(simulation.filter(id.eq(simulation_id.value())))
-            .execute(&mut connection);
+        let _ = diesel::delete(simulation.filter(id.eq(DBUuid::from(simulation_id))))
+            .execute(&mut *connection);
     }
 
-    fn simulation_by_ip(&self, ip_addr: IpAddr) -> Option<Simulation> {
+    fn simulations_by_user_id(&self, owner_id: Id<User>) -> Result<Vec<Simulation>> {
         use crate::schema::simulation::dsl::*;
 
-        let mut connection = self.pool.get().unwrap();
-        let ip_addr = ip_addr.to_string();
-        let rec = simulation
-            .filter(ip.eq(&ip_addr))
+        let mut connection = self.pool.get()?;
+        let recs = simulation
+            .filter(user_id.eq(DBUuid::from(owner_id)))
+            .select(SimulationPostgres::as_select())
+            .load::<SimulationPostgres>(&mut *connection)?
+            .into_iter()
+            .map(|sim| sim.into())
+            .collect();
+
+        Ok(recs)
+    }
+
+    fn active_simulations(&self) -> Result<Vec<Simulation>> {
+        use crate::schema::simulation::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        let recs = simulation
+            .filter(archived_at.is_null())
+            .select(SimulationPostgres::as_select())
+            .load::<SimulationPostgres>(&mut *connection)?
+            .into_iter()
+            .map(|sim| sim.into())
+            .collect();
+
+        Ok(recs)
+    }
+
+    fn all_simulations(&self) -> Result<Vec<Simulation>> {
+        use crate::schema::simulation::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        let recs = simulation
             .select(SimulationPostgres::as_select())
-            .first::<SimulationPostgres>(&mut connection)
-            .ok();
+            .load::<SimulationPostgres>(&mut *connection)?
+            .into_iter()
+            .map(|sim| sim.into())
+            .collect();
 
-        rec.map(|sim| sim.into())
+        Ok(recs)
     }
 
     fn simulation_by_id(&self, sim_id: Id<Simulation>) -> Result<Simulation> {
@@ -86,9 +126,10 @@ impl ISimulationRepo for SimulationRepo {
 
         let mut connection = self.pool.get()?;
         let rec = simulation
-            .filter(id.eq(&sim_id.value()))
+            .filter(id.eq(DBUuid::from(sim_id)))
             .select(SimulationPostgres::as_select())
-            .first::<SimulationPostgres>(&mut connection)?;
+            .first::<SimulationPostgres>(&mut *connection)
+            .map_err(not_found(Entity::Simulation))?;
 
         Ok(rec.into())
     }
@@ -96,17 +137,19 @@ impl ISimulationRepo for SimulationRepo {
     fn update_by_id(&self, simulation: Simulation) -> Result<()> {
         use crate::schema::simulation::{
             self,
-            dsl::{balance, id, round},
+            dsl::{archived_at, balance, id, name, round},
         };
 
         let mut connection = self.pool.get()?;
         diesel::update(simulation::table)
-            .filter(id.eq(&simulation.id().value()))
+            .filter(id.eq(DBUuid::from(simulation.id())))
             .set((
                 round.eq(simulation.round() as i64),
                 balance.eq(simulation.balance().clear_value()),
+                name.eq(simulation.name()),
+                archived_at.eq(simulation.archived_at()),
             ))
-            .execute(&mut connection)?;
+            .execute(&mut *connection)?;
 
         Ok(())
     }
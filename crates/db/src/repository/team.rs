@@ -1,9 +1,11 @@
 use anyhow::Result;
 use diesel::prelude::*;
-use uuid::Uuid;
 
+use super::not_found;
 use crate::DBPool;
 use crate::models::TeamPostgres;
+use crate::sql_types::DBUuid;
+use application::error::Entity;
 use application::repository::ITeamRepo;
 use domain::{entity::Team, value_object::Id};
 
@@ -29,11 +31,11 @@ impl ITeamRepo for TeamRepo {
 
         let mut connection = self.pool.get().unwrap();
         team.select(id)
-            .load(&mut connection)
+            .load(&mut *connection)
             .ok()
             .unwrap_or_default()
             .into_iter()
-            .map(|e: Uuid| e.into())
+            .map(|e: DBUuid| e.into())
             .collect()
     }
 
@@ -42,9 +44,10 @@ impl ITeamRepo for TeamRepo {
 
         let mut connection = self.pool.get()?;
         let t = team
-            .filter(id.eq(&q_id.value()))
+            .filter(id.eq(DBUuid::from(q_id)))
             .select(TeamPostgres::as_select())
-            .first(&mut connection)?
+            .first(&mut *connection)
+            .map_err(not_found(Entity::Team))?
             .into();
 
         Ok(t)
@@ -0,0 +1,20 @@
+use anyhow::Result;
+
+use crate::DBPool;
+use application::repository::IUnitOfWork;
+
+pub struct UnitOfWork {
+    pool: DBPool,
+}
+
+impl UnitOfWork {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl IUnitOfWork for UnitOfWork {
+    fn atomically(&self, work: &mut dyn FnMut() -> Result<()>) -> Result<()> {
+        self.pool.atomically(work)
+    }
+}
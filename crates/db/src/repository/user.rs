@@ -0,0 +1,117 @@
+use anyhow::{Result, anyhow};
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error};
+
+use super::not_found;
+use crate::DBPool;
+use crate::models::UserPostgres;
+use crate::sql_types::DBUuid;
+use application::error::{AppError, Entity};
+use application::repository::IUserRepo;
+use domain::{entity::User, value_object::Id};
+
+impl From<User> for UserPostgres {
+    fn from(u: User) -> Self {
+        Self {
+            id: u.id().into(),
+            login: u.login().to_string(),
+            password_hash: u.password_hash().to_string(),
+            odds_format: u.odds_format().code().to_string(),
+        }
+    }
+}
+
+impl TryFrom<UserPostgres> for User {
+    type Error = anyhow::Error;
+
+    fn try_from(u: UserPostgres) -> Result<Self> {
+        let odds_format = u
+            .odds_format
+            .parse()
+            .map_err(|_| anyhow!("Unknown odds format {}", u.odds_format))?;
+        let mut user = User::new(u.id.into(), u.login, u.password_hash);
+        user.set_odds_format(odds_format);
+
+        Ok(user)
+    }
+}
+
+pub struct UserRepo {
+    pool: DBPool,
+}
+
+impl UserRepo {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl IUserRepo for UserRepo {
+    fn add(&self, user: User) -> Result<()> {
+        use crate::schema::users;
+
+        let mut connection = self.pool.get()?;
+        let user = UserPostgres::from(user);
+        diesel::insert_into(users::table)
+            .values(&user)
+            .execute(&mut *connection)
+            .map_err(|e| match e {
+                Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                    AppError::LoginTaken.into()
+                }
+                e => anyhow::Error::from(e),
+            })?;
+
+        Ok(())
+    }
+
+    fn user_by_id(&self, user_id: Id<User>) -> Result<User> {
+        use crate::schema::users::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        let rec = users
+            .filter(id.eq(DBUuid::from(user_id)))
+            .select(UserPostgres::as_select())
+            .first::<UserPostgres>(&mut *connection)
+            .map_err(not_found(Entity::User))?;
+
+        rec.try_into()
+    }
+
+    fn user_by_login(&self, user_login: &str) -> Option<User> {
+        use crate::schema::users::dsl::*;
+
+        let mut connection = self.pool.get().unwrap();
+        let rec = users
+            .filter(login.eq(user_login))
+            .select(UserPostgres::as_select())
+            .first::<UserPostgres>(&mut *connection)
+            .ok();
+
+        rec.and_then(|u| u.try_into().ok())
+    }
+
+    fn remove_by_id(&self, user_id: Id<User>) {
+        use crate::schema::users::dsl::*;
+
+        let mut connection = self.pool.get().unwrap();
+        let _ =
+            diesel::delete(users.filter(id.eq(DBUuid::from(user_id)))).execute(&mut *connection);
+    }
+
+    fn update_by_id(&self, user: User) -> Result<()> {
+        use crate::schema::users::dsl::*;
+
+        let mut connection = self.pool.get()?;
+        diesel::update(users)
+            .filter(id.eq(DBUuid::from(user.id())))
+            .set(odds_format.eq(user.odds_format().code()))
+            .execute(&mut *connection)?;
+
+        Ok(())
+    }
+
+    fn next_id(&self) -> Id<User> {
+        Id::new()
+    }
+}
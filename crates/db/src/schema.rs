@@ -1,6 +1,41 @@
 // @generated automatically by Diesel CLI.
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use crate::sql_types::Uuid;
+
+    achievement (id) {
+        id -> Uuid,
+        simulation_id -> Uuid,
+        kind -> Text,
+        round -> Int8,
+        unlocked_at -> Int8,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::sql_types::Uuid;
+
+    autoplayrun (id) {
+        id -> Uuid,
+        simulation_id -> Uuid,
+        strategy -> Text,
+        first_round -> Int8,
+        rounds -> Int8,
+        bets -> Int8,
+        staked -> Int8,
+        returned -> Int8,
+        start_balance -> Int8,
+        end_balance -> Int8,
+        finished_at -> Int8,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::sql_types::Uuid;
+
     bet (id) {
         id -> Uuid,
         simulation_id -> Uuid,
@@ -13,6 +48,56 @@ diesel::table! {
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use crate::sql_types::Uuid;
+
+    betlimit (simulation_id) {
+        simulation_id -> Uuid,
+        max_stake -> Nullable<Int8>,
+        max_round_stake -> Nullable<Int8>,
+        loss_limit -> Nullable<Int8>,
+        loss_limit_rounds -> Nullable<Int8>,
+        cool_off_until -> Nullable<Int8>,
+        max_round_deposit -> Nullable<Int8>,
+        pending_max_stake -> Nullable<Int8>,
+        pending_max_round_stake -> Nullable<Int8>,
+        pending_loss_limit -> Nullable<Int8>,
+        pending_loss_limit_rounds -> Nullable<Int8>,
+        pending_max_round_deposit -> Nullable<Int8>,
+        pending_from_round -> Nullable<Int8>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::sql_types::Uuid;
+
+    deposit (id) {
+        id -> Uuid,
+        simulation_id -> Uuid,
+        amount -> Int8,
+        round -> Int8,
+        deposited_at -> Int8,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::sql_types::Uuid;
+
+    forecast (id) {
+        id -> Uuid,
+        simulation_id -> Uuid,
+        game_id -> Uuid,
+        event -> Bytea,
+        probability -> Float8,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::sql_types::Uuid;
+
     game (id) {
         id -> Uuid,
         simulation_id -> Uuid,
@@ -23,6 +108,9 @@ diesel::table! {
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use crate::sql_types::Uuid;
+
     gamestat (id) {
         id -> Uuid,
         game_id -> Uuid,
@@ -32,30 +120,93 @@ diesel::table! {
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use crate::sql_types::Uuid;
+
+    oddssnapshot (id) {
+        id -> Uuid,
+        simulation_id -> Uuid,
+        game_id -> Uuid,
+        event -> Bytea,
+        coefficient -> Int4,
+        created_at -> Int8,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::sql_types::Uuid;
+
+    session (token) {
+        token -> Text,
+        user_id -> Uuid,
+        expires_at -> Int8,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::sql_types::Uuid;
+
     simulation (id) {
         id -> Uuid,
-        ip -> Text,
         round -> Int8,
         balance -> Int8,
+        user_id -> Uuid,
+        name -> Text,
+        archived_at -> Nullable<Int8>,
     }
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use crate::sql_types::Uuid;
+
     team (id) {
         id -> Uuid,
         name -> Text,
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::sql_types::Uuid;
+
+    users (id) {
+        id -> Uuid,
+        login -> Text,
+        password_hash -> Text,
+        odds_format -> Text,
+    }
+}
+
+diesel::joinable!(achievement -> simulation (simulation_id));
+diesel::joinable!(autoplayrun -> simulation (simulation_id));
 diesel::joinable!(bet -> game (game_id));
 diesel::joinable!(bet -> simulation (simulation_id));
+diesel::joinable!(betlimit -> simulation (simulation_id));
+diesel::joinable!(deposit -> simulation (simulation_id));
+diesel::joinable!(forecast -> game (game_id));
+diesel::joinable!(forecast -> simulation (simulation_id));
 diesel::joinable!(game -> simulation (simulation_id));
 diesel::joinable!(gamestat -> game (game_id));
+diesel::joinable!(oddssnapshot -> game (game_id));
+diesel::joinable!(oddssnapshot -> simulation (simulation_id));
+diesel::joinable!(session -> users (user_id));
+diesel::joinable!(simulation -> users (user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    achievement,
+    autoplayrun,
     bet,
+    betlimit,
+    deposit,
+    forecast,
     game,
     gamestat,
+    oddssnapshot,
+    session,
     simulation,
     team,
+    users,
 );
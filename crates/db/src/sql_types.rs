@@ -0,0 +1,87 @@
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql, FromSqlRow};
+use diesel::expression::AsExpression;
+use diesel::pg::Pg;
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::{HasSqlType, SqlType};
+
+use crate::MultiBackend;
+use domain::value_object::Id;
+
+/// UUID column: native `UUID` in Postgres, hyphenated `TEXT` in SQLite.
+#[derive(SqlType, diesel::query_builder::QueryId, Clone, Copy)]
+#[diesel(postgres_type(oid = 2950, array_oid = 2951))]
+#[diesel(sqlite_type(name = "Text"))]
+pub struct Uuid;
+
+#[derive(AsExpression, FromSqlRow, Clone, Copy, PartialEq, Eq, Debug)]
+#[diesel(sql_type = Uuid)]
+pub struct DBUuid(pub uuid::Uuid);
+
+impl From<uuid::Uuid> for DBUuid {
+    fn from(value: uuid::Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<Id<T>> for DBUuid {
+    fn from(value: Id<T>) -> Self {
+        Self(value.value())
+    }
+}
+
+impl<T> From<DBUuid> for Id<T> {
+    fn from(value: DBUuid) -> Self {
+        value.0.into()
+    }
+}
+
+impl ToSql<Uuid, Pg> for DBUuid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        <uuid::Uuid as ToSql<diesel::sql_types::Uuid, Pg>>::to_sql(&self.0, out)
+    }
+}
+
+impl FromSql<Uuid, Pg> for DBUuid {
+    fn from_sql(bytes: <Pg as Backend>::RawValue<'_>) -> deserialize::Result<Self> {
+        <uuid::Uuid as FromSql<diesel::sql_types::Uuid, Pg>>::from_sql(bytes).map(Self)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl ToSql<Uuid, diesel::sqlite::Sqlite> for DBUuid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, diesel::sqlite::Sqlite>) -> serialize::Result {
+        out.set_value(self.0.hyphenated().to_string());
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl FromSql<Uuid, diesel::sqlite::Sqlite> for DBUuid {
+    fn from_sql(
+        bytes: <diesel::sqlite::Sqlite as Backend>::RawValue<'_>,
+    ) -> deserialize::Result<Self> {
+        let value =
+            <String as FromSql<diesel::sql_types::Text, diesel::sqlite::Sqlite>>::from_sql(bytes)?;
+        Ok(Self(uuid::Uuid::parse_str(&value)?))
+    }
+}
+
+impl HasSqlType<Uuid> for MultiBackend {
+    fn metadata(lookup: &mut Self::MetadataLookup) -> Self::TypeMetadata {
+        MultiBackend::lookup_sql_type::<Uuid>(lookup)
+    }
+}
+
+impl ToSql<Uuid, MultiBackend> for DBUuid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, MultiBackend>) -> serialize::Result {
+        out.set_value((Uuid, self));
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Uuid, MultiBackend> for DBUuid {
+    fn from_sql(bytes: <MultiBackend as Backend>::RawValue<'_>) -> deserialize::Result<Self> {
+        bytes.from_sql::<DBUuid, Uuid>()
+    }
+}
@@ -0,0 +1,77 @@
+use application::repository::{IAchievementRepo, ISimulationRepo, IUserRepo};
+use db::init_pool;
+use db::repository::{AchievementRepo, SimulationRepo, UserRepo};
+use domain::{
+    entity::{Achievement, Simulation, User},
+    value_object::{AchievementKind, Amount, MIN_BALANCE_AMOUNT},
+};
+
+#[test]
+fn insert_and_list_achievements() {
+    let pool = init_pool();
+
+    let repo = AchievementRepo::new(pool.clone());
+    let sim_repo = SimulationRepo::new(pool.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let survived = Achievement::new(
+        repo.next_id(),
+        sim_id,
+        AchievementKind::Survived10,
+        10,
+        1_700_000_000,
+    );
+    let first_win = Achievement::new(
+        repo.next_id(),
+        sim_id,
+        AchievementKind::FirstWin,
+        2,
+        1_600_000_000,
+    );
+
+    repo.add(survived).unwrap();
+    repo.add(first_win).unwrap();
+    let recs = repo.achievements_by_simulation_id(sim_id).unwrap();
+
+    assert_eq!(
+        recs.iter().map(|a| a.kind()).collect::<Vec<_>>(),
+        [AchievementKind::FirstWin, AchievementKind::Survived10]
+    );
+    assert_eq!(recs[1].round(), 10);
+    assert_eq!(recs[1].unlocked_at(), 1_700_000_000);
+
+    sim_repo.remove_by_id(sim_id);
+    user_repo.remove_by_id(user_id);
+}
+
+#[test]
+fn unlock_twice() {
+    let pool = init_pool();
+
+    let repo = AchievementRepo::new(pool.clone());
+    let sim_repo = SimulationRepo::new(pool.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let unlock = || Achievement::new(repo.next_id(), sim_id, AchievementKind::Bankrupt, 3, 0);
+
+    let first = repo.add(unlock());
+    let second = repo.add(unlock());
+
+    assert!(first.is_ok());
+    assert!(second.is_err());
+
+    sim_repo.remove_by_id(sim_id);
+    user_repo.remove_by_id(user_id);
+}
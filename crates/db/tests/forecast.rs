@@ -0,0 +1,58 @@
+use application::repository::{IForecastRepo, IGameRepo, ISimulationRepo, ITeamRepo, IUserRepo};
+use db::init_pool;
+use db::repository::{ForecastRepo, GameRepo, SimulationRepo, TeamRepo, UserRepo};
+use domain::{
+    entity::{Forecast, Game, Simulation, User},
+    value_object::{Amount, Event, EventTotal, MIN_BALANCE_AMOUNT, Winner},
+};
+use std::cmp::Ordering;
+
+#[test]
+fn add_all_forecasts() {
+    let pool = init_pool();
+
+    let forecast_repo = ForecastRepo::new(pool.clone());
+    let sim_repo = SimulationRepo::new(pool.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let game_repo = GameRepo::new(pool.clone());
+    let game_id = game_repo.next_id();
+    let team_repo = TeamRepo::new(pool.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+    game_repo.add(game).unwrap();
+    let total = Event::T(EventTotal {
+        total: 2,
+        ordering: Ordering::Greater,
+    });
+    let forecasts = vec![
+        Forecast::new(
+            forecast_repo.next_id(),
+            sim_id,
+            game_id,
+            Event::WDL(Winner::W1),
+            0.45,
+        ),
+        Forecast::new(forecast_repo.next_id(), sim_id, game_id, total, 0.3),
+    ];
+
+    forecast_repo.add_all(forecasts.clone()).unwrap();
+    let recs = forecast_repo.all_forecasts().unwrap();
+    sim_repo.remove_by_id(sim_id);
+    let left = forecast_repo.all_forecasts().unwrap();
+    user_repo.remove_by_id(user_id);
+
+    let own = recs
+        .into_iter()
+        .filter(|f| f.game_id() == game_id)
+        .collect::<Vec<_>>();
+    assert_eq!(own.len(), 2);
+    assert!(forecasts.iter().all(|f| own.contains(f)));
+    assert!(left.iter().all(|f| f.game_id() != game_id));
+}
@@ -1,10 +1,8 @@
-use std::net::{IpAddr, Ipv4Addr};
-
-use application::repository::{IGameRepo, ISimulationRepo, ITeamRepo};
+use application::repository::{IGameRepo, ISimulationRepo, ITeamRepo, IUserRepo};
 use db::init_pool;
-use db::repository::{GameRepo, SimulationRepo, TeamRepo};
+use db::repository::{GameRepo, SimulationRepo, TeamRepo, UserRepo};
 use domain::{
-    entity::{Game, Simulation},
+    entity::{Game, Simulation, User},
     value_object::{Amount, MIN_BALANCE_AMOUNT},
 };
 
@@ -15,9 +13,12 @@ fn game_by_id_found() {
     let game_repo = GameRepo::new(pool.clone());
     let sim_repo = SimulationRepo::new(pool.clone());
     let sim_id = sim_repo.next_id();
-    let ip = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
     let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
-    let simulation = Simulation::new(sim_id, ip, balance, None);
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
     sim_repo.add(simulation).unwrap();
     let game_id = game_repo.next_id();
     let team_repo = TeamRepo::new(pool.clone());
@@ -30,6 +31,7 @@ fn game_by_id_found() {
     assert!(rec.is_ok());
 
     sim_repo.remove_by_id(sim_id);
+    user_repo.remove_by_id(user_id);
 }
 
 #[test]
@@ -39,9 +41,12 @@ fn game_by_id_did_not_found() {
     let game_repo = GameRepo::new(pool.clone());
     let sim_repo = SimulationRepo::new(pool.clone());
     let sim_id = sim_repo.next_id();
-    let ip = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 2));
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
     let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
-    let simulation = Simulation::new(sim_id, ip, balance, None);
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
     sim_repo.add(simulation).unwrap();
     let game_id = game_repo.next_id();
     let team_repo = TeamRepo::new(pool.clone());
@@ -54,4 +59,67 @@ fn game_by_id_did_not_found() {
     assert!(rec.is_err());
 
     sim_repo.remove_by_id(sim_id);
+    user_repo.remove_by_id(user_id);
+}
+
+#[test]
+fn add_all_and_games_by_simulation_id() {
+    let pool = init_pool();
+
+    let game_repo = GameRepo::new(pool.clone());
+    let sim_repo = SimulationRepo::new(pool.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let team_repo = TeamRepo::new(pool.clone());
+    let team_ids = team_repo.all_teams_id();
+    let games = [3, 1, 2]
+        .map(|round| Game::new(game_repo.next_id(), sim_id, team_ids[0], team_ids[1], round));
+
+    game_repo.add_all(games.to_vec()).unwrap();
+    let recs = game_repo.games_by_simulation_id(sim_id, 2).unwrap();
+
+    assert_eq!(recs.iter().map(|g| g.round()).collect::<Vec<_>>(), [1, 2]);
+
+    sim_repo.remove_by_id(sim_id);
+    user_repo.remove_by_id(user_id);
+}
+
+#[test]
+fn add_all_inserts_nothing_on_failure() {
+    let pool = init_pool();
+
+    let game_repo = GameRepo::new(pool.clone());
+    let sim_repo = SimulationRepo::new(pool.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let team_repo = TeamRepo::new(pool.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game = Game::new(game_repo.next_id(), sim_id, team_ids[0], team_ids[1], 1);
+    let orphan = Game::new(
+        game_repo.next_id(),
+        sim_repo.next_id(),
+        team_ids[0],
+        team_ids[1],
+        1,
+    );
+
+    let res = game_repo.add_all(vec![game, orphan]);
+
+    assert!(res.is_err());
+    assert!(game_repo.game_by_id(game.id()).is_err());
+
+    sim_repo.remove_by_id(sim_id);
+    user_repo.remove_by_id(user_id);
 }
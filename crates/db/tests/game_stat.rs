@@ -1,10 +1,8 @@
-use std::net::{IpAddr, Ipv4Addr};
-
-use application::repository::{IGameRepo, IGameStatRepo, ISimulationRepo, ITeamRepo};
+use application::repository::{IGameRepo, IGameStatRepo, ISimulationRepo, ITeamRepo, IUserRepo};
 use db::init_pool;
-use db::repository::{GameRepo, GameStatRepo, SimulationRepo, TeamRepo};
+use db::repository::{GameRepo, GameStatRepo, SimulationRepo, TeamRepo, UserRepo};
 use domain::{
-    entity::{Game, GameStat, Simulation},
+    entity::{Game, GameStat, Simulation, User},
     value_object::{Amount, MIN_BALANCE_AMOUNT},
 };
 
@@ -15,9 +13,12 @@ fn insert_game_stat() {
     let game_stat_repo = GameStatRepo::new(pool.clone());
     let sim_repo = SimulationRepo::new(pool.clone());
     let sim_id = sim_repo.next_id();
-    let ip = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 3));
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
     let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
-    let simulation = Simulation::new(sim_id, ip, balance, None);
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
     sim_repo.add(simulation).unwrap();
     let game_repo = GameRepo::new(pool.clone());
     let game_id = game_repo.next_id();
@@ -34,6 +35,7 @@ fn insert_game_stat() {
     assert!(res.is_ok());
 
     sim_repo.remove_by_id(sim_id);
+    user_repo.remove_by_id(user_id);
 }
 
 #[test]
@@ -43,9 +45,12 @@ fn score_by_game_id() {
     let game_stat_repo = GameStatRepo::new(pool.clone());
     let sim_repo = SimulationRepo::new(pool.clone());
     let sim_id = sim_repo.next_id();
-    let ip = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
     let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
-    let simulation = Simulation::new(sim_id, ip, balance, None);
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
     sim_repo.add(simulation).unwrap();
     let game_repo = GameRepo::new(pool.clone());
     let game_id = game_repo.next_id();
@@ -65,6 +70,7 @@ fn score_by_game_id() {
     assert_eq!(score_guest, (0, 2));
 
     sim_repo.remove_by_id(sim_id);
+    user_repo.remove_by_id(user_id);
 }
 
 #[test]
@@ -74,9 +80,12 @@ fn goals_by_game_id() {
     let game_stat_repo = GameStatRepo::new(pool.clone());
     let sim_repo = SimulationRepo::new(pool.clone());
     let sim_id = sim_repo.next_id();
-    let ip = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 2));
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
     let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
-    let simulation = Simulation::new(sim_id, ip, balance, None);
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
     sim_repo.add(simulation).unwrap();
     let game_repo = GameRepo::new(pool.clone());
     let game_id = game_repo.next_id();
@@ -96,4 +105,39 @@ fn goals_by_game_id() {
     assert_eq!(goals_guest, 0);
 
     sim_repo.remove_by_id(sim_id);
+    user_repo.remove_by_id(user_id);
+}
+
+#[test]
+fn add_all_and_game_stats_by_simulation_id() {
+    let pool = init_pool();
+
+    let game_stat_repo = GameStatRepo::new(pool.clone());
+    let sim_repo = SimulationRepo::new(pool.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let game_repo = GameRepo::new(pool.clone());
+    let team_repo = TeamRepo::new(pool.clone());
+    let team_ids = team_repo.all_teams_id();
+    let games =
+        [1, 2].map(|round| Game::new(game_repo.next_id(), sim_id, team_ids[0], team_ids[1], round));
+    game_repo.add_all(games.to_vec()).unwrap();
+    let game_stats = games.map(|g| GameStat::new(game_stat_repo.next_id(), g.id(), 1, 0));
+
+    game_stat_repo.add_all(game_stats.to_vec()).unwrap();
+    let recs = game_stat_repo
+        .game_stats_by_simulation_id(sim_id, 1)
+        .unwrap();
+
+    assert_eq!(recs.len(), 1);
+    assert!(recs[0].game_id() == games[0].id());
+
+    sim_repo.remove_by_id(sim_id);
+    user_repo.remove_by_id(user_id);
 }
@@ -0,0 +1,57 @@
+use application::repository::{
+    IGameRepo, IOddsSnapshotRepo, ISimulationRepo, ITeamRepo, IUserRepo,
+};
+use db::init_pool;
+use db::repository::{GameRepo, OddsSnapshotRepo, SimulationRepo, TeamRepo, UserRepo};
+use domain::{
+    entity::{Game, OddsSnapshot, Simulation, User},
+    value_object::{Amount, Event, MIN_BALANCE_AMOUNT, Winner},
+};
+
+#[test]
+fn snapshots_by_game_id() {
+    let pool = init_pool();
+
+    let repo = OddsSnapshotRepo::new(pool.clone());
+    let sim_repo = SimulationRepo::new(pool.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let game_repo = GameRepo::new(pool.clone());
+    let team_repo = TeamRepo::new(pool.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game_ids = [game_repo.next_id(), game_repo.next_id()];
+    for game_id in game_ids {
+        let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+        game_repo.add(game).unwrap();
+    }
+    let snapshot = |game: usize, coefficient: f64, created_at: i64| {
+        OddsSnapshot::new(
+            repo.next_id(),
+            sim_id,
+            game_ids[game],
+            Event::WDL(Winner::W1),
+            coefficient.try_into().unwrap(),
+            created_at,
+        )
+    };
+    let later = snapshot(0, 1.9, 200);
+    let opening = snapshot(0, 2.1, 100);
+    let other = snapshot(1, 3.0, 100);
+
+    repo.add_all(vec![later, opening, other]).unwrap();
+    let recs = repo.snapshots_by_game_id(game_ids[0]).unwrap();
+    sim_repo.remove_by_id(sim_id);
+    let left = repo.snapshots_by_game_id(game_ids[0]).unwrap();
+    user_repo.remove_by_id(user_id);
+
+    assert_eq!(recs.len(), 2);
+    assert!(recs[0] == opening);
+    assert!(recs[1] == later);
+    assert!(left.is_empty());
+}
@@ -1,10 +1,8 @@
-use std::net::{IpAddr, Ipv4Addr};
-
-use application::repository::ISimulationRepo;
+use application::repository::{ISimulationRepo, IUserRepo};
 use db::init_pool;
-use db::repository::SimulationRepo;
+use db::repository::{SimulationRepo, UserRepo};
 use domain::{
-    entity::Simulation,
+    entity::{Simulation, User},
     value_object::{Amount, MIN_BALANCE_AMOUNT},
 };
 
@@ -12,15 +10,164 @@ use domain::{
 fn add_get_remove() {
     let pool = init_pool();
 
-    let repo = SimulationRepo::new(pool);
+    let repo = SimulationRepo::new(pool.clone());
     let id = repo.next_id();
-    let ip = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
     let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
-    let simulation = Simulation::new(id, ip, balance, None);
+    let simulation = Simulation::new(id, user_id, "default".to_string(), balance, None);
 
     repo.add(simulation).unwrap();
-    let rec = repo.simulation_by_ip(ip);
+    let rec = repo.simulation_by_id(id);
     repo.remove_by_id(id);
+    user_repo.remove_by_id(user_id);
+
+    let rec = rec.unwrap();
+    assert!(rec.user_id() == user_id);
+    assert_eq!(rec.name(), "default");
+}
+
+#[test]
+fn several_per_user() {
+    let pool = init_pool();
+
+    let repo = SimulationRepo::new(pool.clone());
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    for name in ["conservative", "martingale test"] {
+        let simulation = Simulation::new(repo.next_id(), user_id, name.to_string(), balance, None);
+        repo.add(simulation).unwrap();
+    }
+
+    let recs = repo.simulations_by_user_id(user_id);
+    let duplicate = Simulation::new(
+        repo.next_id(),
+        user_id,
+        "conservative".to_string(),
+        balance,
+        None,
+    );
+    let res = repo.add(duplicate);
+    user_repo.remove_by_id(user_id);
+
+    assert_eq!(recs.unwrap().len(), 2);
+    assert!(res.is_err());
+    assert!(repo.simulations_by_user_id(user_id).unwrap().is_empty());
+}
+
+#[test]
+fn rename() {
+    let pool = init_pool();
+
+    let repo = SimulationRepo::new(pool.clone());
+    let id = repo.next_id();
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let mut simulation = Simulation::new(id, user_id, "old".to_string(), balance, None);
+    repo.add(simulation.clone()).unwrap();
+
+    simulation.rename("new".to_string());
+    repo.update_by_id(simulation).unwrap();
+    let rec = repo.simulation_by_id(id);
+    user_repo.remove_by_id(user_id);
+
+    assert_eq!(rec.unwrap().name(), "new");
+}
+
+#[test]
+fn archive() {
+    let pool = init_pool();
+
+    let repo = SimulationRepo::new(pool.clone());
+    let id = repo.next_id();
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let mut simulation = Simulation::new(id, user_id, "default".to_string(), balance, None);
+    repo.add(simulation.clone()).unwrap();
+
+    simulation.archive(1_700_000_000);
+    repo.update_by_id(simulation).unwrap();
+    let fresh = Simulation::new(
+        repo.next_id(),
+        user_id,
+        "default".to_string(),
+        balance,
+        None,
+    );
+    let fresh_res = repo.add(fresh);
+    let duplicate = Simulation::new(
+        repo.next_id(),
+        user_id,
+        "default".to_string(),
+        balance,
+        None,
+    );
+    let duplicate_res = repo.add(duplicate);
+    let rec = repo.simulation_by_id(id);
+    user_repo.remove_by_id(user_id);
+
+    assert!(fresh_res.is_ok());
+    assert!(duplicate_res.is_err());
+    assert_eq!(rec.unwrap().archived_at(), Some(1_700_000_000));
+}
+
+#[test]
+fn active_simulations() {
+    let pool = init_pool();
+
+    let repo = SimulationRepo::new(pool.clone());
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let mut archived = Simulation::new(repo.next_id(), user_id, "old".to_string(), balance, None);
+    archived.archive(1_700_000_000);
+    repo.add(archived.clone()).unwrap();
+    let active = Simulation::new(repo.next_id(), user_id, "new".to_string(), balance, None);
+    repo.add(active.clone()).unwrap();
+
+    let recs = repo.active_simulations().unwrap();
+    user_repo.remove_by_id(user_id);
+
+    let own = recs
+        .iter()
+        .filter(|s| s.user_id() == user_id)
+        .collect::<Vec<_>>();
+    assert_eq!(own.len(), 1);
+    assert!(own[0].id() == active.id());
+}
+
+#[test]
+fn all_simulations() {
+    let pool = init_pool();
+
+    let repo = SimulationRepo::new(pool.clone());
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let mut archived = Simulation::new(repo.next_id(), user_id, "old".to_string(), balance, None);
+    archived.archive(1_700_000_000);
+    repo.add(archived.clone()).unwrap();
+    let active = Simulation::new(repo.next_id(), user_id, "new".to_string(), balance, None);
+    repo.add(active.clone()).unwrap();
+
+    let recs = repo.all_simulations().unwrap();
+    user_repo.remove_by_id(user_id);
 
-    assert!(rec.unwrap().id() == id);
+    let own = recs.iter().filter(|s| s.user_id() == user_id).count();
+    assert_eq!(own, 2);
 }
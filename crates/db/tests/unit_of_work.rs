@@ -0,0 +1,95 @@
+use anyhow::bail;
+use application::repository::{IGameRepo, ISimulationRepo, ITeamRepo, IUnitOfWork, IUserRepo};
+use db::init_pool;
+use db::repository::{GameRepo, SimulationRepo, TeamRepo, UnitOfWork, UserRepo};
+use domain::{
+    entity::{Game, Simulation, User},
+    value_object::{Amount, MIN_BALANCE_AMOUNT},
+};
+
+#[test]
+fn commit_keeps_changes() {
+    let pool = init_pool();
+
+    let unit_of_work = UnitOfWork::new(pool.clone());
+    let sim_repo = SimulationRepo::new(pool.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    let game_repo = GameRepo::new(pool.clone());
+    let game_id = game_repo.next_id();
+    let team_ids = TeamRepo::new(pool).all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+
+    let res = unit_of_work.atomically(&mut || {
+        sim_repo.add(simulation.clone())?;
+        game_repo.add(game)
+    });
+
+    assert!(res.is_ok());
+    assert!(sim_repo.simulation_by_id(sim_id).is_ok());
+    assert!(game_repo.game_by_id(game_id).is_ok());
+
+    sim_repo.remove_by_id(sim_id);
+    user_repo.remove_by_id(user_id);
+}
+
+#[test]
+fn rollback_discards_changes() {
+    let pool = init_pool();
+
+    let unit_of_work = UnitOfWork::new(pool.clone());
+    let sim_repo = SimulationRepo::new(pool.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    let game_repo = GameRepo::new(pool.clone());
+    let game_id = game_repo.next_id();
+    let team_ids = TeamRepo::new(pool).all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+
+    let res = unit_of_work.atomically(&mut || {
+        sim_repo.add(simulation.clone())?;
+        game_repo.add(game)?;
+        bail!("err")
+    });
+
+    assert_eq!(res.err().unwrap().to_string(), "err");
+    assert!(sim_repo.simulation_by_id(sim_id).is_err());
+    assert!(game_repo.game_by_id(game_id).is_err());
+
+    user_repo.remove_by_id(user_id);
+}
+
+#[test]
+fn nested_rollback_discards_outer_changes() {
+    let pool = init_pool();
+
+    let unit_of_work = UnitOfWork::new(pool.clone());
+    let sim_repo = SimulationRepo::new(pool.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+
+    let res = unit_of_work.atomically(&mut || {
+        sim_repo.add(simulation.clone())?;
+        unit_of_work.atomically(&mut || bail!("err"))
+    });
+
+    assert!(res.is_err());
+    assert!(sim_repo.simulation_by_id(sim_id).is_err());
+
+    user_repo.remove_by_id(user_id);
+}
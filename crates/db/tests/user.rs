@@ -0,0 +1,118 @@
+use application::error::{AppError, Entity};
+use application::repository::{ISessionRepo, IUserRepo};
+use db::init_pool;
+use db::repository::{SessionRepo, UserRepo};
+use domain::entity::{Session, User};
+use domain::value_object::OddsFormat;
+
+#[test]
+fn add_get_remove() {
+    let pool = init_pool();
+
+    let repo = UserRepo::new(pool);
+    let id = repo.next_id();
+    let login = id.value().to_string();
+    let user = User::new(id, login.clone(), "hash".to_string());
+
+    repo.add(user).unwrap();
+    let by_login = repo.user_by_login(&login);
+    let by_id = repo.user_by_id(id);
+    repo.remove_by_id(id);
+
+    assert!(by_login.unwrap().id() == id);
+    assert_eq!(by_id.unwrap().password_hash(), "hash");
+    assert!(repo.user_by_login(&login).is_none());
+}
+
+#[test]
+fn odds_format_updated() {
+    let pool = init_pool();
+
+    let repo = UserRepo::new(pool);
+    let id = repo.next_id();
+    let mut user = User::new(id, id.value().to_string(), "hash".to_string());
+    repo.add(user.clone()).unwrap();
+
+    user.set_odds_format(OddsFormat::HongKong);
+    repo.update_by_id(user).unwrap();
+    let rec = repo.user_by_id(id);
+    repo.remove_by_id(id);
+
+    let rec = rec.unwrap();
+    assert_eq!(rec.odds_format(), OddsFormat::HongKong);
+    assert_eq!(rec.password_hash(), "hash");
+}
+
+#[test]
+fn user_by_id_did_not_found() {
+    let pool = init_pool();
+
+    let repo = UserRepo::new(pool);
+
+    let res = repo.user_by_id(repo.next_id());
+
+    assert_eq!(
+        res.err().unwrap().downcast::<AppError>().unwrap(),
+        AppError::NotFound(Entity::User)
+    );
+}
+
+#[test]
+fn login_is_unique() {
+    let pool = init_pool();
+
+    let repo = UserRepo::new(pool);
+    let first_id = repo.next_id();
+    let login = first_id.value().to_string();
+    repo.add(User::new(first_id, login.clone(), String::new()))
+        .unwrap();
+
+    let res = repo.add(User::new(repo.next_id(), login, String::new()));
+    repo.remove_by_id(first_id);
+
+    assert_eq!(
+        res.err().unwrap().downcast::<AppError>().unwrap(),
+        AppError::LoginTaken
+    );
+}
+
+#[test]
+fn session_add_get_remove() {
+    let pool = init_pool();
+
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let repo = SessionRepo::new(pool);
+    let token = user_id.value().simple().to_string();
+    let session = Session::new(token.clone(), user_id, 100);
+
+    repo.add(session).unwrap();
+    let rec = repo.session_by_token(&token);
+    repo.remove_by_token(&token).unwrap();
+    let removed = repo.session_by_token(&token);
+    user_repo.remove_by_id(user_id);
+
+    let rec = rec.unwrap();
+    assert!(rec.user_id() == user_id);
+    assert_eq!(rec.expires_at(), 100);
+    assert!(removed.is_none());
+}
+
+#[test]
+fn sessions_removed_with_user() {
+    let pool = init_pool();
+
+    let user_repo = UserRepo::new(pool.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let repo = SessionRepo::new(pool);
+    let token = user_id.value().simple().to_string();
+    repo.add(Session::new(token.clone(), user_id, 100)).unwrap();
+
+    user_repo.remove_by_id(user_id);
+
+    assert!(repo.session_by_token(&token).is_none());
+}
@@ -0,0 +1,52 @@
+use super::Simulation;
+use crate::value_object::{AchievementKind, Id};
+
+/// Milestone a simulation has reached, unlocked at most once per kind.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Achievement {
+    id: Id<Achievement>,
+    simulation_id: Id<Simulation>,
+    kind: AchievementKind,
+    round: u32,
+    unlocked_at: i64,
+}
+
+impl Achievement {
+    pub fn new(
+        id: Id<Achievement>,
+        simulation_id: Id<Simulation>,
+        kind: AchievementKind,
+        round: u32,
+        unlocked_at: i64,
+    ) -> Self {
+        Self {
+            id,
+            simulation_id,
+            kind,
+            round,
+            unlocked_at,
+        }
+    }
+
+    pub fn id(&self) -> Id<Self> {
+        self.id
+    }
+
+    pub fn simulation_id(&self) -> Id<Simulation> {
+        self.simulation_id
+    }
+
+    pub fn kind(&self) -> AchievementKind {
+        self.kind
+    }
+
+    /// Round the simulation was in when the achievement was unlocked.
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    /// Unix timestamp in seconds.
+    pub fn unlocked_at(&self) -> i64 {
+        self.unlocked_at
+    }
+}
@@ -0,0 +1,97 @@
+use super::Simulation;
+use crate::value_object::{Amount, Id, StrategyKind};
+
+/// Result of letting a strategy play several rounds of a simulation on its own.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct AutoplayRun {
+    id: Id<AutoplayRun>,
+    simulation_id: Id<Simulation>,
+    strategy: StrategyKind,
+    first_round: u32,
+    rounds: u32,
+    bets: u32,
+    staked: Amount,
+    returned: Amount,
+    start_balance: Amount,
+    end_balance: Amount,
+    finished_at: i64,
+}
+
+impl AutoplayRun {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Id<AutoplayRun>,
+        simulation_id: Id<Simulation>,
+        strategy: StrategyKind,
+        first_round: u32,
+        rounds: u32,
+        bets: u32,
+        staked: Amount,
+        returned: Amount,
+        start_balance: Amount,
+        end_balance: Amount,
+        finished_at: i64,
+    ) -> Self {
+        Self {
+            id,
+            simulation_id,
+            strategy,
+            first_round,
+            rounds,
+            bets,
+            staked,
+            returned,
+            start_balance,
+            end_balance,
+            finished_at,
+        }
+    }
+
+    pub fn id(&self) -> Id<Self> {
+        self.id
+    }
+
+    pub fn simulation_id(&self) -> Id<Simulation> {
+        self.simulation_id
+    }
+
+    pub fn strategy(&self) -> StrategyKind {
+        self.strategy
+    }
+
+    /// First round the strategy bet on.
+    pub fn first_round(&self) -> u32 {
+        self.first_round
+    }
+
+    /// Rounds played, fewer than asked for if the balance ran out.
+    pub fn rounds(&self) -> u32 {
+        self.rounds
+    }
+
+    pub fn bets(&self) -> u32 {
+        self.bets
+    }
+
+    pub fn staked(&self) -> Amount {
+        self.staked
+    }
+
+    /// Payouts of the won bets, stakes included.
+    pub fn returned(&self) -> Amount {
+        self.returned
+    }
+
+    pub fn start_balance(&self) -> Amount {
+        self.start_balance
+    }
+
+    pub fn end_balance(&self) -> Amount {
+        self.end_balance
+    }
+
+    /// Unix timestamp in seconds.
+    pub fn finished_at(&self) -> i64 {
+        self.finished_at
+    }
+}
@@ -1,6 +1,7 @@
 use super::{Game, Simulation};
 use crate::value_object::{Amount, Coefficient, Event, Id, MIN_BALANCE_AMOUNT};
 
+#[derive(Clone, Copy)]
 pub struct Bet {
     id: Id<Bet>,
     simulation_id: Id<Simulation>,
@@ -63,6 +64,11 @@ impl Bet {
     pub fn set_win(&mut self) -> Amount {
         self.is_won = Some(true);
 
+        self.payout()
+    }
+
+    /// What the bet pays out if it wins: the stake in whole units times the coefficient.
+    pub fn payout(&self) -> Amount {
         Amount::new(
             (f64::from(self.amount) as i64) * (self.coefficient.clear_value() as i64),
             Some(MIN_BALANCE_AMOUNT),
@@ -0,0 +1,52 @@
+use super::Simulation;
+use crate::value_object::{Amount, Id};
+
+/// Virtual money put into a simulation on top of its starting balance.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Deposit {
+    id: Id<Deposit>,
+    simulation_id: Id<Simulation>,
+    amount: Amount,
+    round: u32,
+    deposited_at: i64,
+}
+
+impl Deposit {
+    pub fn new(
+        id: Id<Deposit>,
+        simulation_id: Id<Simulation>,
+        amount: Amount,
+        round: u32,
+        deposited_at: i64,
+    ) -> Self {
+        Self {
+            id,
+            simulation_id,
+            amount,
+            round,
+            deposited_at,
+        }
+    }
+
+    pub fn id(&self) -> Id<Self> {
+        self.id
+    }
+
+    pub fn simulation_id(&self) -> Id<Simulation> {
+        self.simulation_id
+    }
+
+    pub fn amount(&self) -> Amount {
+        self.amount
+    }
+
+    /// Round the simulation was in when the money was deposited.
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    /// Unix timestamp in seconds.
+    pub fn deposited_at(&self) -> i64 {
+        self.deposited_at
+    }
+}
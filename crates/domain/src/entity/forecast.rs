@@ -0,0 +1,50 @@
+use super::{Game, Simulation};
+use crate::value_object::{Event, Id};
+
+/// Model probability of an event of a game, taken before the game is played.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Forecast {
+    id: Id<Forecast>,
+    simulation_id: Id<Simulation>,
+    game_id: Id<Game>,
+    event: Event,
+    probability: f64,
+}
+
+impl Forecast {
+    pub fn new(
+        id: Id<Forecast>,
+        simulation_id: Id<Simulation>,
+        game_id: Id<Game>,
+        event: Event,
+        probability: f64,
+    ) -> Self {
+        Self {
+            id,
+            simulation_id,
+            game_id,
+            event,
+            probability,
+        }
+    }
+
+    pub fn id(&self) -> Id<Self> {
+        self.id
+    }
+
+    pub fn simulation_id(&self) -> Id<Simulation> {
+        self.simulation_id
+    }
+
+    pub fn game_id(&self) -> Id<Game> {
+        self.game_id
+    }
+
+    pub fn event(&self) -> Event {
+        self.event
+    }
+
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+}
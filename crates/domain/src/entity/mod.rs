@@ -1,11 +1,25 @@
+mod achievement;
+mod autoplay_run;
 mod bet;
+mod deposit;
+mod forecast;
 mod game;
 mod game_stat;
+mod odds_snapshot;
+mod session;
 mod simulation;
 mod team;
+mod user;
 
+pub use achievement::Achievement;
+pub use autoplay_run::AutoplayRun;
 pub use bet::Bet;
+pub use deposit::Deposit;
+pub use forecast::Forecast;
 pub use game::Game;
 pub use game_stat::GameStat;
+pub use odds_snapshot::OddsSnapshot;
+pub use session::Session;
 pub use simulation::Simulation;
 pub use team::Team;
+pub use user::User;
@@ -0,0 +1,58 @@
+use super::{Game, Simulation};
+use crate::value_object::{Coefficient, Event, Id};
+
+/// Coefficient offered on an event of a game from `created_at` until the next snapshot of
+/// the event.
+#[derive(Clone, Copy, PartialEq)]
+pub struct OddsSnapshot {
+    id: Id<OddsSnapshot>,
+    simulation_id: Id<Simulation>,
+    game_id: Id<Game>,
+    event: Event,
+    coefficient: Coefficient,
+    created_at: i64,
+}
+
+impl OddsSnapshot {
+    pub fn new(
+        id: Id<OddsSnapshot>,
+        simulation_id: Id<Simulation>,
+        game_id: Id<Game>,
+        event: Event,
+        coefficient: Coefficient,
+        created_at: i64,
+    ) -> Self {
+        Self {
+            id,
+            simulation_id,
+            game_id,
+            event,
+            coefficient,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> Id<Self> {
+        self.id
+    }
+
+    pub fn simulation_id(&self) -> Id<Simulation> {
+        self.simulation_id
+    }
+
+    pub fn game_id(&self) -> Id<Game> {
+        self.game_id
+    }
+
+    pub fn event(&self) -> Event {
+        self.event
+    }
+
+    pub fn coefficient(&self) -> Coefficient {
+        self.coefficient
+    }
+
+    pub fn created_at(&self) -> i64 {
+        self.created_at
+    }
+}
@@ -0,0 +1,37 @@
+use super::User;
+use crate::value_object::Id;
+
+/// Login session identified by an opaque bearer token.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Session {
+    token: String,
+    user_id: Id<User>,
+    expires_at: i64,
+}
+
+impl Session {
+    pub fn new(token: String, user_id: Id<User>, expires_at: i64) -> Self {
+        Self {
+            token,
+            user_id,
+            expires_at,
+        }
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn user_id(&self) -> Id<User> {
+        self.user_id
+    }
+
+    /// Unix timestamp in seconds after which the session is no longer valid.
+    pub fn expires_at(&self) -> i64 {
+        self.expires_at
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+}
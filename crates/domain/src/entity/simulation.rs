@@ -1,24 +1,35 @@
-use anyhow::Result;
-use std::net::IpAddr;
+use anyhow::{Result, bail};
 
+use super::User;
+use crate::error::DomainError;
 use crate::value_object::{Amount, Id, MIN_BALANCE_AMOUNT};
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Simulation {
     id: Id<Simulation>,
-    ip: IpAddr,
+    user_id: Id<User>,
+    name: String,
     round: u32,
     balance: Amount,
+    archived_at: Option<i64>,
 }
 
 impl Simulation {
-    pub fn new(id: Id<Simulation>, ip: IpAddr, balance: Amount, round: Option<u32>) -> Self {
+    pub fn new(
+        id: Id<Simulation>,
+        user_id: Id<User>,
+        name: String,
+        balance: Amount,
+        round: Option<u32>,
+    ) -> Self {
         let round = round.unwrap_or_default();
         Self {
             id,
-            ip,
+            user_id,
+            name,
             round,
             balance,
+            archived_at: None,
         }
     }
 
@@ -26,8 +37,16 @@ impl Simulation {
         self.id
     }
 
-    pub fn ip(&self) -> IpAddr {
-        self.ip
+    pub fn user_id(&self) -> Id<User> {
+        self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn rename(&mut self, name: String) {
+        self.name = name;
     }
 
     pub fn round(&self) -> u32 {
@@ -48,6 +67,10 @@ impl Simulation {
     }
 
     pub fn make_bet(&mut self, bet_amount: Amount) -> Result<Amount> {
+        if bet_amount.clear_value() > self.balance.clear_value() {
+            let balance = self.balance.clear_value();
+            bail!(DomainError::InsufficientFunds { balance });
+        }
         self.balance = Amount::new(
             self.balance.clear_value() - bet_amount.clear_value(),
             Some(MIN_BALANCE_AMOUNT),
@@ -56,7 +79,31 @@ impl Simulation {
         Ok(self.balance)
     }
 
+    /// Tops the balance up with `amount` of virtual money.
+    pub fn deposit(&mut self, amount: Amount) -> Result<Amount> {
+        let Some(balance) = self.balance.clear_value().checked_add(amount.clear_value()) else {
+            bail!(DomainError::InvalidAmount);
+        };
+        self.balance = Amount::new(balance, Some(MIN_BALANCE_AMOUNT))?;
+
+        Ok(self.balance)
+    }
+
     pub fn increment_round(&mut self) {
         self.round += 1;
     }
+
+    /// Unix timestamp in seconds when the run was archived.
+    pub fn archived_at(&self) -> Option<i64> {
+        self.archived_at
+    }
+
+    /// Archived runs are kept read-only for browsing their results.
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    pub fn archive(&mut self, at: i64) {
+        self.archived_at = Some(at);
+    }
 }
@@ -2,7 +2,7 @@ use serde::Serialize;
 
 use crate::value_object::Id;
 
-#[derive(PartialEq, PartialOrd, Eq, Ord, Serialize)]
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Serialize)]
 pub struct Team {
     id: Id<Team>,
     name: String,
@@ -0,0 +1,41 @@
+use crate::value_object::{Id, OddsFormat};
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub struct User {
+    id: Id<User>,
+    login: String,
+    password_hash: String,
+    odds_format: OddsFormat,
+}
+
+impl User {
+    pub fn new(id: Id<Self>, login: String, password_hash: String) -> Self {
+        Self {
+            id,
+            login,
+            password_hash,
+            odds_format: OddsFormat::default(),
+        }
+    }
+
+    pub fn id(&self) -> Id<Self> {
+        self.id
+    }
+
+    pub fn login(&self) -> &str {
+        &self.login
+    }
+
+    pub fn password_hash(&self) -> &str {
+        &self.password_hash
+    }
+
+    /// Format offers and bets are shown to the user in.
+    pub fn odds_format(&self) -> OddsFormat {
+        self.odds_format
+    }
+
+    pub fn set_odds_format(&mut self, odds_format: OddsFormat) {
+        self.odds_format = odds_format;
+    }
+}
@@ -0,0 +1,25 @@
+use std::fmt;
+
+/// Invariants of the domain types that a caller tried to break.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DomainError {
+    InvalidAmount,
+    InvalidCoefficient,
+    InvalidMargin,
+    TotalsMismatch,
+    InsufficientFunds { balance: i64 },
+}
+
+impl fmt::Display for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidAmount => write!(f, "Amount doesn't support this value"),
+            Self::InvalidCoefficient => write!(f, "Coefficient doesn't support this value"),
+            Self::InvalidMargin => write!(f, "Margin doesn't support this value"),
+            Self::TotalsMismatch => write!(f, "Can't add PastTotals with different origin_total"),
+            Self::InsufficientFunds { .. } => write!(f, "Haven't enough money"),
+        }
+    }
+}
+
+impl std::error::Error for DomainError {}
@@ -1,2 +1,3 @@
 pub mod entity;
+pub mod error;
 pub mod value_object;
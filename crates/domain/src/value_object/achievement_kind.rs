@@ -0,0 +1,66 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AchievementKind {
+    FirstWin,
+    LongShot,
+    DoubledBalance,
+    #[serde(rename = "survived_10")]
+    Survived10,
+    #[serde(rename = "survived_50")]
+    Survived50,
+    #[serde(rename = "survived_100")]
+    Survived100,
+    Bankrupt,
+}
+
+impl AchievementKind {
+    pub const ALL: [Self; 7] = [
+        Self::FirstWin,
+        Self::LongShot,
+        Self::DoubledBalance,
+        Self::Survived10,
+        Self::Survived50,
+        Self::Survived100,
+        Self::Bankrupt,
+    ];
+
+    /// Stable name used for storage and the API.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::FirstWin => "first_win",
+            Self::LongShot => "long_shot",
+            Self::DoubledBalance => "doubled_balance",
+            Self::Survived10 => "survived_10",
+            Self::Survived50 => "survived_50",
+            Self::Survived100 => "survived_100",
+            Self::Bankrupt => "bankrupt",
+        }
+    }
+}
+
+impl FromStr for AchievementKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter().find(|k| k.code() == s).ok_or(())
+    }
+}
+
+impl fmt::Display for AchievementKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FirstWin => write!(f, "Первая победа"),
+            Self::LongShot => write!(f, "Выигрыш с коэффициентом от 10"),
+            Self::DoubledBalance => write!(f, "Баланс удвоен"),
+            Self::Survived10 => write!(f, "10 туров в игре"),
+            Self::Survived50 => write!(f, "50 туров в игре"),
+            Self::Survived100 => write!(f, "100 туров в игре"),
+            Self::Bankrupt => write!(f, "Банкрот"),
+        }
+    }
+}
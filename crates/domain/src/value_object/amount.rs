@@ -1,8 +1,11 @@
 use anyhow::{Result, bail};
 use serde::{Deserialize, Serialize};
 
+use crate::error::DomainError;
+
 type Float = f64;
 pub const MIN_BET_AMOUNT: i64 = 10_00;
+pub const MAX_BET_AMOUNT: i64 = 10_000_000;
 pub const MIN_BALANCE_AMOUNT: i64 = 0;
 const PENNY: u8 = 100;
 
@@ -27,7 +30,7 @@ impl From<Amount> for Float {
 impl Amount {
     pub fn new(value: i64, l_bound: Option<i64>) -> Result<Self> {
         if l_bound.is_some_and(|b| value < b) {
-            bail!("Amount doesn't support this value")
+            bail!(DomainError::InvalidAmount)
         }
         Ok(Self { value, l_bound })
     }
@@ -35,7 +38,7 @@ impl Amount {
     pub fn new_with_casting(value: f64, l_bound: Option<i64>) -> Result<Self> {
         let value = (value * PENNY as Float).round() as i64;
         if l_bound.is_some_and(|b| value < b) {
-            bail!("Amount doesn't support this value")
+            bail!(DomainError::InvalidAmount)
         }
         Ok(Self { value, l_bound })
     }
@@ -0,0 +1,134 @@
+use serde::Serialize;
+
+use super::Amount;
+
+/// Share of a limit after which placing a bet comes with a warning.
+pub const LIMIT_WARNING_RATIO: f64 = 0.8;
+
+/// Responsible-gaming limits of one simulation, `None` means there is no limit.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct BetLimits {
+    pub max_stake: Option<Amount>,
+    /// Sum of stakes inside one round.
+    pub max_round_stake: Option<Amount>,
+    pub loss_limit: Option<LossLimit>,
+    /// Sum of deposits inside one round.
+    pub max_round_deposit: Option<Amount>,
+    /// Unix timestamp betting is blocked until.
+    pub cool_off_until: Option<i64>,
+    /// Limits set to relax the ones above, waiting for their round to come.
+    pub pending: Option<PendingLimits>,
+}
+
+/// Limits replacing the current ones from `from_round` on.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PendingLimits {
+    pub max_stake: Option<Amount>,
+    pub max_round_stake: Option<Amount>,
+    pub loss_limit: Option<LossLimit>,
+    pub max_round_deposit: Option<Amount>,
+    pub from_round: u32,
+}
+
+impl BetLimits {
+    /// Limits in force in `round`, the pending ones if their round has come.
+    pub fn in_round(self, round: u32) -> Self {
+        match self.pending {
+            Some(pending) if pending.from_round <= round => Self {
+                max_stake: pending.max_stake,
+                max_round_stake: pending.max_round_stake,
+                loss_limit: pending.loss_limit,
+                max_round_deposit: pending.max_round_deposit,
+                cool_off_until: self.cool_off_until,
+                pending: None,
+            },
+            _ => self,
+        }
+    }
+
+    /// Limits after `new` ones are set in `round`. Every limit `new` tightens takes effect at
+    /// once, while the whole of `new` only does `delay` rounds later if it relaxes any of them.
+    pub fn change(self, new: Self, round: u32, delay: u32) -> Self {
+        fn pick<T>(tightens: bool, current: T, new: T) -> T {
+            if tightens { new } else { current }
+        }
+        let tightens = |current: Option<Amount>, new: Option<Amount>| match (current, new) {
+            (Some(current), Some(new)) => new <= current,
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+
+        let current = self.in_round(round);
+        let stake = tightens(current.max_stake, new.max_stake);
+        let round_stake = tightens(current.max_round_stake, new.max_round_stake);
+        let deposit = tightens(current.max_round_deposit, new.max_round_deposit);
+        // A loss limit also tightens when counted over more rounds.
+        let loss = match (current.loss_limit, new.loss_limit) {
+            (Some(current), Some(new)) => {
+                new.amount <= current.amount && new.rounds >= current.rounds
+            }
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+        let relaxes = !(stake && round_stake && deposit && loss);
+
+        Self {
+            max_stake: pick(stake, current.max_stake, new.max_stake),
+            max_round_stake: pick(round_stake, current.max_round_stake, new.max_round_stake),
+            loss_limit: pick(loss, current.loss_limit, new.loss_limit),
+            max_round_deposit: pick(deposit, current.max_round_deposit, new.max_round_deposit),
+            cool_off_until: current.cool_off_until,
+            pending: relaxes.then_some(PendingLimits {
+                max_stake: new.max_stake,
+                max_round_stake: new.max_round_stake,
+                loss_limit: new.loss_limit,
+                max_round_deposit: new.max_round_deposit,
+                from_round: round.saturating_add(delay),
+            }),
+        }
+    }
+}
+
+/// Net loss allowed over the last `rounds` rounds, the current one included.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LossLimit {
+    pub amount: Amount,
+    pub rounds: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitKind {
+    Stake,
+    RoundStake,
+    Loss,
+    RoundDeposit,
+}
+
+impl LimitKind {
+    /// Stable name used for the API.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Stake => "stake",
+            Self::RoundStake => "round_stake",
+            Self::Loss => "loss",
+            Self::RoundDeposit => "round_deposit",
+        }
+    }
+}
+
+/// A limit that is close to being reached.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LimitWarning {
+    pub kind: LimitKind,
+    pub used: Amount,
+    pub max: Amount,
+}
+
+impl LimitWarning {
+    /// Warning if `used` has reached `LIMIT_WARNING_RATIO` of `max`.
+    pub fn check(kind: LimitKind, used: Amount, max: Amount) -> Option<Self> {
+        let threshold = max.clear_value() as f64 * LIMIT_WARNING_RATIO;
+        (used.clear_value() as f64 >= threshold).then_some(Self { kind, used, max })
+    }
+}
@@ -6,13 +6,20 @@ use super::{Amount, Coefficient};
 pub struct BetStatistics {
     min_coefficient_lose: Option<Coefficient>,
     start_balance: Amount,
+    /// Mean closing line value of the settled bets with a known closing price.
+    clv: Option<f64>,
 }
 
 impl BetStatistics {
-    pub fn new(start_balance: Amount, min_coefficient_lose: Option<Coefficient>) -> Self {
+    pub fn new(
+        start_balance: Amount,
+        min_coefficient_lose: Option<Coefficient>,
+        clv: Option<f64>,
+    ) -> Self {
         Self {
             min_coefficient_lose,
             start_balance,
+            clv,
         }
     }
 
@@ -23,4 +30,8 @@ impl BetStatistics {
     pub fn start_balance(&self) -> Amount {
         self.start_balance
     }
+
+    pub fn clv(&self) -> Option<f64> {
+        self.clv
+    }
 }
@@ -1,6 +1,8 @@
 use anyhow::{Error, Result, bail};
 use serde::{Deserialize, Serialize};
 
+use crate::error::DomainError;
+
 type Float = f64;
 const MIN_COEFFICIENT: i32 = 1_01;
 const PENNY: i32 = 100;
@@ -22,7 +24,7 @@ impl TryFrom<Float> for Coefficient {
         if value > MIN_COEFFICIENT {
             Ok(Coefficient(value))
         } else {
-            bail!("Coefficient doesn't support this value")
+            bail!(DomainError::InvalidCoefficient)
         }
     }
 }
@@ -34,7 +36,7 @@ impl TryFrom<i32> for Coefficient {
         if value > MIN_COEFFICIENT {
             Ok(Coefficient(value))
         } else {
-            bail!("Coefficient doesn't support this value")
+            bail!(DomainError::InvalidCoefficient)
         }
     }
 }
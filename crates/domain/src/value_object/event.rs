@@ -3,12 +3,25 @@ use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum Event {
     WDL(Winner),
     T(EventTotal),
 }
 
+impl Event {
+    /// Whether the event came in at `score`, home goals first.
+    pub fn came_in(self, score: (u8, u8)) -> bool {
+        let (home, guest) = score;
+        match self {
+            Event::WDL(Winner::W1) => home > guest,
+            Event::WDL(Winner::X) => home == guest,
+            Event::WDL(Winner::W2) => home < guest,
+            Event::T(total) => (home + guest).cmp(&total.total) == total.ordering,
+        }
+    }
+}
+
 impl fmt::Display for Event {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -35,7 +48,7 @@ impl fmt::Display for Winner {
     }
 }
 
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct EventTotal {
     pub total: u8,
     #[serde(with = "ordering_serde")]
@@ -1,11 +1,13 @@
 use anyhow::{Error, Result, bail};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
 
 type Float = f64;
 const MIN: f64 = 0.0;
 const MAX_EXCLUDED: f64 = 1.0;
 
-#[derive(Deserialize, Clone, Copy)]
+#[derive(Serialize, Deserialize, Clone, Copy)]
 pub struct Margin(Float);
 
 impl TryFrom<Float> for Margin {
@@ -15,7 +17,7 @@ impl TryFrom<Float> for Margin {
         if (MIN..MAX_EXCLUDED).contains(&value) {
             Ok(Margin(value))
         } else {
-            bail!("Margin doesn't support this value")
+            bail!(DomainError::InvalidMargin)
         }
     }
 }
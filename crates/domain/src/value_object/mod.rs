@@ -1,19 +1,29 @@
+mod achievement_kind;
 mod amount;
+mod bet_limits;
 mod bet_statistics;
 mod coefficient;
 mod deviation;
 mod event;
 mod id;
 mod margin;
+mod odds_format;
 mod past_results;
 mod past_totals;
+mod strategy_kind;
 
-pub use amount::{Amount, MIN_BALANCE_AMOUNT, MIN_BET_AMOUNT};
+pub use achievement_kind::AchievementKind;
+pub use amount::{Amount, MAX_BET_AMOUNT, MIN_BALANCE_AMOUNT, MIN_BET_AMOUNT};
+pub use bet_limits::{
+    BetLimits, LIMIT_WARNING_RATIO, LimitKind, LimitWarning, LossLimit, PendingLimits,
+};
 pub use bet_statistics::BetStatistics;
 pub use coefficient::Coefficient;
 pub use deviation::Deviation;
 pub use event::{Event, EventTotal, Winner};
 pub use id::Id;
 pub use margin::Margin;
+pub use odds_format::OddsFormat;
 pub use past_results::PastResults;
 pub use past_totals::PastTotals;
+pub use strategy_kind::StrategyKind;
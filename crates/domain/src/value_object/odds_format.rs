@@ -0,0 +1,143 @@
+use anyhow::{Result, bail};
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+use crate::value_object::Coefficient;
+
+/// Profit on a won stake of one in hundredths, `Coefficient` minus the stake.
+const STAKE: i64 = 100;
+
+/// Way odds are written down. `Coefficient` is stored decimal, the other formats are
+/// rendered from it and parsed back to it.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OddsFormat {
+    /// `2.50`, the stake included.
+    #[default]
+    Decimal,
+    /// `3/2`, profit to stake.
+    Fractional,
+    /// `+150` to win on a stake of 100, `-200` to stake to win 100.
+    American,
+    /// `1.50`, profit on a stake of one.
+    HongKong,
+    /// `0.50` up to evens, `-0.50` stake to win one on longer odds.
+    Malay,
+    /// `+1.50` profit on a stake of one from evens, `-2.00` stake to win one below.
+    Indonesian,
+}
+
+impl OddsFormat {
+    pub const ALL: [Self; 6] = [
+        Self::Decimal,
+        Self::Fractional,
+        Self::American,
+        Self::HongKong,
+        Self::Malay,
+        Self::Indonesian,
+    ];
+
+    /// Stable name used for storage and the API.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Decimal => "decimal",
+            Self::Fractional => "fractional",
+            Self::American => "american",
+            Self::HongKong => "hong_kong",
+            Self::Malay => "malay",
+            Self::Indonesian => "indonesian",
+        }
+    }
+
+    /// Writes `coefficient` in this format. Parsing the result gives `coefficient` back,
+    /// except for Malay odds above 11.00 which four decimals can't pin to a hundredth.
+    pub fn render(self, coefficient: Coefficient) -> String {
+        let profit = coefficient.clear_value() as i64 - STAKE;
+        let ratio = profit as f64 / STAKE as f64;
+        match self {
+            Self::Decimal => format!("{:.2}", f64::from(coefficient)),
+            Self::Fractional => {
+                let gcd = gcd(profit, STAKE);
+                format!("{}/{}", profit / gcd, STAKE / gcd)
+            }
+            Self::American if profit >= STAKE => format!("+{}", profit),
+            Self::American => format!("{}", (-STAKE as f64 / ratio).round()),
+            Self::HongKong => format!("{:.2}", ratio),
+            Self::Malay if profit <= STAKE => format!("{:.2}", ratio),
+            Self::Malay => format!("{:.4}", -1. / ratio),
+            Self::Indonesian if profit >= STAKE => format!("+{:.2}", ratio),
+            Self::Indonesian => format!("{:.2}", -1. / ratio),
+        }
+    }
+
+    /// Reads odds written in this format, e.g. by [`OddsFormat::render`].
+    pub fn parse(self, odds: &str) -> Result<Coefficient> {
+        let odds = odds.trim();
+        let ratio = match self {
+            Self::Decimal => number(odds)? - 1.,
+            Self::Fractional => {
+                let Some((numerator, denominator)) = odds.split_once('/') else {
+                    bail!(DomainError::InvalidCoefficient);
+                };
+                let denominator = number(denominator)?;
+                if denominator <= 0. {
+                    bail!(DomainError::InvalidCoefficient);
+                }
+                number(numerator)? / denominator
+            }
+            Self::American => match number(odds)? {
+                v if v >= STAKE as f64 => v / STAKE as f64,
+                v if v <= -STAKE as f64 => -STAKE as f64 / v,
+                _ => bail!(DomainError::InvalidCoefficient),
+            },
+            Self::HongKong => number(odds)?,
+            Self::Malay => match number(odds)? {
+                v if v > 0. && v <= 1. => v,
+                v if (-1. ..0.).contains(&v) => -1. / v,
+                _ => bail!(DomainError::InvalidCoefficient),
+            },
+            Self::Indonesian => match number(odds)? {
+                v if v >= 1. => v,
+                v if v <= -1. => -1. / v,
+                _ => bail!(DomainError::InvalidCoefficient),
+            },
+        };
+
+        Coefficient::try_from(1. + ratio)
+    }
+}
+
+fn number(s: &str) -> Result<f64> {
+    match s.trim().parse::<f64>() {
+        Ok(v) if v.is_finite() => Ok(v),
+        _ => bail!(DomainError::InvalidCoefficient),
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+impl FromStr for OddsFormat {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter().find(|f| f.code() == s).ok_or(())
+    }
+}
+
+impl fmt::Display for OddsFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decimal => write!(f, "Десятичные"),
+            Self::Fractional => write!(f, "Дробные"),
+            Self::American => write!(f, "Американские"),
+            Self::HongKong => write!(f, "Гонконгские"),
+            Self::Malay => write!(f, "Малайские"),
+            Self::Indonesian => write!(f, "Индонезийские"),
+        }
+    }
+}
@@ -2,6 +2,8 @@ use anyhow::{Result, bail};
 
 use std::{cmp::Ordering, ops::Add};
 
+use crate::error::DomainError;
+
 pub struct PastTotals {
     origin_total: u8,
     less: u8,
@@ -56,7 +58,7 @@ impl Add for PastTotals {
 
     fn add(self, rhs: Self) -> Self::Output {
         if self.origin_total != rhs.origin_total {
-            bail!("Can't add PastTotals with different origin_total")
+            bail!(DomainError::TotalsMismatch)
         }
         let origin_total = self.origin_total;
         let less = self.less + rhs.less;
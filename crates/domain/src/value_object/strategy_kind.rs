@@ -0,0 +1,47 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Built-in betting bot that can play rounds on its own.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StrategyKind {
+    Flat,
+    Martingale,
+    Kelly,
+    Value,
+}
+
+impl StrategyKind {
+    pub const ALL: [Self; 4] = [Self::Flat, Self::Martingale, Self::Kelly, Self::Value];
+
+    /// Stable name used for storage and the API.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Flat => "flat",
+            Self::Martingale => "martingale",
+            Self::Kelly => "kelly",
+            Self::Value => "value",
+        }
+    }
+}
+
+impl FromStr for StrategyKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter().find(|k| k.code() == s).ok_or(())
+    }
+}
+
+impl fmt::Display for StrategyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Flat => write!(f, "Фиксированная ставка"),
+            Self::Martingale => write!(f, "Мартингейл"),
+            Self::Kelly => write!(f, "Критерий Келли"),
+            Self::Value => write!(f, "Поиск валуя"),
+        }
+    }
+}
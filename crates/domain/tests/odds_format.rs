@@ -0,0 +1,105 @@
+use domain::error::DomainError;
+use domain::value_object::{Coefficient, OddsFormat};
+
+fn coefficient(value: f64) -> Coefficient {
+    value.try_into().unwrap()
+}
+
+fn round_trip(format: OddsFormat, hundredths: impl Iterator<Item = i32>) {
+    for value in hundredths {
+        let coefficient = Coefficient::try_from(value).unwrap();
+        let odds = format.render(coefficient);
+        let parsed = format.parse(&odds).unwrap();
+        assert_eq!(parsed, coefficient, "{} rendered {}", value, odds);
+    }
+}
+
+#[test]
+fn exact_formats_round_trip() {
+    for format in [
+        OddsFormat::Decimal,
+        OddsFormat::Fractional,
+        OddsFormat::American,
+        OddsFormat::HongKong,
+        OddsFormat::Indonesian,
+    ] {
+        round_trip(format, 102..=100_000);
+    }
+}
+
+#[test]
+fn malay_round_trips_up_to_eleven() {
+    round_trip(OddsFormat::Malay, 102..=1100);
+}
+
+#[test]
+fn renders_common_odds() {
+    let cases = [
+        (1.5, ["1.50", "1/2", "-200", "0.50", "0.50", "-2.00"]),
+        (2., ["2.00", "1/1", "+100", "1.00", "1.00", "+1.00"]),
+        (2.5, ["2.50", "3/2", "+150", "1.50", "-0.6667", "+1.50"]),
+        (1.03, ["1.03", "3/100", "-3333", "0.03", "0.03", "-33.33"]),
+        (
+            11.,
+            ["11.00", "10/1", "+1000", "10.00", "-0.1000", "+10.00"],
+        ),
+    ];
+    for (value, rendered) in cases {
+        for (format, odds) in OddsFormat::ALL.into_iter().zip(rendered) {
+            assert_eq!(format.render(coefficient(value)), odds, "{:?}", format);
+        }
+    }
+}
+
+#[test]
+fn parses_written_by_hand() {
+    assert_eq!(
+        OddsFormat::Fractional.parse(" 5/4 ").unwrap(),
+        coefficient(2.25)
+    );
+    assert_eq!(OddsFormat::American.parse("250").unwrap(), coefficient(3.5));
+    assert_eq!(
+        OddsFormat::American.parse("-125").unwrap(),
+        coefficient(1.8)
+    );
+    assert_eq!(OddsFormat::Malay.parse("-0.8").unwrap(), coefficient(2.25));
+    assert_eq!(
+        OddsFormat::Indonesian.parse("-1.25").unwrap(),
+        coefficient(1.8)
+    );
+}
+
+#[test]
+fn invalid_odds_refused() {
+    let cases = [
+        (OddsFormat::Decimal, "1.01"),
+        (OddsFormat::Decimal, "abc"),
+        (OddsFormat::Fractional, "3"),
+        (OddsFormat::Fractional, "3/0"),
+        (OddsFormat::American, "50"),
+        (OddsFormat::American, "-99"),
+        (OddsFormat::HongKong, "-0.5"),
+        (OddsFormat::Malay, "1.5"),
+        (OddsFormat::Malay, "0"),
+        (OddsFormat::Indonesian, "0.5"),
+        (OddsFormat::Indonesian, "inf"),
+    ];
+    for (format, odds) in cases {
+        let err = format.parse(odds).err().unwrap();
+        assert_eq!(
+            err.downcast::<DomainError>().unwrap(),
+            DomainError::InvalidCoefficient,
+            "{:?} {}",
+            format,
+            odds
+        );
+    }
+}
+
+#[test]
+fn codes_parse_back() {
+    for format in OddsFormat::ALL {
+        assert_eq!(format.code().parse::<OddsFormat>(), Ok(format));
+    }
+    assert!("european".parse::<OddsFormat>().is_err());
+}
@@ -1,2 +1,3 @@
 pub mod config;
 pub mod logger;
+pub mod storage;
@@ -0,0 +1,144 @@
+use application::config::{StorageBackend, StorageConfig};
+use application::repository::{
+    IAchievementRepo, IAutoplayRunRepo, IBetLimitRepo, IBetRepo, IDepositRepo, IForecastRepo,
+    IGameRepo, IGameStatRepo, IOddsSnapshotRepo, ISessionRepo, ISimulationRepo, ITeamRepo,
+    IUnitOfWork, IUserRepo,
+};
+use db::DBPool;
+use memory::MemoryStore;
+
+pub type AchievementRepo = Box<dyn IAchievementRepo + Send + Sync>;
+pub type AutoplayRunRepo = Box<dyn IAutoplayRunRepo + Send + Sync>;
+pub type BetRepo = Box<dyn IBetRepo + Send + Sync>;
+pub type BetLimitRepo = Box<dyn IBetLimitRepo + Send + Sync>;
+pub type DepositRepo = Box<dyn IDepositRepo + Send + Sync>;
+pub type ForecastRepo = Box<dyn IForecastRepo + Send + Sync>;
+pub type GameRepo = Box<dyn IGameRepo + Send + Sync>;
+pub type GameStatRepo = Box<dyn IGameStatRepo + Send + Sync>;
+pub type OddsSnapshotRepo = Box<dyn IOddsSnapshotRepo + Send + Sync>;
+pub type SessionRepo = Box<dyn ISessionRepo + Send + Sync>;
+pub type SimulationRepo = Box<dyn ISimulationRepo + Send + Sync>;
+pub type TeamRepo = Box<dyn ITeamRepo + Send + Sync>;
+pub type UnitOfWork = Box<dyn IUnitOfWork + Send + Sync>;
+pub type UserRepo = Box<dyn IUserRepo + Send + Sync>;
+
+#[derive(Clone)]
+pub enum Storage {
+    Database(DBPool),
+    Memory(MemoryStore),
+}
+
+impl From<StorageConfig> for Storage {
+    fn from(config: StorageConfig) -> Self {
+        match config.backend {
+            StorageBackend::Database => Self::Database(db::init_pool()),
+            StorageBackend::Memory => Self::Memory(memory::init_store()),
+        }
+    }
+}
+
+impl Storage {
+    pub fn achievement_repo(&self) -> AchievementRepo {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::AchievementRepo::new(pool.clone())),
+            Self::Memory(store) => {
+                Box::new(memory::repository::AchievementRepo::new(store.clone()))
+            }
+        }
+    }
+
+    pub fn autoplay_run_repo(&self) -> AutoplayRunRepo {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::AutoplayRunRepo::new(pool.clone())),
+            Self::Memory(store) => {
+                Box::new(memory::repository::AutoplayRunRepo::new(store.clone()))
+            }
+        }
+    }
+
+    pub fn bet_repo(&self) -> BetRepo {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::BetRepo::new(pool.clone())),
+            Self::Memory(store) => Box::new(memory::repository::BetRepo::new(store.clone())),
+        }
+    }
+
+    pub fn bet_limit_repo(&self) -> BetLimitRepo {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::BetLimitRepo::new(pool.clone())),
+            Self::Memory(store) => Box::new(memory::repository::BetLimitRepo::new(store.clone())),
+        }
+    }
+
+    pub fn deposit_repo(&self) -> DepositRepo {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::DepositRepo::new(pool.clone())),
+            Self::Memory(store) => Box::new(memory::repository::DepositRepo::new(store.clone())),
+        }
+    }
+
+    pub fn forecast_repo(&self) -> ForecastRepo {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::ForecastRepo::new(pool.clone())),
+            Self::Memory(store) => Box::new(memory::repository::ForecastRepo::new(store.clone())),
+        }
+    }
+
+    pub fn game_repo(&self) -> GameRepo {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::GameRepo::new(pool.clone())),
+            Self::Memory(store) => Box::new(memory::repository::GameRepo::new(store.clone())),
+        }
+    }
+
+    pub fn game_stat_repo(&self) -> GameStatRepo {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::GameStatRepo::new(pool.clone())),
+            Self::Memory(store) => Box::new(memory::repository::GameStatRepo::new(store.clone())),
+        }
+    }
+
+    pub fn odds_snapshot_repo(&self) -> OddsSnapshotRepo {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::OddsSnapshotRepo::new(pool.clone())),
+            Self::Memory(store) => {
+                Box::new(memory::repository::OddsSnapshotRepo::new(store.clone()))
+            }
+        }
+    }
+
+    pub fn session_repo(&self) -> SessionRepo {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::SessionRepo::new(pool.clone())),
+            Self::Memory(store) => Box::new(memory::repository::SessionRepo::new(store.clone())),
+        }
+    }
+
+    pub fn simulation_repo(&self) -> SimulationRepo {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::SimulationRepo::new(pool.clone())),
+            Self::Memory(store) => Box::new(memory::repository::SimulationRepo::new(store.clone())),
+        }
+    }
+
+    pub fn team_repo(&self) -> TeamRepo {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::TeamRepo::new(pool.clone())),
+            Self::Memory(store) => Box::new(memory::repository::TeamRepo::new(store.clone())),
+        }
+    }
+
+    pub fn unit_of_work(&self) -> UnitOfWork {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::UnitOfWork::new(pool.clone())),
+            Self::Memory(store) => Box::new(memory::repository::UnitOfWork::new(store.clone())),
+        }
+    }
+
+    pub fn user_repo(&self) -> UserRepo {
+        match self {
+            Self::Database(pool) => Box::new(db::repository::UserRepo::new(pool.clone())),
+            Self::Memory(store) => Box::new(memory::repository::UserRepo::new(store.clone())),
+        }
+    }
+}
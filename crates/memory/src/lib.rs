@@ -0,0 +1,8 @@
+pub mod repository;
+mod store;
+
+pub use store::MemoryStore;
+
+pub fn init_store() -> MemoryStore {
+    MemoryStore::default()
+}
@@ -0,0 +1,64 @@
+use anyhow::{Result, bail};
+
+use crate::MemoryStore;
+use application::repository::IAchievementRepo;
+use domain::{
+    entity::{Achievement, Simulation},
+    value_object::Id,
+};
+
+pub struct AchievementRepo {
+    store: MemoryStore,
+}
+
+impl AchievementRepo {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+impl IAchievementRepo for AchievementRepo {
+    fn add(&self, achievement: Achievement) -> Result<()> {
+        let mut tables = self.store.write();
+        if tables.achievement.contains_key(&achievement.id().value()) {
+            bail!("Achievement with this id already exists");
+        }
+        if !tables
+            .simulation
+            .contains_key(&achievement.simulation_id().value())
+        {
+            bail!("Achievement references unknown simulation");
+        }
+        if tables.achievement.values().any(|a| {
+            a.simulation_id() == achievement.simulation_id() && a.kind() == achievement.kind()
+        }) {
+            bail!("Achievement is already unlocked");
+        }
+        tables
+            .achievement
+            .insert(achievement.id().value(), achievement);
+
+        Ok(())
+    }
+
+    fn achievements_by_simulation_id(
+        &self,
+        simulation_id: Id<Simulation>,
+    ) -> Result<Vec<Achievement>> {
+        let mut achievements = self
+            .store
+            .read()
+            .achievement
+            .values()
+            .filter(|a| a.simulation_id() == simulation_id)
+            .copied()
+            .collect::<Vec<_>>();
+        achievements.sort_by_key(|a| a.round());
+
+        Ok(achievements)
+    }
+
+    fn next_id(&self) -> Id<Achievement> {
+        Id::new()
+    }
+}
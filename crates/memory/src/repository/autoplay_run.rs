@@ -0,0 +1,51 @@
+use anyhow::{Result, bail};
+
+use crate::MemoryStore;
+use application::repository::IAutoplayRunRepo;
+use domain::{
+    entity::{AutoplayRun, Simulation},
+    value_object::Id,
+};
+
+pub struct AutoplayRunRepo {
+    store: MemoryStore,
+}
+
+impl AutoplayRunRepo {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+impl IAutoplayRunRepo for AutoplayRunRepo {
+    fn add(&self, run: AutoplayRun) -> Result<()> {
+        let mut tables = self.store.write();
+        if tables.autoplay_run.contains_key(&run.id().value()) {
+            bail!("Autoplay run with this id already exists");
+        }
+        if !tables.simulation.contains_key(&run.simulation_id().value()) {
+            bail!("Autoplay run references unknown simulation");
+        }
+        tables.autoplay_run.insert(run.id().value(), run);
+
+        Ok(())
+    }
+
+    fn runs_by_simulation_id(&self, simulation_id: Id<Simulation>) -> Result<Vec<AutoplayRun>> {
+        let mut runs = self
+            .store
+            .read()
+            .autoplay_run
+            .values()
+            .filter(|r| r.simulation_id() == simulation_id)
+            .copied()
+            .collect::<Vec<_>>();
+        runs.sort_by_key(|r| (r.finished_at(), r.first_round()));
+
+        Ok(runs)
+    }
+
+    fn next_id(&self) -> Id<AutoplayRun> {
+        Id::new()
+    }
+}
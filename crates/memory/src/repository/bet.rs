@@ -0,0 +1,162 @@
+use anyhow::{Result, bail};
+use std::collections::BTreeMap;
+
+use crate::{MemoryStore, store::Tables};
+use application::repository::{IBetRepo, SettledTotals};
+use domain::{
+    entity::{Bet, Game, Simulation},
+    value_object::{Coefficient, Id},
+};
+
+pub struct BetRepo {
+    store: MemoryStore,
+}
+
+impl BetRepo {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+impl IBetRepo for BetRepo {
+    fn add(&self, bet: Bet) -> Result<()> {
+        insert(&mut self.store.write(), bet)
+    }
+
+    fn add_all(&self, bets: Vec<Bet>) -> Result<()> {
+        let mut tables = self.store.write();
+        let mut added = vec![];
+        for bet in bets {
+            if let Err(e) = insert(&mut tables, bet) {
+                for id in added {
+                    tables.bet.remove(&id);
+                }
+                return Err(e);
+            }
+            added.push(bet.id().value());
+        }
+
+        Ok(())
+    }
+
+    fn min_coefficient_lose(&self, simulation_id: Id<Simulation>) -> Option<Coefficient> {
+        self.store
+            .read()
+            .bet
+            .values()
+            .filter(|b| b.simulation_id() == simulation_id && b.is_won() == Some(false))
+            .map(|b| b.coefficient())
+            .min_by_key(|c| c.clear_value())
+    }
+
+    fn next_id(&self) -> Id<Bet> {
+        Id::new()
+    }
+
+    fn not_calculated_bets(&self, simulation_id: Id<Simulation>) -> Vec<Bet> {
+        self.store
+            .read()
+            .bet
+            .values()
+            .filter(|b| b.simulation_id() == simulation_id && b.is_won().is_none())
+            .copied()
+            .collect()
+    }
+
+    fn bets_by_game_id(&self, game_id: Id<Game>) -> Result<Vec<Bet>> {
+        let bets = self
+            .store
+            .read()
+            .bet
+            .values()
+            .filter(|b| b.game_id() == game_id)
+            .copied()
+            .collect();
+
+        Ok(bets)
+    }
+
+    fn bets_by_simulation_id(&self, simulation_id: Id<Simulation>) -> Result<Vec<Bet>> {
+        let bets = self
+            .store
+            .read()
+            .bet
+            .values()
+            .filter(|b| b.simulation_id() == simulation_id)
+            .copied()
+            .collect();
+
+        Ok(bets)
+    }
+
+    fn settled_totals(&self, rounds: Option<u32>) -> Result<Vec<SettledTotals>> {
+        let tables = self.store.read();
+        let window = rounds.map_or(i64::MAX, i64::from);
+        let mut totals = BTreeMap::new();
+        for bet in tables.bet.values() {
+            let Some(is_won) = bet.is_won() else {
+                continue;
+            };
+            let Some(simulation) = tables
+                .simulation
+                .get(&bet.simulation_id().value())
+                .filter(|s| !s.is_archived())
+            else {
+                continue;
+            };
+            let in_window = tables
+                .game
+                .get(&bet.game_id().value())
+                .is_some_and(|g| i64::from(g.round()) > i64::from(simulation.round()) - window);
+            if !in_window {
+                continue;
+            }
+            let total = totals
+                .entry(bet.simulation_id().value())
+                .or_insert(SettledTotals {
+                    simulation_id: bet.simulation_id(),
+                    bets: 0,
+                    staked: 0,
+                    returned: 0,
+                });
+            total.bets += 1;
+            total.staked += bet.amount().clear_value();
+            if is_won {
+                total.returned += bet.payout().clear_value();
+            }
+        }
+
+        Ok(totals.into_values().collect())
+    }
+
+    fn update_status(&self, bet: Bet) -> Result<()> {
+        if let Some(rec) = self.store.write().bet.get_mut(&bet.id().value()) {
+            *rec = Bet::new(
+                rec.id(),
+                rec.simulation_id(),
+                rec.amount(),
+                rec.coefficient(),
+                rec.game_id(),
+                rec.event(),
+                bet.is_won(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+fn insert(tables: &mut Tables, bet: Bet) -> Result<()> {
+    if tables.bet.contains_key(&bet.id().value()) {
+        bail!("Bet with this id already exists");
+    }
+    if !tables.simulation.contains_key(&bet.simulation_id().value()) {
+        bail!("Bet references unknown simulation");
+    }
+    if !tables.game.contains_key(&bet.game_id().value()) {
+        bail!("Bet references unknown game");
+    }
+    tables.bet.insert(bet.id().value(), bet);
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anyhow::{Result, bail};
+
+use crate::MemoryStore;
+use application::{
+    error::{AppError, Entity},
+    repository::IBetLimitRepo,
+};
+use domain::{
+    entity::Simulation,
+    value_object::{BetLimits, Id},
+};
+
+pub struct BetLimitRepo {
+    store: MemoryStore,
+}
+
+impl BetLimitRepo {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+impl IBetLimitRepo for BetLimitRepo {
+    fn limits_by_simulation_id(&self, simulation_id: Id<Simulation>) -> Result<BetLimits> {
+        Ok(self
+            .store
+            .read()
+            .bet_limit
+            .get(&simulation_id.value())
+            .copied()
+            .unwrap_or_default())
+    }
+
+    fn set_limits(&self, simulation_id: Id<Simulation>, limits: BetLimits) -> Result<()> {
+        let mut tables = self.store.write();
+        if !tables.simulation.contains_key(&simulation_id.value()) {
+            bail!(AppError::NotFound(Entity::Simulation));
+        }
+        tables.bet_limit.insert(simulation_id.value(), limits);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use anyhow::{Result, bail};
+use std::collections::BTreeMap;
+
+use crate::MemoryStore;
+use application::repository::IDepositRepo;
+use domain::{
+    entity::{Deposit, Simulation},
+    value_object::{Amount, Id},
+};
+
+pub struct DepositRepo {
+    store: MemoryStore,
+}
+
+impl DepositRepo {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+impl IDepositRepo for DepositRepo {
+    fn add(&self, deposit: Deposit) -> Result<()> {
+        let mut tables = self.store.write();
+        if tables.deposit.contains_key(&deposit.id().value()) {
+            bail!("Deposit with this id already exists");
+        }
+        if !tables
+            .simulation
+            .contains_key(&deposit.simulation_id().value())
+        {
+            bail!("Deposit references unknown simulation");
+        }
+        tables.deposit.insert(deposit.id().value(), deposit);
+
+        Ok(())
+    }
+
+    fn deposits_by_simulation_id(&self, simulation_id: Id<Simulation>) -> Result<Vec<Deposit>> {
+        let mut deposits = self
+            .store
+            .read()
+            .deposit
+            .values()
+            .filter(|d| d.simulation_id() == simulation_id)
+            .copied()
+            .collect::<Vec<_>>();
+        deposits.sort_by_key(|d| (d.round(), d.deposited_at()));
+
+        Ok(deposits)
+    }
+
+    fn deposited_totals(&self) -> Result<Vec<(Id<Simulation>, Amount)>> {
+        let tables = self.store.read();
+        let mut totals = BTreeMap::new();
+        for deposit in tables.deposit.values() {
+            let active = tables
+                .simulation
+                .get(&deposit.simulation_id().value())
+                .is_some_and(|s| !s.is_archived());
+            if active {
+                *totals.entry(deposit.simulation_id()).or_insert(0) +=
+                    deposit.amount().clear_value();
+            }
+        }
+
+        totals
+            .into_iter()
+            .map(|(simulation_id, amount)| Ok((simulation_id, Amount::new(amount, None)?)))
+            .collect()
+    }
+
+    fn next_id(&self) -> Id<Deposit> {
+        Id::new()
+    }
+}
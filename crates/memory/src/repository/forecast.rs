@@ -0,0 +1,59 @@
+use anyhow::{Result, bail};
+
+use crate::{MemoryStore, store::Tables};
+use application::repository::IForecastRepo;
+use domain::{entity::Forecast, value_object::Id};
+
+pub struct ForecastRepo {
+    store: MemoryStore,
+}
+
+impl ForecastRepo {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+impl IForecastRepo for ForecastRepo {
+    fn add_all(&self, forecasts: Vec<Forecast>) -> Result<()> {
+        let mut tables = self.store.write();
+        let mut added = vec![];
+        for forecast in forecasts {
+            if let Err(e) = insert(&mut tables, forecast) {
+                for id in added {
+                    tables.forecast.remove(&id);
+                }
+                return Err(e);
+            }
+            added.push(forecast.id().value());
+        }
+
+        Ok(())
+    }
+
+    fn all_forecasts(&self) -> Result<Vec<Forecast>> {
+        Ok(self.store.read().forecast.values().copied().collect())
+    }
+
+    fn next_id(&self) -> Id<Forecast> {
+        Id::new()
+    }
+}
+
+fn insert(tables: &mut Tables, forecast: Forecast) -> Result<()> {
+    if tables.forecast.contains_key(&forecast.id().value()) {
+        bail!("Forecast with this id already exists");
+    }
+    if !tables
+        .simulation
+        .contains_key(&forecast.simulation_id().value())
+    {
+        bail!("Forecast references unknown simulation");
+    }
+    if !tables.game.contains_key(&forecast.game_id().value()) {
+        bail!("Forecast references unknown game");
+    }
+    tables.forecast.insert(forecast.id().value(), forecast);
+
+    Ok(())
+}
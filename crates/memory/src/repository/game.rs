@@ -0,0 +1,178 @@
+use anyhow::{Result, bail};
+
+use crate::{MemoryStore, store::Tables};
+use application::error::{AppError, Entity};
+use application::repository::IGameRepo;
+use domain::{
+    entity::{Game, Simulation, Team},
+    value_object::Id,
+};
+
+pub struct GameRepo {
+    store: MemoryStore,
+}
+
+impl GameRepo {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+
+    fn last_games_id(
+        &self,
+        filter: impl Fn(&Game) -> bool,
+        cnt: u8,
+        is_home: bool,
+    ) -> Vec<(Id<Game>, bool)> {
+        let tables = self.store.read();
+        let mut games = tables
+            .game
+            .values()
+            .filter(|g| filter(g))
+            .collect::<Vec<_>>();
+        games.sort_by_key(|g| std::cmp::Reverse(g.round()));
+        games
+            .into_iter()
+            .take(cnt as usize)
+            .map(|g| (g.id(), is_home))
+            .collect()
+    }
+}
+
+impl IGameRepo for GameRepo {
+    fn add(&self, game: Game) -> Result<()> {
+        insert(&mut self.store.write(), game)
+    }
+
+    fn add_all(&self, games: Vec<Game>) -> Result<()> {
+        let mut tables = self.store.write();
+        let mut added = vec![];
+        for game in games {
+            if let Err(e) = insert(&mut tables, game) {
+                for id in added {
+                    tables.game.remove(&id);
+                }
+                return Err(e);
+            }
+            added.push(game.id().value());
+        }
+
+        Ok(())
+    }
+
+    fn game_by_id(&self, game_id: Id<Game>) -> Result<Game> {
+        self.store
+            .read()
+            .game
+            .get(&game_id.value())
+            .copied()
+            .ok_or_else(|| AppError::NotFound(Entity::Game).into())
+    }
+
+    fn games_id_by_round(&self, round: u32, sim_id: Id<Simulation>) -> Result<Vec<Id<Game>>> {
+        let recs = self
+            .store
+            .read()
+            .game
+            .values()
+            .filter(|g| g.simulation_id() == sim_id && g.round() == round)
+            .map(|g| g.id())
+            .collect();
+
+        Ok(recs)
+    }
+
+    fn games_id_by_team_id(
+        &self,
+        team_id: Id<Team>,
+        sim_id: Id<Simulation>,
+        cnt: u8,
+    ) -> Result<Vec<(Id<Game>, bool)>> {
+        let mut games = self.last_games_id(
+            |g| g.simulation_id() == sim_id && g.home_team_id() == team_id,
+            cnt,
+            true,
+        );
+        let mut guest_games = self.last_games_id(
+            |g| g.simulation_id() == sim_id && g.guest_team_id() == team_id,
+            cnt,
+            false,
+        );
+
+        games.append(&mut guest_games);
+
+        Ok(games)
+    }
+
+    fn h2hs_id_by_team_id(
+        &self,
+        ht_id: Id<Team>,
+        gt_id: Id<Team>,
+        sim_id: Id<Simulation>,
+        cnt: u8,
+    ) -> Result<Vec<(Id<Game>, bool)>> {
+        let mut games = self.last_games_id(
+            |g| {
+                g.simulation_id() == sim_id
+                    && g.home_team_id() == ht_id
+                    && g.guest_team_id() == gt_id
+            },
+            cnt,
+            true,
+        );
+        let mut inverse_games = self.last_games_id(
+            |g| {
+                g.simulation_id() == sim_id
+                    && g.home_team_id() == gt_id
+                    && g.guest_team_id() == ht_id
+            },
+            cnt,
+            false,
+        );
+
+        games.append(&mut inverse_games);
+
+        Ok(games)
+    }
+
+    fn games_by_simulation_id(
+        &self,
+        simulation_id: Id<Simulation>,
+        max_round: u32,
+    ) -> Result<Vec<Game>> {
+        let mut games = self
+            .store
+            .read()
+            .game
+            .values()
+            .filter(|g| g.simulation_id() == simulation_id && g.round() <= max_round)
+            .copied()
+            .collect::<Vec<_>>();
+        games.sort_by_key(|g| g.round());
+
+        Ok(games)
+    }
+
+    fn next_id(&self) -> Id<Game> {
+        Id::new()
+    }
+}
+
+fn insert(tables: &mut Tables, game: Game) -> Result<()> {
+    if tables.game.contains_key(&game.id().value()) {
+        bail!("Game with this id already exists");
+    }
+    if !tables
+        .simulation
+        .contains_key(&game.simulation_id().value())
+    {
+        bail!("Game references unknown simulation");
+    }
+    if !tables.team.contains_key(&game.home_team_id().value())
+        || !tables.team.contains_key(&game.guest_team_id().value())
+    {
+        bail!("Game references unknown team");
+    }
+    tables.game.insert(game.id().value(), game);
+
+    Ok(())
+}
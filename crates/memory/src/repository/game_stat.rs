@@ -0,0 +1,114 @@
+use anyhow::{Result, bail};
+use std::cmp::Ordering;
+
+use crate::{MemoryStore, store::Tables};
+use application::error::{AppError, Entity};
+use application::repository::IGameStatRepo;
+use domain::{
+    entity::{Game, GameStat, Simulation},
+    value_object::{Id, Winner},
+};
+
+pub struct GameStatRepo {
+    store: MemoryStore,
+}
+
+impl GameStatRepo {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+impl IGameStatRepo for GameStatRepo {
+    fn add(&self, game_stat: GameStat) -> Result<()> {
+        insert(&mut self.store.write(), game_stat)
+    }
+
+    fn add_all(&self, game_stats: Vec<GameStat>) -> Result<()> {
+        let mut tables = self.store.write();
+        let mut added = vec![];
+        for game_stat in game_stats {
+            if let Err(e) = insert(&mut tables, game_stat) {
+                for id in added {
+                    tables.gamestat.remove(&id);
+                }
+                return Err(e);
+            }
+            added.push(game_stat.id().value());
+        }
+
+        Ok(())
+    }
+
+    fn game_stat_by_game_id(&self, game_id: Id<Game>) -> Result<GameStat> {
+        self.store
+            .read()
+            .gamestat
+            .values()
+            .find(|gs| gs.game_id() == game_id)
+            .copied()
+            .ok_or_else(|| AppError::NotFound(Entity::GameStat).into())
+    }
+
+    fn game_stats_by_simulation_id(
+        &self,
+        simulation_id: Id<Simulation>,
+        max_round: u32,
+    ) -> Result<Vec<GameStat>> {
+        let tables = self.store.read();
+        let game_stats = tables
+            .gamestat
+            .values()
+            .filter(|gs| {
+                tables
+                    .game
+                    .get(&gs.game_id().value())
+                    .is_some_and(|g| g.simulation_id() == simulation_id && g.round() <= max_round)
+            })
+            .copied()
+            .collect();
+
+        Ok(game_stats)
+    }
+
+    fn goals_by_game_id(&self, game_id: Id<Game>, is_home: bool) -> Option<u8> {
+        self.score_by_game_id(game_id, is_home).map(|score| score.0)
+    }
+
+    fn next_id(&self) -> Id<GameStat> {
+        Id::new()
+    }
+
+    fn score_by_game_id(&self, game_id: Id<Game>, is_home: bool) -> Option<(u8, u8)> {
+        let rec = self.game_stat_by_game_id(game_id).ok()?;
+        let score = if is_home {
+            (rec.home_team_total(), rec.guest_team_total())
+        } else {
+            (rec.guest_team_total(), rec.home_team_total())
+        };
+
+        Some(score)
+    }
+
+    fn winner_by_game_id(&self, game_id: Id<Game>, is_home: bool) -> Option<Winner> {
+        let (home_team_total, guest_team_total) = self.score_by_game_id(game_id, is_home)?;
+
+        Some(match home_team_total.cmp(&guest_team_total) {
+            Ordering::Greater => Winner::W1,
+            Ordering::Equal => Winner::X,
+            Ordering::Less => Winner::W2,
+        })
+    }
+}
+
+fn insert(tables: &mut Tables, game_stat: GameStat) -> Result<()> {
+    if tables.gamestat.contains_key(&game_stat.id().value()) {
+        bail!("Game stat with this id already exists");
+    }
+    if !tables.game.contains_key(&game_stat.game_id().value()) {
+        bail!("Game stat references unknown game");
+    }
+    tables.gamestat.insert(game_stat.id().value(), game_stat);
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+mod achievement;
+mod autoplay_run;
+mod bet;
+mod bet_limit;
+mod deposit;
+mod forecast;
+mod game;
+mod game_stat;
+mod odds_snapshot;
+mod session;
+mod simulation;
+mod team;
+mod unit_of_work;
+mod user;
+
+pub use achievement::AchievementRepo;
+pub use autoplay_run::AutoplayRunRepo;
+pub use bet::BetRepo;
+pub use bet_limit::BetLimitRepo;
+pub use deposit::DepositRepo;
+pub use forecast::ForecastRepo;
+pub use game::GameRepo;
+pub use game_stat::GameStatRepo;
+pub use odds_snapshot::OddsSnapshotRepo;
+pub use session::SessionRepo;
+pub use simulation::SimulationRepo;
+pub use team::TeamRepo;
+pub use unit_of_work::UnitOfWork;
+pub use user::UserRepo;
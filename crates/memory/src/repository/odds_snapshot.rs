@@ -0,0 +1,72 @@
+use anyhow::{Result, bail};
+
+use crate::{MemoryStore, store::Tables};
+use application::repository::IOddsSnapshotRepo;
+use domain::{
+    entity::{Game, OddsSnapshot},
+    value_object::Id,
+};
+
+pub struct OddsSnapshotRepo {
+    store: MemoryStore,
+}
+
+impl OddsSnapshotRepo {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+impl IOddsSnapshotRepo for OddsSnapshotRepo {
+    fn add_all(&self, snapshots: Vec<OddsSnapshot>) -> Result<()> {
+        let mut tables = self.store.write();
+        let mut added = vec![];
+        for snapshot in snapshots {
+            if let Err(e) = insert(&mut tables, snapshot) {
+                for id in added {
+                    tables.odds_snapshot.remove(&id);
+                }
+                return Err(e);
+            }
+            added.push(snapshot.id().value());
+        }
+
+        Ok(())
+    }
+
+    fn snapshots_by_game_id(&self, game_id: Id<Game>) -> Result<Vec<OddsSnapshot>> {
+        let mut snapshots = self
+            .store
+            .read()
+            .odds_snapshot
+            .values()
+            .filter(|s| s.game_id() == game_id)
+            .copied()
+            .collect::<Vec<_>>();
+        snapshots.sort_by_key(|s| s.created_at());
+
+        Ok(snapshots)
+    }
+
+    fn next_id(&self) -> Id<OddsSnapshot> {
+        Id::new()
+    }
+}
+
+fn insert(tables: &mut Tables, snapshot: OddsSnapshot) -> Result<()> {
+    if tables.odds_snapshot.contains_key(&snapshot.id().value()) {
+        bail!("Odds snapshot with this id already exists");
+    }
+    if !tables
+        .simulation
+        .contains_key(&snapshot.simulation_id().value())
+    {
+        bail!("Odds snapshot references unknown simulation");
+    }
+    if !tables.game.contains_key(&snapshot.game_id().value()) {
+        bail!("Odds snapshot references unknown game");
+    }
+    tables.odds_snapshot.insert(snapshot.id().value(), snapshot);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anyhow::{Result, bail};
+
+use crate::MemoryStore;
+use application::repository::ISessionRepo;
+use domain::entity::Session;
+
+pub struct SessionRepo {
+    store: MemoryStore,
+}
+
+impl SessionRepo {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+impl ISessionRepo for SessionRepo {
+    fn add(&self, session: Session) -> Result<()> {
+        let mut tables = self.store.write();
+        if tables.session.contains_key(session.token()) {
+            bail!("Session with this token already exists");
+        }
+        if !tables.user.contains_key(&session.user_id().value()) {
+            bail!("Session references unknown user");
+        }
+        tables.session.insert(session.token().to_string(), session);
+
+        Ok(())
+    }
+
+    fn session_by_token(&self, token: &str) -> Option<Session> {
+        self.store.read().session.get(token).cloned()
+    }
+
+    fn remove_by_token(&self, token: &str) -> Result<()> {
+        self.store.write().session.remove(token);
+
+        Ok(())
+    }
+}
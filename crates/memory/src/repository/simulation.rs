@@ -0,0 +1,113 @@
+use anyhow::{Result, bail};
+
+use crate::MemoryStore;
+use application::error::{AppError, Entity};
+use application::repository::ISimulationRepo;
+use domain::{
+    entity::{Simulation, User},
+    value_object::Id,
+};
+
+pub struct SimulationRepo {
+    store: MemoryStore,
+}
+
+impl SimulationRepo {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+impl ISimulationRepo for SimulationRepo {
+    fn add(&self, simulation: Simulation) -> Result<()> {
+        let mut tables = self.store.write();
+        if tables.simulation.contains_key(&simulation.id().value()) {
+            bail!("Simulation with this id already exists");
+        }
+        if !tables.user.contains_key(&simulation.user_id().value()) {
+            bail!("Simulation references unknown user");
+        }
+        if !simulation.is_archived()
+            && tables.simulation.values().any(|s| {
+                !s.is_archived()
+                    && s.user_id() == simulation.user_id()
+                    && s.name() == simulation.name()
+            })
+        {
+            bail!("Simulation with this name already exists");
+        }
+        tables
+            .simulation
+            .insert(simulation.id().value(), simulation);
+
+        Ok(())
+    }
+
+    fn next_id(&self) -> Id<Simulation> {
+        Id::new()
+    }
+
+    fn remove_by_id(&self, simulation_id: Id<Simulation>) {
+        self.store.write().remove_simulation(simulation_id.value());
+    }
+
+    fn simulations_by_user_id(&self, user_id: Id<User>) -> Result<Vec<Simulation>> {
+        let simulations = self
+            .store
+            .read()
+            .simulation
+            .values()
+            .filter(|s| s.user_id() == user_id)
+            .cloned()
+            .collect();
+
+        Ok(simulations)
+    }
+
+    fn active_simulations(&self) -> Result<Vec<Simulation>> {
+        let simulations = self
+            .store
+            .read()
+            .simulation
+            .values()
+            .filter(|s| !s.is_archived())
+            .cloned()
+            .collect();
+
+        Ok(simulations)
+    }
+
+    fn all_simulations(&self) -> Result<Vec<Simulation>> {
+        let simulations = self.store.read().simulation.values().cloned().collect();
+
+        Ok(simulations)
+    }
+
+    fn simulation_by_id(&self, id: Id<Simulation>) -> Result<Simulation> {
+        self.store
+            .read()
+            .simulation
+            .get(&id.value())
+            .cloned()
+            .ok_or_else(|| AppError::NotFound(Entity::Simulation).into())
+    }
+
+    fn update_by_id(&self, simulation: Simulation) -> Result<()> {
+        let mut tables = self.store.write();
+        if let Some(rec) = tables.simulation.get_mut(&simulation.id().value()) {
+            let mut updated = Simulation::new(
+                rec.id(),
+                rec.user_id(),
+                simulation.name().to_string(),
+                simulation.balance(),
+                Some(simulation.round()),
+            );
+            if let Some(at) = simulation.archived_at() {
+                updated.archive(at);
+            }
+            *rec = updated;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use anyhow::Result;
+
+use crate::MemoryStore;
+use application::error::{AppError, Entity};
+use application::repository::ITeamRepo;
+use domain::{entity::Team, value_object::Id};
+
+pub struct TeamRepo {
+    store: MemoryStore,
+}
+
+impl TeamRepo {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+impl ITeamRepo for TeamRepo {
+    fn all_teams_id(&self) -> Vec<Id<Team>> {
+        self.store.read().team.values().map(|t| t.id()).collect()
+    }
+
+    fn team_by_id(&self, id: Id<Team>) -> Result<Team> {
+        self.store
+            .read()
+            .team
+            .get(&id.value())
+            .cloned()
+            .ok_or_else(|| AppError::NotFound(Entity::Team).into())
+    }
+}
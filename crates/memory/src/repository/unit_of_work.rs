@@ -0,0 +1,20 @@
+use anyhow::Result;
+
+use crate::MemoryStore;
+use application::repository::IUnitOfWork;
+
+pub struct UnitOfWork {
+    store: MemoryStore,
+}
+
+impl UnitOfWork {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+impl IUnitOfWork for UnitOfWork {
+    fn atomically(&self, work: &mut dyn FnMut() -> Result<()>) -> Result<()> {
+        self.store.atomically(work)
+    }
+}
@@ -0,0 +1,67 @@
+use anyhow::{Result, bail};
+
+use crate::MemoryStore;
+use application::error::{AppError, Entity};
+use application::repository::IUserRepo;
+use domain::{entity::User, value_object::Id};
+
+pub struct UserRepo {
+    store: MemoryStore,
+}
+
+impl UserRepo {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+impl IUserRepo for UserRepo {
+    fn add(&self, user: User) -> Result<()> {
+        let mut tables = self.store.write();
+        if tables.user.contains_key(&user.id().value()) {
+            bail!("User with this id already exists");
+        }
+        if tables.user.values().any(|u| u.login() == user.login()) {
+            bail!(AppError::LoginTaken);
+        }
+        tables.user.insert(user.id().value(), user);
+
+        Ok(())
+    }
+
+    fn user_by_id(&self, id: Id<User>) -> Result<User> {
+        self.store
+            .read()
+            .user
+            .get(&id.value())
+            .cloned()
+            .ok_or_else(|| AppError::NotFound(Entity::User).into())
+    }
+
+    fn user_by_login(&self, login: &str) -> Option<User> {
+        self.store
+            .read()
+            .user
+            .values()
+            .find(|u| u.login() == login)
+            .cloned()
+    }
+
+    fn remove_by_id(&self, user_id: Id<User>) {
+        self.store.write().remove_user(user_id.value());
+    }
+
+    fn update_by_id(&self, user: User) -> Result<()> {
+        let mut tables = self.store.write();
+        let Some(stored) = tables.user.get_mut(&user.id().value()) else {
+            bail!(AppError::NotFound(Entity::User));
+        };
+        stored.set_odds_format(user.odds_format());
+
+        Ok(())
+    }
+
+    fn next_id(&self) -> Id<User> {
+        Id::new()
+    }
+}
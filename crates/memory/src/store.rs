@@ -0,0 +1,205 @@
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::thread::{self, ThreadId};
+use uuid::Uuid;
+
+use domain::entity::{
+    Achievement, AutoplayRun, Bet, Deposit, Forecast, Game, GameStat, OddsSnapshot, Session,
+    Simulation, Team, User,
+};
+use domain::value_object::BetLimits;
+
+const TEAMS: [(&str, &str); 15] = [
+    ("123e4567-e89b-12d3-a456-426614174000", "Спартак"),
+    ("123e4567-e89b-12d3-a456-426614174001", "Зенит"),
+    ("123e4567-e89b-12d3-a456-426614174002", "Динамо"),
+    ("123e4567-e89b-12d3-a456-426614174003", "Локомотив"),
+    ("123e4567-e89b-12d3-a456-426614174004", "Краснодар"),
+    ("123e4567-e89b-12d3-a456-426614174005", "Ростов"),
+    ("123e4567-e89b-12d3-a456-426614174006", "Сочи"),
+    ("123e4567-e89b-12d3-a456-426614174007", "Урал"),
+    ("123e4567-e89b-12d3-a456-426614174008", "Ахмат"),
+    ("123e4567-e89b-12d3-a456-426614174009", "Рубин"),
+    ("123e4567-e89b-12d3-a456-426614174010", "Крылья Советов"),
+    ("123e4567-e89b-12d3-a456-426614174011", "Тамбов"),
+    ("123e4567-e89b-12d3-a456-426614174012", "Уфа"),
+    ("123e4567-e89b-12d3-a456-426614174013", "Химки"),
+    ("123e4567-e89b-12d3-a456-426614174014", "Ротор"),
+];
+
+#[derive(Clone, Default)]
+pub(crate) struct Tables {
+    pub team: BTreeMap<Uuid, Team>,
+    pub user: BTreeMap<Uuid, User>,
+    pub session: BTreeMap<String, Session>,
+    pub simulation: BTreeMap<Uuid, Simulation>,
+    pub game: BTreeMap<Uuid, Game>,
+    pub gamestat: BTreeMap<Uuid, GameStat>,
+    pub bet: BTreeMap<Uuid, Bet>,
+    pub achievement: BTreeMap<Uuid, Achievement>,
+    /// Keyed by simulation id.
+    pub bet_limit: BTreeMap<Uuid, BetLimits>,
+    pub deposit: BTreeMap<Uuid, Deposit>,
+    pub autoplay_run: BTreeMap<Uuid, AutoplayRun>,
+    pub forecast: BTreeMap<Uuid, Forecast>,
+    pub odds_snapshot: BTreeMap<Uuid, OddsSnapshot>,
+}
+
+impl Tables {
+    fn seeded() -> Self {
+        let mut tables = Self::default();
+        for (id, name) in TEAMS {
+            let id = Uuid::parse_str(id).unwrap();
+            tables
+                .team
+                .insert(id, Team::new(id.into(), name.to_string()));
+        }
+
+        tables
+    }
+
+    pub fn remove_user(&mut self, user_id: Uuid) {
+        self.user.remove(&user_id);
+        self.session.retain(|_, s| s.user_id().value() != user_id);
+        let simulations = self
+            .simulation
+            .values()
+            .filter(|s| s.user_id().value() == user_id)
+            .map(|s| s.id().value())
+            .collect::<Vec<_>>();
+        for simulation_id in simulations {
+            self.remove_simulation(simulation_id);
+        }
+    }
+
+    pub fn remove_simulation(&mut self, simulation_id: Uuid) {
+        self.simulation.remove(&simulation_id);
+        self.game
+            .retain(|_, g| g.simulation_id().value() != simulation_id);
+        self.bet
+            .retain(|_, b| b.simulation_id().value() != simulation_id);
+        self.achievement
+            .retain(|_, a| a.simulation_id().value() != simulation_id);
+        self.bet_limit.remove(&simulation_id);
+        self.deposit
+            .retain(|_, d| d.simulation_id().value() != simulation_id);
+        self.autoplay_run
+            .retain(|_, r| r.simulation_id().value() != simulation_id);
+        self.forecast
+            .retain(|_, f| f.simulation_id().value() != simulation_id);
+        self.odds_snapshot
+            .retain(|_, s| s.simulation_id().value() != simulation_id);
+        self.remove_orphans();
+    }
+
+    fn remove_orphans(&mut self) {
+        let game = &self.game;
+        self.gamestat
+            .retain(|_, gs| game.contains_key(&gs.game_id().value()));
+        self.bet
+            .retain(|_, b| game.contains_key(&b.game_id().value()));
+        self.forecast
+            .retain(|_, f| game.contains_key(&f.game_id().value()));
+        self.odds_snapshot
+            .retain(|_, s| game.contains_key(&s.game_id().value()));
+    }
+}
+
+/// Shared tables. Writes outside a transaction wait for running transactions,
+/// so a rollback never discards another thread's changes.
+#[derive(Clone)]
+pub struct MemoryStore {
+    tables: Arc<RwLock<Tables>>,
+    transaction: Arc<Mutex<()>>,
+    /// Thread running a transaction on these tables, if any.
+    owner: Arc<Mutex<Option<ThreadId>>>,
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        let tables = Arc::new(RwLock::new(Tables::seeded()));
+        let transaction = Arc::new(Mutex::new(()));
+        let owner = Arc::new(Mutex::new(None));
+        Self {
+            tables,
+            transaction,
+            owner,
+        }
+    }
+}
+
+impl MemoryStore {
+    pub(crate) fn read(&self) -> RwLockReadGuard<'_, Tables> {
+        self.tables.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(crate) fn write(&self) -> StoreWriteGuard<'_> {
+        let transaction = (!self.in_transaction()).then(|| self.lock_transaction());
+        let tables = self.tables.write().unwrap_or_else(PoisonError::into_inner);
+        StoreWriteGuard {
+            tables,
+            _transaction: transaction,
+        }
+    }
+
+    /// Runs `work` against a snapshot of the tables and restores the snapshot
+    /// if `work` fails. Nested calls on the same store join the outer one.
+    pub(crate) fn atomically(&self, work: &mut dyn FnMut() -> Result<()>) -> Result<()> {
+        if self.in_transaction() {
+            return work();
+        }
+        let _transaction = self.lock_transaction();
+        let snapshot = self.read().clone();
+        *self.lock_owner() = Some(thread::current().id());
+        let _reset = ResetOwner(&self.owner);
+        let result = work();
+        if result.is_err() {
+            *self.tables.write().unwrap_or_else(PoisonError::into_inner) = snapshot;
+        }
+
+        result
+    }
+
+    fn in_transaction(&self) -> bool {
+        *self.lock_owner() == Some(thread::current().id())
+    }
+
+    fn lock_transaction(&self) -> MutexGuard<'_, ()> {
+        self.transaction
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn lock_owner(&self) -> MutexGuard<'_, Option<ThreadId>> {
+        self.owner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+struct ResetOwner<'a>(&'a Mutex<Option<ThreadId>>);
+
+impl Drop for ResetOwner<'_> {
+    fn drop(&mut self) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+}
+
+pub(crate) struct StoreWriteGuard<'a> {
+    tables: RwLockWriteGuard<'a, Tables>,
+    _transaction: Option<MutexGuard<'a, ()>>,
+}
+
+impl Deref for StoreWriteGuard<'_> {
+    type Target = Tables;
+
+    fn deref(&self) -> &Tables {
+        &self.tables
+    }
+}
+
+impl DerefMut for StoreWriteGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Tables {
+        &mut self.tables
+    }
+}
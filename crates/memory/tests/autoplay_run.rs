@@ -0,0 +1,60 @@
+use application::repository::{IAutoplayRunRepo, ISimulationRepo, IUserRepo};
+use domain::{
+    entity::{AutoplayRun, Simulation, User},
+    value_object::{Amount, MIN_BALANCE_AMOUNT, StrategyKind},
+};
+use memory::init_store;
+use memory::repository::{AutoplayRunRepo, SimulationRepo, UserRepo};
+
+#[test]
+fn insert_and_list_runs() {
+    let store = init_store();
+
+    let repo = AutoplayRunRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let amount = |value| Amount::new(value, None).unwrap();
+    let run = |strategy, first_round, finished_at| {
+        AutoplayRun::new(
+            repo.next_id(),
+            sim_id,
+            strategy,
+            first_round,
+            5,
+            10,
+            amount(50_000),
+            amount(42_000),
+            amount(100_000),
+            amount(92_000),
+            finished_at,
+        )
+    };
+
+    repo.add(run(StrategyKind::Martingale, 6, 1_700_000_000))
+        .unwrap();
+    repo.add(run(StrategyKind::Flat, 1, 1_600_000_000)).unwrap();
+    let recs = repo.runs_by_simulation_id(sim_id).unwrap();
+
+    assert_eq!(
+        recs.iter().map(|r| r.strategy()).collect::<Vec<_>>(),
+        [StrategyKind::Flat, StrategyKind::Martingale]
+    );
+    assert_eq!(recs[1].first_round(), 6);
+    assert_eq!(recs[1].rounds(), 5);
+    assert_eq!(recs[1].bets(), 10);
+    assert_eq!(recs[1].staked().clear_value(), 50_000);
+    assert_eq!(recs[1].returned().clear_value(), 42_000);
+    assert_eq!(recs[1].end_balance().clear_value(), 92_000);
+
+    sim_repo.remove_by_id(sim_id);
+    let removed = repo.runs_by_simulation_id(sim_id).unwrap();
+
+    assert!(removed.is_empty());
+}
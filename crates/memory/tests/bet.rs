@@ -0,0 +1,296 @@
+use application::repository::{IBetRepo, IGameRepo, ISimulationRepo, ITeamRepo, IUserRepo};
+use domain::{
+    entity::{Bet, Game, Simulation, User},
+    value_object::{Amount, Event, MIN_BALANCE_AMOUNT, MIN_BET_AMOUNT, Winner},
+};
+use memory::init_store;
+use memory::repository::{BetRepo, GameRepo, SimulationRepo, TeamRepo, UserRepo};
+
+#[test]
+fn insert_bet() {
+    let store = init_store();
+
+    let bet_repo = BetRepo::new(store.clone());
+    let bet_id = bet_repo.next_id();
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let amount = Amount::new(3000, Some(MIN_BET_AMOUNT)).unwrap();
+    let coefficient = (2.40).try_into().unwrap();
+    let game_repo = GameRepo::new(store.clone());
+    let game_id = game_repo.next_id();
+    let team_repo = TeamRepo::new(store.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+    game_repo.add(game).unwrap();
+    let event = Event::WDL(Winner::W1);
+    let is_won = None;
+    let bet = Bet::new(bet_id, sim_id, amount, coefficient, game_id, event, is_won);
+
+    let res = bet_repo.add(bet);
+
+    assert!(res.is_ok());
+
+    sim_repo.remove_by_id(sim_id);
+}
+
+#[test]
+fn min_coefficient_lose() {
+    let store = init_store();
+
+    let bet_repo = BetRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let game_repo = GameRepo::new(store.clone());
+    let game_id = game_repo.next_id();
+    let team_repo = TeamRepo::new(store.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+    game_repo.add(game).unwrap();
+
+    let bet_id = bet_repo.next_id();
+    let amount = Amount::new(3000, Some(MIN_BET_AMOUNT)).unwrap();
+    let coefficient = (2.40).try_into().unwrap();
+    let event = Event::WDL(Winner::W1);
+    let is_won = Some(false);
+    let bet = Bet::new(bet_id, sim_id, amount, coefficient, game_id, event, is_won);
+    bet_repo.add(bet).unwrap();
+
+    let bet_id = bet_repo.next_id();
+    let amount = Amount::new(2000, Some(MIN_BET_AMOUNT)).unwrap();
+    let coefficient = (2.30).try_into().unwrap();
+    let event = Event::WDL(Winner::W1);
+    let is_won = Some(false);
+    let bet = Bet::new(bet_id, sim_id, amount, coefficient, game_id, event, is_won);
+    bet_repo.add(bet).unwrap();
+
+    let bet_id = bet_repo.next_id();
+    let amount = Amount::new(2000, Some(MIN_BET_AMOUNT)).unwrap();
+    let coefficient = (2.50).try_into().unwrap();
+    let event = Event::WDL(Winner::W1);
+    let is_won = Some(false);
+    let bet = Bet::new(bet_id, sim_id, amount, coefficient, game_id, event, is_won);
+    bet_repo.add(bet).unwrap();
+
+    let res = bet_repo.min_coefficient_lose(sim_id);
+
+    assert_eq!(res, Some((2.30).try_into().unwrap()));
+
+    sim_repo.remove_by_id(sim_id);
+}
+
+#[test]
+fn not_calculated_bets() {
+    let store = init_store();
+
+    let bet_repo = BetRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let game_repo = GameRepo::new(store.clone());
+    let game_id = game_repo.next_id();
+    let team_repo = TeamRepo::new(store.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+    game_repo.add(game).unwrap();
+
+    let bet_id = bet_repo.next_id();
+    let amount = Amount::new(3000, Some(MIN_BET_AMOUNT)).unwrap();
+    let coefficient = (2.40).try_into().unwrap();
+    let event = Event::WDL(Winner::W1);
+    let is_won = None;
+    let bet1 = Bet::new(bet_id, sim_id, amount, coefficient, game_id, event, is_won);
+    bet_repo.add(bet1).unwrap();
+
+    let bet_id = bet_repo.next_id();
+    let amount = Amount::new(2000, Some(MIN_BET_AMOUNT)).unwrap();
+    let coefficient = (2.30).try_into().unwrap();
+    let event = Event::WDL(Winner::W1);
+    let is_won = Some(false);
+    let bet2 = Bet::new(bet_id, sim_id, amount, coefficient, game_id, event, is_won);
+    bet_repo.add(bet2).unwrap();
+
+    let bet_id = bet_repo.next_id();
+    let amount = Amount::new(2000, Some(MIN_BET_AMOUNT)).unwrap();
+    let coefficient = (2.50).try_into().unwrap();
+    let event = Event::WDL(Winner::W1);
+    let is_won = None;
+    let bet3 = Bet::new(bet_id, sim_id, amount, coefficient, game_id, event, is_won);
+    bet_repo.add(bet3).unwrap();
+
+    let res = bet_repo.not_calculated_bets(sim_id);
+
+    assert_eq!(res.len(), 2);
+
+    sim_repo.remove_by_id(sim_id);
+}
+
+#[test]
+fn bets_by_game_id() {
+    let store = init_store();
+
+    let bet_repo = BetRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let game_repo = GameRepo::new(store.clone());
+    let team_repo = TeamRepo::new(store.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game_id = game_repo.next_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+    game_repo.add(game).unwrap();
+    let other_game_id = game_repo.next_id();
+    let other_game = Game::new(other_game_id, sim_id, team_ids[2], team_ids[3], 1);
+    game_repo.add(other_game).unwrap();
+
+    let bet_id = bet_repo.next_id();
+    let amount = Amount::new(3000, Some(MIN_BET_AMOUNT)).unwrap();
+    let coefficient = (2.40).try_into().unwrap();
+    let event = Event::WDL(Winner::W1);
+    let is_won = Some(true);
+    let bet = Bet::new(bet_id, sim_id, amount, coefficient, game_id, event, is_won);
+    bet_repo.add(bet).unwrap();
+
+    let bet_id = bet_repo.next_id();
+    let event = Event::WDL(Winner::X);
+    let bet = Bet::new(
+        bet_id,
+        sim_id,
+        amount,
+        coefficient,
+        other_game_id,
+        event,
+        is_won,
+    );
+    bet_repo.add(bet).unwrap();
+
+    let res = bet_repo.bets_by_game_id(game_id).unwrap();
+
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].event(), Event::WDL(Winner::W1));
+
+    sim_repo.remove_by_id(sim_id);
+}
+
+#[test]
+fn add_all_and_bets_by_simulation_id() {
+    let store = init_store();
+
+    let bet_repo = BetRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let game_repo = GameRepo::new(store.clone());
+    let game_id = game_repo.next_id();
+    let team_repo = TeamRepo::new(store.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+    game_repo.add(game).unwrap();
+    let amount = Amount::new(3000, Some(MIN_BET_AMOUNT)).unwrap();
+    let coefficient = (2.40).try_into().unwrap();
+    let event = Event::WDL(Winner::W1);
+    let bets = [None, Some(true)].map(|is_won| {
+        Bet::new(
+            bet_repo.next_id(),
+            sim_id,
+            amount,
+            coefficient,
+            game_id,
+            event,
+            is_won,
+        )
+    });
+
+    bet_repo.add_all(bets.to_vec()).unwrap();
+    let recs = bet_repo.bets_by_simulation_id(sim_id).unwrap();
+
+    assert_eq!(recs.len(), 2);
+
+    sim_repo.remove_by_id(sim_id);
+}
+
+#[test]
+fn settled_totals_over_window() {
+    let store = init_store();
+
+    let bet_repo = BetRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, Some(2));
+    sim_repo.add(simulation).unwrap();
+    let game_repo = GameRepo::new(store.clone());
+    let team_repo = TeamRepo::new(store);
+    let team_ids = team_repo.all_teams_id();
+    let game_ids = [1, 2].map(|round| {
+        let game = Game::new(game_repo.next_id(), sim_id, team_ids[0], team_ids[1], round);
+        let game_id = game.id();
+        game_repo.add(game).unwrap();
+        game_id
+    });
+    let amount = Amount::new(3000, Some(MIN_BET_AMOUNT)).unwrap();
+    let coefficient = (2.40).try_into().unwrap();
+    let event = Event::WDL(Winner::W1);
+    let bets = [(0, Some(true)), (1, Some(false)), (1, None)].map(|(game, is_won)| {
+        Bet::new(
+            bet_repo.next_id(),
+            sim_id,
+            amount,
+            coefficient,
+            game_ids[game],
+            event,
+            is_won,
+        )
+    });
+    bet_repo.add_all(bets.to_vec()).unwrap();
+
+    let totals = |rounds| {
+        bet_repo
+            .settled_totals(rounds)
+            .unwrap()
+            .into_iter()
+            .find(|t| t.simulation_id == sim_id)
+            .map(|t| (t.bets, t.staked, t.returned))
+    };
+
+    assert_eq!(totals(None), Some((2, 6000, 7200)));
+    assert_eq!(totals(Some(1)), Some((1, 3000, 0)));
+
+    sim_repo.remove_by_id(sim_id);
+    user_repo.remove_by_id(user_id);
+}
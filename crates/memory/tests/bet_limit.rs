@@ -0,0 +1,75 @@
+use application::error::{AppError, Entity};
+use application::repository::{IBetLimitRepo, ISimulationRepo, IUserRepo};
+use domain::{
+    entity::{Simulation, User},
+    value_object::{Amount, BetLimits, LossLimit, MIN_BALANCE_AMOUNT, PendingLimits},
+};
+use memory::init_store;
+use memory::repository::{BetLimitRepo, SimulationRepo, UserRepo};
+
+#[test]
+fn set_and_get_limits() {
+    let store = init_store();
+
+    let repo = BetLimitRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let limits = BetLimits {
+        max_stake: Some(Amount::new(5000, None).unwrap()),
+        max_round_stake: None,
+        loss_limit: Some(LossLimit {
+            amount: Amount::new(20000, None).unwrap(),
+            rounds: 5,
+        }),
+        max_round_deposit: Some(Amount::new(30000, None).unwrap()),
+        cool_off_until: Some(1_700_000_000),
+        pending: Some(PendingLimits {
+            max_stake: None,
+            max_round_stake: Some(Amount::new(8000, None).unwrap()),
+            loss_limit: Some(LossLimit {
+                amount: Amount::new(40000, None).unwrap(),
+                rounds: 3,
+            }),
+            max_round_deposit: None,
+            from_round: 4,
+        }),
+    };
+    let replaced = BetLimits {
+        max_round_stake: Some(Amount::new(9000, None).unwrap()),
+        ..Default::default()
+    };
+
+    let unset = repo.limits_by_simulation_id(sim_id).unwrap();
+    repo.set_limits(sim_id, limits).unwrap();
+    let set = repo.limits_by_simulation_id(sim_id).unwrap();
+    repo.set_limits(sim_id, replaced).unwrap();
+    let rec = repo.limits_by_simulation_id(sim_id).unwrap();
+
+    assert!(unset == BetLimits::default());
+    assert!(set == limits);
+    assert!(rec == replaced);
+
+    sim_repo.remove_by_id(sim_id);
+}
+
+#[test]
+fn set_limits_of_unknown_simulation() {
+    let store = init_store();
+
+    let repo = BetLimitRepo::new(store.clone());
+    let sim_id = SimulationRepo::new(store.clone()).next_id();
+
+    let res = repo.set_limits(sim_id, BetLimits::default());
+
+    assert_eq!(
+        res.err().unwrap().downcast::<AppError>().unwrap(),
+        AppError::NotFound(Entity::Simulation)
+    );
+}
@@ -0,0 +1,93 @@
+use application::repository::{IDepositRepo, ISimulationRepo, IUserRepo};
+use domain::{
+    entity::{Deposit, Simulation, User},
+    value_object::{Amount, MIN_BALANCE_AMOUNT},
+};
+use memory::init_store;
+use memory::repository::{DepositRepo, SimulationRepo, UserRepo};
+
+#[test]
+fn insert_and_list_deposits() {
+    let store = init_store();
+
+    let repo = DepositRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let deposit = |value, round, at| {
+        let amount = Amount::new(value, None).unwrap();
+        Deposit::new(repo.next_id(), sim_id, amount, round, at)
+    };
+
+    repo.add(deposit(50_000, 4, 1_700_000_000)).unwrap();
+    repo.add(deposit(10_000, 1, 1_600_000_000)).unwrap();
+    repo.add(deposit(20_000, 4, 1_650_000_000)).unwrap();
+    let recs = repo.deposits_by_simulation_id(sim_id).unwrap();
+
+    assert_eq!(
+        recs.iter()
+            .map(|d| d.amount().clear_value())
+            .collect::<Vec<_>>(),
+        [10_000, 20_000, 50_000]
+    );
+    assert_eq!(recs[2].round(), 4);
+    assert_eq!(recs[2].deposited_at(), 1_700_000_000);
+
+    sim_repo.remove_by_id(sim_id);
+    let removed = repo.deposits_by_simulation_id(sim_id).unwrap();
+
+    assert!(removed.is_empty());
+}
+
+#[test]
+fn deposit_into_unknown_simulation() {
+    let store = init_store();
+
+    let repo = DepositRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let amount = Amount::new(10_000, None).unwrap();
+    let deposit = Deposit::new(repo.next_id(), sim_repo.next_id(), amount, 0, 0);
+
+    let res = repo.add(deposit);
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn deposited_totals_per_simulation() {
+    let store = init_store();
+
+    let repo = DepositRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    for value in [10_000, 20_000] {
+        let amount = Amount::new(value, None).unwrap();
+        repo.add(Deposit::new(repo.next_id(), sim_id, amount, 0, 0))
+            .unwrap();
+    }
+
+    let total = repo
+        .deposited_totals()
+        .unwrap()
+        .into_iter()
+        .find(|(id, _)| *id == sim_id)
+        .map(|(_, amount)| amount.clear_value());
+
+    assert_eq!(total, Some(30_000));
+
+    sim_repo.remove_by_id(sim_id);
+    user_repo.remove_by_id(user_id);
+}
@@ -0,0 +1,121 @@
+use application::repository::{IGameRepo, ISimulationRepo, ITeamRepo, IUserRepo};
+use domain::{
+    entity::{Game, Simulation, User},
+    value_object::{Amount, MIN_BALANCE_AMOUNT},
+};
+use memory::init_store;
+use memory::repository::{GameRepo, SimulationRepo, TeamRepo, UserRepo};
+
+#[test]
+fn game_by_id_found() {
+    let store = init_store();
+
+    let game_repo = GameRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let game_id = game_repo.next_id();
+    let team_repo = TeamRepo::new(store.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+    game_repo.add(game).unwrap();
+
+    let rec = game_repo.game_by_id(game_id);
+
+    assert!(rec.is_ok());
+
+    sim_repo.remove_by_id(sim_id);
+}
+
+#[test]
+fn game_by_id_did_not_found() {
+    let store = init_store();
+
+    let game_repo = GameRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let game_id = game_repo.next_id();
+    let team_repo = TeamRepo::new(store.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+    game_repo.add(game).unwrap();
+
+    let rec = game_repo.game_by_id(sim_id.value().into());
+
+    assert!(rec.is_err());
+
+    sim_repo.remove_by_id(sim_id);
+}
+
+#[test]
+fn add_all_and_games_by_simulation_id() {
+    let store = init_store();
+
+    let game_repo = GameRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let team_repo = TeamRepo::new(store.clone());
+    let team_ids = team_repo.all_teams_id();
+    let games = [3, 1, 2]
+        .map(|round| Game::new(game_repo.next_id(), sim_id, team_ids[0], team_ids[1], round));
+
+    game_repo.add_all(games.to_vec()).unwrap();
+    let recs = game_repo.games_by_simulation_id(sim_id, 2).unwrap();
+
+    assert_eq!(recs.iter().map(|g| g.round()).collect::<Vec<_>>(), [1, 2]);
+
+    sim_repo.remove_by_id(sim_id);
+}
+
+#[test]
+fn add_all_inserts_nothing_on_failure() {
+    let store = init_store();
+
+    let game_repo = GameRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let team_repo = TeamRepo::new(store.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game = Game::new(game_repo.next_id(), sim_id, team_ids[0], team_ids[1], 1);
+    let orphan = Game::new(
+        game_repo.next_id(),
+        sim_repo.next_id(),
+        team_ids[0],
+        team_ids[1],
+        1,
+    );
+
+    let res = game_repo.add_all(vec![game, orphan]);
+
+    assert!(res.is_err());
+    assert!(game_repo.game_by_id(game.id()).is_err());
+
+    sim_repo.remove_by_id(sim_id);
+}
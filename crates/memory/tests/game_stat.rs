@@ -0,0 +1,139 @@
+use application::repository::{IGameRepo, IGameStatRepo, ISimulationRepo, ITeamRepo, IUserRepo};
+use domain::{
+    entity::{Game, GameStat, Simulation, User},
+    value_object::{Amount, MIN_BALANCE_AMOUNT},
+};
+use memory::init_store;
+use memory::repository::{GameRepo, GameStatRepo, SimulationRepo, TeamRepo, UserRepo};
+
+#[test]
+fn insert_game_stat() {
+    let store = init_store();
+
+    let game_stat_repo = GameStatRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let game_repo = GameRepo::new(store.clone());
+    let game_id = game_repo.next_id();
+    let team_repo = TeamRepo::new(store.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+    game_repo.add(game).unwrap();
+
+    let game_stat_id = game_stat_repo.next_id();
+    let game_stat = GameStat::new(game_stat_id, game_id, 2, 0);
+
+    let res = game_stat_repo.add(game_stat);
+
+    assert!(res.is_ok());
+
+    sim_repo.remove_by_id(sim_id);
+}
+
+#[test]
+fn score_by_game_id() {
+    let store = init_store();
+
+    let game_stat_repo = GameStatRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let game_repo = GameRepo::new(store.clone());
+    let game_id = game_repo.next_id();
+    let team_repo = TeamRepo::new(store.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+    game_repo.add(game).unwrap();
+
+    let game_stat_id = game_stat_repo.next_id();
+    let game_stat = GameStat::new(game_stat_id, game_id, 2, 0);
+    game_stat_repo.add(game_stat).unwrap();
+
+    let score_home = game_stat_repo.score_by_game_id(game_id, true).unwrap();
+    let score_guest = game_stat_repo.score_by_game_id(game_id, false).unwrap();
+
+    assert_eq!(score_home, (2, 0));
+    assert_eq!(score_guest, (0, 2));
+
+    sim_repo.remove_by_id(sim_id);
+}
+
+#[test]
+fn goals_by_game_id() {
+    let store = init_store();
+
+    let game_stat_repo = GameStatRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let game_repo = GameRepo::new(store.clone());
+    let game_id = game_repo.next_id();
+    let team_repo = TeamRepo::new(store.clone());
+    let team_ids = team_repo.all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+    game_repo.add(game).unwrap();
+
+    let game_stat_id = game_stat_repo.next_id();
+    let game_stat = GameStat::new(game_stat_id, game_id, 2, 0);
+    game_stat_repo.add(game_stat).unwrap();
+
+    let goals_home = game_stat_repo.goals_by_game_id(game_id, true).unwrap();
+    let goals_guest = game_stat_repo.goals_by_game_id(game_id, false).unwrap();
+
+    assert_eq!(goals_home, 2);
+    assert_eq!(goals_guest, 0);
+
+    sim_repo.remove_by_id(sim_id);
+}
+
+#[test]
+fn add_all_and_game_stats_by_simulation_id() {
+    let store = init_store();
+
+    let game_stat_repo = GameStatRepo::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    sim_repo.add(simulation).unwrap();
+    let game_repo = GameRepo::new(store.clone());
+    let team_repo = TeamRepo::new(store.clone());
+    let team_ids = team_repo.all_teams_id();
+    let games =
+        [1, 2].map(|round| Game::new(game_repo.next_id(), sim_id, team_ids[0], team_ids[1], round));
+    game_repo.add_all(games.to_vec()).unwrap();
+    let game_stats = games.map(|g| GameStat::new(game_stat_repo.next_id(), g.id(), 1, 0));
+
+    game_stat_repo.add_all(game_stats.to_vec()).unwrap();
+    let recs = game_stat_repo
+        .game_stats_by_simulation_id(sim_id, 1)
+        .unwrap();
+
+    assert_eq!(recs.len(), 1);
+    assert!(recs[0].game_id() == games[0].id());
+
+    sim_repo.remove_by_id(sim_id);
+}
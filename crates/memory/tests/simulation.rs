@@ -0,0 +1,224 @@
+use application::repository::{IGameRepo, ISimulationRepo, ITeamRepo, IUserRepo};
+use domain::{
+    entity::{Game, Simulation, User},
+    value_object::{Amount, MIN_BALANCE_AMOUNT},
+};
+use memory::init_store;
+use memory::repository::{GameRepo, SimulationRepo, TeamRepo, UserRepo};
+
+#[test]
+fn add_get_remove() {
+    let store = init_store();
+
+    let repo = SimulationRepo::new(store.clone());
+    let id = repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(id, user_id, "default".to_string(), balance, None);
+
+    repo.add(simulation).unwrap();
+    let rec = repo.simulation_by_id(id);
+    repo.remove_by_id(id);
+    user_repo.remove_by_id(user_id);
+
+    let rec = rec.unwrap();
+    assert!(rec.user_id() == user_id);
+    assert_eq!(rec.name(), "default");
+}
+
+#[test]
+fn several_per_user() {
+    let store = init_store();
+
+    let repo = SimulationRepo::new(store.clone());
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    for name in ["conservative", "martingale test"] {
+        let simulation = Simulation::new(repo.next_id(), user_id, name.to_string(), balance, None);
+        repo.add(simulation).unwrap();
+    }
+
+    let recs = repo.simulations_by_user_id(user_id);
+    let duplicate = Simulation::new(
+        repo.next_id(),
+        user_id,
+        "conservative".to_string(),
+        balance,
+        None,
+    );
+    let res = repo.add(duplicate);
+    user_repo.remove_by_id(user_id);
+
+    assert_eq!(recs.unwrap().len(), 2);
+    assert!(res.is_err());
+    assert!(repo.simulations_by_user_id(user_id).unwrap().is_empty());
+}
+
+#[test]
+fn rename() {
+    let store = init_store();
+
+    let repo = SimulationRepo::new(store.clone());
+    let id = repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let mut simulation = Simulation::new(id, user_id, "old".to_string(), balance, None);
+    repo.add(simulation.clone()).unwrap();
+
+    simulation.rename("new".to_string());
+    repo.update_by_id(simulation).unwrap();
+    let rec = repo.simulation_by_id(id);
+    user_repo.remove_by_id(user_id);
+
+    assert_eq!(rec.unwrap().name(), "new");
+}
+
+#[test]
+fn add_unknown_user() {
+    let store = init_store();
+
+    let repo = SimulationRepo::new(store.clone());
+    let user_id = UserRepo::new(store).next_id();
+    let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
+
+    let res = repo.add(Simulation::new(
+        repo.next_id(),
+        user_id,
+        "default".to_string(),
+        balance,
+        None,
+    ));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn remove_cascades_games() {
+    let store = init_store();
+
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    sim_repo
+        .add(Simulation::new(
+            sim_id,
+            user_id,
+            "default".to_string(),
+            balance,
+            None,
+        ))
+        .unwrap();
+    let game_repo = GameRepo::new(store.clone());
+    let game_id = game_repo.next_id();
+    let team_ids = TeamRepo::new(store).all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+    game_repo.add(game).unwrap();
+
+    sim_repo.remove_by_id(sim_id);
+
+    assert!(game_repo.game_by_id(game_id).is_err());
+    assert!(sim_repo.simulation_by_id(sim_id).is_err());
+}
+
+#[test]
+fn archive() {
+    let store = init_store();
+
+    let repo = SimulationRepo::new(store.clone());
+    let id = repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let mut simulation = Simulation::new(id, user_id, "default".to_string(), balance, None);
+    repo.add(simulation.clone()).unwrap();
+
+    simulation.archive(1_700_000_000);
+    repo.update_by_id(simulation).unwrap();
+    let fresh = Simulation::new(
+        repo.next_id(),
+        user_id,
+        "default".to_string(),
+        balance,
+        None,
+    );
+    let fresh_res = repo.add(fresh);
+    let duplicate = Simulation::new(
+        repo.next_id(),
+        user_id,
+        "default".to_string(),
+        balance,
+        None,
+    );
+    let duplicate_res = repo.add(duplicate);
+    let rec = repo.simulation_by_id(id);
+    user_repo.remove_by_id(user_id);
+
+    assert!(fresh_res.is_ok());
+    assert!(duplicate_res.is_err());
+    assert_eq!(rec.unwrap().archived_at(), Some(1_700_000_000));
+}
+
+#[test]
+fn active_simulations() {
+    let store = init_store();
+
+    let repo = SimulationRepo::new(store.clone());
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let mut archived = Simulation::new(repo.next_id(), user_id, "old".to_string(), balance, None);
+    archived.archive(1_700_000_000);
+    repo.add(archived.clone()).unwrap();
+    let active = Simulation::new(repo.next_id(), user_id, "new".to_string(), balance, None);
+    repo.add(active.clone()).unwrap();
+
+    let recs = repo.active_simulations().unwrap();
+    user_repo.remove_by_id(user_id);
+
+    let own = recs
+        .iter()
+        .filter(|s| s.user_id() == user_id)
+        .collect::<Vec<_>>();
+    assert_eq!(own.len(), 1);
+    assert!(own[0].id() == active.id());
+}
+
+#[test]
+fn all_simulations() {
+    let store = init_store();
+
+    let repo = SimulationRepo::new(store.clone());
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(100, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let mut archived = Simulation::new(repo.next_id(), user_id, "old".to_string(), balance, None);
+    archived.archive(1_700_000_000);
+    repo.add(archived.clone()).unwrap();
+    let active = Simulation::new(repo.next_id(), user_id, "new".to_string(), balance, None);
+    repo.add(active.clone()).unwrap();
+
+    let recs = repo.all_simulations().unwrap();
+    user_repo.remove_by_id(user_id);
+
+    let own = recs.iter().filter(|s| s.user_id() == user_id).count();
+    assert_eq!(own, 2);
+}
@@ -0,0 +1,14 @@
+use application::repository::ITeamRepo;
+use memory::init_store;
+use memory::repository::TeamRepo;
+
+#[test]
+fn select_all_teams() {
+    let store = init_store();
+
+    let repo = TeamRepo::new(store.clone());
+
+    let ids = repo.all_teams_id();
+
+    assert_eq!(15, ids.len());
+}
@@ -0,0 +1,118 @@
+use anyhow::bail;
+use application::repository::{IGameRepo, ISimulationRepo, ITeamRepo, IUnitOfWork, IUserRepo};
+use domain::{
+    entity::{Game, Simulation, User},
+    value_object::{Amount, MIN_BALANCE_AMOUNT},
+};
+use memory::init_store;
+use memory::repository::{GameRepo, SimulationRepo, TeamRepo, UnitOfWork, UserRepo};
+
+#[test]
+fn commit_keeps_changes() {
+    let store = init_store();
+
+    let unit_of_work = UnitOfWork::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    let game_repo = GameRepo::new(store.clone());
+    let game_id = game_repo.next_id();
+    let team_ids = TeamRepo::new(store).all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+
+    let res = unit_of_work.atomically(&mut || {
+        sim_repo.add(simulation.clone())?;
+        game_repo.add(game)
+    });
+
+    assert!(res.is_ok());
+    assert!(sim_repo.simulation_by_id(sim_id).is_ok());
+    assert!(game_repo.game_by_id(game_id).is_ok());
+
+    sim_repo.remove_by_id(sim_id);
+}
+
+#[test]
+fn rollback_discards_changes() {
+    let store = init_store();
+
+    let unit_of_work = UnitOfWork::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+    let game_repo = GameRepo::new(store.clone());
+    let game_id = game_repo.next_id();
+    let team_ids = TeamRepo::new(store).all_teams_id();
+    let game = Game::new(game_id, sim_id, team_ids[0], team_ids[1], 1);
+
+    let res = unit_of_work.atomically(&mut || {
+        sim_repo.add(simulation.clone())?;
+        game_repo.add(game)?;
+        bail!("err")
+    });
+
+    assert_eq!(res.err().unwrap().to_string(), "err");
+    assert!(sim_repo.simulation_by_id(sim_id).is_err());
+    assert!(game_repo.game_by_id(game_id).is_err());
+}
+
+#[test]
+fn nested_rollback_discards_outer_changes() {
+    let store = init_store();
+
+    let unit_of_work = UnitOfWork::new(store.clone());
+    let sim_repo = SimulationRepo::new(store.clone());
+    let sim_id = sim_repo.next_id();
+    let user_repo = UserRepo::new(store.clone());
+    let user_id = user_repo.next_id();
+    let user = User::new(user_id, user_id.value().to_string(), String::new());
+    user_repo.add(user).unwrap();
+    let balance = Amount::new(1000, Some(MIN_BALANCE_AMOUNT)).unwrap();
+    let simulation = Simulation::new(sim_id, user_id, "default".to_string(), balance, None);
+
+    let res = unit_of_work.atomically(&mut || {
+        sim_repo.add(simulation.clone())?;
+        unit_of_work.atomically(&mut || bail!("err"))
+    });
+
+    assert!(res.is_err());
+    assert!(sim_repo.simulation_by_id(sim_id).is_err());
+}
+
+#[test]
+fn transactions_of_other_stores_kept_apart() {
+    let outer_store = init_store();
+    let inner_store = init_store();
+
+    let outer = UnitOfWork::new(outer_store.clone());
+    let inner = UnitOfWork::new(inner_store.clone());
+    let outer_users = UserRepo::new(outer_store);
+    let inner_users = UserRepo::new(inner_store);
+    let outer_id = outer_users.next_id();
+    let inner_id = inner_users.next_id();
+
+    let res = outer.atomically(&mut || {
+        outer_users.add(User::new(outer_id, "outer".to_string(), String::new()))?;
+        let inner_res = inner.atomically(&mut || {
+            inner_users.add(User::new(inner_id, "inner".to_string(), String::new()))?;
+            bail!("err")
+        });
+        assert!(inner_res.is_err());
+
+        Ok(())
+    });
+
+    assert!(res.is_ok());
+    assert!(outer_users.user_by_id(outer_id).is_ok());
+    assert!(inner_users.user_by_id(inner_id).is_err());
+}
@@ -0,0 +1,51 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::State;
+use domain::{entity::Achievement, value_object::AchievementKind};
+use serde::Serialize;
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::auth::CurrentSimulation;
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::usecase::TrackAchievements;
+
+#[derive(Serialize)]
+pub struct AchievementResponse {
+    pub kind: AchievementKind,
+    pub title: String,
+    pub round: u32,
+    pub unlocked_at: i64,
+}
+
+impl From<Achievement> for AchievementResponse {
+    fn from(achievement: Achievement) -> Self {
+        Self {
+            kind: achievement.kind(),
+            title: achievement.kind().to_string(),
+            round: achievement.round(),
+            unlocked_at: achievement.unlocked_at(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct AchievementsSuccessResponse {
+    pub achievements: Vec<AchievementResponse>,
+}
+
+pub async fn achievements(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+) -> Result<Json<AchievementsSuccessResponse>, FailureResponse> {
+    debug!("Perform achievements operation");
+    let achievements = state
+        .achievement_service()
+        .achievements(current.simulation.id())?
+        .into_iter()
+        .map(AchievementResponse::from)
+        .collect();
+
+    Ok(AchievementsSuccessResponse { achievements }.into())
+}
@@ -0,0 +1,67 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::State;
+use domain::value_object::{Coefficient, Event};
+use serde::Serialize;
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::api::make_bet::CalculateCoefficientsRequest;
+use crate::auth::CurrentSimulation;
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::service::StakeAdvice;
+use application::usecase::AdviseStakes;
+
+#[derive(Serialize)]
+pub struct StakeAdviceResponse {
+    pub event: Event,
+    pub coefficient: Coefficient,
+    pub implied_probability: f64,
+    pub model_probability: f64,
+    pub expected_value: f64,
+    pub kelly_fraction: f64,
+    pub kelly_stake: f64,
+}
+
+impl From<StakeAdvice> for StakeAdviceResponse {
+    fn from(advice: StakeAdvice) -> Self {
+        Self {
+            event: advice.event,
+            coefficient: advice.coefficient,
+            implied_probability: advice.implied_probability,
+            model_probability: advice.model_probability,
+            expected_value: advice.expected_value,
+            kelly_fraction: advice.kelly_fraction,
+            kelly_stake: advice.kelly_stake.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct AdviseSuccessResponse {
+    /// Balance the Kelly stakes are sized for.
+    pub balance: f64,
+    pub advice: Vec<StakeAdviceResponse>,
+}
+
+pub async fn advise(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+    Json(req): Json<CalculateCoefficientsRequest>,
+) -> Result<Json<AdviseSuccessResponse>, FailureResponse> {
+    debug!("Perform advise operation");
+    let simulation = current.simulation;
+    let advice = state
+        .bet_service()
+        .advise(&simulation, req.game_id)?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Ok(AdviseSuccessResponse {
+        balance: simulation.balance().into(),
+        advice,
+    }
+    .into())
+}
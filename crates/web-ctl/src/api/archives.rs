@@ -0,0 +1,79 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::{Path, State};
+use domain::{entity::Simulation, value_object::BetStatistics};
+use serde::Serialize;
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::auth::CurrentUser;
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::usecase::{MakeReport, ManageDeposits, ManageSimulations};
+
+#[derive(Serialize)]
+pub struct ArchiveResponse {
+    pub id: String,
+    pub name: String,
+    pub round: u32,
+    pub balance: f64,
+    pub archived_at: i64,
+}
+
+impl From<Simulation> for ArchiveResponse {
+    fn from(simulation: Simulation) -> Self {
+        Self {
+            id: simulation.id().value().to_string(),
+            name: simulation.name().to_string(),
+            round: simulation.round(),
+            balance: simulation.balance().into(),
+            archived_at: simulation.archived_at().unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ListArchivesSuccessResponse {
+    pub archives: Vec<ArchiveResponse>,
+}
+
+pub async fn list_archives(
+    State(state): State<Arc<AppState>>,
+    current: CurrentUser,
+) -> Result<Json<ListArchivesSuccessResponse>, FailureResponse> {
+    debug!("Perform list archives operation");
+    let archives = state
+        .simulation_service()
+        .list_archived(current.user.id())?
+        .into_iter()
+        .map(ArchiveResponse::from)
+        .collect();
+
+    Ok(ListArchivesSuccessResponse { archives }.into())
+}
+
+#[derive(Serialize)]
+pub struct ArchiveSuccessResponse {
+    pub archive: ArchiveResponse,
+    pub stat: BetStatistics,
+}
+
+pub async fn archive(
+    State(state): State<Arc<AppState>>,
+    current: CurrentUser,
+    Path(simulation_id): Path<Uuid>,
+) -> Result<Json<ArchiveSuccessResponse>, FailureResponse> {
+    debug!("Perform archive operation");
+    let simulation = state
+        .simulation_service()
+        .archived(current.user.id(), simulation_id.into())?;
+    let put_in = state.deposit_service().summary(simulation.id())?.put_in;
+    let stat = state.bet_service().make_report(simulation.id(), put_in)?;
+
+    Ok(ArchiveSuccessResponse {
+        archive: simulation.into(),
+        stat,
+    }
+    .into())
+}
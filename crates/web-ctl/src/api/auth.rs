@@ -0,0 +1,68 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::State;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+use crate::auth::CurrentUser;
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::usecase::Authenticate;
+
+#[derive(Deserialize)]
+pub struct CredentialsRequest {
+    pub login: String,
+    pub password: String,
+}
+
+#[derive(Serialize)]
+pub struct RegisterSuccessResponse {
+    pub id: String,
+    pub login: String,
+}
+
+pub async fn register(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<CredentialsRequest>,
+) -> Result<Json<RegisterSuccessResponse>, FailureResponse> {
+    debug!("Perform register operation");
+    let user = state.auth_service().register(&req.login, &req.password)?;
+    let id = user.id().value().to_string();
+    let login = user.login().to_string();
+    info!(login, "User registered");
+
+    Ok(RegisterSuccessResponse { id, login }.into())
+}
+
+#[derive(Serialize)]
+pub struct LoginSuccessResponse {
+    pub token: String,
+    pub expires_at: i64,
+}
+
+pub async fn login(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<CredentialsRequest>,
+) -> Result<Json<LoginSuccessResponse>, FailureResponse> {
+    debug!("Perform login operation");
+    let session = state.auth_service().login(&req.login, &req.password)?;
+    info!(login = req.login, "User logged in");
+
+    Ok(LoginSuccessResponse {
+        token: session.token().to_string(),
+        expires_at: session.expires_at(),
+    }
+    .into())
+}
+
+pub async fn logout(
+    State(state): State<Arc<AppState>>,
+    current: CurrentUser,
+) -> Result<(), FailureResponse> {
+    debug!("Perform logout operation");
+    state.auth_service().logout(&current.token)?;
+    info!(login = current.user.login(), "User logged out");
+
+    Ok(())
+}
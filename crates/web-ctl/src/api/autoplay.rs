@@ -0,0 +1,140 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::State;
+use domain::entity::AutoplayRun;
+use domain::value_object::StrategyKind;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+use crate::api::achievements::AchievementResponse;
+use crate::auth::CurrentSimulation;
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::error::AppError;
+use application::service::StrategySummary;
+use application::usecase::{Autoplay, TrackAchievements};
+
+#[derive(Serialize)]
+pub struct AutoplayRunResponse {
+    pub strategy: StrategyKind,
+    pub title: String,
+    pub first_round: u32,
+    pub rounds: u32,
+    pub bets: u32,
+    pub staked: f64,
+    pub returned: f64,
+    pub start_balance: f64,
+    pub end_balance: f64,
+    pub finished_at: i64,
+}
+
+impl From<AutoplayRun> for AutoplayRunResponse {
+    fn from(run: AutoplayRun) -> Self {
+        Self {
+            strategy: run.strategy(),
+            title: run.strategy().to_string(),
+            first_round: run.first_round(),
+            rounds: run.rounds(),
+            bets: run.bets(),
+            staked: run.staked().into(),
+            returned: run.returned().into(),
+            start_balance: run.start_balance().into(),
+            end_balance: run.end_balance().into(),
+            finished_at: run.finished_at(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct StrategySummaryResponse {
+    pub strategy: StrategyKind,
+    pub title: String,
+    pub runs: usize,
+    pub rounds: u32,
+    pub bets: u32,
+    pub staked: f64,
+    pub returned: f64,
+    pub profit: f64,
+}
+
+impl From<StrategySummary> for StrategySummaryResponse {
+    fn from(summary: StrategySummary) -> Self {
+        Self {
+            strategy: summary.strategy,
+            title: summary.strategy.to_string(),
+            runs: summary.runs,
+            rounds: summary.rounds,
+            bets: summary.bets,
+            staked: summary.staked.into(),
+            returned: summary.returned.into(),
+            profit: summary.profit.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct AutoplayRunsSuccessResponse {
+    pub runs: Vec<AutoplayRunResponse>,
+    pub summaries: Vec<StrategySummaryResponse>,
+}
+
+pub async fn autoplay_runs(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+) -> Result<Json<AutoplayRunsSuccessResponse>, FailureResponse> {
+    debug!("Perform autoplay runs operation");
+    let autoplay_service = state.autoplay_service();
+    let simulation_id = current.simulation.id();
+    let runs = autoplay_service.runs(simulation_id)?;
+    let summaries = autoplay_service.summaries(simulation_id)?;
+
+    Ok(AutoplayRunsSuccessResponse {
+        runs: runs.into_iter().map(Into::into).collect(),
+        summaries: summaries.into_iter().map(Into::into).collect(),
+    }
+    .into())
+}
+
+#[derive(Deserialize)]
+pub struct AutoplayRequest {
+    pub strategy: String,
+    pub rounds: u32,
+}
+
+#[derive(Serialize)]
+pub struct AutoplaySuccessResponse {
+    pub run: AutoplayRunResponse,
+    /// Unlocked by the rounds played.
+    pub achievements: Vec<AchievementResponse>,
+}
+
+pub async fn autoplay(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+    Json(req): Json<AutoplayRequest>,
+) -> Result<Json<AutoplaySuccessResponse>, FailureResponse> {
+    debug!("Perform autoplay operation");
+    let strategy = req
+        .strategy
+        .parse::<StrategyKind>()
+        .map_err(|_| anyhow::Error::from(AppError::UnsupportedStrategy))?;
+    let simulation_id = current.simulation.id();
+    let run = state
+        .autoplay_service()
+        .autoplay(simulation_id, strategy, req.rounds)?;
+    info!(rounds = run.rounds(), bets = run.bets(), "Autoplay done");
+
+    let achievements = state
+        .achievement_service()
+        .evaluate(simulation_id)?
+        .into_iter()
+        .map(AchievementResponse::from)
+        .collect();
+
+    Ok(AutoplaySuccessResponse {
+        run: run.into(),
+        achievements,
+    }
+    .into())
+}
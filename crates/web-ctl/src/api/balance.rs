@@ -1,13 +1,10 @@
 use anyhow::Result;
 use axum::Json;
-use axum::extract::{ConnectInfo, State};
 use serde::Serialize;
-use std::net::SocketAddr;
-use std::sync::Arc;
 use tracing::debug;
 
+use crate::auth::CurrentSimulation;
 use crate::error::FailureResponse;
-use crate::state::AppState;
 
 #[derive(Serialize)]
 pub struct BalanceSuccessResponse {
@@ -15,11 +12,10 @@ pub struct BalanceSuccessResponse {
 }
 
 pub async fn balance(
-    State(state): State<Arc<AppState>>,
-    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    current: CurrentSimulation,
 ) -> Result<Json<BalanceSuccessResponse>, FailureResponse> {
     debug!("Perform balance operation");
-    let simulation = state.simulation(addr.ip())?;
+    let simulation = current.simulation;
     let amount = simulation.balance().into();
 
     Ok(BalanceSuccessResponse { amount }.into())
@@ -0,0 +1,69 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::State;
+use domain::entity::Game;
+use domain::value_object::{Coefficient, Event, Id, OddsFormat};
+use serde::Serialize;
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::auth::CurrentSimulation;
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::service::ClosingLine;
+use application::usecase::MakeReport;
+
+#[derive(Serialize)]
+pub struct BetResponse {
+    pub game_id: Id<Game>,
+    pub event: Event,
+    pub coefficient: Coefficient,
+    /// `coefficient` in the user's odds format.
+    pub odds: String,
+    pub amount: f64,
+    pub is_won: Option<bool>,
+    /// Last price offered on the event before the game was played.
+    pub closing_coefficient: Option<Coefficient>,
+    /// Closing line value, 0.05 for a price 5% better than the closing one.
+    pub clv: Option<f64>,
+}
+
+impl BetResponse {
+    fn new(line: ClosingLine, odds_format: OddsFormat) -> Self {
+        let bet = line.bet;
+        Self {
+            game_id: bet.game_id(),
+            event: bet.event(),
+            coefficient: bet.coefficient(),
+            odds: odds_format.render(bet.coefficient()),
+            amount: bet.amount().into(),
+            is_won: bet.is_won(),
+            closing_coefficient: line.closing,
+            clv: line.clv(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct BetsSuccessResponse {
+    pub odds_format: OddsFormat,
+    pub bets: Vec<BetResponse>,
+}
+
+pub async fn bets(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+) -> Result<Json<BetsSuccessResponse>, FailureResponse> {
+    debug!("Perform bets operation");
+    let odds_format = current.user.odds_format();
+    let bets = state.bet_service().closing_lines(current.simulation.id())?;
+
+    Ok(BetsSuccessResponse {
+        odds_format,
+        bets: bets
+            .into_iter()
+            .map(|l| BetResponse::new(l, odds_format))
+            .collect(),
+    }
+    .into())
+}
@@ -0,0 +1,114 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::State;
+use serde::Serialize;
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::auth::CurrentUser;
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::service::{BookWinner, MarketHold, OpenGame};
+use application::usecase::ShowBookReport;
+
+#[derive(Serialize)]
+pub struct MarketHoldResponse {
+    pub market: String,
+    pub bets: usize,
+    pub turnover: f64,
+    pub revenue: f64,
+    pub hold: f64,
+}
+
+impl From<MarketHold> for MarketHoldResponse {
+    fn from(market: MarketHold) -> Self {
+        Self {
+            market: market.market.code(),
+            bets: market.bets,
+            turnover: market.turnover.into(),
+            revenue: market.revenue.into(),
+            hold: market.hold,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct BookWinnerResponse {
+    pub simulation_id: String,
+    pub name: String,
+    pub login: String,
+    pub staked: f64,
+    pub profit: f64,
+}
+
+impl From<BookWinner> for BookWinnerResponse {
+    fn from(winner: BookWinner) -> Self {
+        Self {
+            simulation_id: winner.simulation_id.value().to_string(),
+            name: winner.name,
+            login: winner.login,
+            staked: winner.staked.into(),
+            profit: winner.profit.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct OpenGameResponse {
+    pub simulation_id: String,
+    pub game_id: String,
+    pub round: u32,
+    pub bets: usize,
+    pub staked: f64,
+    pub liability: f64,
+}
+
+impl From<OpenGame> for OpenGameResponse {
+    fn from(game: OpenGame) -> Self {
+        Self {
+            simulation_id: game.simulation_id.value().to_string(),
+            game_id: game.game_id.value().to_string(),
+            round: game.round,
+            bets: game.bets,
+            staked: game.staked.into(),
+            liability: game.liability.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct BookReportSuccessResponse {
+    pub simulations: usize,
+    pub bets: usize,
+    pub turnover: f64,
+    pub settled_turnover: f64,
+    pub revenue: f64,
+    pub hold: f64,
+    pub margin: f64,
+    pub markets: Vec<MarketHoldResponse>,
+    pub winners: Vec<BookWinnerResponse>,
+    pub exposure: Vec<OpenGameResponse>,
+}
+
+/// Admins only, the house side of every simulation.
+pub async fn book_report(
+    State(state): State<Arc<AppState>>,
+    current: CurrentUser,
+) -> Result<Json<BookReportSuccessResponse>, FailureResponse> {
+    debug!("Perform book report operation");
+    let report = state.book_service().book_report(&current.user)?;
+
+    Ok(BookReportSuccessResponse {
+        simulations: report.simulations,
+        bets: report.bets,
+        turnover: report.turnover.into(),
+        settled_turnover: report.settled_turnover.into(),
+        revenue: report.revenue.into(),
+        hold: report.hold,
+        margin: report.margin.into(),
+        markets: report.markets.into_iter().map(Into::into).collect(),
+        winners: report.winners.into_iter().map(Into::into).collect(),
+        exposure: report.exposure.into_iter().map(Into::into).collect(),
+    }
+    .into())
+}
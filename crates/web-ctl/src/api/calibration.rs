@@ -0,0 +1,75 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::State;
+use serde::Serialize;
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::auth::CurrentUser;
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::service::{MarketCalibration, ReliabilityBucket};
+use application::usecase::ShowCalibration;
+
+#[derive(Serialize)]
+pub struct ReliabilityBucketResponse {
+    pub lower: f64,
+    pub upper: f64,
+    pub forecasts: usize,
+    pub mean_probability: f64,
+    pub frequency: f64,
+}
+
+impl From<ReliabilityBucket> for ReliabilityBucketResponse {
+    fn from(bucket: ReliabilityBucket) -> Self {
+        Self {
+            lower: bucket.lower,
+            upper: bucket.upper,
+            forecasts: bucket.forecasts,
+            mean_probability: bucket.mean_probability,
+            frequency: bucket.frequency,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct MarketCalibrationResponse {
+    pub market: String,
+    pub games: usize,
+    pub brier: f64,
+    pub log_loss: f64,
+    pub buckets: Vec<ReliabilityBucketResponse>,
+}
+
+impl From<MarketCalibration> for MarketCalibrationResponse {
+    fn from(market: MarketCalibration) -> Self {
+        Self {
+            market: market.market.code(),
+            games: market.games,
+            brier: market.brier,
+            log_loss: market.log_loss,
+            buckets: market.buckets.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CalibrationSuccessResponse {
+    pub games: usize,
+    pub markets: Vec<MarketCalibrationResponse>,
+}
+
+/// Admins only, how well the model priced the games played so far.
+pub async fn calibration(
+    State(state): State<Arc<AppState>>,
+    current: CurrentUser,
+) -> Result<Json<CalibrationSuccessResponse>, FailureResponse> {
+    debug!("Perform calibration operation");
+    let report = state.calibration_service().calibration(&current.user)?;
+
+    Ok(CalibrationSuccessResponse {
+        games: report.games,
+        markets: report.markets.into_iter().map(Into::into).collect(),
+    }
+    .into())
+}
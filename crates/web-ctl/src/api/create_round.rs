@@ -1,33 +1,48 @@
 use anyhow::Result;
 use axum::Json;
-use axum::extract::{ConnectInfo, State};
+use axum::extract::State;
 use serde::Serialize;
-use std::net::SocketAddr;
 use std::sync::Arc;
 use tracing::{debug, info};
 
+use crate::api::achievements::AchievementResponse;
+use crate::auth::CurrentSimulation;
 use crate::error::FailureResponse;
 use crate::state::AppState;
 use application::service::DisplayedGame;
-use application::usecase::CreateRound;
+use application::usecase::{CreateRound, TrackAchievements};
 
 #[derive(Serialize)]
 pub struct CreateRoundSuccessResponse {
     pub round: u32,
     pub games: Vec<DisplayedGame>,
+    /// Unlocked by this round.
+    pub achievements: Vec<AchievementResponse>,
 }
 
 pub async fn create_round(
     State(state): State<Arc<AppState>>,
-    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    current: CurrentSimulation,
 ) -> Result<Json<CreateRoundSuccessResponse>, FailureResponse> {
     debug!("Perform create_round operation");
-    let mut simulation = state.simulation(addr.ip())?;
+    let mut simulation = current.simulation;
     let sim_service = state.simulation_service();
 
     let games: Vec<DisplayedGame> = sim_service.create_round(&mut simulation)?;
     let round = simulation.round();
     info!(round, "Games created");
 
-    Ok(CreateRoundSuccessResponse { round, games }.into())
+    let achievements = state
+        .achievement_service()
+        .evaluate(simulation.id())?
+        .into_iter()
+        .map(AchievementResponse::from)
+        .collect();
+
+    Ok(CreateRoundSuccessResponse {
+        round,
+        games,
+        achievements,
+    }
+    .into())
 }
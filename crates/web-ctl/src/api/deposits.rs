@@ -0,0 +1,105 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::State;
+use domain::entity::Deposit;
+use domain::value_object::Amount;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+use crate::auth::CurrentSimulation;
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::service::DepositSummary;
+use application::usecase::ManageDeposits;
+
+#[derive(Serialize)]
+pub struct DepositResponse {
+    pub amount: f64,
+    pub round: u32,
+    pub deposited_at: i64,
+}
+
+impl From<Deposit> for DepositResponse {
+    fn from(deposit: Deposit) -> Self {
+        Self {
+            amount: deposit.amount().into(),
+            round: deposit.round(),
+            deposited_at: deposit.deposited_at(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct DepositSummaryResponse {
+    pub balance: f64,
+    pub deposited: f64,
+    pub put_in: f64,
+    /// Balance minus the money put in.
+    pub net: f64,
+}
+
+impl From<DepositSummary> for DepositSummaryResponse {
+    fn from(summary: DepositSummary) -> Self {
+        Self {
+            balance: summary.balance.into(),
+            deposited: summary.deposited.into(),
+            put_in: summary.put_in.into(),
+            net: summary.net.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct DepositsSuccessResponse {
+    pub deposits: Vec<DepositResponse>,
+    pub summary: DepositSummaryResponse,
+}
+
+pub async fn deposits(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+) -> Result<Json<DepositsSuccessResponse>, FailureResponse> {
+    debug!("Perform deposits operation");
+    let deposit_service = state.deposit_service();
+    let simulation_id = current.simulation.id();
+    let deposits = deposit_service.deposits(simulation_id)?;
+    let summary = deposit_service.summary(simulation_id)?;
+
+    Ok(DepositsSuccessResponse {
+        deposits: deposits.into_iter().map(Into::into).collect(),
+        summary: summary.into(),
+    }
+    .into())
+}
+
+#[derive(Deserialize)]
+pub struct DepositRequest {
+    pub value: f64,
+}
+
+#[derive(Serialize)]
+pub struct DepositSuccessResponse {
+    pub deposit: DepositResponse,
+    pub summary: DepositSummaryResponse,
+}
+
+pub async fn deposit(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+    Json(req): Json<DepositRequest>,
+) -> Result<Json<DepositSuccessResponse>, FailureResponse> {
+    debug!("Perform deposit operation");
+    let amount = Amount::new_with_casting(req.value, None)?;
+    let deposit_service = state.deposit_service();
+    let simulation_id = current.simulation.id();
+    let deposit = deposit_service.deposit(simulation_id, amount)?;
+    info!("Deposit made");
+    let summary = deposit_service.summary(simulation_id)?;
+
+    Ok(DepositSuccessResponse {
+        deposit: deposit.into(),
+        summary: summary.into(),
+    }
+    .into())
+}
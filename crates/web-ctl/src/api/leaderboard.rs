@@ -0,0 +1,89 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::{Query, State};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::service::{LeaderboardEntry, LeaderboardQuery, RankingMetric};
+use application::usecase::ShowLeaderboard;
+
+#[derive(Deserialize)]
+pub struct LeaderboardParams {
+    pub metric: Option<String>,
+    pub rounds: Option<u32>,
+    pub page: Option<usize>,
+    pub per_page: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct LeaderboardEntryResponse {
+    pub rank: usize,
+    pub simulation_id: String,
+    pub name: String,
+    pub login: String,
+    pub round: u32,
+    pub balance: f64,
+    pub deposited: f64,
+    pub staked: f64,
+    pub profit: f64,
+    pub roi: f64,
+    pub bets: usize,
+}
+
+impl From<LeaderboardEntry> for LeaderboardEntryResponse {
+    fn from(entry: LeaderboardEntry) -> Self {
+        Self {
+            rank: entry.rank,
+            simulation_id: entry.simulation_id.value().to_string(),
+            name: entry.name,
+            login: entry.login,
+            round: entry.round,
+            balance: entry.balance.into(),
+            deposited: entry.deposited.into(),
+            staked: entry.staked.into(),
+            profit: entry.profit.into(),
+            roi: entry.roi,
+            bets: entry.bets,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LeaderboardSuccessResponse {
+    pub entries: Vec<LeaderboardEntryResponse>,
+    pub page: usize,
+    pub per_page: usize,
+    pub total: usize,
+}
+
+/// Public, ranks the active simulations of every user.
+pub async fn leaderboard(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<LeaderboardParams>,
+) -> Result<Json<LeaderboardSuccessResponse>, FailureResponse> {
+    debug!("Perform leaderboard operation");
+    let metric = params
+        .metric
+        .as_deref()
+        .unwrap_or("balance")
+        .parse::<RankingMetric>()
+        .map_err(anyhow::Error::from)?;
+    let query = LeaderboardQuery {
+        metric,
+        rounds: params.rounds,
+        page: params.page.unwrap_or(1),
+        per_page: params.per_page,
+    };
+    let page = state.leaderboard_service().leaderboard(&query)?;
+
+    Ok(LeaderboardSuccessResponse {
+        entries: page.entries.into_iter().map(Into::into).collect(),
+        page: page.page,
+        per_page: page.per_page,
+        total: page.total,
+    }
+    .into())
+}
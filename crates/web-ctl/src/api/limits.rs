@@ -0,0 +1,135 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::State;
+use domain::value_object::{Amount, BetLimits, LossLimit, PendingLimits};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::auth::CurrentSimulation;
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::usecase::ManageLimits;
+
+#[derive(Serialize)]
+pub struct PendingLimitsResponse {
+    pub max_stake: Option<f64>,
+    pub max_round_stake: Option<f64>,
+    pub loss_limit: Option<f64>,
+    pub loss_limit_rounds: Option<u32>,
+    pub max_round_deposit: Option<f64>,
+    pub from_round: u32,
+}
+
+impl From<PendingLimits> for PendingLimitsResponse {
+    fn from(pending: PendingLimits) -> Self {
+        Self {
+            max_stake: pending.max_stake.map(f64::from),
+            max_round_stake: pending.max_round_stake.map(f64::from),
+            loss_limit: pending.loss_limit.map(|l| l.amount.into()),
+            loss_limit_rounds: pending.loss_limit.map(|l| l.rounds),
+            max_round_deposit: pending.max_round_deposit.map(f64::from),
+            from_round: pending.from_round,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LimitsSuccessResponse {
+    pub max_stake: Option<f64>,
+    pub max_round_stake: Option<f64>,
+    pub loss_limit: Option<f64>,
+    pub loss_limit_rounds: Option<u32>,
+    pub max_round_deposit: Option<f64>,
+    pub cool_off_until: Option<i64>,
+    /// Relaxed limits, waiting for their round.
+    pub pending: Option<PendingLimitsResponse>,
+}
+
+impl From<BetLimits> for LimitsSuccessResponse {
+    fn from(limits: BetLimits) -> Self {
+        Self {
+            max_stake: limits.max_stake.map(f64::from),
+            max_round_stake: limits.max_round_stake.map(f64::from),
+            loss_limit: limits.loss_limit.map(|l| l.amount.into()),
+            loss_limit_rounds: limits.loss_limit.map(|l| l.rounds),
+            max_round_deposit: limits.max_round_deposit.map(f64::from),
+            cool_off_until: limits.cool_off_until,
+            pending: limits.pending.map(PendingLimitsResponse::from),
+        }
+    }
+}
+
+/// Omitted limits are removed.
+#[derive(Deserialize)]
+pub struct SetLimitsRequest {
+    pub max_stake: Option<f64>,
+    pub max_round_stake: Option<f64>,
+    pub loss_limit: Option<f64>,
+    /// Required with `loss_limit`.
+    pub loss_limit_rounds: Option<u32>,
+    pub max_round_deposit: Option<f64>,
+}
+
+impl TryFrom<SetLimitsRequest> for BetLimits {
+    type Error = anyhow::Error;
+
+    fn try_from(req: SetLimitsRequest) -> Result<Self> {
+        let amount =
+            |value: Option<f64>| value.map(|v| Amount::new_with_casting(v, None)).transpose();
+        let loss_limit = amount(req.loss_limit)?.map(|amount| LossLimit {
+            amount,
+            rounds: req.loss_limit_rounds.unwrap_or_default(),
+        });
+
+        Ok(BetLimits {
+            max_stake: amount(req.max_stake)?,
+            max_round_stake: amount(req.max_round_stake)?,
+            loss_limit,
+            max_round_deposit: amount(req.max_round_deposit)?,
+            cool_off_until: None,
+            pending: None,
+        })
+    }
+}
+
+pub async fn limits(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+) -> Result<Json<LimitsSuccessResponse>, FailureResponse> {
+    debug!("Perform limits operation");
+    let limits = state.limit_service().limits(current.simulation.id())?;
+
+    Ok(LimitsSuccessResponse::from(limits).into())
+}
+
+pub async fn set_limits(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+    Json(req): Json<SetLimitsRequest>,
+) -> Result<Json<LimitsSuccessResponse>, FailureResponse> {
+    debug!("Perform set limits operation");
+    let limits = state
+        .limit_service()
+        .set_limits(current.simulation.id(), req.try_into()?)?;
+
+    Ok(LimitsSuccessResponse::from(limits).into())
+}
+
+#[derive(Deserialize)]
+pub struct CoolOffRequest {
+    pub secs: i64,
+}
+
+pub async fn cool_off(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+    Json(req): Json<CoolOffRequest>,
+) -> Result<Json<LimitsSuccessResponse>, FailureResponse> {
+    debug!("Perform cool off operation");
+    let limits = state
+        .limit_service()
+        .cool_off(current.simulation.id(), req.secs)?;
+
+    Ok(LimitsSuccessResponse::from(limits).into())
+}
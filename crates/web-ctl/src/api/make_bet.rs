@@ -1,14 +1,14 @@
 use anyhow::Result;
 use application::usecase::MakeBet;
 use axum::Json;
-use axum::extract::{ConnectInfo, State};
-use domain::entity::{Game, Team};
-use domain::value_object::{Amount, Coefficient, Event, Id, MIN_BALANCE_AMOUNT};
+use axum::extract::State;
+use domain::entity::Game;
+use domain::value_object::{Amount, Coefficient, Event, Id, LimitKind, LimitWarning, OddsFormat};
 use serde::{Deserialize, Serialize};
-use std::net::SocketAddr;
 use std::sync::Arc;
 use tracing::{debug, info};
 
+use crate::auth::CurrentSimulation;
 use crate::error::FailureResponse;
 use crate::state::AppState;
 
@@ -16,61 +16,102 @@ use crate::state::AppState;
 pub struct CalculateCoefficientsSuccessResponse {
     pub events: Vec<Event>,
     pub coefficients: Vec<Coefficient>,
+    pub odds_format: OddsFormat,
+    /// `coefficients` in the user's odds format.
+    pub odds: Vec<String>,
+    /// Largest stake the book still takes on each of `events`.
+    pub max_stakes: Vec<f64>,
 }
 
 #[derive(Deserialize)]
 pub struct CalculateCoefficientsRequest {
     pub game_id: Id<Game>,
-    pub home_team_id: Id<Team>,
-    pub guest_team_id: Id<Team>,
 }
 
 pub async fn calculate_coefficients(
     State(state): State<Arc<AppState>>,
-    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    current: CurrentSimulation,
     Json(req): Json<CalculateCoefficientsRequest>,
 ) -> Result<Json<CalculateCoefficientsSuccessResponse>, FailureResponse> {
     debug!("Perform calculate coefficients operation");
-    let simulation = state.simulation(addr.ip())?;
-    let game = Game::new(
-        req.game_id,
-        simulation.id(),
-        req.home_team_id,
-        req.guest_team_id,
-        simulation.round(),
-    );
-    info!("Game selected");
+    let simulation = current.simulation;
     let bet_service = state.bet_service();
-    let (events, coefficients) = bet_service
-        .calculate_coefficients(&game)?
+    let offers = bet_service.calculate_coefficients(&simulation, req.game_id)?;
+    info!("Coefficients calculated");
+    let max_stakes = bet_service
+        .max_stakes(&simulation, req.game_id, &offers)?
         .into_iter()
-        .unzip();
+        .map(f64::from)
+        .collect();
+    let (events, coefficients): (Vec<_>, Vec<_>) = offers.into_iter().unzip();
+    let odds_format = current.user.odds_format();
+    let odds = coefficients
+        .iter()
+        .map(|c| odds_format.render(*c))
+        .collect();
 
     Ok(CalculateCoefficientsSuccessResponse {
         events,
         coefficients,
+        odds_format,
+        odds,
+        max_stakes,
     }
     .into())
 }
 
 #[derive(Deserialize)]
 pub struct MakeBetRequest {
-    pub game: Game,
+    pub game_id: Id<Game>,
     pub event: Event,
     pub coefficient: Coefficient,
     pub value: f64,
 }
 
+#[derive(Serialize)]
+pub struct LimitWarningResponse {
+    pub kind: LimitKind,
+    pub used: f64,
+    pub max: f64,
+}
+
+impl From<LimitWarning> for LimitWarningResponse {
+    fn from(warning: LimitWarning) -> Self {
+        Self {
+            kind: warning.kind,
+            used: warning.used.into(),
+            max: warning.max.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct MakeBetSuccessResponse {
+    /// Limits of the simulation the bet brought close.
+    pub warnings: Vec<LimitWarningResponse>,
+}
+
 pub async fn make_bet(
     State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
     Json(req): Json<MakeBetRequest>,
-) -> Result<(), FailureResponse> {
+) -> Result<Json<MakeBetSuccessResponse>, FailureResponse> {
     debug!("Perform make bet operation");
-    let amount = Amount::new_with_casting(req.value, Some(MIN_BALANCE_AMOUNT))?;
+    let simulation = current.simulation;
+    let amount = Amount::new_with_casting(req.value, None)?;
     debug!("Bet amount parsed");
-    info!("Bet made");
     let bet_service = state.bet_service();
-    bet_service.make_bet(&req.game, amount, req.event, req.coefficient)?;
+    let warnings = bet_service.make_bet(
+        simulation.id(),
+        req.game_id,
+        amount,
+        req.event,
+        req.coefficient,
+    )?;
+    info!(warnings = warnings.len(), "Bet made");
 
-    Ok(())
+    Ok(MakeBetSuccessResponse {
+        warnings: warnings.into_iter().map(Into::into).collect(),
+    }
+    .into())
 }
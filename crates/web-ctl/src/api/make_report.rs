@@ -1,5 +1,5 @@
 use anyhow::Result;
-use application::usecase::MakeReport;
+use application::usecase::{MakeReport, ManageDeposits};
 use axum::Json;
 use axum::extract::State;
 use domain::value_object::BetStatistics;
@@ -7,6 +7,7 @@ use serde::Serialize;
 use std::sync::Arc;
 use tracing::debug;
 
+use crate::auth::CurrentSimulation;
 use crate::error::FailureResponse;
 use crate::state::AppState;
 
@@ -17,10 +18,12 @@ pub struct MakeReportSuccessResponse {
 
 pub async fn make_report(
     State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
 ) -> Result<Json<MakeReportSuccessResponse>, FailureResponse> {
     debug!("Perform make report operation");
-    let bet_service = state.bet_service();
-    let stat = bet_service.make_report(state.setup_config().balance);
+    let simulation_id = current.simulation.id();
+    let put_in = state.deposit_service().summary(simulation_id)?.put_in;
+    let stat = state.bet_service().make_report(simulation_id, put_in)?;
 
     Ok(MakeReportSuccessResponse { stat }.into())
 }
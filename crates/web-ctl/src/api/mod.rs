@@ -1,6 +1,21 @@
+pub mod achievements;
+pub mod advice;
+pub mod archives;
+pub mod auth;
+pub mod autoplay;
 pub mod balance;
+pub mod bets;
+pub mod book;
+pub mod calibration;
 pub mod create_round;
+pub mod deposits;
+pub mod leaderboard;
+pub mod limits;
 pub mod make_bet;
 pub mod make_report;
+pub mod odds;
+pub mod preferences;
 pub mod randomize_round;
+pub mod simulations;
 pub mod start;
+pub mod transfer;
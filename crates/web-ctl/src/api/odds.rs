@@ -0,0 +1,89 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::{Query, State};
+use domain::entity::OddsSnapshot;
+use domain::value_object::{Coefficient, Event, OddsFormat};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::auth::CurrentSimulation;
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::service::OddsHistory;
+use application::usecase::ShowOddsHistory;
+
+#[derive(Deserialize)]
+pub struct OddsParams {
+    pub game_id: Uuid,
+}
+
+#[derive(Serialize)]
+pub struct OddsSnapshotResponse {
+    pub coefficient: Coefficient,
+    /// `coefficient` in the user's odds format.
+    pub odds: String,
+    pub created_at: i64,
+}
+
+impl OddsSnapshotResponse {
+    fn new(snapshot: &OddsSnapshot, odds_format: OddsFormat) -> Self {
+        Self {
+            coefficient: snapshot.coefficient(),
+            odds: odds_format.render(snapshot.coefficient()),
+            created_at: snapshot.created_at(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct OddsHistoryResponse {
+    pub event: Event,
+    pub opening: Coefficient,
+    pub closing: Coefficient,
+    pub snapshots: Vec<OddsSnapshotResponse>,
+}
+
+impl OddsHistoryResponse {
+    fn new(history: OddsHistory, odds_format: OddsFormat) -> Self {
+        Self {
+            event: history.event,
+            opening: history.opening(),
+            closing: history.closing(),
+            snapshots: history
+                .snapshots
+                .iter()
+                .map(|s| OddsSnapshotResponse::new(s, odds_format))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct OddsSuccessResponse {
+    pub odds_format: OddsFormat,
+    pub events: Vec<OddsHistoryResponse>,
+}
+
+/// Every price offered on a game of the simulation, for charting how it moved.
+pub async fn odds(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+    Query(params): Query<OddsParams>,
+) -> Result<Json<OddsSuccessResponse>, FailureResponse> {
+    debug!("Perform odds operation");
+    let odds_format = current.user.odds_format();
+    let history = state
+        .bet_service()
+        .odds_history(current.simulation.id(), params.game_id.into())?;
+
+    Ok(OddsSuccessResponse {
+        odds_format,
+        events: history
+            .into_iter()
+            .map(|h| OddsHistoryResponse::new(h, odds_format))
+            .collect(),
+    }
+    .into())
+}
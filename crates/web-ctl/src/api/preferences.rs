@@ -0,0 +1,58 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::State;
+use domain::value_object::OddsFormat;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::auth::CurrentUser;
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::error::AppError;
+use application::usecase::ManagePreferences;
+
+#[derive(Serialize)]
+pub struct PreferencesSuccessResponse {
+    pub odds_format: OddsFormat,
+    pub title: String,
+}
+
+impl From<OddsFormat> for PreferencesSuccessResponse {
+    fn from(odds_format: OddsFormat) -> Self {
+        Self {
+            odds_format,
+            title: odds_format.to_string(),
+        }
+    }
+}
+
+pub async fn preferences(
+    current: CurrentUser,
+) -> Result<Json<PreferencesSuccessResponse>, FailureResponse> {
+    debug!("Perform preferences operation");
+
+    Ok(PreferencesSuccessResponse::from(current.user.odds_format()).into())
+}
+
+#[derive(Deserialize)]
+pub struct SetPreferencesRequest {
+    pub odds_format: String,
+}
+
+pub async fn set_preferences(
+    State(state): State<Arc<AppState>>,
+    current: CurrentUser,
+    Json(req): Json<SetPreferencesRequest>,
+) -> Result<Json<PreferencesSuccessResponse>, FailureResponse> {
+    debug!("Perform set preferences operation");
+    let odds_format = req
+        .odds_format
+        .parse::<OddsFormat>()
+        .map_err(|_| anyhow::Error::from(AppError::UnsupportedOddsFormat))?;
+    let user = state
+        .preference_service()
+        .set_odds_format(current.user.id(), odds_format)?;
+
+    Ok(PreferencesSuccessResponse::from(user.odds_format()).into())
+}
@@ -1,29 +1,32 @@
 use anyhow::Result;
 use axum::Json;
-use axum::extract::{ConnectInfo, State};
+use axum::extract::State;
 use serde::Serialize;
-use std::net::SocketAddr;
 use std::sync::Arc;
 use tracing::{debug, info};
 
+use crate::api::achievements::AchievementResponse;
+use crate::auth::CurrentSimulation;
 use crate::error::FailureResponse;
 use crate::state::AppState;
 use application::service::DisplayedGameStat;
-use application::usecase::{CalculateBet, RandomizeRound};
+use application::usecase::{CalculateBet, RandomizeRound, TrackAchievements};
 
 #[derive(Serialize)]
 pub struct RandomizeRoundSuccessResponse {
     pub round: u32,
     pub games_stat: Vec<DisplayedGameStat>,
     pub profit: f64,
+    /// Unlocked by the settled bets.
+    pub achievements: Vec<AchievementResponse>,
 }
 
 pub async fn randomize_round(
     State(state): State<Arc<AppState>>,
-    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    current: CurrentSimulation,
 ) -> Result<Json<RandomizeRoundSuccessResponse>, FailureResponse> {
     debug!("Perform randomize round operation");
-    let simulation = state.simulation(addr.ip())?;
+    let simulation = current.simulation;
     let game_service = state.game_service();
     let bet_service = state.bet_service();
     let games_stat = game_service.randomize_round(&simulation)?;
@@ -31,13 +34,21 @@ pub async fn randomize_round(
     let round = simulation.round();
     info!(round, "Show game results");
 
-    let profit = f64::from(bet_service.calculate_bets()?);
+    let profit = f64::from(bet_service.calculate_bets(simulation.id())?);
     info!(profit, "Credit to balance");
 
+    let achievements = state
+        .achievement_service()
+        .evaluate(simulation.id())?
+        .into_iter()
+        .map(AchievementResponse::from)
+        .collect();
+
     Ok(RandomizeRoundSuccessResponse {
         round,
         games_stat,
         profit,
+        achievements,
     }
     .into())
 }
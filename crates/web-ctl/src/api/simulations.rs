@@ -0,0 +1,98 @@
+use anyhow::Result;
+use axum::Json;
+use axum::extract::State;
+use domain::entity::Simulation;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+use crate::auth::{CurrentSimulation, CurrentUser};
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::usecase::ManageSimulations;
+
+#[derive(Serialize)]
+pub struct SimulationResponse {
+    pub id: String,
+    pub name: String,
+    pub round: u32,
+    pub balance: f64,
+}
+
+impl From<Simulation> for SimulationResponse {
+    fn from(simulation: Simulation) -> Self {
+        Self {
+            id: simulation.id().value().to_string(),
+            name: simulation.name().to_string(),
+            round: simulation.round(),
+            balance: simulation.balance().into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ListSimulationsSuccessResponse {
+    pub simulations: Vec<SimulationResponse>,
+}
+
+pub async fn list_simulations(
+    State(state): State<Arc<AppState>>,
+    current: CurrentUser,
+) -> Result<Json<ListSimulationsSuccessResponse>, FailureResponse> {
+    debug!("Perform list simulations operation");
+    let simulations = state
+        .simulation_service()
+        .list(current.user.id())?
+        .into_iter()
+        .map(SimulationResponse::from)
+        .collect();
+
+    Ok(ListSimulationsSuccessResponse { simulations }.into())
+}
+
+#[derive(Deserialize)]
+pub struct NameRequest {
+    pub name: String,
+}
+
+pub async fn create_simulation(
+    State(state): State<Arc<AppState>>,
+    current: CurrentUser,
+    Json(req): Json<NameRequest>,
+) -> Result<Json<SimulationResponse>, FailureResponse> {
+    debug!("Perform create simulation operation");
+    let simulation = state
+        .simulation_service()
+        .create(current.user.id(), &req.name)?;
+    info!(name = simulation.name(), "Simulation created");
+
+    Ok(SimulationResponse::from(simulation).into())
+}
+
+pub async fn rename_simulation(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+    Json(req): Json<NameRequest>,
+) -> Result<Json<SimulationResponse>, FailureResponse> {
+    debug!("Perform rename simulation operation");
+    let simulation =
+        state
+            .simulation_service()
+            .rename(current.user.id(), current.simulation.id(), &req.name)?;
+    info!(name = simulation.name(), "Simulation renamed");
+
+    Ok(SimulationResponse::from(simulation).into())
+}
+
+pub async fn delete_simulation(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+) -> Result<(), FailureResponse> {
+    debug!("Perform delete simulation operation");
+    state
+        .simulation_service()
+        .delete(current.user.id(), current.simulation.id())?;
+    info!(name = current.simulation.name(), "Simulation deleted");
+
+    Ok(())
+}
@@ -1,10 +1,10 @@
 use axum::Json;
-use axum::extract::{ConnectInfo, State};
+use axum::extract::State;
 use serde::Serialize;
-use std::net::SocketAddr;
 use std::sync::Arc;
 use tracing::{debug, info};
 
+use crate::auth::{CurrentSimulation, CurrentUser};
 use crate::error::FailureResponse;
 use crate::state::AppState;
 use application::usecase::Start;
@@ -17,10 +17,10 @@ pub struct StartSuccessResponse {
 
 pub async fn start(
     State(state): State<Arc<AppState>>,
-    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    current: CurrentUser,
 ) -> Result<Json<StartSuccessResponse>, FailureResponse> {
     let sim_service = state.simulation_service();
-    let simulation = sim_service.start(addr.ip())?;
+    let simulation = sim_service.start(current.user.id())?;
     let balance = f64::from(simulation.balance());
     let id = simulation.id().value().to_string();
     info!(balance, "Simulation started successfully");
@@ -30,12 +30,11 @@ pub async fn start(
 
 pub async fn restart(
     State(state): State<Arc<AppState>>,
-    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    current: CurrentSimulation,
 ) -> Result<Json<StartSuccessResponse>, FailureResponse> {
     debug!("Perform restart operation");
     let sim_service = state.simulation_service();
-    let simulation = state.simulation(addr.ip())?;
-    sim_service.restart(simulation.id())?;
+    let simulation = sim_service.restart(current.simulation.id())?;
     let balance = f64::from(simulation.balance());
     let id = simulation.id().value().to_string();
     info!(balance, "Restart successful");
@@ -0,0 +1,108 @@
+use anyhow::Result;
+use axum::Json;
+use axum::body::Bytes;
+use axum::extract::{Query, State};
+use axum::http::{
+    HeaderMap, HeaderValue,
+    header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+};
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+use crate::api::simulations::SimulationResponse;
+use crate::auth::{CurrentSimulation, CurrentUser};
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::service::{DocumentFormat, SimulationDocument};
+use application::usecase::Transfer;
+
+#[derive(Deserialize)]
+pub struct ExportQuery {
+    pub format: Option<String>,
+}
+
+pub async fn export_simulation(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+    Query(query): Query<ExportQuery>,
+) -> Result<Response, FailureResponse> {
+    debug!("Perform export operation");
+    let format = query
+        .format
+        .as_deref()
+        .unwrap_or("json")
+        .parse::<DocumentFormat>()
+        .map_err(anyhow::Error::from)?;
+    let document = state.transfer_service().export(&current.simulation)?;
+    let bytes = document.encode(format)?;
+    let disposition = format!(
+        "attachment; filename=\"{}.{}\"",
+        current.simulation.id().value(),
+        format.extension()
+    );
+    info!(name = current.simulation.name(), "Simulation exported");
+
+    Ok((
+        [
+            (
+                CONTENT_TYPE,
+                HeaderValue::from_static(format.content_type()),
+            ),
+            (
+                CONTENT_DISPOSITION,
+                HeaderValue::from_str(&disposition).map_err(anyhow::Error::from)?,
+            ),
+        ],
+        bytes,
+    )
+        .into_response())
+}
+
+#[derive(Deserialize)]
+pub struct ImportQuery {
+    pub name: Option<String>,
+}
+
+/// MessagePack is expected for `application/msgpack` bodies, JSON otherwise.
+pub async fn import_simulation(
+    State(state): State<Arc<AppState>>,
+    current: CurrentUser,
+    Query(query): Query<ImportQuery>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<SimulationResponse>, FailureResponse> {
+    debug!("Perform import operation");
+    let format = match headers.get(CONTENT_TYPE).and_then(|v| v.to_str().ok()) {
+        Some("application/msgpack" | "application/x-msgpack") => DocumentFormat::MessagePack,
+        _ => DocumentFormat::Json,
+    };
+    let document = SimulationDocument::decode(&body, format)?;
+    let simulation = state
+        .transfer_service()
+        .import(current.user.id(), document, query.name)?;
+    info!(name = simulation.name(), "Simulation imported");
+
+    Ok(SimulationResponse::from(simulation).into())
+}
+
+#[derive(Deserialize)]
+pub struct ForkRequest {
+    pub round: u32,
+    pub name: String,
+}
+
+pub async fn fork_simulation(
+    State(state): State<Arc<AppState>>,
+    current: CurrentSimulation,
+    Json(req): Json<ForkRequest>,
+) -> Result<Json<SimulationResponse>, FailureResponse> {
+    debug!("Perform fork operation");
+    let simulation = state
+        .transfer_service()
+        .fork(&current.simulation, req.round, &req.name)?;
+    info!(name = simulation.name(), "Simulation forked");
+
+    Ok(SimulationResponse::from(simulation).into())
+}
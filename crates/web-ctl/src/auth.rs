@@ -0,0 +1,62 @@
+use anyhow::Result;
+use axum::extract::{FromRequestParts, Path};
+use axum::http::{header::AUTHORIZATION, request::Parts};
+use std::sync::Arc;
+
+use crate::error::FailureResponse;
+use crate::state::AppState;
+use application::error::{AppError, Entity};
+use application::usecase::{Authenticate, ManageSimulations};
+use domain::entity::{Simulation, User};
+use uuid::Uuid;
+
+/// User owning the bearer token of the request.
+pub struct CurrentUser {
+    pub user: User,
+    pub token: String,
+}
+
+impl FromRequestParts<Arc<AppState>> for CurrentUser {
+    type Rejection = FailureResponse;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or_else(|| anyhow::Error::from(AppError::Unauthorized))?
+            .to_string();
+        let user = state.auth_service().authenticate(&token)?;
+
+        Ok(Self { user, token })
+    }
+}
+
+/// Simulation from the `{simulation_id}` path segment, owned by the current user.
+pub struct CurrentSimulation {
+    pub user: User,
+    pub simulation: Simulation,
+}
+
+impl FromRequestParts<Arc<AppState>> for CurrentSimulation {
+    type Rejection = FailureResponse;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let CurrentUser { user, .. } = CurrentUser::from_request_parts(parts, state).await?;
+        let Path(simulation_id) = Path::<Uuid>::from_request_parts(parts, state)
+            .await
+            .map_err(|_| anyhow::Error::from(AppError::NotFound(Entity::Simulation)))?;
+        let simulation = state
+            .simulation_service()
+            .simulation(user.id(), simulation_id.into())?;
+
+        Ok(Self { user, simulation })
+    }
+}
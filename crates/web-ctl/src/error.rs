@@ -1,10 +1,11 @@
+use application::error::{ErrorKind, classify};
 use axum::Json;
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
 };
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
+use tracing::{error, warn};
 use uuid::Uuid;
 
 #[derive(Serialize, Deserialize)]
@@ -12,11 +13,13 @@ pub struct FailureResponse {
     error_message: String,
     error_id: String,
     error_code: String,
+    #[serde(skip)]
+    status_code: StatusCode,
 }
 
 impl IntoResponse for FailureResponse {
     fn into_response(self) -> Response {
-        let status_code = StatusCode::from_str(&self.error_code).unwrap_or(StatusCode::BAD_REQUEST);
+        let status_code = self.status_code;
         let mut resp = Json::from(self).into_response();
         *resp.status_mut() = status_code;
 
@@ -26,10 +29,29 @@ impl IntoResponse for FailureResponse {
 
 impl From<anyhow::Error> for FailureResponse {
     fn from(value: anyhow::Error) -> Self {
+        let (kind, error_code) = classify(&value);
+        let status_code = match kind {
+            ErrorKind::Validation => StatusCode::BAD_REQUEST,
+            ErrorKind::NotFound => StatusCode::NOT_FOUND,
+            ErrorKind::Conflict => StatusCode::CONFLICT,
+            ErrorKind::InsufficientFunds | ErrorKind::Unprocessable => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+            ErrorKind::Unauthorized => StatusCode::UNAUTHORIZED,
+            ErrorKind::Forbidden => StatusCode::FORBIDDEN,
+            ErrorKind::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        let error_id = Uuid::new_v4().to_string();
+        if kind == ErrorKind::Internal {
+            error!(error_id, error_code, "{:#}", value);
+        } else {
+            warn!(error_id, error_code, "{:#}", value);
+        }
         FailureResponse {
             error_message: value.to_string(),
-            error_id: Uuid::new_v4().to_string(),
-            error_code: StatusCode::INTERNAL_SERVER_ERROR.as_str().to_string(),
+            error_id,
+            error_code: error_code.to_string(),
+            status_code,
         }
     }
 }
@@ -1,14 +1,14 @@
 mod api;
+mod auth;
 mod error;
 mod state;
 
 use anyhow::Result;
 use axum::{
     Router,
-    routing::{get, post},
+    routing::{get, patch, post},
 };
 use dotenv::dotenv;
-use std::net::SocketAddr;
 use std::path::Path;
 use std::{env, sync::Arc};
 use tokio::net::TcpListener;
@@ -16,12 +16,27 @@ use tower_http::cors::{Any, CorsLayer};
 use tracing::info;
 
 use crate::api::{
+    achievements::achievements,
+    advice::advise,
+    archives::{archive, list_archives},
+    auth::{login, logout, register},
+    autoplay::{autoplay, autoplay_runs},
     balance::balance,
+    bets::bets,
+    book::book_report,
+    calibration::calibration,
     create_round::create_round,
+    deposits::{deposit, deposits},
+    leaderboard::leaderboard,
+    limits::{cool_off, limits, set_limits},
     make_bet::{calculate_coefficients, make_bet},
     make_report::make_report,
+    odds::odds,
+    preferences::{preferences, set_preferences},
     randomize_round::randomize_round,
+    simulations::{create_simulation, delete_simulation, list_simulations, rename_simulation},
     start::{restart, start},
+    transfer::{export_simulation, fork_simulation, import_simulation},
 };
 use infrastructure::{config, logger};
 use state::AppState;
@@ -40,14 +55,64 @@ pub async fn start_server() -> Result<()> {
         .allow_headers(Any);
 
     let api_router = Router::new()
+        .route("/register", post(register))
+        .route("/login", post(login))
+        .route("/logout", post(logout))
         .route("/start", get(start))
-        .route("/restart", post(restart))
-        .route("/create_round", post(create_round))
-        .route("/randomize_round", post(randomize_round))
-        .route("/calculate_coefficients", post(calculate_coefficients))
-        .route("/make_bet", post(make_bet))
-        .route("/make_report", get(make_report))
-        .route("/balance", get(balance))
+        .route(
+            "/simulations",
+            get(list_simulations).post(create_simulation),
+        )
+        .route("/simulations/import", post(import_simulation))
+        .route(
+            "/simulations/{simulation_id}",
+            patch(rename_simulation).delete(delete_simulation),
+        )
+        .route("/simulations/{simulation_id}/restart", post(restart))
+        .route(
+            "/simulations/{simulation_id}/create_round",
+            post(create_round),
+        )
+        .route(
+            "/simulations/{simulation_id}/randomize_round",
+            post(randomize_round),
+        )
+        .route(
+            "/simulations/{simulation_id}/calculate_coefficients",
+            post(calculate_coefficients),
+        )
+        .route("/simulations/{simulation_id}/advise", post(advise))
+        .route("/simulations/{simulation_id}/make_bet", post(make_bet))
+        .route("/simulations/{simulation_id}/make_report", get(make_report))
+        .route("/simulations/{simulation_id}/bets", get(bets))
+        .route("/simulations/{simulation_id}/odds", get(odds))
+        .route("/simulations/{simulation_id}/balance", get(balance))
+        .route(
+            "/simulations/{simulation_id}/export",
+            get(export_simulation),
+        )
+        .route("/simulations/{simulation_id}/fork", post(fork_simulation))
+        .route(
+            "/simulations/{simulation_id}/achievements",
+            get(achievements),
+        )
+        .route(
+            "/simulations/{simulation_id}/limits",
+            get(limits).put(set_limits),
+        )
+        .route("/simulations/{simulation_id}/cool_off", post(cool_off))
+        .route("/simulations/{simulation_id}/deposit", post(deposit))
+        .route("/simulations/{simulation_id}/deposits", get(deposits))
+        .route(
+            "/simulations/{simulation_id}/autoplay",
+            get(autoplay_runs).post(autoplay),
+        )
+        .route("/archives", get(list_archives))
+        .route("/archives/{simulation_id}", get(archive))
+        .route("/leaderboard", get(leaderboard))
+        .route("/preferences", get(preferences).put(set_preferences))
+        .route("/admin/book", get(book_report))
+        .route("/admin/calibration", get(calibration))
         .layer(cors)
         .with_state(app_state);
 
@@ -55,11 +120,7 @@ pub async fn start_server() -> Result<()> {
 
     let addr = env::var("ROM_BET_SOCK")?;
     let listener = TcpListener::bind(addr).await?;
-    axum::serve(
-        listener,
-        app.into_make_service_with_connect_info::<SocketAddr>(),
-    )
-    .await?;
+    axum::serve(listener, app).await?;
 
     Ok(())
 }
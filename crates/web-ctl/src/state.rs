@@ -1,20 +1,84 @@
-use std::net::IpAddr;
-
 use anyhow::Result;
-use application::usecase::Start;
-use domain::entity::Simulation;
 use tracing::debug;
 
-use application::config::{AppConfig, SetupConfig};
-use application::service::{BetService, GameService, SimulationService};
-use db::init_pool;
-use db::repository::{BetRepo, GameRepo, GameStatRepo, SimulationRepo, TeamRepo};
+use application::config::{AppConfig, CoefficientConfig, ExposureConfig};
+use application::service::{
+    AchievementService, AuthService, AutoplayService, BetService, BookService, CalibrationService,
+    ConfigSnapshot, DepositService, GameService, LeaderboardService, LimitService,
+    PreferenceService, SimulationService, TransferService,
+};
+use infrastructure::storage::{
+    AchievementRepo, AutoplayRunRepo, BetLimitRepo, BetRepo, DepositRepo, ForecastRepo, GameRepo,
+    GameStatRepo, OddsSnapshotRepo, SessionRepo, SimulationRepo, Storage, TeamRepo, UnitOfWork,
+    UserRepo,
+};
+
+type AppAutoplayService = AutoplayService<
+    SimulationService<GameRepo, TeamRepo, GameStatRepo, SimulationRepo, UnitOfWork>,
+    BetService<
+        BetRepo,
+        GameRepo,
+        GameStatRepo,
+        SimulationRepo,
+        BetLimitRepo,
+        UnitOfWork,
+        OddsSnapshotRepo,
+    >,
+    BetService<
+        BetRepo,
+        GameRepo,
+        GameStatRepo,
+        SimulationRepo,
+        BetLimitRepo,
+        UnitOfWork,
+        OddsSnapshotRepo,
+    >,
+    BetService<
+        BetRepo,
+        GameRepo,
+        GameStatRepo,
+        SimulationRepo,
+        BetLimitRepo,
+        UnitOfWork,
+        OddsSnapshotRepo,
+    >,
+    GameService<GameRepo, GameStatRepo, TeamRepo, ForecastRepo>,
+    GameRepo,
+    BetRepo,
+    SimulationRepo,
+    AutoplayRunRepo,
+>;
 
 pub struct AppState {
-    sim_service: SimulationService<GameRepo, TeamRepo, GameStatRepo, SimulationRepo>,
-    game_service: GameService<GameRepo, GameStatRepo, TeamRepo>,
-    bet_service: BetService<BetRepo, GameRepo, GameStatRepo, SimulationRepo>,
-    setup_config: SetupConfig,
+    sim_service: SimulationService<GameRepo, TeamRepo, GameStatRepo, SimulationRepo, UnitOfWork>,
+    game_service: GameService<GameRepo, GameStatRepo, TeamRepo, ForecastRepo>,
+    bet_service: BetService<
+        BetRepo,
+        GameRepo,
+        GameStatRepo,
+        SimulationRepo,
+        BetLimitRepo,
+        UnitOfWork,
+        OddsSnapshotRepo,
+    >,
+    auth_service: AuthService<UserRepo, SessionRepo>,
+    transfer_service: TransferService<
+        TeamRepo,
+        SimulationRepo,
+        GameRepo,
+        GameStatRepo,
+        BetRepo,
+        DepositRepo,
+        UnitOfWork,
+    >,
+    leaderboard_service: LeaderboardService<SimulationRepo, UserRepo, BetRepo, DepositRepo>,
+    achievement_service: AchievementService<AchievementRepo, SimulationRepo, BetRepo>,
+    limit_service: LimitService<BetLimitRepo, SimulationRepo>,
+    deposit_service: DepositService<DepositRepo, SimulationRepo, BetLimitRepo, UnitOfWork>,
+    autoplay_service: AppAutoplayService,
+    preference_service: PreferenceService<UserRepo>,
+    book_service: BookService<SimulationRepo, UserRepo, GameRepo, BetRepo>,
+    calibration_service: CalibrationService<ForecastRepo, GameStatRepo>,
 }
 
 impl TryFrom<AppConfig> for AppState {
@@ -24,45 +88,152 @@ impl TryFrom<AppConfig> for AppState {
         let setup_config = config.setup;
         let coefficient_config = config.coefficient;
 
-        let pool = init_pool();
-        let game_repo = GameRepo::new(pool.clone());
-        let bet_repo = BetRepo::new(pool.clone());
-        let game_stat_repo = GameStatRepo::new(pool.clone());
-        let simulation_repo = SimulationRepo::new(pool.clone());
-        let bet_service = BetService::new(
-            bet_repo,
+        let storage = Storage::from(config.storage);
+        let bet_service = new_bet_service(&storage, coefficient_config.clone(), config.exposure);
+        debug!("Bet service started");
+
+        let game_repo = storage.game_repo();
+        let game_stat_repo = storage.game_stat_repo();
+        let team_repo = storage.team_repo();
+        let game_service = GameService::new(
             game_repo,
             game_stat_repo,
-            simulation_repo,
+            team_repo,
+            storage.forecast_repo(),
             coefficient_config.clone(),
+            config.engine,
         );
-        debug!("Bet service started");
-
-        let game_repo = GameRepo::new(pool.clone());
-        let game_stat_repo = GameStatRepo::new(pool.clone());
-        let team_repo = TeamRepo::new(pool.clone());
-        let game_service =
-            GameService::new(game_repo, game_stat_repo, team_repo, coefficient_config);
         debug!("Game service started");
 
-        let team_repo = TeamRepo::new(pool.clone());
-        let game_repo = GameRepo::new(pool.clone());
-        let simulation_repo = SimulationRepo::new(pool.clone());
-        let game_stat_repo = GameStatRepo::new(pool.clone());
+        let team_repo = storage.team_repo();
+        let game_repo = storage.game_repo();
+        let simulation_repo = storage.simulation_repo();
+        let game_stat_repo = storage.game_stat_repo();
         let sim_service = SimulationService::new(
             game_repo,
             team_repo,
             game_stat_repo,
             simulation_repo,
+            storage.unit_of_work(),
             setup_config,
         );
         debug!("Simulation service started");
 
+        let auth_service =
+            AuthService::new(storage.user_repo(), storage.session_repo(), config.auth);
+        debug!("Auth service started");
+
+        let transfer_service = TransferService::new(
+            storage.team_repo(),
+            storage.simulation_repo(),
+            storage.game_repo(),
+            storage.game_stat_repo(),
+            storage.bet_repo(),
+            storage.deposit_repo(),
+            storage.unit_of_work(),
+            ConfigSnapshot {
+                setup: setup_config,
+                coefficient: coefficient_config.clone(),
+            },
+        );
+        debug!("Transfer service started");
+
+        let leaderboard_service = LeaderboardService::new(
+            storage.simulation_repo(),
+            storage.user_repo(),
+            storage.bet_repo(),
+            storage.deposit_repo(),
+            config.leaderboard,
+        );
+        debug!("Leaderboard service started");
+
+        let achievement_service = AchievementService::new(
+            storage.achievement_repo(),
+            storage.simulation_repo(),
+            storage.bet_repo(),
+            setup_config,
+        );
+        debug!("Achievement service started");
+
+        let limit_service = LimitService::new(
+            storage.bet_limit_repo(),
+            storage.simulation_repo(),
+            config.limit,
+        );
+        debug!("Limit service started");
+
+        let deposit_service = DepositService::new(
+            storage.deposit_repo(),
+            storage.simulation_repo(),
+            storage.bet_limit_repo(),
+            storage.unit_of_work(),
+            config.deposit,
+            setup_config,
+        );
+        debug!("Deposit service started");
+
+        let autoplay_service = AutoplayService::new(
+            SimulationService::new(
+                storage.game_repo(),
+                storage.team_repo(),
+                storage.game_stat_repo(),
+                storage.simulation_repo(),
+                storage.unit_of_work(),
+                setup_config,
+            ),
+            new_bet_service(&storage, coefficient_config.clone(), config.exposure),
+            new_bet_service(&storage, coefficient_config.clone(), config.exposure),
+            new_bet_service(&storage, coefficient_config.clone(), config.exposure),
+            GameService::new(
+                storage.game_repo(),
+                storage.game_stat_repo(),
+                storage.team_repo(),
+                storage.forecast_repo(),
+                coefficient_config.clone(),
+                config.engine,
+            ),
+            storage.game_repo(),
+            storage.bet_repo(),
+            storage.simulation_repo(),
+            storage.autoplay_run_repo(),
+            config.autoplay,
+        );
+        debug!("Autoplay service started");
+
+        let preference_service = PreferenceService::new(storage.user_repo());
+        debug!("Preference service started");
+
+        let book_service = BookService::new(
+            storage.simulation_repo(),
+            storage.user_repo(),
+            storage.game_repo(),
+            storage.bet_repo(),
+            coefficient_config.margin,
+            config.admin.clone(),
+        );
+        debug!("Book service started");
+
+        let calibration_service = CalibrationService::new(
+            storage.forecast_repo(),
+            storage.game_stat_repo(),
+            config.admin,
+        );
+        debug!("Calibration service started");
+
         Ok(Self {
             game_service,
             bet_service,
             sim_service,
-            setup_config,
+            auth_service,
+            transfer_service,
+            leaderboard_service,
+            achievement_service,
+            limit_service,
+            deposit_service,
+            autoplay_service,
+            preference_service,
+            book_service,
+            calibration_service,
         })
     }
 }
@@ -70,23 +241,107 @@ impl TryFrom<AppConfig> for AppState {
 impl AppState {
     pub fn simulation_service(
         &self,
-    ) -> &SimulationService<GameRepo, TeamRepo, GameStatRepo, SimulationRepo> {
+    ) -> &SimulationService<GameRepo, TeamRepo, GameStatRepo, SimulationRepo, UnitOfWork> {
         &self.sim_service
     }
 
-    pub fn game_service(&self) -> &GameService<GameRepo, GameStatRepo, TeamRepo> {
+    pub fn game_service(&self) -> &GameService<GameRepo, GameStatRepo, TeamRepo, ForecastRepo> {
         &self.game_service
     }
 
-    pub fn bet_service(&self) -> &BetService<BetRepo, GameRepo, GameStatRepo, SimulationRepo> {
+    pub fn bet_service(
+        &self,
+    ) -> &BetService<
+        BetRepo,
+        GameRepo,
+        GameStatRepo,
+        SimulationRepo,
+        BetLimitRepo,
+        UnitOfWork,
+        OddsSnapshotRepo,
+    > {
         &self.bet_service
     }
 
-    pub fn simulation(&self, ip: IpAddr) -> Result<Simulation> {
-        self.sim_service.start(ip)
+    pub fn auth_service(&self) -> &AuthService<UserRepo, SessionRepo> {
+        &self.auth_service
+    }
+
+    pub fn transfer_service(
+        &self,
+    ) -> &TransferService<
+        TeamRepo,
+        SimulationRepo,
+        GameRepo,
+        GameStatRepo,
+        BetRepo,
+        DepositRepo,
+        UnitOfWork,
+    > {
+        &self.transfer_service
+    }
+
+    pub fn leaderboard_service(
+        &self,
+    ) -> &LeaderboardService<SimulationRepo, UserRepo, BetRepo, DepositRepo> {
+        &self.leaderboard_service
+    }
+
+    pub fn achievement_service(
+        &self,
+    ) -> &AchievementService<AchievementRepo, SimulationRepo, BetRepo> {
+        &self.achievement_service
+    }
+
+    pub fn limit_service(&self) -> &LimitService<BetLimitRepo, SimulationRepo> {
+        &self.limit_service
+    }
+
+    pub fn deposit_service(
+        &self,
+    ) -> &DepositService<DepositRepo, SimulationRepo, BetLimitRepo, UnitOfWork> {
+        &self.deposit_service
+    }
+
+    pub fn autoplay_service(&self) -> &AppAutoplayService {
+        &self.autoplay_service
     }
 
-    pub fn setup_config(&self) -> &SetupConfig {
-        &self.setup_config
+    pub fn preference_service(&self) -> &PreferenceService<UserRepo> {
+        &self.preference_service
     }
+
+    pub fn book_service(&self) -> &BookService<SimulationRepo, UserRepo, GameRepo, BetRepo> {
+        &self.book_service
+    }
+
+    pub fn calibration_service(&self) -> &CalibrationService<ForecastRepo, GameStatRepo> {
+        &self.calibration_service
+    }
+}
+
+fn new_bet_service(
+    storage: &Storage,
+    config: CoefficientConfig,
+    exposure: ExposureConfig,
+) -> BetService<
+    BetRepo,
+    GameRepo,
+    GameStatRepo,
+    SimulationRepo,
+    BetLimitRepo,
+    UnitOfWork,
+    OddsSnapshotRepo,
+> {
+    BetService::new(
+        storage.bet_repo(),
+        storage.game_repo(),
+        storage.game_stat_repo(),
+        storage.simulation_repo(),
+        storage.bet_limit_repo(),
+        storage.unit_of_work(),
+        storage.odds_snapshot_repo(),
+        config,
+        exposure,
+    )
 }
@@ -5,9 +5,11 @@ import { CreateRoundButton } from './components/CreateRoundButton';
 import { Balance } from './components/Balance';
 import { ReportModal } from './components/ReportModal';
 import { GameResults } from './components/GameResults';
+import { LoginForm } from './components/LoginForm';
+import { SimulationSelector } from './components/SimulationSelector';
 import { useApi } from './hooks/useApi';
-import { apiClient } from './api/client';
-import { Balance as BalanceType, BetStatistics, DisplayedGame, DisplayedGameStat } from './types';
+import { apiClient, getToken } from './api/client';
+import { Balance as BalanceType, BetStatistics, DisplayedGame, DisplayedGameStat, SimulationInfo } from './types';
 import './App.css';
 
 function App() {
@@ -21,12 +23,37 @@ function App() {
   const [currentRound, setCurrentRound] = useState(0);
   const [lastRound, setLastRound] = useState(0);
   const [createState, setCurrentState] = useState(true);
+  const [loggedIn, setLoggedIn] = useState(getToken() !== null);
   
   const { loading, error, callApi, clearError } = useApi();
 
   useEffect(() => {
-    startSimulation();
-  }, []);
+    if (loggedIn) {
+      startSimulation();
+    }
+  }, [loggedIn]);
+
+  const handleLogin = async (login: string, password: string, register: boolean) => {
+    await callApi(async () => {
+      if (register) {
+        await apiClient.register(login, password);
+      }
+      await apiClient.login(login, password);
+      setLoggedIn(true);
+    });
+  };
+
+  const handleLogout = async () => {
+    await callApi(apiClient.logout);
+    setGames([]);
+    setCurrentGames([]);
+    setGameStats([]);
+    setCurrentRound(0);
+    setLastRound(0);
+    setId("");
+    setCurrentState(true);
+    setLoggedIn(false);
+  };
 
   const startSimulation = async () => {
     await callApi(async () => {
@@ -37,13 +64,13 @@ function App() {
   };
 
   const loadBalance = async () => {
-    const balanceData = await apiClient.getBalance();
+    const balanceData = await apiClient.getBalance(id);
     setBalance(balanceData);
   };
 
   const handleRestart = async () => {
     await callApi(async () => {
-      const resp = await apiClient.restart();
+      const resp = await apiClient.restart(id);
       setBalance({ amount: resp.balance });
       setGames([]);
       setCurrentGames([]);
@@ -55,12 +82,23 @@ function App() {
     });
   };
 
+  const handleSelectSimulation = (simulation: SimulationInfo) => {
+    setId(simulation.id);
+    setBalance({ amount: simulation.balance });
+    setGames([]);
+    setCurrentGames([]);
+    setGameStats([]);
+    setCurrentRound(simulation.round);
+    setLastRound(simulation.round);
+    setCurrentState(true);
+  };
+
   const handleCreateRound = async () => {
     if (!createState) {
       alert("Раунд ещё не закончен. Он будет рандомизирован");
     }
     await callApi(async () => {
-      const roundData = await apiClient.createRound();
+      const roundData = await apiClient.createRound(id);
       if (roundData === null) {
         await handleRandomizeRound();
       } else {
@@ -77,7 +115,7 @@ function App() {
       alert("Раунд уже закончен. Будет создан новый раунд");
     } 
     await callApi(async () => {
-      const result = await apiClient.randomizeRound();
+      const result = await apiClient.randomizeRound(id);
       if (result === null) {
         await handleCreateRound();
       } else {
@@ -91,20 +129,34 @@ function App() {
   };
 
   const handleShowReport = async () => {
-    const reportData = await callApi(apiClient.makeReport);
+    const reportData = await callApi(() => apiClient.makeReport(id));
     if (reportData) {
       setReport(reportData);
       setShowReport(true);
     }
   };
 
+  if (!loggedIn) {
+    return (
+      <div className="app">
+        {error && (
+          <div className="error">
+            {error}
+            <button onClick={clearError}>×</button>
+          </div>
+        )}
+        <LoginForm onSubmit={handleLogin} />
+      </div>
+    );
+  }
+
   if (loading && games.length === 0) {
     return <div className="loading">Загрузка...</div>;
   }
 
   return (
     <div className="app">
-      <Header onRestart={handleRestart} onShowReport={handleShowReport} />
+      <Header onRestart={handleRestart} onShowReport={handleShowReport} onLogout={handleLogout} />
       
       {error && (
         <div className="error">
@@ -115,7 +167,8 @@ function App() {
 
       <div className="main-content">
         <div className="sidebar">
-          <Balance balance={balance} />
+          <SimulationSelector currentId={id} onSelect={handleSelectSimulation} />
+          <Balance balance={balance} simulationId={id} onDeposited={loadBalance} />
           <CreateRoundButton onRoundCreated={handleCreateRound} />
           {currentGames.length > 0 && currentRound > lastRound && (
             <BetForm games={currentGames} round={currentRound} simulation_id={id} onBetPlaced={loadBalance} />
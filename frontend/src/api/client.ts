@@ -1,8 +1,14 @@
 import axios from 'axios';
 import { 
   Bet, DisplayedGameStat, DisplayedGame, Balance, BetStatistics, 
-  StartResponse, RandomizeRoundResponse, CreateRoundResponse,
-  CalculateCoefficientsRequest, Event,
+  StartResponse, LoginResponse, SimulationInfo, ArchiveInfo, ArchiveReport,
+  LeaderboardPage, LeaderboardParams, Achievement,
+  BetLimits, BetLimitsRequest, LimitWarning,
+  DepositsResponse, DepositResponse,
+  AutoplayRunsResponse, AutoplayResponse, StrategyKind,
+  RandomizeRoundResponse, CreateRoundResponse,
+  CalculateCoefficientsRequest, CoefficientOffer, Event, AdviseResponse,
+  BetsResponse, OddsFormat, Preferences, BookReport, CalibrationReport, OddsHistoryResponse,
   DataHelpers,
   EventTotal,
   Winner
@@ -12,6 +18,18 @@ const api = axios.create({
   baseURL: '/api',
 });
 
+const TOKEN_KEY = 'rombet_token';
+
+export const getToken = (): string | null => localStorage.getItem(TOKEN_KEY);
+
+api.interceptors.request.use(config => {
+  const token = getToken();
+  if (token) {
+    config.headers.Authorization = `Bearer ${token}`;
+  }
+  return config;
+});
+
 const serializeEvent = (event: Event): any => {
   if (typeof event === 'string') {
         return { WDL: event };
@@ -25,6 +43,32 @@ const serializeEvent = (event: Event): any => {
   }
 };
 
+const parseEvent = (eventData: any): Event => {
+  let event: Event = 'W1';
+
+  if (eventData && typeof eventData === 'object') {
+    if ('WDL' in eventData) {
+      event = eventData.WDL as Winner;
+    } else if ('T' in eventData) {
+      const totalData = eventData.T;
+      if (totalData && typeof totalData === 'object') {
+        event = {
+          total: Number(totalData.total) || 0,
+          ordering: [-1, 0, 1].includes(Number(totalData.ordering)) 
+            ? Number(totalData.ordering) as -1 | 0 | 1 
+            : 0
+        } as EventTotal;
+      }
+    }
+  } else if (typeof eventData === 'string') {
+    if (eventData === 'W1' || eventData === 'X' || eventData === 'W2') {
+      event = eventData as Winner;
+    }
+  }
+
+  return event;
+};
+
 const transformDisplayedGame = (game: any): DisplayedGame => ({
   id: DataHelpers.parseId(game.id),
   home_team: {
@@ -46,6 +90,9 @@ const transformDisplayedGameStat = (stat: any): DisplayedGameStat => ({
   guest_score: stat.guest_score || stat.guest_team_total || 0
 });
 
+const simulationPath = (simulationId: string, action: string): string =>
+  `/simulations/${simulationId}/${action}`;
+
 const createIdObject = (id: string): any => {
   return { 
     value: id,
@@ -53,52 +100,138 @@ const createIdObject = (id: string): any => {
   };
 };
 
+const transformStatistics = (stat: any): BetStatistics => {
+  return {
+    min_coefficient_lose: stat.min_coefficient_lose ? 
+      (typeof stat.min_coefficient_lose === 'object' && 'value' in stat.min_coefficient_lose 
+        ? Number(stat.min_coefficient_lose.value / 100) 
+        : Number(stat.min_coefficient_lose / 100)
+      ) : undefined,
+    
+    start_balance: stat.start_balance ? 
+      (typeof stat.start_balance === 'object' && 'value' in stat.start_balance 
+        ? Number(stat.start_balance.value / 100) 
+        : Number(stat.start_balance / 100)
+      ) : 0,
+    
+    total_bets: stat.total_bets ? Number(stat.total_bets) : 0,
+    total_amount: stat.total_amount ? 
+      (typeof stat.total_amount === 'object' && 'value' in stat.total_amount 
+        ? Number(stat.total_amount.value) 
+        : Number(stat.total_amount)
+      ) : 0,
+    
+    rounds_played: stat.rounds_played ? Number(stat.rounds_played) : 0,
+    profit: stat.profit ? Number(stat.profit) : 0,
+    clv: stat.clv ?? undefined
+  };
+};
+
 export const apiClient = {
+  register: async (login: string, password: string): Promise<void> => {
+    await api.post('/register', { login, password });
+  },
+
+  login: async (login: string, password: string): Promise<LoginResponse> => {
+    const response = await api.post<LoginResponse>('/login', { login, password });
+    localStorage.setItem(TOKEN_KEY, response.data.token);
+    return response.data;
+  },
+
+  logout: async (): Promise<void> => {
+    try {
+      await api.post('/logout');
+    } finally {
+      localStorage.removeItem(TOKEN_KEY);
+    }
+  },
+
   start: async (): Promise<StartResponse> => {
     const response = await api.get<StartResponse>('/start');
     return response.data;
   },
 
-  restart: async (): Promise<StartResponse> => {
-    const response = await api.post<StartResponse>('/restart');
+  listSimulations: async (): Promise<SimulationInfo[]> => {
+    const response = await api.get<{ simulations: SimulationInfo[] }>('/simulations');
+    return response.data.simulations;
+  },
+
+  createSimulation: async (name: string): Promise<SimulationInfo> => {
+    const response = await api.post<SimulationInfo>('/simulations', { name });
+    return response.data;
+  },
+
+  renameSimulation: async (simulationId: string, name: string): Promise<SimulationInfo> => {
+    const response = await api.patch<SimulationInfo>(`/simulations/${simulationId}`, { name });
+    return response.data;
+  },
+
+  deleteSimulation: async (simulationId: string): Promise<void> => {
+    await api.delete(`/simulations/${simulationId}`);
+  },
+
+  exportSimulation: async (simulationId: string, format: 'json' | 'msgpack' = 'json'): Promise<Blob> => {
+    const response = await api.get<Blob>(simulationPath(simulationId, 'export'), {
+      params: { format },
+      responseType: 'blob'
+    });
+    return response.data;
+  },
+
+  importSimulation: async (file: File, name?: string): Promise<SimulationInfo> => {
+    const contentType = file.name.endsWith('.msgpack') ? 'application/msgpack' : 'application/json';
+    const response = await api.post<SimulationInfo>('/simulations/import', file, {
+      params: name ? { name } : {},
+      headers: { 'Content-Type': contentType }
+    });
     return response.data;
   },
 
-  createRound: async (): Promise<CreateRoundResponse | null> => {
+  forkSimulation: async (simulationId: string, round: number, name: string): Promise<SimulationInfo> => {
+    const response = await api.post<SimulationInfo>(simulationPath(simulationId, 'fork'), { round, name });
+    return response.data;
+  },
+
+  restart: async (simulationId: string): Promise<StartResponse> => {
+    const response = await api.post<StartResponse>(simulationPath(simulationId, 'restart'));
+    return response.data;
+  },
+
+  createRound: async (simulationId: string): Promise<CreateRoundResponse | null> => {
     try {
-      const response = await api.post<any>('/create_round');
+      const response = await api.post<any>(simulationPath(simulationId, 'create_round'));
       return {
         round: response.data.round,
-        games: response.data.games.map(transformDisplayedGame)
+        games: response.data.games.map(transformDisplayedGame),
+        achievements: response.data.achievements
       };
     } catch {
       return null;
     }
   },
 
-  randomizeRound: async (): Promise<RandomizeRoundResponse | null> => {
+  randomizeRound: async (simulationId: string): Promise<RandomizeRoundResponse | null> => {
     try {
-      const response = await api.post<any>('/randomize_round');
+      const response = await api.post<any>(simulationPath(simulationId, 'randomize_round'));
       return {
         round: response.data.round,
         games_stat: response.data.games_stat.map(transformDisplayedGameStat),
-        profit: response.data.profit
+        profit: response.data.profit,
+        achievements: response.data.achievements
       };
     } catch {
       return null;
     }
   },
 
-  calculateCoefficients: async (request: CalculateCoefficientsRequest): Promise<Array<{event: Event; coefficient: number}>> => {
+  calculateCoefficients: async (simulationId: string, request: CalculateCoefficientsRequest): Promise<CoefficientOffer[]> => {
     try {
       const serverRequest = {
-        game_id: { value: request.game_id },
-        home_team_id: { value: request.home_team_id },
-        guest_team_id: { value: request.guest_team_id }
+        game_id: { value: request.game_id }
       };
 
       const response = await api.post<any>(
-        '/calculate_coefficients', 
+        simulationPath(simulationId, 'calculate_coefficients'),
         serverRequest
       );
       
@@ -106,42 +239,29 @@ export const apiClient = {
       
       const events = response.data.events || [];
       const coefficients = response.data.coefficients || [];
+      const odds = response.data.odds || [];
+      const maxStakes = response.data.max_stakes || [];
       
       const minLength = Math.min(events.length, coefficients.length);
       
-      const transformedOffers: Array<{event: Event; coefficient: number}> = [];
+      const transformedOffers: CoefficientOffer[] = [];
       
       for (let i = 0; i < minLength; i++) {
         const eventData = events[i];
         const coefficientValue = coefficients[i];
         
-        let event: Event = 'W1';
-        
-        if (eventData && typeof eventData === 'object') {
-          if ('WDL' in eventData) {
-            event = eventData.WDL as Winner;
-          } else if ('T' in eventData) {
-            const totalData = eventData.T;
-            if (totalData && typeof totalData === 'object') {
-              event = {
-                total: Number(totalData.total) || 0,
-                ordering: [-1, 0, 1].includes(Number(totalData.ordering)) 
-                  ? Number(totalData.ordering) as -1 | 0 | 1 
-                  : 0
-              } as EventTotal;
-            }
-          }
-        } else if (typeof eventData === 'string') {
-          if (eventData === 'W1' || eventData === 'X' || eventData === 'W2') {
-            event = eventData as Winner;
-          }
-        }
+        const event = parseEvent(eventData);
         
         const coefficient = typeof coefficientValue === 'number' 
           ? coefficientValue / 100
           : 1.0;
         
-        transformedOffers.push({ event, coefficient });
+        transformedOffers.push({
+          event,
+          coefficient,
+          odds: odds[i] ?? coefficient.toFixed(2),
+          max_stake: maxStakes[i],
+        });
       }
       
       console.log('Transformed offers:', transformedOffers);
@@ -153,57 +273,153 @@ export const apiClient = {
     }
   },
   
-  makeBet: async (bet: Bet): Promise<void> => {
-        const serverBet = {
-      game: {
-        id: createIdObject(bet.game.id),
-        simulation_id: createIdObject(bet.game.simulation_id || ''),         home_team_id: createIdObject(bet.game.home_team_id),
-        guest_team_id: createIdObject(bet.game.guest_team_id),
-        round: bet.game.round || 0
-      },
+  makeBet: async (bet: Bet): Promise<LimitWarning[]> => {
+    const serverBet = {
+      game_id: createIdObject(bet.game.id),
       event: serializeEvent(bet.event),
-      coefficient: (bet.coefficient * 100) | 0,
+      coefficient: Math.round(bet.coefficient * 100),
       value: bet.value
     };
   
     console.log('Sending bet to server:', JSON.stringify(serverBet, null, 2));
     
-    await api.post('/make_bet', serverBet);
+    const response = await api.post<{ warnings: LimitWarning[] }>(simulationPath(bet.game.simulation_id, 'make_bet'), serverBet);
+    return response.data.warnings;
   },
 
-  makeReport: async (): Promise<BetStatistics> => {
-    const response = await api.get<{ stat: any }>('/make_report');
+  makeReport: async (simulationId: string): Promise<BetStatistics> => {
+    const response = await api.get<{ stat: any }>(simulationPath(simulationId, 'make_report'));
     const stat = response.data.stat;
     
     console.log('Raw report data:', stat);
     
+    return transformStatistics(stat);
+  },
+
+  listArchives: async (): Promise<ArchiveInfo[]> => {
+    const response = await api.get<{ archives: ArchiveInfo[] }>('/archives');
+    return response.data.archives;
+  },
+
+  getArchive: async (simulationId: string): Promise<ArchiveReport> => {
+    const response = await api.get<{ archive: ArchiveInfo; stat: any }>(`/archives/${simulationId}`);
     return {
-      min_coefficient_lose: stat.min_coefficient_lose ? 
-        (typeof stat.min_coefficient_lose === 'object' && 'value' in stat.min_coefficient_lose 
-          ? Number(stat.min_coefficient_lose.value / 100) 
-          : Number(stat.min_coefficient_lose / 100)
-        ) : undefined,
-      
-      start_balance: stat.start_balance ? 
-        (typeof stat.start_balance === 'object' && 'value' in stat.start_balance 
-          ? Number(stat.start_balance.value / 100) 
-          : Number(stat.start_balance / 100)
-        ) : 0,
-      
-      total_bets: stat.total_bets ? Number(stat.total_bets) : 0,
-      total_amount: stat.total_amount ? 
-        (typeof stat.total_amount === 'object' && 'value' in stat.total_amount 
-          ? Number(stat.total_amount.value) 
-          : Number(stat.total_amount)
-        ) : 0,
-      
-      rounds_played: stat.rounds_played ? Number(stat.rounds_played) : 0,
-      profit: stat.profit ? Number(stat.profit) : 0
+      archive: response.data.archive,
+      stat: transformStatistics(response.data.stat)
     };
   },
 
-  getBalance: async (): Promise<Balance> => {
-    const response = await api.get<{ amount: number }>('/balance');
+  getLeaderboard: async (params: LeaderboardParams = {}): Promise<LeaderboardPage> => {
+    const response = await api.get<LeaderboardPage>('/leaderboard', { params });
+    return response.data;
+  },
+
+  getAchievements: async (simulationId: string): Promise<Achievement[]> => {
+    const response = await api.get<{ achievements: Achievement[] }>(simulationPath(simulationId, 'achievements'));
+    return response.data.achievements;
+  },
+
+  getLimits: async (simulationId: string): Promise<BetLimits> => {
+    const response = await api.get<BetLimits>(simulationPath(simulationId, 'limits'));
+    return response.data;
+  },
+
+  setLimits: async (simulationId: string, limits: BetLimitsRequest): Promise<BetLimits> => {
+    const response = await api.put<BetLimits>(simulationPath(simulationId, 'limits'), limits);
+    return response.data;
+  },
+
+  coolOff: async (simulationId: string, secs: number): Promise<BetLimits> => {
+    const response = await api.post<BetLimits>(simulationPath(simulationId, 'cool_off'), { secs });
+    return response.data;
+  },
+
+  getDeposits: async (simulationId: string): Promise<DepositsResponse> => {
+    const response = await api.get<DepositsResponse>(simulationPath(simulationId, 'deposits'));
+    return response.data;
+  },
+
+  deposit: async (simulationId: string, value: number): Promise<DepositResponse> => {
+    const response = await api.post<DepositResponse>(simulationPath(simulationId, 'deposit'), { value });
+    return response.data;
+  },
+
+  getAutoplayRuns: async (simulationId: string): Promise<AutoplayRunsResponse> => {
+    const response = await api.get<AutoplayRunsResponse>(simulationPath(simulationId, 'autoplay'));
+    return response.data;
+  },
+
+  autoplay: async (simulationId: string, strategy: StrategyKind, rounds: number): Promise<AutoplayResponse> => {
+    const response = await api.post<AutoplayResponse>(simulationPath(simulationId, 'autoplay'), { strategy, rounds });
+    return response.data;
+  },
+
+  advise: async (simulationId: string, request: CalculateCoefficientsRequest): Promise<AdviseResponse> => {
+    const response = await api.post<any>(simulationPath(simulationId, 'advise'), {
+      game_id: createIdObject(request.game_id)
+    });
+    return {
+      balance: response.data.balance,
+      advice: response.data.advice.map((a: any) => ({
+        ...a,
+        event: parseEvent(a.event),
+        coefficient: a.coefficient / 100
+      }))
+    };
+  },
+
+  getBets: async (simulationId: string): Promise<BetsResponse> => {
+    const response = await api.get<any>(simulationPath(simulationId, 'bets'));
+    return {
+      odds_format: response.data.odds_format,
+      bets: response.data.bets.map((b: any) => ({
+        ...b,
+        game_id: b.game_id.value,
+        event: parseEvent(b.event),
+        coefficient: b.coefficient / 100,
+        closing_coefficient: b.closing_coefficient != null ? b.closing_coefficient / 100 : undefined,
+        clv: b.clv ?? undefined
+      }))
+    };
+  },
+
+  getOddsHistory: async (simulationId: string, gameId: string): Promise<OddsHistoryResponse> => {
+    const response = await api.get<any>(simulationPath(simulationId, 'odds'), {
+      params: { game_id: gameId }
+    });
+    return {
+      odds_format: response.data.odds_format,
+      events: response.data.events.map((h: any) => ({
+        event: parseEvent(h.event),
+        opening: h.opening / 100,
+        closing: h.closing / 100,
+        snapshots: h.snapshots.map((s: any) => ({ ...s, coefficient: s.coefficient / 100 }))
+      }))
+    };
+  },
+
+  getPreferences: async (): Promise<Preferences> => {
+    const response = await api.get<Preferences>('/preferences');
+    return response.data;
+  },
+
+  setOddsFormat: async (odds_format: OddsFormat): Promise<Preferences> => {
+    const response = await api.put<Preferences>('/preferences', { odds_format });
+    return response.data;
+  },
+
+  getBookReport: async (): Promise<BookReport> => {
+    const response = await api.get<BookReport>('/admin/book');
+    return response.data;
+  },
+
+  getCalibration: async (): Promise<CalibrationReport> => {
+    const response = await api.get<CalibrationReport>('/admin/calibration');
+    return response.data;
+  },
+
+  getBalance: async (simulationId: string): Promise<Balance> => {
+    const response = await api.get<{ amount: number }>(simulationPath(simulationId, 'balance'));
     return { amount: response.data.amount };
   }
 };
\ No newline at end of file
@@ -1,18 +1,71 @@
-import React from 'react';
-import { Balance as BalanceType } from '../types';
+import React, { useEffect, useState } from 'react';
+import { Balance as BalanceType, DepositSummary } from '../types';
+import { apiClient } from '../api/client';
+import { useApi } from '../hooks/useApi';
 import '../App.css'
 
 interface BalanceProps {
   balance: BalanceType;
+  simulationId: string;
+  onDeposited: () => void;
 }
 
-export const Balance: React.FC<BalanceProps> = ({ balance }) => {
+export const Balance: React.FC<BalanceProps> = ({ balance, simulationId, onDeposited }) => {
+  const [amount, setAmount] = useState('');
+  const [summary, setSummary] = useState<DepositSummary | null>(null);
+
+  const { loading, error, callApi, clearError } = useApi();
+
+  useEffect(() => {
+    if (!simulationId) return;
+    apiClient.getDeposits(simulationId)
+      .then(resp => setSummary(resp.summary))
+      .catch(() => setSummary(null));
+  }, [simulationId, balance.amount]);
+
+  const handleDeposit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!amount) return;
+
+    await callApi(async () => {
+      const resp = await apiClient.deposit(simulationId, parseFloat(amount) || 0);
+      setSummary(resp.summary);
+      setAmount('');
+      onDeposited();
+    });
+  };
+
   return (
     <div className="balance">
       <h2>Баланс</h2>
       <div className="balance-amount">
         {balance.amount.toFixed(2)} руб.
       </div>
+      {summary && summary.deposited > 0 && (
+        <div className="balance-net">
+          Внесено {summary.put_in.toFixed(2)} руб., итог {summary.net.toFixed(2)} руб.
+        </div>
+      )}
+      <form className="deposit-form" onSubmit={handleDeposit}>
+        <input
+          type="number"
+          value={amount}
+          onChange={(e) => setAmount(e.target.value)}
+          min="1"
+          step="0.01"
+          placeholder="Сумма"
+          required
+        />
+        <button type="submit" disabled={loading} className="btn btn-primary">
+          Пополнить
+        </button>
+      </form>
+      {error && (
+        <div className="error">
+          {error}
+          <button onClick={clearError}>×</button>
+        </div>
+      )}
     </div>
   );
-};
\ No newline at end of file
+};
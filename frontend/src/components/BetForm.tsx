@@ -1,9 +1,16 @@
 import React, { useState, useEffect } from 'react';
-import { Bet, Event, DisplayedGame, EventHelpers } from '../types';
+import { Bet, Event, DisplayedGame, EventHelpers, LimitKind, LimitWarning } from '../types';
 import { apiClient } from '../api/client';
 import { useApi } from '../hooks/useApi';
 import '../App.css';
 
+const LIMIT_NAMES: Record<LimitKind, string> = {
+  stake: 'ставку',
+  round_stake: 'ставки за тур',
+  loss: 'проигрыш',
+  round_deposit: 'пополнения за тур'
+};
+
 interface BetFormProps {
   games: DisplayedGame[];
   round: number;
@@ -16,6 +23,7 @@ export const BetForm: React.FC<BetFormProps> = ({ games, round, simulation_id, o
   const [selectedEventIndex, setSelectedEventIndex] = useState<number>(0);
   const [coefficient, setCoefficient] = useState(1.0);
   const [amount, setAmount] = useState('');
+  const [warnings, setWarnings] = useState<LimitWarning[]>([]);
   const [availableEvents, setAvailableEvents] = useState<{event: Event, coefficient: number}[]>([]);
 
   const { loading, error, callApi, clearError } = useApi();
@@ -31,7 +39,7 @@ export const BetForm: React.FC<BetFormProps> = ({ games, round, simulation_id, o
 
     await callApi(async () => {
       try {
-        const coefficients = await apiClient.calculateCoefficients({
+        const coefficients = await apiClient.calculateCoefficients(simulation_id, {
           game_id: selectedGame.id,
           home_team_id: selectedGame.home_team.id,
           guest_team_id: selectedGame.guest_team.id
@@ -100,7 +108,7 @@ export const BetForm: React.FC<BetFormProps> = ({ games, round, simulation_id, o
     };
 
     await callApi(async () => {
-      await apiClient.makeBet(bet);
+      setWarnings(await apiClient.makeBet(bet));
       onBetPlaced();
       setAmount('');
     });
@@ -181,6 +189,12 @@ export const BetForm: React.FC<BetFormProps> = ({ games, round, simulation_id, o
         </button>
       </form>
 
+      {warnings.map(w => (
+        <div key={w.kind} className="warning">
+          Лимит на {LIMIT_NAMES[w.kind]} почти исчерпан: {w.used} из {w.max}
+        </div>
+      ))}
+
       {error && (
         <div className="error">
           {error}
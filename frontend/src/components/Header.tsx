@@ -4,9 +4,10 @@ import '../App.css'
 interface HeaderProps {
   onRestart: () => void;
   onShowReport: () => void;
+  onLogout: () => void;
 }
 
-export const Header: React.FC<HeaderProps> = ({ onRestart, onShowReport }) => {
+export const Header: React.FC<HeaderProps> = ({ onRestart, onShowReport, onLogout }) => {
   return (
     <header className="header">
       <div className="header-content">
@@ -18,6 +19,9 @@ export const Header: React.FC<HeaderProps> = ({ onRestart, onShowReport }) => {
           <button onClick={onRestart} className="btn btn-warning">
             Перезапуск системы
           </button>
+          <button onClick={onLogout} className="btn btn-secondary">
+            Выйти
+          </button>
         </div>
       </div>
     </header>
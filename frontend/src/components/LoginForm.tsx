@@ -0,0 +1,49 @@
+import React, { useState } from 'react';
+import '../App.css';
+
+interface LoginFormProps {
+  onSubmit: (login: string, password: string, register: boolean) => void;
+}
+
+export const LoginForm: React.FC<LoginFormProps> = ({ onSubmit }) => {
+  const [login, setLogin] = useState('');
+  const [password, setPassword] = useState('');
+  const [register, setRegister] = useState(false);
+
+  const handleSubmit = (e: React.FormEvent) => {
+    e.preventDefault();
+    onSubmit(login, password, register);
+  };
+
+  return (
+    <div className="bet-form">
+      <h2>{register ? 'Регистрация' : 'Вход'}</h2>
+      <form onSubmit={handleSubmit}>
+        <div className="form-group">
+          <label>Логин:</label>
+          <input
+            type="text"
+            value={login}
+            onChange={(e) => setLogin(e.target.value)}
+            required
+          />
+        </div>
+        <div className="form-group">
+          <label>Пароль:</label>
+          <input
+            type="password"
+            value={password}
+            onChange={(e) => setPassword(e.target.value)}
+            required
+          />
+        </div>
+        <button type="submit" className="btn btn-primary">
+          {register ? 'Зарегистрироваться' : 'Войти'}
+        </button>
+        <button type="button" className="btn btn-secondary" onClick={() => setRegister(!register)}>
+          {register ? 'Уже есть аккаунт' : 'Создать аккаунт'}
+        </button>
+      </form>
+    </div>
+  );
+};
@@ -70,6 +70,15 @@ export const ReportModal: React.FC<ReportModalProps> = ({ report, isOpen, onClos
                 <span>{formatNumber(report.min_coefficient_lose)}</span>
               </div>
             )}
+
+            {report.clv !== undefined && (
+              <div className="report-item">
+                <span>Средний CLV:</span>
+                <span className={report.clv >= 0 ? 'profit-positive' : 'profit-negative'}>
+                  {report.clv >= 0 ? '+' : ''}{formatNumber(report.clv * 100)}%
+                </span>
+              </div>
+            )}
           </div>
         ) : (
           <div>Загрузка отчета...</div>
@@ -0,0 +1,107 @@
+import React, { useState, useEffect } from 'react';
+import { apiClient } from '../api/client';
+import { useApi } from '../hooks/useApi';
+import { SimulationInfo } from '../types';
+import '../App.css';
+
+interface SimulationSelectorProps {
+  currentId: string;
+  onSelect: (simulation: SimulationInfo) => void;
+}
+
+export const SimulationSelector: React.FC<SimulationSelectorProps> = ({ currentId, onSelect }) => {
+  const [simulations, setSimulations] = useState<SimulationInfo[]>([]);
+  const [name, setName] = useState('');
+
+  const { loading, error, callApi, clearError } = useApi();
+
+  useEffect(() => {
+    if (currentId) {
+      loadSimulations();
+    }
+  }, [currentId]);
+
+  const loadSimulations = async () => {
+    const list = await callApi(apiClient.listSimulations);
+    if (list) {
+      setSimulations(list);
+    }
+  };
+
+  const handleSwitch = (id: string) => {
+    const simulation = simulations.find(s => s.id === id);
+    if (simulation) {
+      onSelect(simulation);
+    }
+  };
+
+  const handleCreate = async () => {
+    const simulation = await callApi(() => apiClient.createSimulation(name));
+    if (simulation) {
+      setName('');
+      onSelect(simulation);
+    }
+  };
+
+  const handleRename = async () => {
+    const simulation = await callApi(() => apiClient.renameSimulation(currentId, name));
+    if (simulation) {
+      setName('');
+      await loadSimulations();
+    }
+  };
+
+  const handleDelete = async () => {
+    if (!window.confirm('Удалить текущую симуляцию?')) {
+      return;
+    }
+    await callApi(async () => {
+      await apiClient.deleteSimulation(currentId);
+      const next = await apiClient.start();
+      const list = await apiClient.listSimulations();
+      setSimulations(list);
+      const simulation = list.find(s => s.id === next.id);
+      if (simulation) {
+        onSelect(simulation);
+      }
+    });
+  };
+
+  return (
+    <div className="bet-form">
+      <h3>Симуляции</h3>
+      {error && (
+        <div className="error">
+          {error}
+          <button onClick={clearError}>×</button>
+        </div>
+      )}
+      <div className="form-group">
+        <select value={currentId} onChange={(e) => handleSwitch(e.target.value)} disabled={loading}>
+          {simulations.map(simulation => (
+            <option key={simulation.id} value={simulation.id}>
+              {simulation.name} (тур {simulation.round})
+            </option>
+          ))}
+        </select>
+      </div>
+      <div className="form-group">
+        <input
+          type="text"
+          value={name}
+          placeholder="Название"
+          onChange={(e) => setName(e.target.value)}
+        />
+      </div>
+      <button onClick={handleCreate} disabled={loading || !name} className="btn btn-primary">
+        Создать
+      </button>
+      <button onClick={handleRename} disabled={loading || !name} className="btn btn-secondary">
+        Переименовать
+      </button>
+      <button onClick={handleDelete} disabled={loading} className="btn btn-warning">
+        Удалить
+      </button>
+    </div>
+  );
+};
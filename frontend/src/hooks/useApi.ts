@@ -12,7 +12,7 @@ export const useApi = () => {
       const result = await apiCall();
       return result;
     } catch (err: any) {
-      const errorMessage = err.response?.data?.message || err.message || 'Unknown error';
+      const errorMessage = err.response?.data?.error_message || err.message || 'Unknown error';
       setError(errorMessage);
       return null;
     } finally {
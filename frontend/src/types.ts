@@ -41,6 +41,177 @@ export interface BetStatistics {
   total_amount?: number;
   rounds_played?: number;
   profit?: number;
+  clv?: number;
+}
+
+export interface LoginResponse {
+  token: string;
+  expires_at: number;
+}
+
+export interface SimulationInfo {
+  id: string;
+  name: string;
+  round: number;
+  balance: number;
+}
+
+export interface ArchiveInfo extends SimulationInfo {
+  archived_at: number;
+}
+
+export interface ArchiveReport {
+  archive: ArchiveInfo;
+  stat: BetStatistics;
+}
+
+export type RankingMetric = 'balance' | 'roi' | 'profit';
+
+export interface LeaderboardEntry {
+  rank: number;
+  simulation_id: string;
+  name: string;
+  login: string;
+  round: number;
+  balance: number;
+  deposited: number;
+  staked: number;
+  profit: number;
+  roi: number;
+  bets: number;
+}
+
+export interface LeaderboardPage {
+  entries: LeaderboardEntry[];
+  page: number;
+  per_page: number;
+  total: number;
+}
+
+export type AchievementKind =
+  | 'first_win'
+  | 'long_shot'
+  | 'doubled_balance'
+  | 'survived_10'
+  | 'survived_50'
+  | 'survived_100'
+  | 'bankrupt';
+
+export interface Achievement {
+  kind: AchievementKind;
+  title: string;
+  round: number;
+  unlocked_at: number;
+}
+
+export type LimitKind = 'stake' | 'round_stake' | 'loss' | 'round_deposit';
+
+export interface LimitWarning {
+  kind: LimitKind;
+  used: number;
+  max: number;
+}
+
+export interface PendingLimits {
+  max_stake: number | null;
+  max_round_stake: number | null;
+  loss_limit: number | null;
+  loss_limit_rounds: number | null;
+  max_round_deposit: number | null;
+  from_round: number;
+}
+
+export interface BetLimits {
+  max_stake: number | null;
+  max_round_stake: number | null;
+  loss_limit: number | null;
+  loss_limit_rounds: number | null;
+  max_round_deposit: number | null;
+  cool_off_until: number | null;
+  pending: PendingLimits | null;
+}
+
+export interface Deposit {
+  amount: number;
+  round: number;
+  deposited_at: number;
+}
+
+export interface DepositSummary {
+  balance: number;
+  deposited: number;
+  put_in: number;
+  net: number;
+}
+
+export interface DepositsResponse {
+  deposits: Deposit[];
+  summary: DepositSummary;
+}
+
+export interface DepositResponse {
+  deposit: Deposit;
+  summary: DepositSummary;
+}
+
+export interface StakeAdvice {
+  event: Event;
+  coefficient: number;
+  implied_probability: number;
+  model_probability: number;
+  expected_value: number;
+  kelly_fraction: number;
+  kelly_stake: number;
+}
+
+export interface AdviseResponse {
+  balance: number;
+  advice: StakeAdvice[];
+}
+
+export type StrategyKind = 'flat' | 'martingale' | 'kelly' | 'value';
+
+export interface AutoplayRun {
+  strategy: StrategyKind;
+  title: string;
+  first_round: number;
+  rounds: number;
+  bets: number;
+  staked: number;
+  returned: number;
+  start_balance: number;
+  end_balance: number;
+  finished_at: number;
+}
+
+export interface StrategySummary {
+  strategy: StrategyKind;
+  title: string;
+  runs: number;
+  rounds: number;
+  bets: number;
+  staked: number;
+  returned: number;
+  profit: number;
+}
+
+export interface AutoplayRunsResponse {
+  runs: AutoplayRun[];
+  summaries: StrategySummary[];
+}
+
+export interface AutoplayResponse {
+  run: AutoplayRun;
+  achievements: Achievement[];
+}
+
+export type BetLimitsRequest = Partial<Omit<BetLimits, 'cool_off_until' | 'pending'>>;
+
+export interface LeaderboardParams {
+  metric?: RankingMetric;
+  rounds?: number;
+  page?: number;
+  per_page?: number;
 }
 
 export interface StartResponse {
@@ -52,16 +223,64 @@ export interface RandomizeRoundResponse {
   round: number;
   games_stat: DisplayedGameStat[];
   profit: number;
+  achievements: Achievement[];
 }
 
 export interface CreateRoundResponse {
   round: number;
   games: DisplayedGame[];
+  achievements: Achievement[];
 }
 
 export interface CoefficientOffer {
   event: Event;
   coefficient: number;
+  /** The coefficient in the user's odds format. */
+  odds: string;
+  /** Largest stake the book still takes on the event, if known. */
+  max_stake?: number;
+}
+
+export type OddsFormat = 'decimal' | 'fractional' | 'american' | 'hong_kong' | 'malay' | 'indonesian';
+
+export interface Preferences {
+  odds_format: OddsFormat;
+  title: string;
+}
+
+export interface BetRecord {
+  game_id: string;
+  event: Event;
+  coefficient: number;
+  odds: string;
+  amount: number;
+  is_won: boolean | null;
+  closing_coefficient?: number;
+  clv?: number;
+}
+
+export interface BetsResponse {
+  odds_format: OddsFormat;
+  bets: BetRecord[];
+}
+
+export interface OddsSnapshot {
+  coefficient: number;
+  odds: string;
+  created_at: number;
+}
+
+/** Every price offered on an event of a game, oldest first. */
+export interface EventOddsHistory {
+  event: Event;
+  opening: number;
+  closing: number;
+  snapshots: OddsSnapshot[];
+}
+
+export interface OddsHistoryResponse {
+  odds_format: OddsFormat;
+  events: EventOddsHistory[];
 }
 
 export interface CalculateCoefficientsResponse {
@@ -118,6 +337,67 @@ export const EventHelpers = {
   }
 };
 
+export interface MarketHold {
+  market: string;
+  bets: number;
+  turnover: number;
+  revenue: number;
+  hold: number;
+}
+
+export interface BookWinner {
+  simulation_id: string;
+  name: string;
+  login: string;
+  staked: number;
+  profit: number;
+}
+
+export interface OpenGame {
+  simulation_id: string;
+  game_id: string;
+  round: number;
+  bets: number;
+  staked: number;
+  liability: number;
+}
+
+/** House-side results of every simulation, admins only. */
+export interface BookReport {
+  simulations: number;
+  bets: number;
+  turnover: number;
+  settled_turnover: number;
+  revenue: number;
+  hold: number;
+  margin: number;
+  markets: MarketHold[];
+  winners: BookWinner[];
+  exposure: OpenGame[];
+}
+
+export interface ReliabilityBucket {
+  lower: number;
+  upper: number;
+  forecasts: number;
+  mean_probability: number;
+  frequency: number;
+}
+
+export interface MarketCalibration {
+  market: string;
+  games: number;
+  brier: number;
+  log_loss: number;
+  buckets: ReliabilityBucket[];
+}
+
+/** Pre-game model probabilities against the results of every played game, admins only. */
+export interface CalibrationReport {
+  games: number;
+  markets: MarketCalibration[];
+}
+
 export const DataHelpers = {
   parseId: (id: any): string => {
     if (typeof id === 'string') return id;